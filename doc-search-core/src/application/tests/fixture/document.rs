@@ -23,7 +23,7 @@ pub fn build_large_document() -> LargeDocument {
 pub fn build_short_document() -> LargeDocument {
     LargeDocumentBuilder::default()
         .file_name(DOC_FILE_NAME.to_string())
-        .file_path(DOC_FILE_PATH.to_string())
+        .file_path(DOC_SHORT_FILE_PATH.to_string())
         .file_size(DOC_FILE_SIZE)
         .created_at(DOC_FILE_TIMESTAMP)
         .modified_at(DOC_FILE_TIMESTAMP)
@@ -7,6 +7,7 @@ pub const LARGE_DOC_ID: &str = "29346839246dsf987a1173sfa7sd781h";
 pub const FIRST_DOC_PART_ID: &str = "k3j5b49246dsf987a1173sfa7sd781h";
 pub const DOC_FILE_NAME: &str = "test-document.docx";
 pub const DOC_FILE_PATH: &str = "./test-document.docx";
+pub const DOC_SHORT_FILE_PATH: &str = "./test-short-document.docx";
 pub const DOC_FILE_SIZE: u32 = 1024;
 pub const DOC_FILE_TIMESTAMP: i64 = 1750957215;
 pub const DOC_FILE_LARGE_CONTENT: &str = include_str!("../resources/doc-content-large.txt");
@@ -1,6 +1,7 @@
 #![allow(dead_code)]
 use rstest::fixture;
 
+use crate::application::tests::fixture::DEFAULT_INDEX_ID;
 use crate::domain::searcher::models::{FilterParams, FilterParamsBuilder};
 use crate::domain::searcher::models::{FullTextSearchingParams, FullTextSearchingParamsBuilder};
 use crate::domain::searcher::models::{HybridSearchingParams, HybridSearchingParamsBuilder};
@@ -9,6 +10,8 @@ use crate::domain::searcher::models::{
     RetrieveIndexDocumentsParams, RetrieveIndexDocumentsParamsBuilder,
 };
 use crate::domain::searcher::models::{SemanticSearchingParams, SemanticSearchingParamsBuilder};
+use crate::domain::searcher::models::{SimilarMode, SimilarSource};
+use crate::domain::searcher::models::{SimilarSearchingParams, SimilarSearchingParamsBuilder};
 
 pub const QUERY_PARAMETER: &str = "There is some query";
 pub const DOCUMENT_PATH: &str = "./test-document.docx";
@@ -65,6 +68,16 @@ pub fn build_simple_semantic_params() -> SemanticSearchingParams {
         .expect("failed building semantic params builder")
 }
 
+pub fn build_similar_params(source: SimilarSource, mode: SimilarMode) -> SimilarSearchingParams {
+    SimilarSearchingParamsBuilder::default()
+        .source_index(DEFAULT_INDEX_ID.to_string())
+        .source(source)
+        .mode(mode)
+        .knn_amount(KNN_AMOUNT)
+        .build()
+        .expect("failed building similar params builder")
+}
+
 #[fixture]
 pub fn build_semantic_params_with_tokens() -> SemanticSearchingParams {
     let query = QUERY_PARAMETER.to_string();
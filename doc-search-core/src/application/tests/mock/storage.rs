@@ -2,8 +2,10 @@ use mockall::mock;
 
 use crate::domain::searcher::SearchError;
 use crate::domain::searcher::models::{Pagination, PaginationParams, SearchingParams};
+use crate::domain::searcher::models::{SuggestParams, Suggestions};
 use crate::domain::searcher::{IPaginator, ISearcher};
 use crate::domain::storage::StorageError;
+use crate::domain::storage::models::UpdateDocumentParams;
 use crate::domain::storage::models::{AllDocumentParts, DocumentPart};
 use crate::domain::storage::models::{CreateIndexParams, StoredDocumentPartsInfo};
 use crate::domain::storage::{IDocumentPartStorage, IIndexStorage};
@@ -44,6 +46,13 @@ mock! {
             doc_part_id: &DocumentPartId,
         ) -> Result<DocumentPart, StorageError>;
 
+        async fn update_document_parts(
+            &self,
+            index_id: &IndexId,
+            large_doc_id: &LargeDocumentId,
+            params: &UpdateDocumentParams,
+        ) -> Result<(), StorageError>;
+
         async fn delete_document_parts(
             &self,
             index_id: &IndexId,
@@ -54,10 +63,12 @@ mock! {
     #[async_trait::async_trait]
     impl ISearcher for Storage {
         async fn search(&self, params: &SearchingParams) -> Result<Pagination, SearchError>;
+        async fn suggest(&self, params: &SuggestParams) -> Result<Suggestions, SearchError>;
     }
 
     #[async_trait::async_trait]
     impl IPaginator for Storage {
         async fn paginate(&self, params: &PaginationParams) -> Result<Pagination, SearchError>;
+        async fn release(&self, params: &PaginationParams) -> Result<(), SearchError>;
     }
 }
@@ -1,4 +1,5 @@
 pub mod fixture;
 pub mod mock;
 
+mod test_searcher_usecase;
 mod test_storage_usecase;
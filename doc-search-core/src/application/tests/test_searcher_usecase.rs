@@ -0,0 +1,178 @@
+use rstest::rstest;
+use std::sync::Arc;
+
+use crate::application::tests::fixture::DEFAULT_INDEX_ID;
+use crate::application::tests::fixture::search_params::build_result_params;
+use crate::application::tests::fixture::search_params::build_simple_hybrid_params;
+use crate::application::tests::fixture::search_params::{
+    build_semantic_params_with_tokens, build_simple_semantic_params,
+};
+use crate::application::tests::mock::storage::MockStorage;
+use crate::application::tests::mock::{TestEnvironment, init_test_environment};
+use crate::application::usecase::searcher::SearcherUseCase;
+use crate::domain::searcher::models::SemanticSearchingParams;
+use crate::domain::searcher::models::TotalHits;
+use crate::domain::searcher::models::{FoundedDocument, HybridFusion, HybridSearchingParams};
+use crate::domain::searcher::models::{Pagination, SearchKindParams, SearchingParams};
+use crate::domain::searcher::tests::fixture::founded::build_founded_part;
+use crate::infrastructure::embedder::HashingEmbedder;
+
+const LARGE_DOC_ID: &str = "large-doc";
+const EMBEDDINGS_DIMENSION: usize = 8;
+
+fn build_founded_parts(doc_part_ids: &[usize], scores: &[f64]) -> Vec<FoundedDocument> {
+    doc_part_ids
+        .iter()
+        .zip(scores)
+        .map(|(id, score)| build_founded_part(LARGE_DOC_ID, *id, Some(*score), &[]))
+        .collect()
+}
+
+fn build_fused_params(
+    mut hybrid: HybridSearchingParams,
+    fusion: HybridFusion,
+    offset: i64,
+    size: i64,
+) -> SearchingParams {
+    hybrid.fusion = Some(fusion);
+    hybrid.min_score = None;
+
+    let mut result = build_result_params();
+    result.offset = offset;
+    result.size = size;
+
+    let indexes = vec![DEFAULT_INDEX_ID.to_string()];
+    SearchingParams::new(indexes, SearchKindParams::Hybrid(hybrid), result, None)
+}
+
+fn init_fused_searcher(mut test_env: TestEnvironment) -> SearcherUseCase<MockStorage> {
+    test_env
+        .storage
+        .expect_search()
+        .times(2)
+        .returning(|params| {
+            let founded = match params.get_kind() {
+                SearchKindParams::FullText(_) => build_founded_parts(&[1, 2, 3], &[9.0, 5.0, 1.0]),
+                SearchKindParams::Semantic(_) => build_founded_parts(&[3, 1, 4], &[0.9, 0.8, 0.2]),
+                _ => panic!("unexpected searching kind"),
+            };
+
+            assert_eq!(params.get_result().offset, 0);
+            Ok(Pagination::new(None, founded))
+        });
+
+    SearcherUseCase::new(Arc::new(test_env.storage))
+}
+
+fn collect_part_ids(pagination: &Pagination) -> Vec<usize> {
+    pagination
+        .founded
+        .iter()
+        .map(|it| it.document.doc_part_id)
+        .collect()
+}
+
+#[rstest]
+#[case(0, 10, vec![1, 3, 2, 4])]
+#[case(1, 2, vec![3, 2])]
+#[case(4, 2, vec![])]
+#[tokio::test]
+async fn test_search_fused_by_reciprocal_rank(
+    #[from(init_test_environment)] test_env: TestEnvironment,
+    #[from(build_simple_hybrid_params)] hybrid: HybridSearchingParams,
+    #[case] offset: i64,
+    #[case] size: i64,
+    #[case] expected: Vec<usize>,
+) -> anyhow::Result<()> {
+    let searcher_uc = init_fused_searcher(test_env);
+    let params = build_fused_params(hybrid, HybridFusion::ReciprocalRank, offset, size);
+    let pagination = searcher_uc.search_document_parts(&params).await?;
+
+    assert_eq!(collect_part_ids(&pagination), expected);
+    assert_eq!(pagination.total, Some(TotalHits::exact(4)));
+    assert!(pagination.cursor.is_none());
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_search_fused_by_weighted_score(
+    #[from(init_test_environment)] test_env: TestEnvironment,
+    #[from(build_simple_hybrid_params)] hybrid: HybridSearchingParams,
+) -> anyhow::Result<()> {
+    let searcher_uc = init_fused_searcher(test_env);
+    let params = build_fused_params(hybrid, HybridFusion::WeightedScore, 0, 10);
+    let pagination = searcher_uc.search_document_parts(&params).await?;
+
+    assert_eq!(collect_part_ids(&pagination), vec![1, 2, 3, 4]);
+
+    let scores = pagination
+        .founded
+        .iter()
+        .filter_map(|it| it.score)
+        .collect::<Vec<f64>>();
+
+    assert!(scores.windows(2).all(|pair| pair[0] >= pair[1]));
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_search_hybrid_without_fusion(
+    #[from(init_test_environment)] mut test_env: TestEnvironment,
+    #[from(build_simple_hybrid_params)] hybrid: HybridSearchingParams,
+) -> anyhow::Result<()> {
+    test_env
+        .storage
+        .expect_search()
+        .times(1)
+        .returning(|params| {
+            assert!(matches!(params.get_kind(), SearchKindParams::Hybrid(_)));
+            Ok(Pagination::new(None, build_founded_parts(&[1], &[1.0])))
+        });
+
+    let searcher_uc = SearcherUseCase::new(Arc::new(test_env.storage));
+    let indexes = vec![DEFAULT_INDEX_ID.to_string()];
+    let kind = SearchKindParams::Hybrid(hybrid);
+    let params = SearchingParams::new(indexes, kind, build_result_params(), None);
+    let pagination = searcher_uc.search_document_parts(&params).await?;
+
+    assert_eq!(collect_part_ids(&pagination), vec![1]);
+    Ok(())
+}
+
+#[rstest]
+#[case(build_simple_semantic_params(), "test-*", Some(EMBEDDINGS_DIMENSION))]
+#[case(build_simple_semantic_params(), "other-*", None)]
+#[case(build_semantic_params_with_tokens(), "test-*", Some(2))]
+#[tokio::test]
+async fn test_search_semantic_with_embedder(
+    #[from(init_test_environment)] mut test_env: TestEnvironment,
+    #[case] semantic: SemanticSearchingParams,
+    #[case] embedded_pattern: &str,
+    #[case] expected_dimension: Option<usize>,
+) -> anyhow::Result<()> {
+    test_env
+        .storage
+        .expect_search()
+        .times(1)
+        .returning(move |params| {
+            let SearchKindParams::Semantic(semantic) = params.get_kind() else {
+                panic!("unexpected searching kind");
+            };
+
+            let dimension = semantic.tokens.as_ref().map(Vec::len);
+            assert_eq!(dimension, expected_dimension);
+            Ok(Pagination::new(None, Vec::new()))
+        });
+
+    let embedder = Arc::new(HashingEmbedder::new(EMBEDDINGS_DIMENSION));
+    let searcher_uc = SearcherUseCase::new(Arc::new(test_env.storage))
+        .with_embedder(embedder, vec![embedded_pattern.to_string()]);
+
+    let indexes = vec![DEFAULT_INDEX_ID.to_string()];
+    let kind = SearchKindParams::Semantic(semantic);
+    let params = SearchingParams::new(indexes, kind, build_result_params(), None);
+    let _ = searcher_uc.search_document_parts(&params).await?;
+    Ok(())
+}
@@ -5,7 +5,11 @@ use crate::application::tests::fixture::document::build_large_document;
 use crate::application::tests::fixture::{DEFAULT_INDEX_ID, FIRST_DOC_PART_ID, LARGE_DOC_ID};
 use crate::application::tests::mock::{TestEnvironment, init_test_environment};
 use crate::application::usecase::storage::StorageUseCase;
+use crate::domain::storage::StorageError;
+use crate::domain::storage::models::DOCUMENT_PARTS_SEPARATOR;
+use crate::domain::storage::models::UploadedFileBuilder;
 use crate::domain::storage::models::{LargeDocument, StoredDocumentPartsInfoBuilder};
+use crate::domain::storage::splitter::TokenSplitter;
 use crate::shared::kernel::{DocumentPartId, IndexId, LargeDocumentId};
 
 const MAX_CONTENT_SIZE: usize = 1024;
@@ -48,6 +52,11 @@ async fn test_store_document(
         .times(1)
         .returning(move |index| Ok(index.clone()));
 
+    mock_storage
+        .expect_get_document_parts()
+        .times(1)
+        .returning(move |_index, _id| Ok(Vec::new()));
+
     mock_storage
         .expect_store_document_parts()
         .times(1)
@@ -80,3 +89,367 @@ async fn test_store_document(
 
     Ok(())
 }
+
+#[rstest]
+#[tokio::test]
+async fn test_store_document_already_exists(
+    #[from(init_test_environment)] test_env: TestEnvironment,
+    #[from(build_large_document)] test_doc: LargeDocument,
+) -> anyhow::Result<()> {
+    let stored_parts = build_large_document().divide_large_document_on_parts(MAX_CONTENT_SIZE)?;
+
+    let mut mock_storage = test_env.storage;
+    mock_storage.expect_store_document_parts().never();
+    mock_storage
+        .expect_get_index()
+        .times(1)
+        .returning(move |index| Ok(index.clone()));
+
+    mock_storage
+        .expect_get_document_parts()
+        .times(1)
+        .returning(move |_index, _id| Ok(stored_parts.clone()));
+
+    let storage = Arc::new(mock_storage);
+    let storage_uc = StorageUseCase::new(storage, MAX_CONTENT_SIZE);
+
+    let index_id = IndexId(DEFAULT_INDEX_ID.to_string());
+    let result = storage_uc.store_document(&index_id, test_doc, false).await;
+    assert!(matches!(
+        result,
+        Err(StorageError::DocumentAlreadyExists(_))
+    ));
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_store_document_force_replaces(
+    #[from(init_test_environment)] test_env: TestEnvironment,
+    #[from(build_large_document)] test_doc: LargeDocument,
+) -> anyhow::Result<()> {
+    let mut mock_storage = test_env.storage;
+    mock_storage.expect_get_document_parts().never();
+    mock_storage
+        .expect_get_index()
+        .times(1)
+        .returning(move |index| Ok(index.clone()));
+
+    mock_storage
+        .expect_store_document_parts()
+        .times(1)
+        .returning(move |_index, parts| {
+            let stored_doc_parts_info = StoredDocumentPartsInfoBuilder::default()
+                .large_doc_id(parts[0].large_doc_id.clone())
+                .first_part_id(DocumentPartId(FIRST_DOC_PART_ID.to_string()))
+                .doc_parts_amount(parts.len())
+                .build()
+                .expect("failed to build stored document parts information");
+
+            Ok(stored_doc_parts_info)
+        });
+
+    let storage = Arc::new(mock_storage);
+    let storage_uc = StorageUseCase::new(storage, MAX_CONTENT_SIZE);
+
+    let index_id = IndexId(DEFAULT_INDEX_ID.to_string());
+    let result = storage_uc.store_document(&index_id, test_doc, true).await;
+    assert!(result.is_ok());
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_update_document(
+    #[from(init_test_environment)] test_env: TestEnvironment,
+    #[from(build_large_document)] mut test_doc: LargeDocument,
+) -> anyhow::Result<()> {
+    const STORED_CREATED_AT: i64 = 1;
+
+    let mut stored_parts =
+        build_large_document().divide_large_document_on_parts(MAX_CONTENT_SIZE)?;
+    stored_parts
+        .iter_mut()
+        .for_each(|it| it.created_at = STORED_CREATED_AT);
+
+    let mut mock_storage = test_env.storage;
+    mock_storage
+        .expect_get_document_parts()
+        .times(1)
+        .returning(move |_index, _id| Ok(stored_parts.clone()));
+
+    mock_storage
+        .expect_store_document_parts()
+        .times(1)
+        .returning(move |_index, parts| {
+            assert!(parts.iter().all(|it| it.large_doc_id.0 == LARGE_DOC_ID));
+            assert!(parts.iter().all(|it| it.created_at == STORED_CREATED_AT));
+
+            let stored_doc_parts_info = StoredDocumentPartsInfoBuilder::default()
+                .large_doc_id(parts[0].large_doc_id.clone())
+                .first_part_id(DocumentPartId(FIRST_DOC_PART_ID.to_string()))
+                .doc_parts_amount(parts.len())
+                .build()
+                .expect("failed to build stored document parts information");
+
+            Ok(stored_doc_parts_info)
+        });
+
+    let storage = Arc::new(mock_storage);
+    let storage_uc = StorageUseCase::new(storage, MAX_CONTENT_SIZE);
+
+    test_doc.file_path = "./renamed-document.docx".to_string();
+    let index_id = IndexId(DEFAULT_INDEX_ID.to_string());
+    let large_doc_id = LargeDocumentId(LARGE_DOC_ID.to_string());
+    let result = storage_uc
+        .update_document(&index_id, &large_doc_id, test_doc)
+        .await?;
+
+    assert_eq!(LARGE_DOC_ID, result.large_doc_id.0);
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_update_document_not_found(
+    #[from(init_test_environment)] test_env: TestEnvironment,
+    #[from(build_large_document)] test_doc: LargeDocument,
+) -> anyhow::Result<()> {
+    let mut mock_storage = test_env.storage;
+    mock_storage.expect_store_document_parts().never();
+    mock_storage
+        .expect_get_document_parts()
+        .times(1)
+        .returning(move |_index, _id| Ok(Vec::new()));
+
+    let storage = Arc::new(mock_storage);
+    let storage_uc = StorageUseCase::new(storage, MAX_CONTENT_SIZE);
+
+    let index_id = IndexId(DEFAULT_INDEX_ID.to_string());
+    let large_doc_id = LargeDocumentId(LARGE_DOC_ID.to_string());
+    let result = storage_uc
+        .update_document(&index_id, &large_doc_id, test_doc)
+        .await;
+
+    assert!(matches!(result, Err(StorageError::DocumentNotFound(_))));
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_upload_document_unsupported_format(
+    #[from(init_test_environment)] test_env: TestEnvironment,
+) -> anyhow::Result<()> {
+    let mut mock_storage = test_env.storage;
+    mock_storage.expect_get_index().never();
+    mock_storage.expect_store_document_parts().never();
+
+    let storage = Arc::new(mock_storage);
+    let storage_uc = StorageUseCase::new(storage, MAX_CONTENT_SIZE);
+
+    let uploaded_file = UploadedFileBuilder::default()
+        .file_name("image.png".to_string())
+        .file_path("./image.png".to_string())
+        .uploaded_at(0)
+        .data(b"\x89PNG".to_vec())
+        .build()?;
+
+    let index_id = IndexId(DEFAULT_INDEX_ID.to_string());
+    let result = storage_uc
+        .upload_document(&index_id, uploaded_file, false)
+        .await;
+
+    assert!(matches!(result, Err(StorageError::ValidationError(_))));
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_store_documents_partial_failure(
+    #[from(init_test_environment)] test_env: TestEnvironment,
+) -> anyhow::Result<()> {
+    let mut empty_doc = build_large_document();
+    empty_doc.content = String::default();
+    empty_doc.file_path = "./empty-document.docx".to_string();
+
+    let mut mock_storage = test_env.storage;
+    mock_storage
+        .expect_get_index()
+        .times(3)
+        .returning(move |index| Ok(index.clone()));
+
+    mock_storage
+        .expect_store_document_parts()
+        .times(1)
+        .returning(move |_index, parts| {
+            let stored_doc_parts_info = StoredDocumentPartsInfoBuilder::default()
+                .large_doc_id(parts[0].large_doc_id.clone())
+                .first_part_id(DocumentPartId(FIRST_DOC_PART_ID.to_string()))
+                .doc_parts_amount(parts.len())
+                .build()
+                .expect("failed to build stored document parts information");
+
+            Ok(stored_doc_parts_info)
+        });
+
+    let storage = Arc::new(mock_storage);
+    let storage_uc = StorageUseCase::new(storage, MAX_CONTENT_SIZE);
+
+    let index_id = IndexId(DEFAULT_INDEX_ID.to_string());
+    let documents = vec![empty_doc, build_large_document()];
+    let report = storage_uc
+        .store_documents(&index_id, documents, true)
+        .await?;
+
+    assert!(report.has_failures());
+    assert_eq!(report.succeeded.len(), 1);
+    assert_eq!(report.succeeded[0].position, 1);
+    assert_eq!(report.failed.len(), 1);
+    assert_eq!(report.failed[0].position, 0);
+    assert_eq!(report.failed[0].file_path, "./empty-document.docx");
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_upload_documents_partial_failure(
+    #[from(init_test_environment)] test_env: TestEnvironment,
+) -> anyhow::Result<()> {
+    let mut mock_storage = test_env.storage;
+    mock_storage
+        .expect_get_index()
+        .times(2)
+        .returning(move |index| Ok(index.clone()));
+
+    mock_storage
+        .expect_store_document_parts()
+        .times(1)
+        .returning(move |_index, parts| {
+            let stored_doc_parts_info = StoredDocumentPartsInfoBuilder::default()
+                .large_doc_id(parts[0].large_doc_id.clone())
+                .first_part_id(DocumentPartId(FIRST_DOC_PART_ID.to_string()))
+                .doc_parts_amount(parts.len())
+                .build()
+                .expect("failed to build stored document parts information");
+
+            Ok(stored_doc_parts_info)
+        });
+
+    let storage = Arc::new(mock_storage);
+    let storage_uc = StorageUseCase::new(storage, MAX_CONTENT_SIZE);
+
+    let image_file = UploadedFileBuilder::default()
+        .file_name("image.png".to_string())
+        .file_path("./uploads/image.png".to_string())
+        .uploaded_at(0)
+        .data(b"\x89PNG".to_vec())
+        .build()?;
+
+    let text_file = UploadedFileBuilder::default()
+        .file_name("notes.txt".to_string())
+        .file_path("./uploads/notes.txt".to_string())
+        .uploaded_at(0)
+        .data(b"uploaded plain text about intuition".to_vec())
+        .build()?;
+
+    let index_id = IndexId(DEFAULT_INDEX_ID.to_string());
+    let files = vec![image_file, text_file];
+    let report = storage_uc.upload_documents(&index_id, files, true).await?;
+
+    assert!(report.has_failures());
+    assert_eq!(report.succeeded.len(), 1);
+    assert_eq!(report.succeeded[0].position, 1);
+    assert_eq!(report.failed.len(), 1);
+    assert_eq!(report.failed[0].position, 0);
+    assert_eq!(report.failed[0].file_path, "./uploads/image.png");
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_get_large_document(
+    #[from(init_test_environment)] test_env: TestEnvironment,
+) -> anyhow::Result<()> {
+    let splitter = TokenSplitter::new(5, 0.0);
+    let stored_parts = build_large_document().divide_large_document_with(&splitter)?;
+    let expected_content = stored_parts
+        .iter()
+        .map(|it| it.content.as_str())
+        .collect::<Vec<&str>>()
+        .join(DOCUMENT_PARTS_SEPARATOR);
+
+    let mut reversed_parts = stored_parts.clone();
+    reversed_parts.reverse();
+
+    let mut mock_storage = test_env.storage;
+    mock_storage
+        .expect_get_document_parts()
+        .times(1)
+        .returning(move |_index, _id| Ok(reversed_parts.clone()));
+
+    let storage = Arc::new(mock_storage);
+    let storage_uc = StorageUseCase::new(storage, MAX_CONTENT_SIZE);
+
+    let index_id = IndexId(DEFAULT_INDEX_ID.to_string());
+    let large_doc_id = LargeDocumentId(LARGE_DOC_ID.to_string());
+    let large_doc = storage_uc
+        .get_large_document(&index_id, &large_doc_id)
+        .await?;
+
+    assert_eq!(large_doc.content, expected_content);
+    assert_eq!(large_doc.file_path, stored_parts[0].file_path);
+    assert!(large_doc.metadata.is_some());
+
+    Ok(())
+}
+
+#[rstest]
+#[case(3, 1, Some(vec![2, 3, 4]))]
+#[case(1, 2, Some(vec![1, 2, 3]))]
+#[case(3, 0, Some(vec![3]))]
+#[case(1000, 1, None)]
+#[tokio::test]
+async fn test_get_document_parts_window(
+    #[from(init_test_environment)] test_env: TestEnvironment,
+    #[case] doc_part_id: usize,
+    #[case] window: usize,
+    #[case] expected_part_ids: Option<Vec<usize>>,
+) -> anyhow::Result<()> {
+    let splitter = TokenSplitter::new(5, 0.0);
+    let stored_parts = build_large_document().divide_large_document_with(&splitter)?;
+
+    let mut mock_storage = test_env.storage;
+    mock_storage
+        .expect_get_document_parts()
+        .times(1)
+        .returning(move |_index, _id| Ok(stored_parts.clone()));
+
+    let storage = Arc::new(mock_storage);
+    let storage_uc = StorageUseCase::new(storage, MAX_CONTENT_SIZE);
+
+    let index_id = IndexId(DEFAULT_INDEX_ID.to_string());
+    let large_doc_id = LargeDocumentId(LARGE_DOC_ID.to_string());
+    let result = storage_uc
+        .get_document_parts_window(&index_id, &large_doc_id, doc_part_id, window)
+        .await;
+
+    match expected_part_ids {
+        Some(expected) => {
+            let part_ids = result?
+                .iter()
+                .map(|it| it.doc_part_id)
+                .collect::<Vec<usize>>();
+            assert_eq!(part_ids, expected);
+        }
+        None => assert!(matches!(result, Err(StorageError::DocumentNotFound(_)))),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use std::collections::HashMap;
+
+use crate::domain::searcher::models::{FoundedDocument, HybridScoringParams};
+
+/// Smoothing constant of reciprocal rank fusion, which lowers the impact
+/// of the top ranks in comparison with the following ones.
+const RANK_CONSTANT: f64 = 60.0;
+
+/// Fuses ranked lists by sum of `1 / (RANK_CONSTANT + rank)` of each part.
+pub fn reciprocal_rank(
+    lexical: Vec<FoundedDocument>,
+    semantic: Vec<FoundedDocument>,
+) -> Vec<FoundedDocument> {
+    let rank_scores = |founded: &[FoundedDocument]| {
+        (1..=founded.len())
+            .map(|rank| 1.0 / (RANK_CONSTANT + rank as f64))
+            .collect::<Vec<f64>>()
+    };
+
+    let lexical_scores = rank_scores(&lexical);
+    let semantic_scores = rank_scores(&semantic);
+    fuse(vec![(lexical, lexical_scores), (semantic, semantic_scores)])
+}
+
+/// Fuses lists by sum of min-max normalized scores multiplied by weights.
+pub fn weighted_score(
+    lexical: Vec<FoundedDocument>,
+    semantic: Vec<FoundedDocument>,
+    scoring: &HybridScoringParams,
+) -> Vec<FoundedDocument> {
+    let lexical_scores = normalize_scores(&lexical, scoring.lexical_weight);
+    let semantic_scores = normalize_scores(&semantic, scoring.semantic_weight);
+    fuse(vec![(lexical, lexical_scores), (semantic, semantic_scores)])
+}
+
+/// Parts without score are considered the least relevant ones.
+fn normalize_scores(founded: &[FoundedDocument], weight: f32) -> Vec<f64> {
+    let scores = founded
+        .iter()
+        .map(|it| it.score)
+        .collect::<Vec<Option<f64>>>();
+    let min = scores.iter().flatten().copied().reduce(f64::min);
+    let max = scores.iter().flatten().copied().reduce(f64::max);
+    scores
+        .into_iter()
+        .map(|score| match (score, min, max) {
+            (Some(score), Some(min), Some(max)) if max > min => (score - min) / (max - min),
+            (Some(_), _, _) => 1.0,
+            (None, _, _) => 0.0,
+        })
+        .map(|score| score * f64::from(weight))
+        .collect()
+}
+
+/// Sums scores of the same document part found by several searches and
+/// sorts parts by fused score. Highlights are taken from the first search
+/// providing them.
+fn fuse(ranked: Vec<(Vec<FoundedDocument>, Vec<f64>)>) -> Vec<FoundedDocument> {
+    let mut positions: HashMap<(String, String), usize> = HashMap::new();
+    let mut fused: Vec<(f64, FoundedDocument)> = Vec::new();
+    for (founded, scores) in ranked {
+        for (doc, score) in founded.into_iter().zip(scores) {
+            let key = (doc.index.clone(), doc.id.clone());
+            let Some(&position) = positions.get(&key) else {
+                positions.insert(key, fused.len());
+                fused.push((score, doc));
+                continue;
+            };
+
+            let (fused_score, fused_doc) = &mut fused[position];
+            *fused_score += score;
+            if fused_doc.highlight.is_empty() {
+                fused_doc.highlight = doc.highlight;
+            }
+        }
+    }
+
+    fused.sort_by(|(a, _), (b, _)| b.total_cmp(a));
+    fused
+        .into_iter()
+        .map(|(score, mut doc)| {
+            doc.score = Some(score);
+            doc
+        })
+        .collect()
+}
@@ -1,2 +1,3 @@
+mod fusion;
 pub mod searcher;
 pub mod storage;
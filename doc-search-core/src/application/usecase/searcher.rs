@@ -2,9 +2,17 @@ use metrics::{counter, histogram};
 use std::sync::Arc;
 use tracing::instrument;
 
+use crate::application::usecase::fusion;
+use crate::domain::embedder::IEmbedder;
 use crate::domain::searcher::SearchResult;
+use crate::domain::searcher::models::{FoundedDocument, FullTextSearchingParams};
+use crate::domain::searcher::models::{HybridFusion, HybridSearchingParams};
 use crate::domain::searcher::models::{Pagination, PaginationParams, SearchingParams};
+use crate::domain::searcher::models::{ResultParams, SearchKindParams, SemanticSearchingParams};
+use crate::domain::searcher::models::{SuggestParams, Suggestions};
+use crate::domain::searcher::models::{TotalHits, TotalHitsRelation};
 use crate::domain::searcher::{IPaginator, ISearcher};
+use crate::shared::kernel::is_index_pattern_matched;
 
 #[derive(Clone)]
 pub struct SearcherUseCase<Searcher>
@@ -12,6 +20,8 @@ where
     Searcher: ISearcher + IPaginator + Send + Sync,
 {
     searcher: Arc<Searcher>,
+    embedder: Option<Arc<dyn IEmbedder + Send + Sync>>,
+    embedded_indexes: Vec<String>,
 }
 
 impl<Searcher> SearcherUseCase<Searcher>
@@ -19,7 +29,23 @@ where
     Searcher: ISearcher + IPaginator + Send + Sync,
 {
     pub fn new(searcher: Arc<Searcher>) -> Self {
-        SearcherUseCase { searcher }
+        SearcherUseCase {
+            searcher,
+            embedder: None,
+            embedded_indexes: Vec::default(),
+        }
+    }
+
+    /// Embeds queries of semantic searching over indexes matching passed
+    /// patterns by the embedder, unless the request passes its own tokens.
+    pub fn with_embedder(
+        mut self,
+        embedder: Arc<dyn IEmbedder + Send + Sync>,
+        indexes: Vec<String>,
+    ) -> Self {
+        self.embedder = Some(embedder);
+        self.embedded_indexes = indexes;
+        self
     }
 }
 
@@ -33,7 +59,12 @@ where
         params: &SearchingParams,
     ) -> SearchResult<Pagination> {
         let instant = tokio::time::Instant::now();
-        let result = self.searcher.search(params).await;
+        let result = match params.get_kind() {
+            SearchKindParams::Hybrid(hybrid) if hybrid.fusion.is_some() => {
+                self.search_fused(params, hybrid).await
+            }
+            _ => self.search_embedded(params).await,
+        };
 
         let is_error = result.is_err();
         let searching_kind = params.get_kind().to_string();
@@ -55,6 +86,162 @@ where
         Ok(pagination)
     }
 
+    async fn search_embedded(&self, params: &SearchingParams) -> SearchResult<Pagination> {
+        match self.embed_semantic_query(params).await? {
+            Some(embedded_params) => self.searcher.search(&embedded_params).await,
+            None => self.searcher.search(params).await,
+        }
+    }
+
+    /// Builds semantic searching params with query tokens computed by the
+    /// embedder when all searched indexes store external embeddings.
+    async fn embed_semantic_query(
+        &self,
+        params: &SearchingParams,
+    ) -> SearchResult<Option<SearchingParams>> {
+        let Some(embedder) = self.embedder.as_ref() else {
+            return Ok(None);
+        };
+
+        let SearchKindParams::Semantic(semantic) = params.get_kind() else {
+            return Ok(None);
+        };
+
+        let is_embedded_externally = params.get_indexes().iter().all(|index| {
+            self.embedded_indexes
+                .iter()
+                .any(|pattern| is_index_pattern_matched(pattern, index))
+        });
+
+        if semantic.tokens.is_some() || !is_embedded_externally {
+            return Ok(None);
+        }
+
+        let tokens = embedder.embed(&semantic.query).await?;
+        let semantic = SemanticSearchingParams {
+            query: semantic.query.clone(),
+            knn_amount: semantic.knn_amount,
+            min_score: semantic.min_score,
+            model_id: semantic.model_id.clone(),
+            tokens: Some(tokens),
+        };
+
+        let embedded_params = params
+            .derive_searching(
+                SearchKindParams::Semantic(semantic),
+                params.get_result().clone(),
+            )
+            .with_aggregations(params.get_aggregations().cloned());
+
+        Ok(Some(embedded_params))
+    }
+
+    /// Searches hybrid by fusion of fulltext and semantic results retrieved
+    /// concurrently. Fused results are paginated by offset only.
+    async fn search_fused(
+        &self,
+        params: &SearchingParams,
+        hybrid: &HybridSearchingParams,
+    ) -> SearchResult<Pagination> {
+        let result = params.get_result();
+        let offset = result.offset.max(0) as usize;
+        let size = result.size.max(0) as usize;
+        let depth = hybrid
+            .pagination_depth
+            .unwrap_or(hybrid.knn_amount as usize)
+            .max(offset + size);
+
+        let sub_result = ResultParams {
+            size: depth as i64,
+            offset: 0,
+            group_by_document: None,
+            sort: Vec::default(),
+            ..result.clone()
+        };
+
+        let fulltext = FullTextSearchingParams {
+            query: Some(hybrid.query.clone()),
+        };
+
+        let semantic = SemanticSearchingParams {
+            query: hybrid.query.clone(),
+            knn_amount: hybrid.knn_amount,
+            min_score: None,
+            model_id: hybrid.model_id.clone(),
+            tokens: None,
+        };
+
+        let fulltext_params = params
+            .derive_searching(SearchKindParams::FullText(fulltext), sub_result.clone())
+            .with_aggregations(params.get_aggregations().cloned());
+
+        let semantic_params =
+            params.derive_searching(SearchKindParams::Semantic(semantic), sub_result);
+
+        let (lexical, semantic) = tokio::try_join!(
+            self.searcher.search(&fulltext_params),
+            self.search_embedded(&semantic_params),
+        )?;
+
+        // Sub searches are fused at once, so their cursors are released
+        // instead of waiting for the expiration.
+        for cursor in [&lexical.cursor, &semantic.cursor].into_iter().flatten() {
+            let pagination_params = PaginationParams {
+                cursor: cursor.clone(),
+            };
+
+            if let Err(err) = self.searcher.release(&pagination_params).await {
+                tracing::warn!(err=?err, "failed to release sub search cursor");
+            }
+        }
+
+        // Fused results are limited by depth of sub searches, so their amount
+        // is a lower bound once any of sub searches matched more results.
+        let is_depth_exceeded = [&lexical.total, &semantic.total]
+            .into_iter()
+            .flatten()
+            .any(|it| it.value > depth as u64 || it.relation == TotalHitsRelation::LowerBound);
+
+        let took = lexical.took.max(semantic.took);
+        let scoring = hybrid.scoring.clone().unwrap_or_default();
+        let mut founded = match hybrid.fusion {
+            Some(HybridFusion::WeightedScore) => {
+                let mut founded =
+                    fusion::weighted_score(lexical.founded, semantic.founded, &scoring);
+                if let Some(min_score) = hybrid.min_score {
+                    founded.retain(|it| it.score >= Some(f64::from(min_score)));
+                }
+                founded
+            }
+            _ => fusion::reciprocal_rank(lexical.founded, semantic.founded),
+        };
+
+        if !result.sort.is_empty() {
+            FoundedDocument::sort_by_params(&mut founded, &result.sort);
+        }
+
+        if result.is_grouped_by_document() {
+            founded = FoundedDocument::group_by_document(founded);
+        }
+
+        let total = TotalHits {
+            value: founded.len() as u64,
+            relation: match is_depth_exceeded {
+                true => TotalHitsRelation::LowerBound,
+                false => TotalHitsRelation::Exact,
+            },
+        };
+
+        let max_score = founded.iter().filter_map(|it| it.score).reduce(f64::max);
+        let page = founded.into_iter().skip(offset).take(size).collect();
+        let paginated = Pagination::new(None, page)
+            .with_facets(lexical.facets)
+            .with_total(Some(total), max_score)
+            .with_took(took);
+
+        Ok(paginated)
+    }
+
     #[instrument(level = "info", skip(self))]
     pub async fn load_next_pagination(
         &self,
@@ -64,4 +251,14 @@ where
 
         Ok(pagination)
     }
+
+    #[instrument(level = "info", skip(self))]
+    pub async fn release_pagination(&self, params: &PaginationParams) -> SearchResult<()> {
+        self.searcher.release(params).await
+    }
+
+    #[instrument(level = "info", skip(self))]
+    pub async fn suggest(&self, params: &SuggestParams) -> SearchResult<Suggestions> {
+        self.searcher.suggest(params).await
+    }
 }
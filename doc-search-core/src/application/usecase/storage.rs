@@ -2,10 +2,13 @@ use metrics::{counter, histogram};
 use std::sync::Arc;
 use tracing::instrument;
 
-use crate::domain::storage::StorageResult;
-use crate::domain::storage::models::{AllDocumentParts, LargeDocument};
+use crate::domain::storage::models::UpdateDocumentParams;
+use crate::domain::storage::models::{AllDocumentParts, BulkStoreReport};
 use crate::domain::storage::models::{CreateIndexParams, StoredDocumentPartsInfo};
+use crate::domain::storage::models::{LargeDocument, SplitterParams, UploadedFile};
+use crate::domain::storage::splitter::{CharacterSplitter, DocumentSplitter};
 use crate::domain::storage::{IDocumentPartStorage, IIndexStorage};
+use crate::domain::storage::{StorageError, StorageResult};
 use crate::shared::kernel::{IndexId, LargeDocumentId};
 
 #[derive(Clone)]
@@ -35,6 +38,10 @@ where
 {
     #[instrument(level = "info", skip(self))]
     pub async fn create_index(&self, params: &CreateIndexParams) -> StorageResult<IndexId> {
+        if let Some(splitter) = params.splitter.as_ref() {
+            splitter.validate()?;
+        }
+
         let created_index_id = self.storage.create_index(params).await?;
 
         Ok(created_index_id)
@@ -76,23 +83,62 @@ where
         Ok(all_document_parts)
     }
 
+    #[instrument(level = "info", skip(self))]
+    pub async fn get_document_parts_window(
+        &self,
+        index_id: &IndexId,
+        large_doc_id: &LargeDocumentId,
+        doc_part_id: usize,
+        window: usize,
+    ) -> StorageResult<AllDocumentParts> {
+        let mut all_document_parts = self.get_all_document_parts(index_id, large_doc_id).await?;
+        if !all_document_parts
+            .iter()
+            .any(|it| it.doc_part_id == doc_part_id)
+        {
+            let err = anyhow::anyhow!("document {large_doc_id} has no part {doc_part_id}");
+            return Err(StorageError::DocumentNotFound(err));
+        }
+
+        let first_part_id = doc_part_id.saturating_sub(window);
+        let last_part_id = doc_part_id.saturating_add(window);
+        all_document_parts.retain(|it| (first_part_id..=last_part_id).contains(&it.doc_part_id));
+        all_document_parts.sort_by_key(|it| it.doc_part_id);
+        Ok(all_document_parts)
+    }
+
+    #[instrument(level = "info", skip(self))]
+    pub async fn get_large_document(
+        &self,
+        index_id: &IndexId,
+        large_doc_id: &LargeDocumentId,
+    ) -> StorageResult<LargeDocument> {
+        let all_document_parts = self.get_all_document_parts(index_id, large_doc_id).await?;
+        let merge_overlaps = match self.storage.get_index_splitter(index_id).await? {
+            Some(SplitterParams::Token { overlap_rate, .. }) => overlap_rate > 0.0,
+            _ => false,
+        };
+
+        LargeDocument::assemble_document_parts(all_document_parts, merge_overlaps)
+    }
+
     #[instrument(level = "info", skip(self))]
     pub async fn store_document(
         &self,
         index: &IndexId,
         large_doc: LargeDocument,
-        _force: bool,
+        force: bool,
     ) -> StorageResult<StoredDocumentPartsInfo> {
         let _ = self.check_index_exists(index).await?;
 
-        let part_size = self.max_content_size;
-        let document_parts = large_doc.divide_large_document_on_parts(part_size)?;
-
+        let splitter = self.build_index_splitter(index).await?;
+        let document_parts = large_doc.divide_large_document_with(splitter.as_ref())?;
         let instant = tokio::time::Instant::now();
-        let result = self
-            .storage
-            .store_document_parts(index, document_parts)
-            .await;
+        let result = match force {
+            true => self.storage.store_document_parts(index, document_parts),
+            false => self.storage.create_document_parts(index, document_parts),
+        }
+        .await;
 
         let is_error = result.is_err();
         counter!(
@@ -111,21 +157,115 @@ where
         Ok(stored_doc_info)
     }
 
+    #[instrument(level = "info", skip(self))]
+    pub async fn upload_document(
+        &self,
+        index: &IndexId,
+        file: UploadedFile,
+        force: bool,
+    ) -> StorageResult<StoredDocumentPartsInfo> {
+        let large_doc = tokio::task::spawn_blocking(move || file.extract_large_document())
+            .await
+            .map_err(|err| StorageError::InternalError(err.into()))??;
+
+        self.store_document(index, large_doc, force).await
+    }
+
+    #[instrument(level = "info", skip_all)]
+    pub async fn upload_documents(
+        &self,
+        index: &IndexId,
+        files: Vec<UploadedFile>,
+        force: bool,
+    ) -> StorageResult<BulkStoreReport> {
+        let _ = self.check_index_exists(index).await?;
+
+        let mut report = BulkStoreReport::default();
+        for (position, file) in files.into_iter().enumerate() {
+            let file_path = file.file_path.clone();
+            match self.upload_document(index, file, force).await {
+                Ok(stored_doc) => report.add_stored(position, file_path, stored_doc),
+                Err(err) => {
+                    tracing::warn!(position, file_path, err=?err, "failed to upload document");
+                    report.add_failed(position, file_path, err.to_string());
+                }
+            }
+        }
+
+        Ok(report)
+    }
+
     #[instrument(level = "info", skip_all)]
     pub async fn store_documents(
         &self,
         index: &IndexId,
         large_docs: Vec<LargeDocument>,
-    ) -> StorageResult<Vec<StoredDocumentPartsInfo>> {
+        force: bool,
+    ) -> StorageResult<BulkStoreReport> {
         let _ = self.check_index_exists(index).await?;
 
-        let mut stored_docs = Vec::with_capacity(large_docs.len());
-        for doc in large_docs.into_iter() {
-            let stored_doc = self.store_document(index, doc, true).await?;
-            stored_docs.push(stored_doc);
+        let mut report = BulkStoreReport::default();
+        for (position, doc) in large_docs.into_iter().enumerate() {
+            let file_path = doc.file_path.clone();
+            match self.store_document(index, doc, force).await {
+                Ok(stored_doc) => report.add_stored(position, file_path, stored_doc),
+                Err(err) => {
+                    tracing::warn!(position, file_path, err=?err, "failed to store document");
+                    report.add_failed(position, file_path, err.to_string());
+                }
+            }
         }
 
-        Ok(stored_docs)
+        Ok(report)
+    }
+
+    #[instrument(level = "info", skip(self))]
+    pub async fn update_document(
+        &self,
+        index: &IndexId,
+        large_doc_id: &LargeDocumentId,
+        mut large_doc: LargeDocument,
+    ) -> StorageResult<StoredDocumentPartsInfo> {
+        let stored_parts = self.storage.get_document_parts(index, large_doc_id).await?;
+        let Some(first_part) = stored_parts.first() else {
+            let err = anyhow::anyhow!("document {large_doc_id} not found");
+            return Err(StorageError::DocumentNotFound(err));
+        };
+
+        large_doc.created_at = first_part.created_at;
+        let splitter = self.build_index_splitter(index).await?;
+        let mut document_parts = large_doc.divide_large_document_with(splitter.as_ref())?;
+        document_parts
+            .iter_mut()
+            .for_each(|it| it.large_doc_id = large_doc_id.clone());
+
+        self.storage
+            .store_document_parts(index, document_parts)
+            .await
+    }
+
+    #[instrument(level = "info", skip(self))]
+    pub async fn update_document_metadata(
+        &self,
+        index: &IndexId,
+        large_doc_id: &LargeDocumentId,
+        params: &UpdateDocumentParams,
+    ) -> StorageResult<()> {
+        self.storage
+            .update_document_parts(index, large_doc_id, params)
+            .await
+    }
+
+    async fn build_index_splitter(
+        &self,
+        index: &IndexId,
+    ) -> StorageResult<Box<dyn DocumentSplitter>> {
+        let splitter = match self.storage.get_index_splitter(index).await? {
+            Some(params) => params.build_splitter(self.max_content_size),
+            None => Box::new(CharacterSplitter::new(self.max_content_size)),
+        };
+
+        Ok(splitter)
     }
 
     #[instrument(level = "info", skip(self))]
@@ -0,0 +1,31 @@
+use thiserror::Error;
+
+use crate::domain::searcher::SearchError;
+
+pub type EmbedderResult<T> = Result<T, EmbedderError>;
+
+/// Represents possible errors of computing embeddings.
+///
+/// # Variants
+/// * `ConnectionError` - Embeddings service is unavailable
+/// * `ServiceError` - Embeddings service returned error or malformed response
+/// * `ValidationError` - Passed text can't be embedded
+#[derive(Debug, Error)]
+pub enum EmbedderError {
+    #[error("embedder: connection error: {0}")]
+    ConnectionError(anyhow::Error),
+    #[error("embedder: returned error into response: {0}")]
+    ServiceError(anyhow::Error),
+    #[error("embedder: validation error: {0}")]
+    ValidationError(anyhow::Error),
+}
+
+impl From<EmbedderError> for SearchError {
+    fn from(err: EmbedderError) -> Self {
+        match err {
+            EmbedderError::ConnectionError(err) => SearchError::ConnectionError(err),
+            EmbedderError::ServiceError(err) => SearchError::ServiceError(err),
+            EmbedderError::ValidationError(err) => SearchError::ValidationError(err),
+        }
+    }
+}
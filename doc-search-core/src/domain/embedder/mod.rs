@@ -0,0 +1,5 @@
+mod repository;
+pub use repository::IEmbedder;
+
+mod error;
+pub use error::{EmbedderError, EmbedderResult};
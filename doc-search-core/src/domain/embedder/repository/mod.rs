@@ -0,0 +1,23 @@
+use crate::domain::embedder::EmbedderResult;
+
+/// Trait for computing embeddings of text by an external model.
+///
+/// Used to fill tokens of semantic searching when the searched indexes
+/// store embeddings computed outside of the storage.
+///
+/// # Methods
+/// * `embed` - Computes embedding vector of passed text
+///
+/// # Example
+/// ```
+/// #[async_trait::async_trait]
+/// impl IEmbedder for MyEmbedder {
+///     async fn embed(&self, text: &str) -> EmbedderResult<Vec<f64>> {
+///         Ok(self.model.encode(text))
+///     }
+/// }
+/// ```
+#[async_trait::async_trait]
+pub trait IEmbedder {
+    async fn embed(&self, text: &str) -> EmbedderResult<Vec<f64>>;
+}
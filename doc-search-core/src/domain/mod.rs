@@ -1,2 +1,3 @@
+pub mod embedder;
 pub mod searcher;
 pub mod storage;
@@ -25,6 +25,7 @@ pub type SearchResult<T> = Result<T, SearchError>;
 /// * `AuthenticationFailed` - Occurs when authentication with the search service fails
 /// * `ConnectionError` - Happens when unable to establish or maintain connection to search service
 /// * `IndexNotFound` - Indicates that the specified search index does not exist
+/// * `DocumentNotFound` - Indicates that a document the search refers to does not exist
 /// * `ValidationError` - Occurs when search parameters fail validation rules
 /// * `ServiceError` - Represents errors returned by the search service itself
 /// * `InternalError` - Covers internal system errors during search processing
@@ -154,6 +155,21 @@ pub enum SearchError {
     #[error("searcher: index has not been founded: {0}")]
     IndexNotFound(anyhow::Error),
 
+    /// The document referenced by search parameters was not found.
+    ///
+    /// This error occurs when:
+    /// * The source document of similar documents searching doesn't exist
+    /// * The source document has been deleted from the index
+    ///
+    /// # Example
+    /// ```
+    /// Err(SearchError::DocumentNotFound(
+    ///     anyhow::anyhow!("document 'c5cdd3bfad598ec7' does not exist")
+    /// ))
+    /// ```
+    #[error("searcher: document has not been founded: {0}")]
+    DocumentNotFound(anyhow::Error),
+
     /// Validation error in search parameters.
     ///
     /// This error occurs when:
@@ -2,6 +2,7 @@
 pub(crate) mod tests;
 
 pub mod models;
+pub mod query;
 
 mod repository;
 pub use repository::{IPaginator, ISearcher};
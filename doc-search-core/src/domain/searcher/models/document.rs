@@ -1,6 +1,9 @@
+use crate::domain::searcher::models::{ResultOrder, SortField, SortParams};
 use crate::shared::kernel::LargeDocumentId;
 use crate::shared::kernel::metadata::DocumentMetadata;
 use derive_builder::Builder;
+use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::fmt::{Debug, Formatter};
 
 /// Represents a document found in search results.
@@ -14,6 +17,7 @@ use std::fmt::{Debug, Formatter};
 /// * `score` - Relevance score of the document (optional, used in full-text search)
 /// * `highlight` - Vector of text fragments with highlighted matching terms
 /// * `document` - Complete document content and metadata
+/// * `grouped` - Summary of collapsed parts when results are grouped by document
 ///
 /// # Example
 /// ```
@@ -23,6 +27,7 @@ use std::fmt::{Debug, Formatter};
 ///     score: Some(0.95),
 ///     highlight: vec!["<em>search</em> term".to_string()],
 ///     document: document_part,
+///     grouped: None,
 /// };
 /// ```
 #[derive(Clone, Builder)]
@@ -32,6 +37,79 @@ pub struct FoundedDocument {
     pub score: Option<f64>,
     pub highlight: Vec<String>,
     pub document: DocumentPartEntrails,
+    #[builder(default)]
+    pub grouped: Option<GroupedParts>,
+}
+
+impl FoundedDocument {
+    /// Sorts founded documents by passed keys in turn. Sorting is stable,
+    /// so documents equal by all keys keep their previous order.
+    pub fn sort_by_params(founded: &mut [FoundedDocument], sort: &[SortParams]) {
+        founded.sort_by(|a, b| {
+            sort.iter()
+                .map(|key| compare_by_sort_key(key, a, b))
+                .find(|it| it.is_ne())
+                .unwrap_or(Ordering::Equal)
+        });
+    }
+
+    /// Collapses founded parts of the same large document into a single
+    /// result placed at the position of the highest ranked part.
+    pub fn group_by_document(founded: Vec<FoundedDocument>) -> Vec<FoundedDocument> {
+        let mut positions: HashMap<(String, String), usize> = HashMap::new();
+        let mut groups: Vec<Vec<FoundedDocument>> = Vec::new();
+        for doc in founded {
+            let key = (doc.index.clone(), doc.document.large_doc_id.0.clone());
+            match positions.get(&key) {
+                Some(&position) => groups[position].push(doc),
+                None => {
+                    positions.insert(key, groups.len());
+                    groups.push(vec![doc]);
+                }
+            }
+        }
+
+        groups
+            .into_iter()
+            .filter_map(FoundedDocument::collapse_parts)
+            .collect()
+    }
+
+    /// Builds a single result from parts of the same large document: the
+    /// best scoring part is returned with highlights of all parts, while
+    /// the sum of part scores is kept as the aggregated score.
+    pub fn collapse_parts(mut parts: Vec<FoundedDocument>) -> Option<FoundedDocument> {
+        if parts.is_empty() {
+            return None;
+        }
+
+        let mut best_position = 0;
+        for (position, part) in parts.iter().enumerate() {
+            if part.score > parts[best_position].score {
+                best_position = position;
+            }
+        }
+
+        let matched_parts = parts.len();
+        let score = parts
+            .iter()
+            .filter_map(|it| it.score)
+            .reduce(|total, score| total + score);
+
+        let mut best_part = parts.remove(best_position);
+        for fragment in parts.into_iter().flat_map(|it| it.highlight) {
+            if !best_part.highlight.contains(&fragment) {
+                best_part.highlight.push(fragment);
+            }
+        }
+
+        best_part.grouped = Some(GroupedParts {
+            matched_parts,
+            score,
+        });
+
+        Some(best_part)
+    }
 }
 
 impl Debug for FoundedDocument {
@@ -44,6 +122,54 @@ impl Debug for FoundedDocument {
     }
 }
 
+fn compare_by_sort_key(key: &SortParams, a: &FoundedDocument, b: &FoundedDocument) -> Ordering {
+    let order = &key.order;
+    let (a_doc, b_doc) = (&a.document, &b.document);
+    match key.field {
+        SortField::Score => compare_values(a.score, b.score, order),
+        SortField::CreatedAt => {
+            compare_values(Some(a_doc.created_at), Some(b_doc.created_at), order)
+        }
+        SortField::ModifiedAt => {
+            compare_values(Some(a_doc.modified_at), Some(b_doc.modified_at), order)
+        }
+        SortField::FileSize => compare_values(Some(a_doc.file_size), Some(b_doc.file_size), order),
+        SortField::FileName => {
+            compare_values(Some(&a_doc.file_name), Some(&b_doc.file_name), order)
+        }
+        SortField::PipelineId => {
+            let pipeline_id = |doc: &DocumentPartEntrails| doc.metadata.as_ref()?.pipeline_id;
+            compare_values(pipeline_id(a_doc), pipeline_id(b_doc), order)
+        }
+    }
+}
+
+fn compare_values<T: PartialOrd>(a: Option<T>, b: Option<T>, order: &ResultOrder) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => {
+            let ordering = a.partial_cmp(&b).unwrap_or(Ordering::Equal);
+            match order {
+                ResultOrder::ASC => ordering,
+                ResultOrder::DESC => ordering.reverse(),
+            }
+        }
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+/// Summary of document parts collapsed into a single search result.
+///
+/// # Fields
+/// * `matched_parts` - Number of document parts matched the search query
+/// * `score` - Aggregated score of matched parts (optional, absent without scoring)
+#[derive(Clone, Debug)]
+pub struct GroupedParts {
+    pub matched_parts: usize,
+    pub score: Option<f64>,
+}
+
 /// Represents the detailed content and metadata of a document part.
 ///
 /// This structure holds comprehensive information about a specific part of a larger document,
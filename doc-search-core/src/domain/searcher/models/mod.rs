@@ -1,17 +1,30 @@
 mod document;
 pub use document::Embeddings;
 pub use document::{DocumentPartEntrails, DocumentPartEntrailsBuilder};
-pub use document::{FoundedDocument, FoundedDocumentBuilder};
+pub use document::{FoundedDocument, FoundedDocumentBuilder, GroupedParts};
 
 mod pagination;
+pub use pagination::{FacetBucket, FacetBucketBuilder, Facets};
 pub use pagination::{Pagination, PaginationBuilder};
+pub use pagination::{TotalHits, TotalHitsRelation};
 
 mod params;
-pub use params::{FilterParams, FilterParamsBuilder};
+pub use params::{AggregationParams, AggregationParamsBuilder};
+pub use params::{DEFAULT_FACET_SIZE, FacetKind, HistogramInterval};
+pub use params::{FilterParams, FilterParamsBuilder, LocationFilter};
 pub use params::{FullTextSearchingParams, FullTextSearchingParamsBuilder};
+pub use params::{HybridFusion, HybridScoringParams, HybridScoringParamsBuilder};
 pub use params::{HybridSearchingParams, HybridSearchingParamsBuilder};
 pub use params::{PaginationParams, PaginationParamsBuilder};
 pub use params::{ResultOrder, SearchKindParams, SearchingParams};
 pub use params::{ResultParams, ResultParamsBuilder};
 pub use params::{RetrieveIndexDocumentsParams, RetrieveIndexDocumentsParamsBuilder};
+pub use params::{ScoreCombination, ScoreNormalization};
 pub use params::{SemanticSearchingParams, SemanticSearchingParamsBuilder};
+pub use params::{SimilarMode, SimilarSource};
+pub use params::{SimilarSearchingParams, SimilarSearchingParamsBuilder};
+pub use params::{SortField, SortParams};
+pub use params::{SuggestParams, SuggestParamsBuilder};
+
+mod suggestion;
+pub use suggestion::{SuggestField, Suggestion, Suggestions};
@@ -1,31 +1,113 @@
 use derive_builder::Builder;
+use std::collections::BTreeMap;
 
 use crate::domain::searcher::models::document::FoundedDocument;
 
+/// Facet buckets of search results keyed by facet name.
+pub type Facets = BTreeMap<String, Vec<FacetBucket>>;
+
 /// Represents a paginated search result.
 ///
-/// Contains the search results for a single page along with a scroll ID
-/// for retrieving subsequent pages.
+/// Contains the search results for a single page along with an opaque
+/// cursor for retrieving subsequent pages.
 ///
 /// # Fields
-/// * `scroll_id` - Identifier for retrieving the next page of results
+/// * `cursor` - Opaque cursor of the next page, absent when results are exhausted
 /// * `founded` - Vector of documents found in the current page
+/// * `facets` - Facet buckets, present only when aggregations were requested
+/// * `total` - Total amount of matched results, absent if backend does not count them
+/// * `max_score` - Maximum score of matched results, absent without scoring
+/// * `took` - Time in milliseconds spent by search engine
 ///
 /// # Example
 /// ```
 /// let page = Pagination {
-///     scroll_id: Some("scroll_abc123".to_string()),
+///     cursor: Some("cursor_abc123".to_string()),
 ///     founded: vec![found_doc1, found_doc2],
+///     facets: None,
+///     total: Some(TotalHits::exact(2)),
+///     max_score: Some(1.5),
+///     took: Some(12),
 /// };
 /// ```
 #[derive(Builder, Debug)]
 pub struct Pagination {
-    pub scroll_id: Option<String>,
+    pub cursor: Option<String>,
     pub founded: Vec<FoundedDocument>,
+    #[builder(default)]
+    pub facets: Option<Facets>,
+    #[builder(default)]
+    pub total: Option<TotalHits>,
+    #[builder(default)]
+    pub max_score: Option<f64>,
+    #[builder(default)]
+    pub took: Option<u64>,
 }
 
 impl Pagination {
-    pub fn new(scroll_id: Option<String>, founded: Vec<FoundedDocument>) -> Self {
-        Self { scroll_id, founded }
+    pub fn new(cursor: Option<String>, founded: Vec<FoundedDocument>) -> Self {
+        Self {
+            cursor,
+            founded,
+            facets: None,
+            total: None,
+            max_score: None,
+            took: None,
+        }
+    }
+
+    pub fn with_facets(mut self, facets: Option<Facets>) -> Self {
+        self.facets = facets;
+        self
+    }
+
+    pub fn with_total(mut self, total: Option<TotalHits>, max_score: Option<f64>) -> Self {
+        self.total = total;
+        self.max_score = max_score;
+        self
+    }
+
+    pub fn with_took(mut self, took: Option<u64>) -> Self {
+        self.took = took;
+        self
+    }
+}
+
+/// Total amount of matched results.
+///
+/// # Fields
+/// * `value` - Amount of matched results
+/// * `relation` - Whether `value` is exact or a lower bound of the amount,
+///   engines may stop counting after a threshold unless exact count is requested
+#[derive(Clone, Debug, PartialEq)]
+pub struct TotalHits {
+    pub value: u64,
+    pub relation: TotalHitsRelation,
+}
+
+impl TotalHits {
+    pub fn exact(value: u64) -> Self {
+        TotalHits {
+            value,
+            relation: TotalHitsRelation::Exact,
+        }
     }
 }
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum TotalHitsRelation {
+    #[default]
+    Exact,
+    LowerBound,
+}
+
+/// Single facet bucket with the amount of matched documents.
+///
+/// # Fields
+/// * `key` - Bucket value, e.g. source name or histogram timestamp
+/// * `count` - Amount of matched documents falling into the bucket
+#[derive(Clone, Debug, Builder, PartialEq)]
+pub struct FacetBucket {
+    pub key: String,
+    pub count: u64,
+}
@@ -1,6 +1,12 @@
 use derive_builder::Builder;
+use serde_derive::{Deserialize, Serialize};
 use std::fmt::{Debug, Display, Formatter};
 
+use crate::shared::kernel::geo::{DEFAULT_DISTANCE, haversine_distance, parse_distance};
+use crate::shared::kernel::is_index_pattern_matched;
+use crate::shared::kernel::metadata::DocumentMetadata;
+use crate::shared::kernel::{DocumentPartId, LargeDocumentId};
+
 /// Type alias for a collection of search indexes.
 ///
 /// Represents multiple indexes to search across.
@@ -17,19 +23,29 @@ pub type SearchIndexes = Vec<String>;
 /// * `kind` - Type of search to perform (retrieve, full-text, semantic, or hybrid)
 /// * `result` - Pagination and result formatting parameters
 /// * `filter` - Optional filters to narrow down results
+/// * `aggregations` - Optional facets to compute over matched documents
+/// * `allowed_indexes` - Optional index patterns the `*` wildcard is restricted to
+#[derive(Clone, Deserialize, Serialize)]
 pub struct SearchingParams {
     indexes: SearchIndexes,
     kind: SearchKindParams,
     result: ResultParams,
     filter: Option<FilterParams>,
+    aggregations: Option<AggregationParams>,
+    allowed_indexes: Option<Vec<String>>,
 }
 
 impl Debug for SearchingParams {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "indexes: {:?}, kind: {:?}, result: {:?}, filter: {:?}",
-            &self.indexes, &self.kind, &self.result, &self.filter,
+            "indexes: {:?}, kind: {:?}, result: {:?}, filter: {:?}, aggregations: {:?}, allowed_indexes: {:?}",
+            &self.indexes,
+            &self.kind,
+            &self.result,
+            &self.filter,
+            &self.aggregations,
+            &self.allowed_indexes,
         )
     }
 }
@@ -46,6 +62,51 @@ impl SearchingParams {
             kind,
             result,
             filter,
+            aggregations: None,
+            allowed_indexes: None,
+        }
+    }
+
+    /// Builds params of another kind of searching within the same indexes
+    /// and filters. Aggregations are not inherited.
+    pub fn derive_searching(&self, kind: SearchKindParams, result: ResultParams) -> Self {
+        Self {
+            indexes: self.indexes.clone(),
+            kind,
+            result,
+            filter: self.filter.clone(),
+            aggregations: None,
+            allowed_indexes: self.allowed_indexes.clone(),
+        }
+    }
+
+    pub fn with_aggregations(mut self, aggregations: Option<AggregationParams>) -> Self {
+        self.aggregations = aggregations;
+        self
+    }
+
+    /// Restricts `*` wildcard searching to indexes matching passed patterns.
+    ///
+    /// `None` keeps searching over all indexes, while an empty list makes
+    /// wildcard searching return nothing.
+    pub fn with_allowed_indexes(mut self, patterns: Option<Vec<String>>) -> Self {
+        self.allowed_indexes = patterns;
+        self
+    }
+
+    /// Drops embedding tokens passed to semantic searching once the query
+    /// is built by them, so kept params do not carry the whole vector.
+    pub fn without_tokens(mut self) -> Self {
+        if let SearchKindParams::Semantic(semantic) = &mut self.kind {
+            semantic.tokens = None;
+        }
+        self
+    }
+
+    pub fn has_tokens(&self) -> bool {
+        match &self.kind {
+            SearchKindParams::Semantic(semantic) => semantic.tokens.is_some(),
+            _ => false,
         }
     }
 
@@ -61,9 +122,39 @@ impl SearchingParams {
         &self.result
     }
 
+    /// Returns sort keys of results: documents are retrieved by creation
+    /// date, while search results are sorted by relevance by default.
+    pub fn get_sort(&self) -> Vec<SortParams> {
+        match &self.kind {
+            SearchKindParams::Retrieve(_) => self.result.date_sort(),
+            _ => self.result.relevance_sort(),
+        }
+    }
+
     pub fn get_filter(&self) -> Option<&FilterParams> {
         self.filter.as_ref()
     }
+
+    pub fn get_aggregations(&self) -> Option<&AggregationParams> {
+        self.aggregations.as_ref()
+    }
+
+    pub fn get_allowed_indexes(&self) -> Option<&[String]> {
+        self.allowed_indexes.as_deref()
+    }
+
+    pub fn is_wildcard(&self) -> bool {
+        matches!(self.indexes.first().map(String::as_str), Some("*"))
+    }
+
+    pub fn is_index_allowed(&self, index: &str) -> bool {
+        match &self.allowed_indexes {
+            None => true,
+            Some(patterns) => patterns
+                .iter()
+                .any(|it| is_index_pattern_matched(it, index)),
+        }
+    }
 }
 
 /// Enum representing different types of search operations.
@@ -73,12 +164,14 @@ impl SearchingParams {
 /// * `FullText` - Traditional full-text search with query string
 /// * `Semantic` - Vector-based semantic similarity search
 /// * `Hybrid` - Combination of full-text and semantic search
-#[derive(Debug)]
+/// * `Similar` - Search of documents similar to a stored one
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub enum SearchKindParams {
     Retrieve(RetrieveIndexDocumentsParams),
     FullText(FullTextSearchingParams),
     Semantic(SemanticSearchingParams),
     Hybrid(HybridSearchingParams),
+    Similar(SimilarSearchingParams),
 }
 
 impl Display for SearchKindParams {
@@ -88,6 +181,7 @@ impl Display for SearchKindParams {
             SearchKindParams::FullText(_) => "fulltext",
             SearchKindParams::Semantic(_) => "semantic",
             SearchKindParams::Hybrid(_) => "hybrid",
+            SearchKindParams::Similar(_) => "similar",
         };
         write!(fmt, "{}", kind)
     }
@@ -97,7 +191,7 @@ impl Display for SearchKindParams {
 ///
 /// # Fields
 /// * `path` - Optional file path to filter documents by
-#[derive(Debug, Builder)]
+#[derive(Clone, Debug, Builder, Deserialize, Serialize)]
 pub struct RetrieveIndexDocumentsParams {
     pub path: Option<String>,
 }
@@ -106,7 +200,7 @@ pub struct RetrieveIndexDocumentsParams {
 ///
 /// # Fields
 /// * `query` - Optional search query string
-#[derive(Debug, Builder)]
+#[derive(Clone, Debug, Builder, Deserialize, Serialize)]
 pub struct FullTextSearchingParams {
     pub query: Option<String>,
 }
@@ -130,7 +224,7 @@ pub struct FullTextSearchingParams {
 ///     tokens: None,
 /// };
 /// ```
-#[derive(Builder)]
+#[derive(Clone, Builder, Deserialize, Serialize)]
 pub struct SemanticSearchingParams {
     pub query: String,
     pub knn_amount: u16,
@@ -156,31 +250,226 @@ impl Debug for SemanticSearchingParams {
 /// * `knn_amount` - Number of nearest neighbors for semantic component
 /// * `min_score` - Minimum combined score threshold (optional)
 /// * `model_id` - Identifier of the embedding model to use (optional)
-#[derive(Builder)]
+/// * `scoring` - Combination of sub-query scores (optional, defaults of the search engine otherwise)
+/// * `pagination_depth` - Number of results retrieved by each sub-query (optional)
+/// * `fusion` - Fusion of sub-query results made by the application instead of
+///   the search engine (optional)
+#[derive(Clone, Builder, Deserialize, Serialize)]
 pub struct HybridSearchingParams {
     pub query: String,
     pub knn_amount: u16,
     pub min_score: Option<f32>,
     pub model_id: Option<String>,
+    #[builder(default)]
+    pub scoring: Option<HybridScoringParams>,
+    #[builder(default)]
+    pub pagination_depth: Option<usize>,
+    #[builder(default)]
+    pub fusion: Option<HybridFusion>,
 }
 
 impl Debug for HybridSearchingParams {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "query: {}, knn_amount: {}, min_score: {:?}, model_id: {:?}",
-            &self.query, &self.knn_amount, &self.min_score, &self.model_id,
+            "query: {}, knn_amount: {}, min_score: {:?}, model_id: {:?}, scoring: {:?}, pagination_depth: {:?}, fusion: {:?}",
+            &self.query,
+            &self.knn_amount,
+            &self.min_score,
+            &self.model_id,
+            &self.scoring,
+            &self.pagination_depth,
+            &self.fusion,
         )
     }
 }
 
+/// Parameters for searching documents similar to a stored one. Parts of
+/// the source document are excluded from results.
+///
+/// # Fields
+/// * `source_index` - Index where the source document is stored
+/// * `source` - Id of the source document or its single part
+/// * `mode` - Whether documents are compared by content terms or embeddings
+/// * `knn_amount` - Number of nearest neighbors for vector mode
+/// * `min_score` - Minimum similarity score threshold (optional)
+#[derive(Clone, Debug, Builder, Deserialize, Serialize)]
+pub struct SimilarSearchingParams {
+    pub source_index: String,
+    pub source: SimilarSource,
+    #[builder(default)]
+    pub mode: SimilarMode,
+    pub knn_amount: u16,
+    #[builder(default)]
+    pub min_score: Option<f32>,
+}
+
+/// Source document of similar documents searching: all parts of a large
+/// document or a single document part.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub enum SimilarSource {
+    LargeDocument(LargeDocumentId),
+    DocumentPart(DocumentPartId),
+}
+
+/// Comparison of documents by similar documents searching.
+///
+/// # Variants
+/// * `Lexical` - Content sharing the most significant terms of the source content
+/// * `Vector` - Nearest neighbours of the mean stored embedding of the source parts
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum SimilarMode {
+    #[default]
+    Lexical,
+    Vector,
+}
+
+impl Display for SimilarMode {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        let mode = match &self {
+            SimilarMode::Lexical => "lexical",
+            SimilarMode::Vector => "vector",
+        };
+        write!(fmt, "{}", mode)
+    }
+}
+
+/// Fusion of fulltext and semantic search results, which are retrieved
+/// separately, so any backend supporting both of them can search hybrid.
+///
+/// # Variants
+/// * `ReciprocalRank` - Sums reciprocal ranks of a document within results
+/// * `WeightedScore` - Sums min-max normalized scores multiplied by weights of `HybridScoringParams`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum HybridFusion {
+    ReciprocalRank,
+    WeightedScore,
+}
+
+const DEFAULT_SEMANTIC_WEIGHT: f32 = 0.3;
+const DEFAULT_LEXICAL_WEIGHT: f32 = 0.7;
+
+/// Parameters of combining semantic and lexical scores of hybrid search.
+/// Scores of each sub-query are normalized at first, then combined with
+/// passed weights, which are expected to sum up to `1.0`.
+///
+/// # Fields
+/// * `normalization` - Technique of sub-query scores normalization
+/// * `combination` - Technique of normalized scores combination
+/// * `semantic_weight` - Weight of the semantic sub-query score
+/// * `lexical_weight` - Weight of the lexical sub-query score
+#[derive(Clone, Debug, Builder, Deserialize, Serialize)]
+pub struct HybridScoringParams {
+    #[builder(default)]
+    pub normalization: ScoreNormalization,
+    #[builder(default)]
+    pub combination: ScoreCombination,
+    #[builder(default = "DEFAULT_SEMANTIC_WEIGHT")]
+    pub semantic_weight: f32,
+    #[builder(default = "DEFAULT_LEXICAL_WEIGHT")]
+    pub lexical_weight: f32,
+}
+
+impl Default for HybridScoringParams {
+    fn default() -> Self {
+        HybridScoringParams {
+            normalization: ScoreNormalization::default(),
+            combination: ScoreCombination::default(),
+            semantic_weight: DEFAULT_SEMANTIC_WEIGHT,
+            lexical_weight: DEFAULT_LEXICAL_WEIGHT,
+        }
+    }
+}
+
+/// Techniques of hybrid search sub-query scores normalization.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum ScoreNormalization {
+    #[default]
+    MinMax,
+    L2,
+}
+
+impl Display for ScoreNormalization {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        let technique = match &self {
+            ScoreNormalization::MinMax => "min_max",
+            ScoreNormalization::L2 => "l2",
+        };
+        write!(fmt, "{}", technique)
+    }
+}
+
+/// Techniques of hybrid search normalized scores combination.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum ScoreCombination {
+    #[default]
+    ArithmeticMean,
+    GeometricMean,
+    HarmonicMean,
+}
+
+impl Display for ScoreCombination {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        let technique = match &self {
+            ScoreCombination::ArithmeticMean => "arithmetic_mean",
+            ScoreCombination::GeometricMean => "geometric_mean",
+            ScoreCombination::HarmonicMean => "harmonic_mean",
+        };
+        write!(fmt, "{}", technique)
+    }
+}
+
+/// Parameters of search-as-you-type suggestions.
+///
+/// # Fields
+/// * `indexes` - Indexes to collect suggestions from, `*` for all of them
+/// * `query` - Text typed so far, its last word may be incomplete
+/// * `size` - Maximal amount of completions and of corrections each
+/// * `allowed_indexes` - Optional index patterns the `*` wildcard is restricted to
+#[derive(Debug, Builder)]
+pub struct SuggestParams {
+    pub indexes: SearchIndexes,
+    pub query: String,
+    pub size: usize,
+    #[builder(default)]
+    pub allowed_indexes: Option<Vec<String>>,
+}
+
+impl SuggestParams {
+    pub fn is_wildcard(&self) -> bool {
+        matches!(self.indexes.first().map(String::as_str), Some("*"))
+    }
+
+    pub fn is_index_allowed(&self, index: &str) -> bool {
+        match &self.allowed_indexes {
+            None => true,
+            Some(patterns) => patterns
+                .iter()
+                .any(|it| is_index_pattern_matched(it, index)),
+        }
+    }
+
+    /// Splits query into completed words and the last one being typed,
+    /// which is empty when query ends with whitespace.
+    pub fn split_last_word(&self) -> (&str, &str) {
+        let query = self.query.trim_start();
+        match query.rfind(char::is_whitespace) {
+            None => ("", query),
+            Some(position) => {
+                let (head, last) = query.split_at(position);
+                (head.trim_end(), last.trim_start())
+            }
+        }
+    }
+}
+
 /// Parameters for paginating through search results.
 ///
 /// # Fields
-/// * `scroll_id` - Scroll ID from previous search results for continuation
+/// * `cursor` - Opaque cursor returned with the previous page of search results
 #[derive(Debug, Builder)]
 pub struct PaginationParams {
-    pub scroll_id: String,
+    pub cursor: String,
 }
 
 /// Filter parameters for narrowing down search results.
@@ -222,7 +511,7 @@ pub struct PaginationParams {
 ///     doc_class_probability: None,
 /// };
 /// ```
-#[derive(Clone, Debug, Builder)]
+#[derive(Clone, Debug, Builder, Deserialize, Serialize)]
 pub struct FilterParams {
     pub doc_part_id: Option<usize>,
     pub size_from: Option<u32>,
@@ -247,6 +536,78 @@ pub struct FilterParams {
     pub doc_class_probability: Option<f64>,
 }
 
+impl FilterParams {
+    /// Parses location filter of `location_coords` passed as longitude and
+    /// latitude with `distance` radius, `DEFAULT_DISTANCE` by default.
+    pub fn location_filter(&self) -> anyhow::Result<Option<LocationFilter>> {
+        let Some(coords) = self.location_coords.as_deref() else {
+            return Ok(None);
+        };
+
+        let [longitude, latitude] = coords else {
+            anyhow::bail!("location coords must contain longitude and latitude");
+        };
+
+        let distance = self.distance.as_deref().unwrap_or(DEFAULT_DISTANCE);
+        let radius = parse_distance(distance)?;
+        Ok(Some(LocationFilter {
+            longitude: *longitude,
+            latitude: *latitude,
+            radius,
+        }))
+    }
+
+    /// Checks whether metadata has a class with `doc_class` name and with
+    /// probability not lower than `doc_class_probability` if they are set.
+    pub fn match_class(&self, metadata: Option<&DocumentMetadata>) -> bool {
+        if self.doc_class.is_none() && self.doc_class_probability.is_none() {
+            return true;
+        }
+
+        metadata.is_some_and(|meta| {
+            meta.classes.iter().any(|class| {
+                let is_same_class = self.doc_class.as_ref().is_none_or(|it| it.eq(&class.name));
+                let is_probable = self
+                    .doc_class_probability
+                    .is_none_or(|it| class.probability >= it);
+
+                is_same_class && is_probable
+            })
+        })
+    }
+}
+
+/// Geo distance filter parsed from `FilterParams`.
+///
+/// # Fields
+/// * `longitude` - Longitude of the center in degrees
+/// * `latitude` - Latitude of the center in degrees
+/// * `radius` - Maximum distance from the center in meters
+#[derive(Clone, Debug)]
+pub struct LocationFilter {
+    pub longitude: f64,
+    pub latitude: f64,
+    pub radius: f64,
+}
+
+impl LocationFilter {
+    /// Checks whether any location of metadata is within the radius.
+    pub fn match_locations(&self, metadata: Option<&DocumentMetadata>) -> bool {
+        metadata.is_some_and(|meta| {
+            meta.locations.iter().any(|location| {
+                let distance = haversine_distance(
+                    self.latitude,
+                    self.longitude,
+                    location.latitude,
+                    location.longitude,
+                );
+
+                distance <= self.radius
+            })
+        })
+    }
+}
+
 /// Parameters for controlling result pagination and formatting.
 ///
 /// # Fields
@@ -256,6 +617,10 @@ pub struct FilterParams {
 /// * `highlight_items` - Maximum number of highlight fragments per document
 /// * `highlight_item_size` - Maximum size of each highlight fragment
 /// * `include_extra_fields` - Whether to include additional metadata fields
+/// * `group_by_document` - Whether to collapse parts of the same large document
+///   into a single result
+/// * `sort` - Sort keys applied in turn, overriding default sorting by `order`
+/// * `track_total_hits` - Whether to count all matched results exactly
 ///
 /// # Example
 /// ```
@@ -266,9 +631,12 @@ pub struct FilterParams {
 ///     highlight_items: Some(3),
 ///     highlight_item_size: Some(100),
 ///     include_extra_fields: Some(true),
+///     group_by_document: None,
+///     sort: vec![SortParams::new(SortField::Score, ResultOrder::DESC)],
+///     track_total_hits: None,
 /// };
 /// ```
-#[derive(Clone, Default, Debug, Builder)]
+#[derive(Clone, Default, Debug, Builder, Deserialize, Serialize)]
 pub struct ResultParams {
     pub size: i64,
     pub offset: i64,
@@ -276,6 +644,42 @@ pub struct ResultParams {
     pub highlight_items: Option<u16>,
     pub highlight_item_size: Option<u32>,
     pub include_extra_fields: Option<bool>,
+    #[builder(default)]
+    pub group_by_document: Option<bool>,
+    #[builder(default)]
+    pub sort: Vec<SortParams>,
+    #[builder(default)]
+    pub track_total_hits: Option<bool>,
+}
+
+impl ResultParams {
+    pub fn is_grouped_by_document(&self) -> bool {
+        self.group_by_document.unwrap_or_default()
+    }
+
+    pub fn is_total_hits_tracked(&self) -> bool {
+        self.track_total_hits.unwrap_or_default()
+    }
+
+    /// Returns requested sort keys or sorting by relevance, where equally
+    /// relevant results are sorted by creation date in `order` direction.
+    pub fn relevance_sort(&self) -> Vec<SortParams> {
+        match self.sort.is_empty() {
+            false => self.sort.clone(),
+            true => vec![
+                SortParams::new(SortField::Score, ResultOrder::DESC),
+                SortParams::new(SortField::CreatedAt, self.order.clone()),
+            ],
+        }
+    }
+
+    /// Returns requested sort keys or sorting by creation date in `order` direction.
+    pub fn date_sort(&self) -> Vec<SortParams> {
+        match self.sort.is_empty() {
+            false => self.sort.clone(),
+            true => vec![SortParams::new(SortField::CreatedAt, self.order.clone())],
+        }
+    }
 }
 
 /// Sort order for search results.
@@ -283,9 +687,143 @@ pub struct ResultParams {
 /// # Variants
 /// * `ASC` - Ascending order (oldest/smallest first)
 /// * `DESC` - Descending order (newest/largest first) - default
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
 pub enum ResultOrder {
     ASC,
     #[default]
     DESC,
 }
+
+/// Sort key of search results: field and its own direction.
+///
+/// Documents missing the sorting field value are placed last
+/// regardless of direction.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SortParams {
+    pub field: SortField,
+    pub order: ResultOrder,
+}
+
+impl SortParams {
+    pub fn new(field: SortField, order: ResultOrder) -> Self {
+        SortParams { field, order }
+    }
+}
+
+/// Fields available to sort search results by.
+///
+/// # Variants
+/// * `Score` - Relevance score of the document part
+/// * `CreatedAt` - File creation date
+/// * `ModifiedAt` - File modification date
+/// * `FileSize` - File size in bytes
+/// * `FileName` - File name compared as a whole keyword
+/// * `PipelineId` - Identifier of the pipeline which processed the document
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum SortField {
+    Score,
+    CreatedAt,
+    ModifiedAt,
+    FileSize,
+    FileName,
+    PipelineId,
+}
+
+impl Display for SortField {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        let field = match &self {
+            SortField::Score => "_score",
+            SortField::CreatedAt => "created_at",
+            SortField::ModifiedAt => "modified_at",
+            SortField::FileSize => "file_size",
+            SortField::FileName => "file_name.keyword",
+            SortField::PipelineId => "metadata.pipeline_id",
+        };
+        write!(fmt, "{}", field)
+    }
+}
+
+/// Default amount of buckets returned for each terms facet.
+pub const DEFAULT_FACET_SIZE: u32 = 10;
+
+/// Parameters of facets computed along with search results.
+///
+/// # Fields
+/// * `facets` - Kinds of facets to compute
+/// * `size` - Maximum amount of buckets for each terms facet
+/// * `interval` - Bucket interval of the created date histogram
+///
+/// # Example
+/// ```
+/// let aggregations = AggregationParams {
+///     facets: vec![FacetKind::Source, FacetKind::CreatedAt],
+///     size: 20,
+///     interval: HistogramInterval::Month,
+/// };
+/// ```
+#[derive(Clone, Debug, Builder, Deserialize, Serialize)]
+pub struct AggregationParams {
+    pub facets: Vec<FacetKind>,
+    #[builder(default = "DEFAULT_FACET_SIZE")]
+    pub size: u32,
+    #[builder(default)]
+    pub interval: HistogramInterval,
+}
+
+/// Kinds of facets available for search results.
+///
+/// # Variants
+/// * `Source` - Counts by `metadata.source`
+/// * `SemanticSource` - Counts by `metadata.semantic_source`
+/// * `Classes` - Counts by document class names
+/// * `Subjects` - Counts by document subjects
+/// * `Groups` - Counts by document groups
+/// * `FileExtension` - Counts by extension of the file name
+/// * `CreatedAt` - Histogram of document creation dates
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum FacetKind {
+    Source,
+    SemanticSource,
+    Classes,
+    Subjects,
+    Groups,
+    FileExtension,
+    CreatedAt,
+}
+
+impl Display for FacetKind {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        let kind = match &self {
+            FacetKind::Source => "source",
+            FacetKind::SemanticSource => "semantic_source",
+            FacetKind::Classes => "classes",
+            FacetKind::Subjects => "subjects",
+            FacetKind::Groups => "groups",
+            FacetKind::FileExtension => "file_extension",
+            FacetKind::CreatedAt => "created_at",
+        };
+        write!(fmt, "{}", kind)
+    }
+}
+
+/// Calendar interval of date histogram facets.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum HistogramInterval {
+    Day,
+    Week,
+    #[default]
+    Month,
+    Year,
+}
+
+impl Display for HistogramInterval {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        let interval = match &self {
+            HistogramInterval::Day => "day",
+            HistogramInterval::Week => "week",
+            HistogramInterval::Month => "month",
+            HistogramInterval::Year => "year",
+        };
+        write!(fmt, "{}", interval)
+    }
+}
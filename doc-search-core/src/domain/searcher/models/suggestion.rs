@@ -0,0 +1,55 @@
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+
+/// Suggestions for the text typed into a search box.
+///
+/// # Fields
+/// * `completions` - Texts completing the last typed word by file names or content terms
+/// * `corrections` - Query texts with misspelled words replaced ("did you mean")
+#[derive(Clone, Debug, Default)]
+pub struct Suggestions {
+    pub completions: Vec<Suggestion>,
+    pub corrections: Vec<Suggestion>,
+}
+
+impl Suggestions {
+    /// Sorts suggestions by score and keeps first `size` ones of distinct texts.
+    pub fn new(completions: Vec<Suggestion>, corrections: Vec<Suggestion>, size: usize) -> Self {
+        Suggestions {
+            completions: Self::select_best(completions, size),
+            corrections: Self::select_best(corrections, size),
+        }
+    }
+
+    fn select_best(mut suggestions: Vec<Suggestion>, size: usize) -> Vec<Suggestion> {
+        suggestions.sort_by(|a, b| b.score.total_cmp(&a.score));
+        let mut texts = HashSet::new();
+        suggestions.retain(|it| texts.insert(it.text.to_lowercase()));
+        suggestions.truncate(size);
+        suggestions
+    }
+}
+
+/// Single suggested text with its relevance score and source field.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Suggestion {
+    pub text: String,
+    pub score: f64,
+    pub field: SuggestField,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SuggestField {
+    FileName,
+    Content,
+}
+
+impl Display for SuggestField {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        let field = match self {
+            SuggestField::FileName => "file_name",
+            SuggestField::Content => "content",
+        };
+        write!(fmt, "{}", field)
+    }
+}
@@ -0,0 +1,176 @@
+mod parser;
+pub use parser::parse_query;
+
+use std::fmt::{Display, Formatter};
+use std::ops::Bound;
+
+/// Backend-neutral syntax tree of a fulltext query string.
+///
+/// Produced by [`parse_query`] and translated by every searcher backend
+/// into its own query language.
+///
+/// # Example
+/// ```
+/// let node = parse_query("\"exact phrase\" AND source:bbc -draft file_name:report*")?;
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum QueryNode {
+    Bool(BoolQuery),
+    Term(QueryTerm),
+}
+
+/// Boolean combination of query clauses.
+///
+/// Document matches when it matches all `must` clauses and none of `must_not`
+/// ones. `should` clauses are optional if there are `must` clauses, otherwise
+/// at least one of them has to match.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BoolQuery {
+    pub must: Vec<QueryNode>,
+    pub should: Vec<QueryNode>,
+    pub must_not: Vec<QueryNode>,
+}
+
+/// Single query term scoped onto a document field.
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueryTerm {
+    pub field: QueryField,
+    pub value: QueryValue,
+}
+
+/// Value of query term.
+///
+/// # Variants
+/// * `Word` - Single word matched by analyzed field terms
+/// * `Phrase` - Words matched in the same order one by one
+/// * `Wildcard` - Pattern with `*` and `?`, escaped by backslash
+/// * `Fuzzy` - Word matched within edit distance
+/// * `Range` - Range of numeric or date (unix timestamp) field values
+#[derive(Clone, Debug, PartialEq)]
+pub enum QueryValue {
+    Word(String),
+    Phrase(String),
+    Wildcard(String),
+    Fuzzy { word: String, distance: u8 },
+    Range(QueryRange),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueryRange {
+    pub from: Bound<i64>,
+    pub to: Bound<i64>,
+}
+
+impl QueryRange {
+    pub fn contains(&self, value: i64) -> bool {
+        let is_above = match self.from {
+            Bound::Included(from) => value >= from,
+            Bound::Excluded(from) => value > from,
+            Bound::Unbounded => true,
+        };
+
+        let is_below = match self.to {
+            Bound::Included(to) => value <= to,
+            Bound::Excluded(to) => value < to,
+            Bound::Unbounded => true,
+        };
+
+        is_above && is_below
+    }
+}
+
+/// Document fields available for scoping query terms by `field:value`.
+/// Terms without field are matched by document content.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum QueryField {
+    #[default]
+    Content,
+    FileName,
+    FilePath,
+    Source,
+    SemanticSource,
+    Summary,
+    CreatedAt,
+    ModifiedAt,
+    FileSize,
+    PipelineId,
+}
+
+impl QueryField {
+    pub const ALL: [QueryField; 10] = [
+        QueryField::Content,
+        QueryField::FileName,
+        QueryField::FilePath,
+        QueryField::Source,
+        QueryField::SemanticSource,
+        QueryField::Summary,
+        QueryField::CreatedAt,
+        QueryField::ModifiedAt,
+        QueryField::FileSize,
+        QueryField::PipelineId,
+    ];
+
+    /// Numeric fields accept exact values and ranges only.
+    pub fn is_numeric(&self) -> bool {
+        matches!(
+            self,
+            QueryField::CreatedAt
+                | QueryField::ModifiedAt
+                | QueryField::FileSize
+                | QueryField::PipelineId
+        )
+    }
+
+    pub fn is_date(&self) -> bool {
+        matches!(self, QueryField::CreatedAt | QueryField::ModifiedAt)
+    }
+}
+
+impl Display for QueryField {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        let field = match self {
+            QueryField::Content => "content",
+            QueryField::FileName => "file_name",
+            QueryField::FilePath => "file_path",
+            QueryField::Source => "source",
+            QueryField::SemanticSource => "semantic_source",
+            QueryField::Summary => "summary",
+            QueryField::CreatedAt => "created_at",
+            QueryField::ModifiedAt => "modified_at",
+            QueryField::FileSize => "file_size",
+            QueryField::PipelineId => "pipeline_id",
+        };
+        write!(fmt, "{}", field)
+    }
+}
+
+impl QueryNode {
+    /// Returns words of query without any syntax, so backends may keep
+    /// their usual matching of plain text queries.
+    pub fn plain_text(&self) -> Option<String> {
+        let plain_word = |node: &QueryNode| match node {
+            QueryNode::Term(QueryTerm {
+                field: QueryField::Content,
+                value: QueryValue::Word(word),
+            }) => Some(word.clone()),
+            _ => None,
+        };
+
+        match self {
+            QueryNode::Term(_) => plain_word(self),
+            QueryNode::Bool(query) => {
+                if !query.must.is_empty() || !query.must_not.is_empty() {
+                    return None;
+                }
+
+                let words = query
+                    .should
+                    .iter()
+                    .map(plain_word)
+                    .collect::<Option<Vec<String>>>()?;
+
+                Some(words.join(" "))
+            }
+        }
+    }
+}
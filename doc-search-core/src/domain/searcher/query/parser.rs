@@ -0,0 +1,584 @@
+use anyhow::anyhow;
+use std::iter::Peekable;
+use std::ops::Bound;
+use std::str::CharIndices;
+
+use crate::domain::searcher::query::{BoolQuery, QueryField, QueryNode, QueryRange};
+use crate::domain::searcher::query::{QueryTerm, QueryValue};
+use crate::domain::searcher::{SearchError, SearchResult};
+
+const DEFAULT_FUZZY_DISTANCE: u8 = 2;
+const MAX_FUZZY_DISTANCE: u8 = 2;
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+const MAX_NESTING_DEPTH: usize = 32;
+
+/// Parses query string into syntax tree.
+///
+/// Supported syntax:
+/// * `word`, `"exact phrase"` - terms matched by content by default
+/// * `AND` / `&&`, `OR` / `||`, `NOT` / `!`, `+required`, `-excluded`
+///   and `( ... )` groups, juxtaposed terms are combined by `OR`
+/// * `field:value`, `field:( ... )` - terms scoped onto document field
+/// * `report*`, `rep?rt` - wildcards, `smith~`, `smith~1` - fuzzy terms
+/// * `created_at:[2024-01-01 TO 2024-12-31]`, `file_size:{* TO 1024}`,
+///   `file_size:>=1024` - ranges of numeric and date fields
+///
+/// Syntax mistakes are returned as validation error with the position,
+/// groups are nested up to `MAX_NESTING_DEPTH` levels.
+pub fn parse_query(query: &str) -> SearchResult<QueryNode> {
+    let tokens = Lexer::new(query).tokenize()?;
+    let mut parser = Parser {
+        tokens,
+        position: 0,
+        depth: 0,
+        end: query.len(),
+    };
+
+    let node = parser.parse_clauses(QueryField::default())?;
+    match parser.next() {
+        None => Ok(node),
+        Some((pos, _)) => Err(syntax_error(pos, "unexpected closing parenthesis")),
+    }
+}
+
+fn syntax_error(pos: usize, msg: &str) -> SearchError {
+    SearchError::ValidationError(anyhow!("invalid query at position {pos}: {msg}"))
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    LeftParen,
+    RightParen,
+    RangeStart { inclusive: bool },
+    RangeEnd { inclusive: bool },
+    And,
+    Or,
+    Not,
+    Plus,
+    Minus,
+    To,
+    Field(String),
+    Phrase(String),
+    Word(Word),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Word {
+    /// Unescaped word, or the raw pattern keeping escapes for wildcards.
+    text: String,
+    is_wildcard: bool,
+    fuzziness: Option<u8>,
+}
+
+struct Lexer<'a> {
+    chars: Peekable<CharIndices<'a>>,
+}
+
+impl<'a> Lexer<'a> {
+    fn new(query: &'a str) -> Self {
+        Lexer {
+            chars: query.char_indices().peekable(),
+        }
+    }
+
+    fn tokenize(mut self) -> SearchResult<Vec<(usize, Token)>> {
+        let mut tokens = Vec::new();
+        while let Some((pos, ch)) = self.chars.next() {
+            let token = match ch {
+                _ if ch.is_whitespace() => continue,
+                '(' => Token::LeftParen,
+                ')' => Token::RightParen,
+                '[' => Token::RangeStart { inclusive: true },
+                '{' => Token::RangeStart { inclusive: false },
+                ']' => Token::RangeEnd { inclusive: true },
+                '}' => Token::RangeEnd { inclusive: false },
+                '"' => Token::Phrase(self.read_phrase(pos)?),
+                '+' | '-' | '!' => {
+                    if self
+                        .chars
+                        .peek()
+                        .is_none_or(|(_, next)| next.is_whitespace())
+                    {
+                        return Err(syntax_error(pos, &format!("expected term after '{ch}'")));
+                    }
+
+                    match ch {
+                        '+' => Token::Plus,
+                        '-' => Token::Minus,
+                        _ => Token::Not,
+                    }
+                }
+                '&' | '|' => {
+                    if self.chars.next_if(|(_, next)| *next == ch).is_none() {
+                        return Err(syntax_error(pos, &format!("expected '{ch}{ch}' operator")));
+                    }
+
+                    match ch {
+                        '&' => Token::And,
+                        _ => Token::Or,
+                    }
+                }
+                _ => self.read_word(pos, ch)?,
+            };
+
+            tokens.push((pos, token));
+        }
+
+        Ok(tokens)
+    }
+
+    fn read_phrase(&mut self, start: usize) -> SearchResult<String> {
+        let mut phrase = String::new();
+        loop {
+            match self.chars.next() {
+                None => return Err(syntax_error(start, "unterminated phrase")),
+                Some((_, '"')) => break,
+                Some((pos, '\\')) => match self.chars.next() {
+                    None => return Err(syntax_error(pos, "unterminated escape sequence")),
+                    Some((_, escaped)) => phrase.push(escaped),
+                },
+                Some((_, ch)) => phrase.push(ch),
+            }
+        }
+
+        if let Some((pos, '~')) = self.chars.peek() {
+            return Err(syntax_error(*pos, "proximity search is not supported"));
+        }
+
+        Ok(phrase)
+    }
+
+    fn read_word(&mut self, start: usize, first: char) -> SearchResult<Token> {
+        let mut text = String::new();
+        let mut pattern = String::new();
+        let mut is_escaped = false;
+        let mut is_wildcard = false;
+        let mut fuzziness = None;
+
+        let mut current = Some((start, first));
+        while let Some((pos, ch)) = current {
+            match ch {
+                '\\' => {
+                    let Some((_, escaped)) = self.chars.next() else {
+                        return Err(syntax_error(pos, "unterminated escape sequence"));
+                    };
+
+                    is_escaped = true;
+                    text.push(escaped);
+                    pattern.push('\\');
+                    pattern.push(escaped);
+                }
+                ':' => {
+                    let is_field_name = !text.is_empty()
+                        && !is_escaped
+                        && !is_wildcard
+                        && text
+                            .chars()
+                            .all(|it| it.is_ascii_alphanumeric() || it == '_');
+
+                    if !is_field_name {
+                        return Err(syntax_error(pos, "unexpected ':', escape it by '\\'"));
+                    }
+
+                    return Ok(Token::Field(text));
+                }
+                '~' => {
+                    fuzziness = Some(self.read_fuzziness(pos)?);
+                    break;
+                }
+                '*' | '?' => {
+                    is_wildcard = true;
+                    text.push(ch);
+                    pattern.push(ch);
+                }
+                _ => {
+                    text.push(ch);
+                    pattern.push(ch);
+                }
+            }
+
+            current = self.chars.next_if(|(_, next)| !is_word_boundary(*next));
+        }
+
+        if let Some((pos, ch)) = self.chars.peek().filter(|(_, it)| !is_word_boundary(*it)) {
+            return Err(syntax_error(*pos, &format!("unexpected '{ch}'")));
+        }
+
+        let token = match text.as_str() {
+            _ if is_escaped => None,
+            "AND" => Some(Token::And),
+            "OR" => Some(Token::Or),
+            "NOT" => Some(Token::Not),
+            "TO" => Some(Token::To),
+            _ => None,
+        };
+
+        if let Some(token) = token {
+            return Ok(token);
+        }
+
+        if is_wildcard && fuzziness.is_some() {
+            return Err(syntax_error(start, "wildcard term can not be fuzzy"));
+        }
+
+        Ok(Token::Word(Word {
+            text: if is_wildcard { pattern } else { text },
+            is_wildcard,
+            fuzziness,
+        }))
+    }
+
+    fn read_fuzziness(&mut self, start: usize) -> SearchResult<u8> {
+        let mut digits = String::new();
+        while let Some((_, ch)) = self.chars.next_if(|(_, it)| it.is_ascii_digit()) {
+            digits.push(ch);
+        }
+
+        if digits.is_empty() {
+            return Ok(DEFAULT_FUZZY_DISTANCE);
+        }
+
+        match digits.parse::<u8>() {
+            Ok(distance) if distance <= MAX_FUZZY_DISTANCE => Ok(distance),
+            _ => {
+                let msg = format!("fuzzy distance must be within [0, {MAX_FUZZY_DISTANCE}]");
+                Err(syntax_error(start, &msg))
+            }
+        }
+    }
+}
+
+fn is_word_boundary(ch: char) -> bool {
+    ch.is_whitespace() || matches!(ch, '(' | ')' | '[' | ']' | '{' | '}' | '"')
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Occur {
+    Must,
+    Should,
+    MustNot,
+}
+
+struct Parser {
+    tokens: Vec<(usize, Token)>,
+    position: usize,
+    depth: usize,
+    end: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position).map(|(_, token)| token)
+    }
+
+    fn next(&mut self) -> Option<(usize, Token)> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn current_position(&self) -> usize {
+        self.tokens
+            .get(self.position)
+            .map_or(self.end, |(pos, _)| *pos)
+    }
+
+    /// Combines clauses like lucene classic query parser: `AND` makes both
+    /// sides required, `+` and `-` modifiers make clause required or excluded
+    /// and other clauses are optional.
+    fn parse_clauses(&mut self, field: QueryField) -> SearchResult<QueryNode> {
+        let mut clauses: Vec<(Occur, QueryNode)> = Vec::new();
+        loop {
+            let is_group_end = matches!(self.peek(), None | Some(Token::RightParen));
+            if is_group_end {
+                break;
+            }
+
+            let conjunction = match self.peek() {
+                Some(Token::And | Token::Or) => {
+                    let (pos, token) = self.next().expect("token has been peeked");
+                    if clauses.is_empty() {
+                        return Err(syntax_error(pos, "expected term before operator"));
+                    }
+                    Some(token)
+                }
+                _ => None,
+            };
+
+            let pos = self.current_position();
+            let modifier = match self.peek() {
+                Some(Token::Plus) => Some(Occur::Must),
+                Some(Token::Minus | Token::Not) => Some(Occur::MustNot),
+                _ => None,
+            };
+
+            if modifier.is_some() {
+                self.next();
+            }
+
+            if matches!(self.peek(), None | Some(Token::RightParen)) {
+                return Err(syntax_error(pos, "expected term"));
+            }
+
+            let node = self.parse_primary(field)?;
+            let is_and = conjunction == Some(Token::And);
+            if let Some((occur, _)) = clauses.last_mut().filter(|_| is_and)
+                && *occur != Occur::MustNot
+            {
+                *occur = Occur::Must;
+            }
+
+            let occur = match (modifier, is_and) {
+                (Some(occur), _) => occur,
+                (None, true) => Occur::Must,
+                (None, false) => Occur::Should,
+            };
+
+            clauses.push((occur, node));
+        }
+
+        if clauses.is_empty() {
+            return Err(syntax_error(self.current_position(), "expected term"));
+        }
+
+        if clauses.len() == 1 && clauses[0].0 != Occur::MustNot {
+            let (_, node) = clauses.pop().expect("clause exists");
+            return Ok(node);
+        }
+
+        let mut query = BoolQuery::default();
+        for (occur, node) in clauses {
+            match occur {
+                Occur::Must => query.must.push(node),
+                Occur::Should => query.should.push(node),
+                Occur::MustNot => query.must_not.push(node),
+            }
+        }
+
+        Ok(QueryNode::Bool(query))
+    }
+
+    fn parse_primary(&mut self, field: QueryField) -> SearchResult<QueryNode> {
+        let Some((pos, token)) = self.next() else {
+            return Err(syntax_error(self.end, "expected term"));
+        };
+
+        match token {
+            Token::Field(name) => {
+                if field != QueryField::default() {
+                    return Err(syntax_error(pos, "field scopes can not be nested"));
+                }
+
+                let field = parse_field(pos, &name)?;
+                match self.peek() {
+                    None => Err(syntax_error(self.end, "expected value of field")),
+                    Some(Token::Field(_)) => Err(syntax_error(pos, "expected value of field")),
+                    Some(_) => self.parse_primary(field),
+                }
+            }
+            Token::LeftParen => {
+                if self.depth >= MAX_NESTING_DEPTH {
+                    return Err(syntax_error(pos, "query is nested too deeply"));
+                }
+
+                self.depth += 1;
+                let node = self.parse_clauses(field)?;
+                self.depth -= 1;
+                match self.next() {
+                    Some((_, Token::RightParen)) => Ok(node),
+                    _ => Err(syntax_error(pos, "unclosed parenthesis")),
+                }
+            }
+            Token::Phrase(phrase) => {
+                if field.is_numeric() {
+                    let msg = format!("field {field} does not support phrases");
+                    return Err(syntax_error(pos, &msg));
+                }
+
+                Ok(build_term(field, QueryValue::Phrase(phrase)))
+            }
+            Token::Word(word) => parse_word(pos, field, word),
+            Token::RangeStart { inclusive } => self.parse_range(pos, field, inclusive),
+            _ => Err(syntax_error(pos, "expected term")),
+        }
+    }
+
+    fn parse_range(
+        &mut self,
+        start: usize,
+        field: QueryField,
+        include_from: bool,
+    ) -> SearchResult<QueryNode> {
+        if !field.is_numeric() {
+            let msg = format!("field {field} does not support ranges");
+            return Err(syntax_error(start, &msg));
+        }
+
+        let from = self.parse_range_bound(field, include_from, false)?;
+        match self.next() {
+            Some((_, Token::To)) => {}
+            _ => return Err(syntax_error(start, "expected 'TO' within range")),
+        }
+
+        let pos = self.current_position();
+        let include_to = match self.tokens.get(self.position + 1) {
+            Some((_, Token::RangeEnd { inclusive })) => *inclusive,
+            _ => return Err(syntax_error(pos, "unclosed range")),
+        };
+
+        let to = self.parse_range_bound(field, include_to, true)?;
+        self.next();
+
+        let range = QueryRange { from, to };
+        Ok(build_term(field, QueryValue::Range(range)))
+    }
+
+    fn parse_range_bound(
+        &mut self,
+        field: QueryField,
+        inclusive: bool,
+        is_upper: bool,
+    ) -> SearchResult<Bound<i64>> {
+        match self.next() {
+            Some((_, Token::Word(word))) if word.text == "*" => Ok(Bound::Unbounded),
+            Some((pos, Token::Word(word))) if !word.is_wildcard && word.fuzziness.is_none() => {
+                let value = parse_numeric(pos, field, &word.text, is_upper == inclusive)?;
+                match inclusive {
+                    true => Ok(Bound::Included(value)),
+                    false => Ok(Bound::Excluded(value)),
+                }
+            }
+            Some((pos, _)) => Err(syntax_error(pos, "expected range bound")),
+            None => Err(syntax_error(self.end, "unclosed range")),
+        }
+    }
+}
+
+fn build_term(field: QueryField, value: QueryValue) -> QueryNode {
+    QueryNode::Term(QueryTerm { field, value })
+}
+
+fn parse_field(pos: usize, name: &str) -> SearchResult<QueryField> {
+    QueryField::ALL
+        .into_iter()
+        .find(|it| it.to_string() == name)
+        .ok_or_else(|| {
+            let fields = QueryField::ALL.map(|it| it.to_string()).join(", ");
+            let msg = format!("unknown field {name}, expected one of: {fields}");
+            syntax_error(pos, &msg)
+        })
+}
+
+fn parse_word(pos: usize, field: QueryField, word: Word) -> SearchResult<QueryNode> {
+    if !field.is_numeric() {
+        let value = match word.fuzziness {
+            Some(distance) => QueryValue::Fuzzy {
+                word: word.text,
+                distance,
+            },
+            None if word.is_wildcard => QueryValue::Wildcard(word.text),
+            None => QueryValue::Word(word.text),
+        };
+
+        return Ok(build_term(field, value));
+    }
+
+    if word.is_wildcard || word.fuzziness.is_some() {
+        let msg = format!("field {field} supports exact values and ranges only");
+        return Err(syntax_error(pos, &msg));
+    }
+
+    let text = word.text.as_str();
+    let range = if let Some(value) = text.strip_prefix(">=") {
+        let from = parse_numeric(pos, field, value, false)?;
+        QueryRange {
+            from: Bound::Included(from),
+            to: Bound::Unbounded,
+        }
+    } else if let Some(value) = text.strip_prefix('>') {
+        let from = parse_numeric(pos, field, value, true)?;
+        QueryRange {
+            from: Bound::Excluded(from),
+            to: Bound::Unbounded,
+        }
+    } else if let Some(value) = text.strip_prefix("<=") {
+        let to = parse_numeric(pos, field, value, true)?;
+        QueryRange {
+            from: Bound::Unbounded,
+            to: Bound::Included(to),
+        }
+    } else if let Some(value) = text.strip_prefix('<') {
+        let to = parse_numeric(pos, field, value, false)?;
+        QueryRange {
+            from: Bound::Unbounded,
+            to: Bound::Excluded(to),
+        }
+    } else {
+        QueryRange {
+            from: Bound::Included(parse_numeric(pos, field, text, false)?),
+            to: Bound::Included(parse_numeric(pos, field, text, true)?),
+        }
+    };
+
+    Ok(build_term(field, QueryValue::Range(range)))
+}
+
+/// Parses value of numeric field. Date fields accept unix timestamps and
+/// `YYYY-MM-DD` dates, which are rounded up to the end of the day when
+/// `is_day_end` is set, so the whole day is covered by inclusive bounds.
+fn parse_numeric(
+    pos: usize,
+    field: QueryField,
+    value: &str,
+    is_day_end: bool,
+) -> SearchResult<i64> {
+    if let Ok(number) = value.parse::<i64>() {
+        return Ok(number);
+    }
+
+    let date = field.is_date().then(|| parse_date(value)).flatten();
+    match date {
+        Some(day_start) if is_day_end => Ok(day_start + SECONDS_PER_DAY - 1),
+        Some(day_start) => Ok(day_start),
+        None if field.is_date() => {
+            let msg = format!("field {field} expects timestamp or YYYY-MM-DD date, got '{value}'");
+            Err(syntax_error(pos, &msg))
+        }
+        None => {
+            let msg = format!("field {field} expects integer value, got '{value}'");
+            Err(syntax_error(pos, &msg))
+        }
+    }
+}
+
+/// Returns unix timestamp of `YYYY-MM-DD` date start in UTC.
+fn parse_date(value: &str) -> Option<i64> {
+    let mut parts = value.splitn(3, '-').map(str::parse::<i64>);
+    let (Some(Ok(year)), Some(Ok(month)), Some(Ok(day))) =
+        (parts.next(), parts.next(), parts.next())
+    else {
+        return None;
+    };
+
+    let is_leap = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+    let days_in_month = match month {
+        2 if is_leap => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        1..=12 => 31,
+        _ => return None,
+    };
+
+    if !(1..=days_in_month).contains(&day) {
+        return None;
+    }
+
+    // Days from civil algorithm counting days since 1970-01-01.
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = if year >= 0 { year } else { year - 399 } / 400;
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146097 + day_of_era - 719468;
+    Some(days * SECONDS_PER_DAY)
+}
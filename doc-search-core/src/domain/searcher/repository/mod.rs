@@ -1,6 +1,7 @@
 use crate::domain::searcher::SearchResult;
 use crate::domain::searcher::models::PaginationParams;
 use crate::domain::searcher::models::{Pagination, SearchingParams};
+use crate::domain::searcher::models::{SuggestParams, Suggestions};
 
 /// Trait for performing search operations.
 ///
@@ -9,12 +10,14 @@ use crate::domain::searcher::models::{Pagination, SearchingParams};
 ///
 /// # Methods
 /// * `search` - Performs a search based on provided parameters
+/// * `suggest` - Completes and corrects the text typed into search box
 ///
 /// # Arguments
 /// * `params` - Configuration parameters for the search operation
 ///
 /// # Returns
 /// * `SearchResult<Pagination>` - Paginated search results or error
+/// * `SearchResult<Suggestions>` - Completions and corrections of query or error
 ///
 /// # Example
 /// ```
@@ -23,7 +26,7 @@ use crate::domain::searcher::models::{Pagination, SearchingParams};
 ///     async fn search(&self, params: &SearchingParams) -> SearchResult<Pagination> {
 ///         // Implementation logic here
 ///         Ok(Pagination {
-///             scroll_id: None,
+///             cursor: None,
 ///             founded: vec![],
 ///         })
 ///     }
@@ -32,6 +35,7 @@ use crate::domain::searcher::models::{Pagination, SearchingParams};
 #[async_trait::async_trait]
 pub trait ISearcher {
     async fn search(&self, params: &SearchingParams) -> SearchResult<Pagination>;
+    async fn suggest(&self, params: &SuggestParams) -> SearchResult<Suggestions>;
 }
 
 /// Trait for paginating through search results.
@@ -40,14 +44,19 @@ pub trait ISearcher {
 /// retrieving subsequent pages of search results.
 ///
 /// # Methods
-/// * `paginate` - Retrieves the next page of results using a scroll ID
+/// * `paginate` - Retrieves the next page of results by an opaque cursor
+/// * `release` - Releases resources kept by the cursor before it expires
 ///
 /// # Arguments
-/// * `params` - Parameters containing the scroll ID for pagination
+/// * `params` - Parameters containing the cursor returned with previous page
 ///
 /// # Returns
 /// * `SearchResult<Pagination>` - Next page of search results or error
 ///
+/// # Notes
+/// - Cursor keeps the sort of original search, so each page continues it
+/// - Cursor is released by implementation once results are exhausted
+///
 /// # Example
 /// ```
 /// #[async_trait::async_trait]
@@ -55,7 +64,7 @@ pub trait ISearcher {
 ///     async fn paginate(&self, params: &PaginationParams) -> SearchResult<Pagination> {
 ///         // Implementation logic here
 ///         Ok(Pagination {
-///             scroll_id: Some("next_scroll_id".to_string()),
+///             cursor: Some("next_cursor".to_string()),
 ///             founded: next_page_results,
 ///         })
 ///     }
@@ -64,4 +73,5 @@ pub trait ISearcher {
 #[async_trait::async_trait]
 pub trait IPaginator {
     async fn paginate(&self, params: &PaginationParams) -> SearchResult<Pagination>;
+    async fn release(&self, params: &PaginationParams) -> SearchResult<()>;
 }
@@ -0,0 +1,37 @@
+use crate::domain::searcher::models::FoundedDocumentBuilder;
+use crate::domain::searcher::models::{DocumentPartEntrailsBuilder, FoundedDocument};
+use crate::domain::searcher::tests::fixture::CURRENT_TIMESTAMP;
+use crate::shared::kernel::LargeDocumentId;
+
+pub const FOUNDED_INDEX_ID: &str = "test-index";
+
+pub fn build_founded_part(
+    large_doc_id: &str,
+    doc_part_id: usize,
+    score: Option<f64>,
+    highlight: &[&str],
+) -> FoundedDocument {
+    let document = DocumentPartEntrailsBuilder::default()
+        .large_doc_id(LargeDocumentId(large_doc_id.to_string()))
+        .doc_part_id(doc_part_id)
+        .file_name("test-document.txt".to_string())
+        .file_path("./test-document.txt".to_string())
+        .file_size(1024)
+        .created_at(CURRENT_TIMESTAMP)
+        .modified_at(CURRENT_TIMESTAMP)
+        .content(None)
+        .chunked_text(None)
+        .embeddings(None)
+        .metadata(None)
+        .build()
+        .expect("build founded document part failed");
+
+    FoundedDocumentBuilder::default()
+        .id(format!("{large_doc_id}-{doc_part_id}"))
+        .index(FOUNDED_INDEX_ID.to_string())
+        .score(score)
+        .highlight(highlight.iter().map(|it| it.to_string()).collect())
+        .document(document)
+        .build()
+        .expect("build founded document failed")
+}
@@ -1,3 +1,4 @@
+pub mod founded;
 mod pagination;
 pub mod params;
 
@@ -1,2 +1,4 @@
 pub mod fixture;
+mod test_founded;
 mod test_params;
+mod test_query_parser;
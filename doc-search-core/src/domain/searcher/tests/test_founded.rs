@@ -0,0 +1,95 @@
+use rstest::rstest;
+
+use crate::domain::searcher::models::{FoundedDocument, ResultOrder, SortField, SortParams};
+use crate::domain::searcher::tests::fixture::founded::build_founded_part;
+
+#[rstest]
+fn test_group_founded_by_document() -> anyhow::Result<()> {
+    let founded = vec![
+        build_founded_part("first", 1, Some(0.5), &["first highlight"]),
+        build_founded_part("second", 1, Some(0.9), &["second highlight"]),
+        build_founded_part(
+            "first",
+            2,
+            Some(0.7),
+            &["other highlight", "first highlight"],
+        ),
+        build_founded_part("first", 3, Some(0.2), &[]),
+    ];
+
+    let grouped = FoundedDocument::group_by_document(founded);
+    assert_eq!(grouped.len(), 2);
+
+    let first = &grouped[0];
+    assert_eq!(first.document.large_doc_id.0, "first");
+    assert_eq!(first.document.doc_part_id, 2);
+    assert_eq!(first.score, Some(0.7));
+    assert_eq!(first.highlight, vec!["other highlight", "first highlight"]);
+
+    let first_grouped = first.grouped.as_ref().expect("expected grouped parts");
+    assert_eq!(first_grouped.matched_parts, 3);
+    assert!(
+        first_grouped
+            .score
+            .is_some_and(|it| (it - 1.4).abs() < 1e-9)
+    );
+
+    let second = grouped[1].grouped.as_ref().expect("expected grouped parts");
+    assert_eq!(second.matched_parts, 1);
+    assert_eq!(second.score, Some(0.9));
+    Ok(())
+}
+
+#[rstest]
+fn test_collapse_parts_without_scores() -> anyhow::Result<()> {
+    let parts = vec![
+        build_founded_part("first", 1, None, &["first highlight"]),
+        build_founded_part("first", 2, None, &["second highlight"]),
+    ];
+
+    let collapsed = FoundedDocument::collapse_parts(parts).expect("expected collapsed document");
+    assert_eq!(collapsed.document.doc_part_id, 1);
+    assert_eq!(
+        collapsed.highlight,
+        vec!["first highlight", "second highlight"]
+    );
+
+    let grouped = collapsed.grouped.expect("expected grouped parts");
+    assert_eq!(grouped.matched_parts, 2);
+    assert_eq!(grouped.score, None);
+
+    assert!(FoundedDocument::collapse_parts(Vec::new()).is_none());
+    Ok(())
+}
+
+#[rstest]
+fn test_sort_founded_by_params() -> anyhow::Result<()> {
+    let mut founded = vec![
+        build_founded_part("first", 1, Some(0.5), &[]),
+        build_founded_part("second", 1, None, &[]),
+        build_founded_part("third", 2, Some(0.5), &[]),
+        build_founded_part("fourth", 1, Some(0.9), &[]),
+    ];
+
+    let sort = vec![
+        SortParams::new(SortField::Score, ResultOrder::ASC),
+        SortParams::new(SortField::CreatedAt, ResultOrder::DESC),
+        SortParams::new(SortField::PipelineId, ResultOrder::DESC),
+    ];
+
+    FoundedDocument::sort_by_params(&mut founded, &sort);
+    let ids = founded
+        .iter()
+        .map(|it| it.id.as_str())
+        .collect::<Vec<&str>>();
+    assert_eq!(ids, vec!["first-1", "third-2", "fourth-1", "second-1"]);
+
+    let sort = vec![SortParams::new(SortField::Score, ResultOrder::DESC)];
+    FoundedDocument::sort_by_params(&mut founded, &sort);
+    let ids = founded
+        .iter()
+        .map(|it| it.id.as_str())
+        .collect::<Vec<&str>>();
+    assert_eq!(ids, vec!["fourth-1", "first-1", "third-2", "second-1"]);
+    Ok(())
+}
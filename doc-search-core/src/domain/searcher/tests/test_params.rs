@@ -1,7 +1,8 @@
 use rstest::rstest;
 
-use crate::domain::searcher::models::SearchingParams;
+use crate::domain::searcher::models::{FilterParams, SearchingParams};
 use crate::domain::searcher::tests::fixture::params::*;
+use crate::shared::kernel::is_index_pattern_matched;
 
 #[rstest]
 #[case(build_retrieve_searching_params())]
@@ -13,3 +14,59 @@ fn test_build_searching_params(#[case] searching_params: SearchingParams) -> any
     assert_eq!(2, result.get_indexes().len());
     Ok(())
 }
+
+#[rstest]
+#[case("*", "test-index", true)]
+#[case("test-index", "test-index", true)]
+#[case("test-index", "test-index-2", false)]
+#[case("test-*", "test-index", true)]
+#[case("test-*", "other-index", false)]
+#[case("*-index", "test-index", true)]
+#[case("*-index", "test-index-2", false)]
+#[case("test-*-2", "test-index-2", true)]
+#[case("test-*-2", "test-2", false)]
+#[case("*index*", "test-index-2", true)]
+fn test_index_pattern_matching(
+    #[case] pattern: &str,
+    #[case] index: &str,
+    #[case] is_matched: bool,
+) -> anyhow::Result<()> {
+    assert_eq!(is_index_pattern_matched(pattern, index), is_matched);
+    Ok(())
+}
+
+#[rstest]
+#[case(None, true)]
+#[case(Some(vec![]), false)]
+#[case(Some(vec!["other-*".to_string()]), false)]
+#[case(Some(vec!["other-*".to_string(), "test-*".to_string()]), true)]
+fn test_searching_params_allowed_indexes(
+    #[from(build_full_text_searching_params)] searching_params: SearchingParams,
+    #[case] allowed_indexes: Option<Vec<String>>,
+    #[case] is_allowed: bool,
+) -> anyhow::Result<()> {
+    let params = searching_params.with_allowed_indexes(allowed_indexes);
+    assert_eq!(params.is_index_allowed("test-index"), is_allowed);
+    Ok(())
+}
+
+#[rstest]
+fn test_filter_params_location_filter(
+    #[from(build_filter_searching_params)] mut filter_params: FilterParams,
+) -> anyhow::Result<()> {
+    filter_params.location_coords = Some(vec![30.52, 50.45]);
+    filter_params.distance = None;
+    let location = filter_params
+        .location_filter()?
+        .expect("location filter is set");
+    assert_eq!(location.longitude, 30.52);
+    assert_eq!(location.latitude, 50.45);
+    assert_eq!(location.radius, 5000.0);
+
+    filter_params.location_coords = Some(vec![30.52]);
+    assert!(filter_params.location_filter().is_err());
+
+    filter_params.location_coords = None;
+    assert!(filter_params.location_filter()?.is_none());
+    Ok(())
+}
@@ -0,0 +1,168 @@
+use rstest::rstest;
+use std::ops::Bound;
+
+use crate::domain::searcher::SearchError;
+use crate::domain::searcher::query::{BoolQuery, QueryField, QueryNode, QueryRange};
+use crate::domain::searcher::query::{QueryTerm, QueryValue, parse_query};
+
+fn term(field: QueryField, value: QueryValue) -> QueryNode {
+    QueryNode::Term(QueryTerm { field, value })
+}
+
+fn word(text: &str) -> QueryNode {
+    term(QueryField::Content, QueryValue::Word(text.to_string()))
+}
+
+#[test]
+fn test_parse_plain_text_query() -> anyhow::Result<()> {
+    let node = parse_query("There is some query")?;
+    assert_eq!(Some("There is some query".to_string()), node.plain_text());
+    Ok(())
+}
+
+#[test]
+fn test_parse_boolean_query() -> anyhow::Result<()> {
+    let node = parse_query("\"exact phrase\" AND source:smith -draft file_name:report*")?;
+    let expected = QueryNode::Bool(BoolQuery {
+        must: vec![
+            term(
+                QueryField::Content,
+                QueryValue::Phrase("exact phrase".into()),
+            ),
+            term(QueryField::Source, QueryValue::Word("smith".into())),
+        ],
+        should: vec![term(
+            QueryField::FileName,
+            QueryValue::Wildcard("report*".into()),
+        )],
+        must_not: vec![word("draft")],
+    });
+
+    assert_eq!(expected, node);
+    assert_eq!(None, node.plain_text());
+    Ok(())
+}
+
+#[test]
+fn test_parse_grouped_query() -> anyhow::Result<()> {
+    let node = parse_query("+file_path:(first || second) NOT (draft OR smith~1)")?;
+    let expected = QueryNode::Bool(BoolQuery {
+        must: vec![QueryNode::Bool(BoolQuery {
+            should: vec![
+                term(QueryField::FilePath, QueryValue::Word("first".into())),
+                term(QueryField::FilePath, QueryValue::Word("second".into())),
+            ],
+            ..Default::default()
+        })],
+        should: vec![],
+        must_not: vec![QueryNode::Bool(BoolQuery {
+            should: vec![
+                word("draft"),
+                term(
+                    QueryField::Content,
+                    QueryValue::Fuzzy {
+                        word: "smith".into(),
+                        distance: 1,
+                    },
+                ),
+            ],
+            ..Default::default()
+        })],
+    });
+
+    assert_eq!(expected, node);
+    Ok(())
+}
+
+#[rstest]
+#[case("file_size:[100 TO 200]", Bound::Included(100), Bound::Included(200))]
+#[case("file_size:{100 TO *}", Bound::Excluded(100), Bound::Unbounded)]
+#[case("file_size:>=100", Bound::Included(100), Bound::Unbounded)]
+#[case("file_size:<100", Bound::Unbounded, Bound::Excluded(100))]
+#[case("file_size:100", Bound::Included(100), Bound::Included(100))]
+#[case(
+    "created_at:[2024-01-01 TO 2024-01-31]",
+    Bound::Included(1704067200),
+    Bound::Included(1706745599)
+)]
+#[case(
+    "modified_at:2024-02-29",
+    Bound::Included(1709164800),
+    Bound::Included(1709251199)
+)]
+fn test_parse_range_query(
+    #[case] query: &str,
+    #[case] from: Bound<i64>,
+    #[case] to: Bound<i64>,
+) -> anyhow::Result<()> {
+    let QueryNode::Term(QueryTerm {
+        value: QueryValue::Range(range),
+        ..
+    }) = parse_query(query)?
+    else {
+        anyhow::bail!("expected range term for query: {query}");
+    };
+
+    assert_eq!(QueryRange { from, to }, range);
+    Ok(())
+}
+
+#[test]
+fn test_parse_escaped_query() -> anyhow::Result<()> {
+    let node = parse_query("C\\+\\+ \"say \\\"hi\\\"\" \\AND")?;
+    let expected = QueryNode::Bool(BoolQuery {
+        should: vec![
+            word("C++"),
+            term(QueryField::Content, QueryValue::Phrase("say \"hi\"".into())),
+            word("AND"),
+        ],
+        ..Default::default()
+    });
+
+    assert_eq!(expected, node);
+    Ok(())
+}
+
+#[rstest]
+#[case("", 0)]
+#[case("AND draft", 0)]
+#[case("draft AND", 9)]
+#[case("\"unterminated phrase", 0)]
+#[case("(draft OR smith", 0)]
+#[case("draft)", 5)]
+#[case("author:smith", 0)]
+#[case("file_size:big", 10)]
+#[case("created_at:2024-02-30", 11)]
+#[case("content:[1 TO 2]", 8)]
+#[case("file_size:[1 TO 2", 16)]
+#[case("smith~3", 5)]
+#[case("- draft", 0)]
+fn test_parse_invalid_query(#[case] query: &str, #[case] position: usize) -> anyhow::Result<()> {
+    let result = parse_query(query);
+    let Err(SearchError::ValidationError(err)) = result else {
+        anyhow::bail!("expected validation error for query: {query}, got {result:?}");
+    };
+
+    let prefix = format!("invalid query at position {position}:");
+    assert!(err.to_string().starts_with(&prefix), "{err}");
+    Ok(())
+}
+
+#[rstest]
+fn test_parse_deeply_nested_query() -> anyhow::Result<()> {
+    let nested = |depth: usize| format!("{}draft{}", "(".repeat(depth), ")".repeat(depth));
+    let node = parse_query(&nested(32))?;
+    assert_eq!(node, parse_query("draft")?);
+
+    let result = parse_query(&nested(33));
+    let Err(SearchError::ValidationError(err)) = result else {
+        anyhow::bail!("expected validation error, got {result:?}");
+    };
+
+    let expected = "invalid query at position 32: query is nested too deeply";
+    assert_eq!(err.to_string(), expected);
+
+    let result = parse_query(&"(".repeat(100_000));
+    assert!(matches!(result, Err(SearchError::ValidationError(_))));
+    Ok(())
+}
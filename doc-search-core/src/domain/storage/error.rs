@@ -16,6 +16,7 @@ pub type StorageResult<T> = Result<T, StorageError>;
 /// * `AuthenticationFailed` - Authentication failure with storage service
 /// * `ConnectionError` - Network or connection issues
 /// * `IndexNotFound` - Requested index does not exist
+/// * `IndexAlreadyExists` - Attempt to create index that already exists
 /// * `DocumentNotFound` - Requested document or document part not found
 /// * `DocumentAlreadyExists` - Attempt to store document that already exists
 /// * `CantSplitLargeDocuments` - Error during document splitting process
@@ -28,6 +29,7 @@ pub type StorageResult<T> = Result<T, StorageError>;
 /// * Authentication: "storage: auth failed: {0}"
 /// * Connection: "storage: connection error: {0}"
 /// * Index: "storage: index has not been found: {0}"
+/// * Index exists: "storage: index already exists: {0}"
 /// * Document: "storage: document has not been found: {0}"
 /// * Document exists: "storage: document already exists: {0}"
 /// * Split error: "can't split large document: {0}"
@@ -170,6 +172,20 @@ pub enum StorageError {
     #[error("storage: index has not been founded: {0}")]
     IndexNotFound(anyhow::Error),
 
+    /// The requested index has been already created.
+    ///
+    /// This error occurs when:
+    /// * The index with the same name already exists
+    ///
+    /// # Example
+    /// ```
+    /// Err(StorageError::IndexAlreadyExists(
+    ///     anyhow::anyhow!("index 'documents_2023' already exists")
+    /// ))
+    /// ```
+    #[error("storage: index already exists: {0}")]
+    IndexAlreadyExists(anyhow::Error),
+
     /// The requested document was not found.
     ///
     /// This error occurs when:
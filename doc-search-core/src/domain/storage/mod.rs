@@ -2,9 +2,10 @@
 pub(crate) mod tests;
 
 pub mod models;
+pub mod splitter;
 
 mod repository;
-pub use repository::{IDocumentPartStorage, IIndexStorage};
+pub use repository::{IDocumentPartStorage, IDocumentVectorStorage, IIndexStorage};
 
 mod error;
 pub use error::{StorageError, StorageResult};
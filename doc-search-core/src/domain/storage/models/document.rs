@@ -1,10 +1,12 @@
 use anyhow::{Context, anyhow};
-use character_text_splitter::CharacterTextSplitter;
 use derive_builder::Builder;
 use std::fmt::{Debug, Formatter};
 
+use crate::domain::storage::models::UpdateDocumentParams;
+use crate::domain::storage::splitter::{CharacterSplitter, DocumentSplitter};
 use crate::domain::storage::{StorageError, StorageResult};
 use crate::shared::kernel::metadata::DocumentMetadata;
+use crate::shared::kernel::vector::mean_vector;
 use crate::shared::kernel::{DocumentPartId, LargeDocumentId};
 
 /// The ID of the first document part in a sequence.
@@ -13,6 +15,10 @@ use crate::shared::kernel::{DocumentPartId, LargeDocumentId};
 /// consistent ordering when splitting large documents.
 pub const FIRST_DOCUMENT_PART_ID: usize = 1;
 
+/// Separator placed between contents of assembled document parts which
+/// have no overlapping text.
+pub const DOCUMENT_PARTS_SEPARATOR: &str = "\n";
+
 /// Type alias for a collection of all document parts belonging to a large document.
 ///
 /// Represents the complete set of parts that make up a single large document
@@ -33,6 +39,8 @@ pub type AllDocumentParts = Vec<DocumentPart>;
 /// * `modified_at` - Unix timestamp of last modification
 /// * `content` - Complete text content of the document
 /// * `metadata` - Additional document metadata (optional)
+/// * `embeddings` - Chunks and embeddings of each part computed by an external
+///   service (optional). Document is divided by them instead of index splitter.
 ///
 /// # Example
 /// ```
@@ -48,6 +56,7 @@ pub type AllDocumentParts = Vec<DocumentPart>;
 ///         language: "en".to_string(),
 ///         // ... other metadata fields
 ///     }),
+///     embeddings: None,
 /// };
 /// ```
 #[derive(Builder)]
@@ -59,6 +68,8 @@ pub struct LargeDocument {
     pub modified_at: i64,
     pub content: String,
     pub metadata: Option<DocumentMetadata>,
+    #[builder(default)]
+    pub embeddings: Option<Vec<DocumentPartEmbeddings>>,
 }
 
 impl Debug for LargeDocument {
@@ -90,6 +101,7 @@ impl Debug for LargeDocument {
 /// * `modified_at` - Modification timestamp (inherited from parent)
 /// * `content` - Text content of this specific part
 /// * `metadata` - Document metadata (inherited and possibly extended)
+/// * `embeddings` - Precomputed chunks and embeddings of this part (optional)
 ///
 /// # Example
 /// ```
@@ -103,6 +115,7 @@ impl Debug for LargeDocument {
 ///     modified_at: 1634567890,
 ///     content: "Chapter 3, Part 1...".to_string(),
 ///     metadata: None,
+///     embeddings: None,
 /// };
 /// ```
 #[derive(Clone, Builder)]
@@ -116,6 +129,8 @@ pub struct DocumentPart {
     pub modified_at: i64,
     pub content: String,
     pub metadata: Option<DocumentMetadata>,
+    #[builder(default)]
+    pub embeddings: Option<DocumentPartEmbeddings>,
 }
 
 impl Debug for DocumentPart {
@@ -128,17 +143,118 @@ impl Debug for DocumentPart {
     }
 }
 
+/// Text chunks of a document part with embeddings computed by an external
+/// service, stored as they are instead of embedding content by the storage.
+///
+/// # Fields
+/// * `chunked_text` - Text chunks the part content is made of
+/// * `embeddings` - Embedding vector of each chunk in the same order
+#[derive(Clone, Builder)]
+pub struct DocumentPartEmbeddings {
+    pub chunked_text: Vec<String>,
+    pub embeddings: Vec<Vec<f64>>,
+}
+
+impl Debug for DocumentPartEmbeddings {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "chunks: {}", self.chunked_text.len())
+    }
+}
+
+impl DocumentPartEmbeddings {
+    /// Checks that each chunk has a non-empty embedding vector and all
+    /// vectors have the same dimension.
+    pub fn validate(&self) -> StorageResult<()> {
+        if self.chunked_text.is_empty() {
+            let err = anyhow!("document part embeddings have no chunks");
+            return Err(StorageError::ValidationError(err));
+        }
+
+        if self.chunked_text.len() != self.embeddings.len() {
+            let chunks = self.chunked_text.len();
+            let vectors = self.embeddings.len();
+            let err = anyhow!("chunks amount {chunks} differs from embeddings amount {vectors}");
+            return Err(StorageError::ValidationError(err));
+        }
+
+        let dimension = self.embeddings[0].len();
+        if dimension == 0 || self.embeddings.iter().any(|it| it.len() != dimension) {
+            let err = anyhow!("embeddings must be non-empty vectors of the same dimension");
+            return Err(StorageError::ValidationError(err));
+        }
+
+        Ok(())
+    }
+}
+
+/// Document part with a single embedding vector, stored by vector storages
+/// keeping one point per document part.
+///
+/// # Fields
+/// * `id` - Point id derived from large document id and part position
+/// * `document` - Document part stored as point payload
+/// * `vector` - Mean of embeddings of the part chunks
+pub struct DocumentPartVector {
+    pub id: DocumentPartId,
+    pub document: DocumentPart,
+    pub vector: Vec<f64>,
+}
+
+impl DocumentPartVector {
+    /// Builds vector of document part from its stored embeddings, parts
+    /// stored without embeddings have no vector.
+    pub fn from_document_part(doc_part: &DocumentPart) -> Option<Self> {
+        let embeddings = doc_part.embeddings.as_ref()?;
+        let vector = mean_vector(&embeddings.embeddings)?;
+        let name = format!("{}/{}", doc_part.large_doc_id.0, doc_part.doc_part_id);
+        let id = uuid::Uuid::new_v5(&uuid::Uuid::NAMESPACE_OID, name.as_bytes());
+        let mut document = doc_part.clone();
+        document.embeddings = None;
+        Some(DocumentPartVector {
+            id: DocumentPartId(id.to_string()),
+            document,
+            vector,
+        })
+    }
+}
+
+impl DocumentPart {
+    /// Overwrites document level fields while keeping part content, position
+    /// and creation timestamp as they are.
+    pub fn apply_update(&mut self, params: &UpdateDocumentParams) {
+        self.file_name = params.file_name.clone();
+        self.file_path = params.file_path.clone();
+        self.file_size = params.file_size;
+        self.modified_at = params.modified_at;
+        self.metadata = params.metadata.clone();
+    }
+}
+
 impl LargeDocument {
     pub fn divide_large_document_on_parts(
         self,
         part_size: usize,
+    ) -> StorageResult<Vec<DocumentPart>> {
+        self.divide_large_document_with(&CharacterSplitter::new(part_size))
+    }
+
+    pub fn divide_large_document_with(
+        self,
+        splitter: &dyn DocumentSplitter,
     ) -> StorageResult<Vec<DocumentPart>> {
         if self.content.is_empty() {
             let err = anyhow!("document content is empty.");
             return Err(StorageError::CantSplitLargeDocuments(err));
         }
 
-        let large_doc_id = uuid::Uuid::new_v4().to_string();
+        // Identity is derived from the file path, so uploading the same file
+        // again resolves to the same large document id. Documents without
+        // path are identified by content instead of sharing a single id.
+        let large_doc_id = match self.file_path.trim().is_empty() {
+            false => uuid::Uuid::new_v5(&uuid::Uuid::NAMESPACE_URL, self.file_path.as_bytes()),
+            true => uuid::Uuid::new_v5(&uuid::Uuid::NAMESPACE_OID, self.content.as_bytes()),
+        };
+        let large_doc_id = large_doc_id.to_string();
         let document_part = DocumentPartBuilder::default()
             .large_doc_id(LargeDocumentId(large_doc_id))
             .doc_part_id(FIRST_DOCUMENT_PART_ID)
@@ -153,9 +269,17 @@ impl LargeDocument {
             .context("failed to build document part")
             .map_err(StorageError::CantSplitLargeDocuments)?;
 
-        let document_parts = CharacterTextSplitter::new()
-            .with_chunk_size(part_size)
-            .split_text(&self.content)
+        if let Some(parts_embeddings) = self.embeddings {
+            return divide_by_embeddings(document_part, parts_embeddings);
+        }
+
+        let parts_content = splitter.split(&self.content);
+        if parts_content.is_empty() {
+            let err = anyhow!("document content has been split on zero parts.");
+            return Err(StorageError::CantSplitLargeDocuments(err));
+        }
+
+        let document_parts = parts_content
             .into_iter()
             .enumerate()
             .map(|(part_id, part_content)| {
@@ -170,6 +294,92 @@ impl LargeDocument {
     }
 }
 
+impl LargeDocument {
+    /// Rebuilds a large document from its stored parts ordered by `doc_part_id`.
+    ///
+    /// Document level fields are taken from the first part. When parts have
+    /// been split with overlap, pass `merge_overlaps` to drop the text each
+    /// part repeats from the end of the previous one.
+    pub fn assemble_document_parts(
+        mut parts: AllDocumentParts,
+        merge_overlaps: bool,
+    ) -> StorageResult<LargeDocument> {
+        parts.sort_by_key(|it| it.doc_part_id);
+        let mut parts_iter = parts.into_iter();
+        let Some(first_part) = parts_iter.next() else {
+            let err = anyhow!("there are no document parts to assemble");
+            return Err(StorageError::DocumentNotFound(err));
+        };
+
+        let mut content = first_part.content.clone();
+        let mut prev_content = first_part.content;
+        for part in parts_iter {
+            let overlap = match merge_overlaps {
+                true => overlap_length(&prev_content, &part.content),
+                false => 0,
+            };
+
+            if overlap == 0 {
+                content.push_str(DOCUMENT_PARTS_SEPARATOR);
+            }
+
+            content.push_str(&part.content[overlap..]);
+            prev_content = part.content;
+        }
+
+        Ok(LargeDocument {
+            file_name: first_part.file_name,
+            file_path: first_part.file_path,
+            file_size: first_part.file_size,
+            created_at: first_part.created_at,
+            modified_at: first_part.modified_at,
+            content,
+            metadata: first_part.metadata,
+            embeddings: None,
+        })
+    }
+}
+
+/// Builds a document part per precomputed part embeddings, the part content
+/// is made of its chunks.
+fn divide_by_embeddings(
+    document_part: DocumentPart,
+    parts_embeddings: Vec<DocumentPartEmbeddings>,
+) -> StorageResult<Vec<DocumentPart>> {
+    if parts_embeddings.is_empty() {
+        let err = anyhow!("document embeddings have no parts.");
+        return Err(StorageError::CantSplitLargeDocuments(err));
+    }
+
+    parts_embeddings
+        .into_iter()
+        .enumerate()
+        .map(|(part_id, embeddings)| {
+            embeddings.validate()?;
+            let mut doc_part_cln = document_part.clone();
+            doc_part_cln.doc_part_id = part_id + 1;
+            doc_part_cln.content = embeddings.chunked_text.join(DOCUMENT_PARTS_SEPARATOR);
+            doc_part_cln.embeddings = Some(embeddings);
+            Ok(doc_part_cln)
+        })
+        .collect()
+}
+
+/// Returns length in bytes of the longest prefix of `next` repeating the end
+/// of `prev`. Overlaps are made of whole words, so a prefix breaking a word
+/// on either side is not taken into account.
+fn overlap_length(prev: &str, next: &str) -> usize {
+    let is_word_char = |it: char| it.is_alphanumeric();
+    next.char_indices()
+        .map(|(offset, it)| offset + it.len_utf8())
+        .take_while(|end| *end <= prev.len())
+        .filter(|end| !next[*end..].starts_with(is_word_char))
+        .filter(|end| prev.ends_with(&next[..*end]))
+        .filter(|end| !prev[..prev.len() - end].ends_with(is_word_char))
+        .last()
+        .unwrap_or_default()
+}
+
 /// Information about stored document parts for a large document.
 ///
 /// This structure provides metadata about how a large document was split
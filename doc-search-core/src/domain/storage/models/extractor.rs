@@ -0,0 +1,215 @@
+use anyhow::{Context, anyhow, bail};
+use quick_xml::Reader;
+use quick_xml::escape::resolve_predefined_entity;
+use quick_xml::events::Event;
+use std::io::{Cursor, Read};
+use std::path::Path;
+
+/// Line width used while rendering HTML into plain text. It is wide enough
+/// to keep html2text from wrapping ordinary paragraphs.
+const HTML_RENDER_WIDTH: usize = 4096;
+
+/// Limits of uncompressed archive content, so DOCX or ODT zip bomb can
+/// not exhaust memory of the service.
+const MAX_ENTRY_BYTES: u64 = 64 * 1024 * 1024;
+const MAX_ARCHIVE_BYTES: u64 = 256 * 1024 * 1024;
+
+const DOCX_CONTENT_ENTRY: &str = "word/document.xml";
+const ODT_CONTENT_ENTRY: &str = "content.xml";
+
+/// File formats the text of which may be extracted from uploaded files.
+///
+/// # Variants
+/// * `PlainText` - `.txt` files and any `text/plain` payload
+/// * `Markdown` - `.md`/`.markdown` files, stored as is
+/// * `Html` - `.html`/`.htm` files, rendered into plain text
+/// * `Pdf` - `.pdf` files
+/// * `Docx` - Office Open XML documents
+/// * `Odt` - OpenDocument text documents
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DocumentFormat {
+    PlainText,
+    Markdown,
+    Html,
+    Pdf,
+    Docx,
+    Odt,
+}
+
+impl DocumentFormat {
+    /// Detects format by the file extension, falling back to the content type
+    /// passed by client when the extension is missing or unknown.
+    pub fn detect(file_name: &str, content_type: Option<&str>) -> Option<Self> {
+        Path::new(file_name)
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .and_then(Self::from_extension)
+            .or_else(|| content_type.and_then(Self::from_content_type))
+    }
+
+    fn from_extension(extension: &str) -> Option<Self> {
+        match extension.to_lowercase().as_str() {
+            "txt" | "text" | "log" | "csv" => Some(DocumentFormat::PlainText),
+            "md" | "markdown" => Some(DocumentFormat::Markdown),
+            "html" | "htm" => Some(DocumentFormat::Html),
+            "pdf" => Some(DocumentFormat::Pdf),
+            "docx" => Some(DocumentFormat::Docx),
+            "odt" => Some(DocumentFormat::Odt),
+            _ => None,
+        }
+    }
+
+    fn from_content_type(content_type: &str) -> Option<Self> {
+        let mime = content_type.split(';').next().unwrap_or_default().trim();
+        match mime.to_lowercase().as_str() {
+            "text/plain" => Some(DocumentFormat::PlainText),
+            "text/markdown" => Some(DocumentFormat::Markdown),
+            "text/html" => Some(DocumentFormat::Html),
+            "application/pdf" => Some(DocumentFormat::Pdf),
+            "application/vnd.openxmlformats-officedocument.wordprocessingml.document" => {
+                Some(DocumentFormat::Docx)
+            }
+            "application/vnd.oasis.opendocument.text" => Some(DocumentFormat::Odt),
+            _ => None,
+        }
+    }
+
+    pub fn extract_text(&self, data: &[u8]) -> anyhow::Result<String> {
+        let text = match self {
+            DocumentFormat::PlainText | DocumentFormat::Markdown => {
+                String::from_utf8(data.to_vec()).context("file is not valid utf-8 text")?
+            }
+            DocumentFormat::Html => html2text::from_read(data, HTML_RENDER_WIDTH)
+                .context("failed to render html content")?,
+            DocumentFormat::Pdf => extract_pdf_text(data)?,
+            DocumentFormat::Docx => {
+                let xml = read_archive_entry(data, DOCX_CONTENT_ENTRY)?;
+                extract_xml_text(&xml)?
+            }
+            DocumentFormat::Odt => {
+                let xml = read_archive_entry(data, ODT_CONTENT_ENTRY)?;
+                extract_xml_text(&xml)?
+            }
+        };
+
+        Ok(text.trim().to_string())
+    }
+}
+
+/// Malformed files are known to make pdf extractor panic, so the panic is
+/// caught and reported as an extraction error of the uploaded file.
+fn extract_pdf_text(data: &[u8]) -> anyhow::Result<String> {
+    std::panic::catch_unwind(|| pdf_extract::extract_text_from_mem(data))
+        .map_err(|_| anyhow!("pdf content is malformed"))?
+        .context("failed to extract pdf content")
+}
+
+fn read_archive_entry(data: &[u8], entry: &str) -> anyhow::Result<Vec<u8>> {
+    let mut archive =
+        zip::ZipArchive::new(Cursor::new(data)).context("file is not a valid zip archive")?;
+
+    let mut total_size = 0u64;
+    for index in 0..archive.len() {
+        let file = archive
+            .by_index_raw(index)
+            .context("failed to read archive entries")?;
+        total_size = total_size.saturating_add(file.size());
+    }
+
+    if total_size > MAX_ARCHIVE_BYTES {
+        bail!("archive content exceeds {MAX_ARCHIVE_BYTES} bytes");
+    }
+
+    let file = archive
+        .by_name(entry)
+        .map_err(|err| anyhow!("archive entry {entry} is missing: {err}"))?;
+
+    // Sizes stored in archive headers are not trusted, so the entry is read
+    // one byte over the limit to find out it is exceeded.
+    let mut buffer = Vec::new();
+    file.take(MAX_ENTRY_BYTES + 1)
+        .read_to_end(&mut buffer)
+        .with_context(|| format!("failed to read archive entry {entry}"))?;
+
+    if buffer.len() as u64 > MAX_ENTRY_BYTES {
+        bail!("archive entry {entry} exceeds {MAX_ENTRY_BYTES} bytes");
+    }
+
+    Ok(buffer)
+}
+
+/// Collects text nodes of DOCX and ODT document bodies. Both formats keep
+/// paragraphs and headings as `p`/`h` elements and mark tabs and line breaks
+/// with dedicated empty elements, so local names are enough to tell them apart.
+fn extract_xml_text(xml: &[u8]) -> anyhow::Result<String> {
+    let mut reader = Reader::from_reader(xml);
+    let mut buffer = Vec::new();
+    let mut text = String::new();
+    let mut skip_depth = 0usize;
+
+    loop {
+        let event = reader
+            .read_event_into(&mut buffer)
+            .context("failed to parse document xml")?;
+
+        match event {
+            Event::Eof => break,
+            Event::Start(elem) => {
+                if skip_depth > 0 || is_skipped_element(elem.local_name().as_ref()) {
+                    skip_depth += 1;
+                }
+            }
+            Event::End(elem) => match skip_depth {
+                0 => {
+                    if matches!(elem.local_name().as_ref(), b"p" | b"h") {
+                        text.push('\n');
+                    }
+                }
+                _ => skip_depth -= 1,
+            },
+            Event::Empty(elem) if skip_depth == 0 => match elem.local_name().as_ref() {
+                b"tab" => text.push('\t'),
+                b"br" | b"cr" | b"line-break" => text.push('\n'),
+                b"s" => text.push(' '),
+                b"p" | b"h" => text.push('\n'),
+                _ => {}
+            },
+            Event::Text(elem) if skip_depth == 0 => {
+                let chunk = elem.decode().context("failed to decode xml text")?;
+                if !is_indentation(&chunk) {
+                    text.push_str(&chunk);
+                }
+            }
+            Event::CData(elem) if skip_depth == 0 => {
+                text.push_str(&elem.decode().context("failed to decode xml text")?);
+            }
+            Event::GeneralRef(elem) if skip_depth == 0 => {
+                if let Some(ch) = elem.resolve_char_ref()? {
+                    text.push(ch);
+                } else {
+                    let entity = elem.decode().context("failed to decode xml entity")?;
+                    let resolved = resolve_predefined_entity(&entity)
+                        .ok_or_else(|| anyhow!("unknown xml entity: {entity}"))?;
+                    text.push_str(resolved);
+                }
+            }
+            _ => {}
+        }
+
+        buffer.clear();
+    }
+
+    Ok(text)
+}
+
+/// Field instructions and tracked deletions are part of document markup
+/// but not of visible document text.
+fn is_skipped_element(local_name: &[u8]) -> bool {
+    matches!(local_name, b"instrText" | b"delText")
+}
+
+/// Pretty printed documents contain whitespace between tags which must not
+/// leak into extracted text.
+fn is_indentation(chunk: &str) -> bool {
+    chunk.contains('\n') && chunk.trim().is_empty()
+}
@@ -1,10 +1,26 @@
 mod document;
 pub use crate::domain::storage::models::document::AllDocumentParts;
+pub use crate::domain::storage::models::document::DOCUMENT_PARTS_SEPARATOR;
+pub use crate::domain::storage::models::document::DocumentPartVector;
+pub use crate::domain::storage::models::document::FIRST_DOCUMENT_PART_ID;
 pub use crate::domain::storage::models::document::StoredDocumentPartsInfo;
 pub use crate::domain::storage::models::document::StoredDocumentPartsInfoBuilder;
 pub use crate::domain::storage::models::document::{DocumentPart, DocumentPartBuilder};
+pub use crate::domain::storage::models::document::{
+    DocumentPartEmbeddings, DocumentPartEmbeddingsBuilder,
+};
 pub use crate::domain::storage::models::document::{LargeDocument, LargeDocumentBuilder};
 
+mod extractor;
+pub use extractor::DocumentFormat;
+
 mod params;
 pub use params::{CreateIndexParams, CreateIndexParamsBuilder};
-pub use params::{KnnIndexParams, KnnIndexParamsBuilder};
+pub use params::{KnnIndexParams, KnnIndexParamsBuilder, SplitterParams};
+pub use params::{UpdateDocumentParams, UpdateDocumentParamsBuilder};
+
+mod report;
+pub use report::{BulkStoreReport, FailedBulkItem, StoredBulkItem};
+
+mod upload;
+pub use upload::{UploadedFile, UploadedFileBuilder};
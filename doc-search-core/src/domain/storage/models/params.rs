@@ -1,10 +1,13 @@
 use derive_builder::Builder;
 
+use crate::shared::kernel::metadata::DocumentMetadata;
+
 /// Parameters for creating a new search index.
 ///
 /// # Fields
 /// * `id` - Unique identifier for the new index
 /// * `knn` - Optional KNN (k-nearest neighbors) index parameters for semantic search support
+/// * `splitter` - Optional strategy dividing stored documents on parts
 ///
 /// # Example
 /// ```
@@ -15,12 +18,15 @@ use derive_builder::Builder;
 ///         token_limit: 512,
 ///         overlap_rate: 0.2,
 ///     }),
+///     splitter: Some(SplitterParams::Markdown { chunk_size: None }),
 /// };
 /// ```
 #[derive(Debug, Builder)]
 pub struct CreateIndexParams {
     pub id: String,
     pub knn: Option<KnnIndexParams>,
+    #[builder(default)]
+    pub splitter: Option<SplitterParams>,
 }
 
 /// Parameters for configuring a KNN (k-nearest neighbors) index.
@@ -52,3 +58,43 @@ pub struct KnnIndexParams {
     pub token_limit: u32,
     pub overlap_rate: f32,
 }
+
+/// Strategy used to divide documents stored within index on parts.
+///
+/// # Variants
+/// * `Character` - Merges blocks separated by blank lines up to `chunk_size` bytes
+/// * `Sentence` - Packs whole paragraphs and sentences up to `chunk_size` characters
+/// * `Markdown` - Keeps each heading section within its own parts of `chunk_size` characters
+/// * `Token` - Parts of `token_limit` word tokens overlapping by `overlap_rate`
+///
+/// # Notes
+/// - `chunk_size` falls back to the service `max_content_size` when it is not set
+/// - `Token` counts tokens the same way the `standard` tokenizer of the opensearch
+///   chunking processor does, so parts match `KnnIndexParams` of the index
+#[derive(Clone, Debug, PartialEq)]
+pub enum SplitterParams {
+    Character { chunk_size: Option<usize> },
+    Sentence { chunk_size: Option<usize> },
+    Markdown { chunk_size: Option<usize> },
+    Token { token_limit: u32, overlap_rate: f32 },
+}
+
+/// Document level fields rewritten on every stored part of a large document.
+///
+/// Used for metadata-only updates: part content, chunking and embeddings
+/// stay untouched, as does the original `created_at` timestamp.
+///
+/// # Fields
+/// * `file_name` - New file name
+/// * `file_path` - New file path
+/// * `file_size` - New file size in bytes
+/// * `modified_at` - Unix timestamp of the update
+/// * `metadata` - New document metadata, replacing the stored one
+#[derive(Clone, Debug, Builder)]
+pub struct UpdateDocumentParams {
+    pub file_name: String,
+    pub file_path: String,
+    pub file_size: u32,
+    pub modified_at: i64,
+    pub metadata: Option<DocumentMetadata>,
+}
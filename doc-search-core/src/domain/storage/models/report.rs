@@ -0,0 +1,77 @@
+use crate::domain::storage::models::StoredDocumentPartsInfo;
+
+/// Result of storing a batch of documents where every document
+/// is stored or rejected independently of others.
+///
+/// # Fields
+/// * `succeeded` - Documents which have been stored
+/// * `failed` - Documents which have been rejected with reasons
+///
+/// Items are ordered by `position`, the index of document within
+/// the batch passed by client.
+#[derive(Debug, Default)]
+pub struct BulkStoreReport {
+    pub succeeded: Vec<StoredBulkItem>,
+    pub failed: Vec<FailedBulkItem>,
+}
+
+#[derive(Debug)]
+pub struct StoredBulkItem {
+    pub position: usize,
+    pub file_path: String,
+    pub info: StoredDocumentPartsInfo,
+}
+
+#[derive(Debug)]
+pub struct FailedBulkItem {
+    pub position: usize,
+    pub file_path: String,
+    pub reason: String,
+}
+
+impl BulkStoreReport {
+    pub fn add_stored(
+        &mut self,
+        position: usize,
+        file_path: String,
+        info: StoredDocumentPartsInfo,
+    ) {
+        self.succeeded.push(StoredBulkItem {
+            position,
+            file_path,
+            info,
+        });
+    }
+
+    pub fn add_failed(&mut self, position: usize, file_path: String, reason: String) {
+        self.failed.push(FailedBulkItem {
+            position,
+            file_path,
+            reason,
+        });
+    }
+
+    pub fn has_failures(&self) -> bool {
+        !self.failed.is_empty()
+    }
+
+    /// Merges report built for a subset of the batch. The `positions` maps
+    /// positions of the subset report onto positions within the whole batch.
+    pub fn merge(&mut self, other: BulkStoreReport, positions: &[usize]) {
+        let translate = |position: usize| positions.get(position).copied().unwrap_or(position);
+
+        self.succeeded
+            .extend(other.succeeded.into_iter().map(|mut item| {
+                item.position = translate(item.position);
+                item
+            }));
+
+        self.failed.extend(other.failed.into_iter().map(|mut item| {
+            item.position = translate(item.position);
+            item
+        }));
+
+        self.succeeded.sort_by_key(|item| item.position);
+        self.failed.sort_by_key(|item| item.position);
+    }
+}
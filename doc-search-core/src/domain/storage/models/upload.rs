@@ -0,0 +1,73 @@
+use anyhow::anyhow;
+use derive_builder::Builder;
+use std::fmt::{Debug, Formatter};
+
+use crate::domain::storage::models::{DocumentFormat, LargeDocument};
+use crate::domain::storage::{StorageError, StorageResult};
+use crate::shared::kernel::metadata::DocumentMetadata;
+
+/// Raw file uploaded by client which content must be extracted
+/// before storing it as a large document.
+///
+/// # Fields
+/// * `file_name` - Original name of the uploaded file
+/// * `file_path` - Path to store document by, the base of large document id
+/// * `content_type` - Content type passed by client (optional)
+/// * `uploaded_at` - Unix timestamp used as creation and modification time
+/// * `data` - Raw file bytes
+/// * `metadata` - Additional document metadata (optional)
+#[derive(Builder)]
+pub struct UploadedFile {
+    pub file_name: String,
+    pub file_path: String,
+    #[builder(default)]
+    pub content_type: Option<String>,
+    pub uploaded_at: i64,
+    pub data: Vec<u8>,
+    #[builder(default)]
+    pub metadata: Option<DocumentMetadata>,
+}
+
+impl Debug for UploadedFile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "file_path: {}, file_size: {}",
+            &self.file_path,
+            self.data.len()
+        )
+    }
+}
+
+impl UploadedFile {
+    pub fn get_format(&self) -> StorageResult<DocumentFormat> {
+        DocumentFormat::detect(&self.file_name, self.content_type.as_deref()).ok_or_else(|| {
+            let err = anyhow!("unsupported file format: {}", &self.file_name);
+            StorageError::ValidationError(err)
+        })
+    }
+
+    pub fn extract_large_document(self) -> StorageResult<LargeDocument> {
+        let format = self.get_format()?;
+        let file_size = u32::try_from(self.data.len()).map_err(|_| {
+            let err = anyhow!("file is too large: {}", &self.file_name);
+            StorageError::ValidationError(err)
+        })?;
+
+        let content = format.extract_text(&self.data).map_err(|err| {
+            let err = err.context(format!("failed to extract text: {}", &self.file_name));
+            StorageError::ValidationError(err)
+        })?;
+
+        Ok(LargeDocument {
+            file_name: self.file_name,
+            file_path: self.file_path,
+            file_size,
+            created_at: self.uploaded_at,
+            modified_at: self.uploaded_at,
+            content,
+            metadata: self.metadata,
+            embeddings: None,
+        })
+    }
+}
@@ -1,6 +1,7 @@
-use crate::domain::storage::StorageResult;
-use crate::domain::storage::models::CreateIndexParams;
+use crate::domain::storage::models::DocumentPartVector;
 use crate::domain::storage::models::{AllDocumentParts, DocumentPart, StoredDocumentPartsInfo};
+use crate::domain::storage::models::{CreateIndexParams, SplitterParams, UpdateDocumentParams};
+use crate::domain::storage::{StorageError, StorageResult};
 use crate::shared::kernel::{DocumentPartId, IndexId, LargeDocumentId};
 
 /// Trait for managing search index lifecycle operations.
@@ -13,6 +14,7 @@ use crate::shared::kernel::{DocumentPartId, IndexId, LargeDocumentId};
 /// * `delete_index` - Removes an existing search index and all its contents
 /// * `get_index` - Retrieves information about a specific index
 /// * `get_all_indexes` - Lists all available indexes in the storage system
+/// * `get_index_splitter` - Retrieves splitter the index has been created with
 ///
 /// # Arguments
 /// * `create_index`:
@@ -23,12 +25,16 @@ use crate::shared::kernel::{DocumentPartId, IndexId, LargeDocumentId};
 ///   - `id` - Identifier of the index to retrieve
 /// * `get_all_indexes`:
 ///   - No arguments
+/// * `get_index_splitter`:
+///   - `id` - Identifier of the index
 ///
 /// # Returns
 /// * `create_index` - `StorageResult<IndexId>` - ID of the created index
 /// * `delete_index` - `StorageResult<()>` - Empty result on success
 /// * `get_index` - `StorageResult<IndexId>` - Index information
 /// * `get_all_indexes` - `StorageResult<Vec<IndexId>>` - List of index IDs
+/// * `get_index_splitter` - `StorageResult<Option<SplitterParams>>` - Stored splitter params,
+///   `None` for storages which do not keep them, so the default splitter is used
 ///
 /// # Example
 /// ```
@@ -61,6 +67,10 @@ pub trait IIndexStorage {
     async fn delete_index(&self, id: &IndexId) -> StorageResult<()>;
     async fn get_index(&self, id: &IndexId) -> StorageResult<IndexId>;
     async fn get_all_indexes(&self) -> StorageResult<Vec<IndexId>>;
+
+    async fn get_index_splitter(&self, _id: &IndexId) -> StorageResult<Option<SplitterParams>> {
+        Ok(None)
+    }
 }
 
 /// Trait for managing document part storage operations.
@@ -69,29 +79,43 @@ pub trait IIndexStorage {
 /// document parts within search indexes.
 ///
 /// # Methods
-/// * `store_document_parts` - Stores multiple document parts in an index
+/// * `store_document_parts` - Stores multiple document parts in an index, replacing
+///   any parts previously stored under the same large document id
+/// * `create_document_parts` - Stores document parts only if there is no document with
+///   the same large document id yet
 /// * `get_document_parts` - Retrieves all parts of a large document
 /// * `get_document_part` - Retrieves a single specific document part
+/// * `update_document_parts` - Rewrites document level fields of all parts, keeping content
 /// * `delete_document_parts` - Deletes all parts of a large document
 ///
 /// # Arguments
 /// * `store_document_parts`:
 ///   - `index` - Target index name
 ///   - `all_doc_parts` - Complete collection of document parts to store
+/// * `create_document_parts`:
+///   - `index` - Target index name
+///   - `all_doc_parts` - Complete collection of document parts to store
 /// * `get_document_parts`:
 ///   - `index` - Index to search in
 ///   - `large_doc_id` - ID of the large document whose parts to retrieve
 /// * `get_document_part`:
 ///   - `index` - Index to search in
 ///   - `doc_part_id` - ID of the specific document part to retrieve
+/// * `update_document_parts`:
+///   - `index` - Index containing the document
+///   - `large_doc_id` - ID of the large document whose parts to update
+///   - `params` - Document level fields to write into every part
 /// * `delete_document_parts`:
 ///   - `index` - Index containing the document
 ///   - `large_doc_id` - ID of the large document whose parts to delete
 ///
 /// # Returns
 /// * `store_document_parts` - `StorageResult<StoredDocumentPartsInfo>` - Information about stored parts
+/// * `create_document_parts` - `StorageResult<StoredDocumentPartsInfo>` - Information about stored
+///   parts or `StorageError::DocumentAlreadyExists`
 /// * `get_document_parts` - `StorageResult<AllDocumentParts>` - Collection of document parts
 /// * `get_document_part` - `StorageResult<DocumentPart>` - Single document part
+/// * `update_document_parts` - `StorageResult<()>` - Empty result on success
 /// * `delete_document_parts` - `StorageResult<()>` - Empty result on success
 ///
 /// # Example
@@ -133,6 +157,16 @@ pub trait IIndexStorage {
 ///         ))
 ///     }
 ///
+///     async fn update_document_parts(
+///         &self,
+///         index: &IndexId,
+///         large_doc_id: &LargeDocumentId,
+///         params: &UpdateDocumentParams,
+///     ) -> StorageResult<()> {
+///         // Implementation for updating metadata of document parts
+///         Ok(())
+///     }
+///
 ///     async fn delete_document_parts(
 ///         &self,
 ///         index: &IndexId,
@@ -151,6 +185,21 @@ pub trait IDocumentPartStorage {
         all_doc_parts: AllDocumentParts,
     ) -> StorageResult<StoredDocumentPartsInfo>;
 
+    /// Default implementation checks existence before storing and is NOT
+    /// atomic: concurrent creates of the same document may both pass the
+    /// check. Backends able to write conditionally must override it.
+    async fn create_document_parts(
+        &self,
+        index: &IndexId,
+        all_doc_parts: AllDocumentParts,
+    ) -> StorageResult<StoredDocumentPartsInfo>
+    where
+        Self: Sync,
+    {
+        check_document_not_exists(self, index, &all_doc_parts).await?;
+        self.store_document_parts(index, all_doc_parts).await
+    }
+
     async fn get_document_parts(
         &self,
         index: &IndexId,
@@ -162,9 +211,84 @@ pub trait IDocumentPartStorage {
         index: &IndexId,
         doc_part_id: &DocumentPartId,
     ) -> StorageResult<DocumentPart>;
+
+    async fn update_document_parts(
+        &self,
+        index: &IndexId,
+        large_doc_id: &LargeDocumentId,
+        params: &UpdateDocumentParams,
+    ) -> StorageResult<()>;
+
     async fn delete_document_parts(
         &self,
         index: &IndexId,
         large_doc_id: &LargeDocumentId,
     ) -> StorageResult<()>;
 }
+
+async fn check_document_not_exists<Storage>(
+    storage: &Storage,
+    index: &IndexId,
+    all_doc_parts: &AllDocumentParts,
+) -> StorageResult<()>
+where
+    Storage: IDocumentPartStorage + Sync + ?Sized,
+{
+    let Some(large_doc_id) = all_doc_parts.first().map(|it| &it.large_doc_id) else {
+        return Ok(());
+    };
+
+    match storage.get_document_parts(index, large_doc_id).await {
+        Ok(parts) if !parts.is_empty() => {
+            let index = index.as_string();
+            let msg = format!("document {large_doc_id} already exists in index {index}");
+            Err(StorageError::DocumentAlreadyExists(anyhow::anyhow!(msg)))
+        }
+        Ok(_) | Err(StorageError::DocumentNotFound(_)) => Ok(()),
+        Err(err) => Err(err),
+    }
+}
+
+/// Trait for storages keeping embedding vectors of document parts apart
+/// from the storage of source documents.
+///
+/// # Methods
+/// * `store_document_vectors` - Stores vectors of document parts, overwriting ones with same ids
+/// * `update_document_vectors` - Rewrites document level fields kept along with vectors
+/// * `delete_document_vectors` - Deletes vectors of all parts of a large document
+///
+/// # Arguments
+/// * `store_document_vectors`:
+///   - `index` - Target index name
+///   - `vectors` - Document parts with their embedding vectors
+/// * `update_document_vectors`:
+///   - `index` - Index containing the document
+///   - `large_doc_id` - ID of the large document whose vectors to update
+///   - `params` - Document level fields to write into every vector
+/// * `delete_document_vectors`:
+///   - `index` - Index containing the document
+///   - `large_doc_id` - ID of the large document whose vectors to delete
+///
+/// # Returns
+/// * `StorageResult<()>` - Empty result on success
+#[async_trait::async_trait]
+pub trait IDocumentVectorStorage {
+    async fn store_document_vectors(
+        &self,
+        index: &IndexId,
+        vectors: Vec<DocumentPartVector>,
+    ) -> StorageResult<()>;
+
+    async fn update_document_vectors(
+        &self,
+        index: &IndexId,
+        large_doc_id: &LargeDocumentId,
+        params: &UpdateDocumentParams,
+    ) -> StorageResult<()>;
+
+    async fn delete_document_vectors(
+        &self,
+        index: &IndexId,
+        large_doc_id: &LargeDocumentId,
+    ) -> StorageResult<()>;
+}
@@ -0,0 +1,23 @@
+use character_text_splitter::CharacterTextSplitter;
+
+use crate::domain::storage::splitter::DocumentSplitter;
+
+/// Fallback splitter merging blocks separated by blank lines while they fit
+/// into `chunk_size` bytes. Blocks exceeding the size are stored as is.
+pub struct CharacterSplitter {
+    chunk_size: usize,
+}
+
+impl CharacterSplitter {
+    pub fn new(chunk_size: usize) -> Self {
+        CharacterSplitter { chunk_size }
+    }
+}
+
+impl DocumentSplitter for CharacterSplitter {
+    fn split(&self, content: &str) -> Vec<String> {
+        CharacterTextSplitter::new()
+            .with_chunk_size(self.chunk_size)
+            .split_text(content)
+    }
+}
@@ -0,0 +1,76 @@
+use crate::domain::storage::splitter::segment::{self, Span};
+use crate::domain::storage::splitter::{DocumentSplitter, SentenceSplitter};
+
+const MAX_HEADING_LEVEL: usize = 6;
+
+/// Splits markdown on sections started by ATX headings (`#` .. `######`),
+/// so a part never mixes content of different sections. Sections longer
+/// than `chunk_size` characters are divided like by `SentenceSplitter`.
+pub struct MarkdownSplitter {
+    sections: SentenceSplitter,
+}
+
+impl MarkdownSplitter {
+    pub fn new(chunk_size: usize) -> Self {
+        MarkdownSplitter {
+            sections: SentenceSplitter::new(chunk_size),
+        }
+    }
+}
+
+impl DocumentSplitter for MarkdownSplitter {
+    fn split(&self, content: &str) -> Vec<String> {
+        sections(content)
+            .into_iter()
+            .flat_map(|span| self.sections.split_span(content, span))
+            .collect()
+    }
+}
+
+/// Headings following each other without any content between them are
+/// kept within the same section as the content below them.
+fn sections(content: &str) -> Vec<Span> {
+    let mut spans = Vec::new();
+    let mut section_start = 0;
+    let mut has_body = false;
+    let mut within_code = false;
+    for (offset, line) in segment::lines(content, 0..content.len()) {
+        if segment::is_code_fence(line) {
+            within_code = !within_code;
+            has_body = true;
+            continue;
+        }
+
+        if within_code || !is_heading(line) {
+            has_body |= !line.trim().is_empty();
+            continue;
+        }
+
+        if has_body {
+            spans.push(section_start..offset);
+            section_start = offset;
+            has_body = false;
+        }
+    }
+
+    spans.push(section_start..content.len());
+    spans
+}
+
+fn is_heading(line: &str) -> bool {
+    let indent = line.len() - line.trim_start_matches(' ').len();
+    if indent > 3 {
+        return false;
+    }
+
+    let line = &line[indent..];
+    let level = line.len() - line.trim_start_matches('#').len();
+    if level == 0 || level > MAX_HEADING_LEVEL {
+        return false;
+    }
+
+    line[level..]
+        .chars()
+        .next()
+        .is_none_or(|it| it.is_whitespace())
+}
@@ -0,0 +1,81 @@
+mod character;
+mod markdown;
+mod segment;
+mod sentence;
+mod token;
+
+pub use character::CharacterSplitter;
+pub use markdown::MarkdownSplitter;
+pub use sentence::SentenceSplitter;
+pub use token::TokenSplitter;
+
+use anyhow::anyhow;
+
+use crate::domain::storage::models::SplitterParams;
+use crate::domain::storage::{StorageError, StorageResult};
+
+/// Strategy dividing content of a large document on parts stored within index.
+///
+/// Returned parts are stored in the same order, so `parts[0]` becomes the
+/// first document part. Empty parts must not be returned.
+///
+/// # Example
+/// ```
+/// let splitter = SentenceSplitter::new(1024);
+/// let parts = splitter.split("First sentence. Second sentence.");
+/// ```
+pub trait DocumentSplitter: Send + Sync {
+    fn split(&self, content: &str) -> Vec<String>;
+}
+
+impl SplitterParams {
+    pub fn validate(&self) -> StorageResult<()> {
+        match self {
+            SplitterParams::Character { chunk_size }
+            | SplitterParams::Sentence { chunk_size }
+            | SplitterParams::Markdown { chunk_size } => {
+                if chunk_size.is_some_and(|size| size == 0) {
+                    let err = anyhow!("splitter chunk size must be greater than zero");
+                    return Err(StorageError::ValidationError(err));
+                }
+            }
+            SplitterParams::Token {
+                token_limit,
+                overlap_rate,
+            } => {
+                if *token_limit == 0 {
+                    let err = anyhow!("splitter token limit must be greater than zero");
+                    return Err(StorageError::ValidationError(err));
+                }
+
+                if !(0.0..1.0).contains(overlap_rate) {
+                    let err = anyhow!("splitter overlap rate must be within [0.0, 1.0)");
+                    return Err(StorageError::ValidationError(err));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn build_splitter(&self, default_chunk_size: usize) -> Box<dyn DocumentSplitter> {
+        match self {
+            SplitterParams::Character { chunk_size } => {
+                let chunk_size = chunk_size.unwrap_or(default_chunk_size);
+                Box::new(CharacterSplitter::new(chunk_size))
+            }
+            SplitterParams::Sentence { chunk_size } => {
+                let chunk_size = chunk_size.unwrap_or(default_chunk_size);
+                Box::new(SentenceSplitter::new(chunk_size))
+            }
+            SplitterParams::Markdown { chunk_size } => {
+                let chunk_size = chunk_size.unwrap_or(default_chunk_size);
+                Box::new(MarkdownSplitter::new(chunk_size))
+            }
+            SplitterParams::Token {
+                token_limit,
+                overlap_rate,
+            } => Box::new(TokenSplitter::new(*token_limit as usize, *overlap_rate)),
+        }
+    }
+}
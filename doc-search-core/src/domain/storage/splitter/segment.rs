@@ -0,0 +1,159 @@
+use std::ops::Range;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Byte range of the splitting content.
+pub type Span = Range<usize>;
+
+const CODE_FENCES: [&str; 2] = ["```", "~~~"];
+
+/// Granularity used to break a span which does not fit into a part.
+/// Every level falls back to the next one for its own oversized pieces.
+#[derive(Clone, Copy)]
+pub enum Level {
+    Block,
+    Sentence,
+    Word,
+    Char,
+}
+
+impl Level {
+    fn next(self) -> Level {
+        match self {
+            Level::Block => Level::Sentence,
+            Level::Sentence => Level::Word,
+            Level::Word | Level::Char => Level::Char,
+        }
+    }
+}
+
+pub fn char_len(text: &str, span: &Span) -> usize {
+    text[span.clone()].chars().count()
+}
+
+/// Breaks `span` on contiguous units which are no longer than `limit`
+/// characters, preferring the coarsest possible boundaries.
+pub fn refine(text: &str, span: Span, limit: usize, level: Level, units: &mut Vec<Span>) {
+    if char_len(text, &span) <= limit {
+        units.push(span);
+        return;
+    }
+
+    let pieces = match level {
+        Level::Block => blocks(text, span),
+        Level::Sentence => sentences(text, span),
+        Level::Word => words(text, span),
+        Level::Char => {
+            units.extend(chars(text, span, limit));
+            return;
+        }
+    };
+
+    for piece in pieces {
+        refine(text, piece, limit, level.next(), units);
+    }
+}
+
+/// Greedily merges contiguous units into parts of at most `limit` characters.
+pub fn pack(text: &str, units: &[Span], limit: usize) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current: Option<(Span, usize)> = None;
+    for unit in units {
+        let unit_len = char_len(text, unit);
+        current = match current {
+            Some((span, len)) if len + unit_len <= limit => {
+                Some((span.start..unit.end, len + unit_len))
+            }
+            Some((span, _)) => {
+                push_part(text, span, &mut parts);
+                Some((unit.clone(), unit_len))
+            }
+            None => Some((unit.clone(), unit_len)),
+        };
+    }
+
+    if let Some((span, _)) = current {
+        push_part(text, span, &mut parts);
+    }
+
+    parts
+}
+
+pub fn push_part(text: &str, span: Span, parts: &mut Vec<String>) {
+    let part = text[span].trim();
+    if !part.is_empty() {
+        parts.push(part.to_string());
+    }
+}
+
+/// Iterates lines of `span` along with their byte offsets. Line breaks
+/// stay attached to lines, so lines cover the whole span.
+pub fn lines(text: &str, span: Span) -> impl Iterator<Item = (usize, &str)> {
+    let start = span.start;
+    text[span]
+        .split_inclusive('\n')
+        .scan(start, |offset, line| {
+            let line_start = *offset;
+            *offset += line.len();
+            Some((line_start, line))
+        })
+}
+
+pub fn is_code_fence(line: &str) -> bool {
+    let line = line.trim_start();
+    CODE_FENCES.iter().any(|fence| line.starts_with(fence))
+}
+
+/// Splits on blocks separated by blank lines. Blank lines within fenced
+/// code do not break blocks, so code snippets are kept together.
+fn blocks(text: &str, span: Span) -> Vec<Span> {
+    let end = span.end;
+    let mut pieces = Vec::new();
+    let mut block_start = span.start;
+    let mut within_code = false;
+    let mut after_blank = false;
+    for (offset, line) in lines(text, span) {
+        let is_blank = line.trim().is_empty();
+        if after_blank && !is_blank && offset > block_start {
+            pieces.push(block_start..offset);
+            block_start = offset;
+        }
+
+        if is_code_fence(line) {
+            within_code = !within_code;
+        }
+
+        after_blank = is_blank && !within_code;
+    }
+
+    pieces.push(block_start..end);
+    pieces
+}
+
+fn sentences(text: &str, span: Span) -> Vec<Span> {
+    let start = span.start;
+    text[span]
+        .split_sentence_bound_indices()
+        .map(|(offset, sentence)| start + offset..start + offset + sentence.len())
+        .collect()
+}
+
+fn words(text: &str, span: Span) -> Vec<Span> {
+    let start = span.start;
+    text[span]
+        .split_word_bound_indices()
+        .map(|(offset, word)| start + offset..start + offset + word.len())
+        .collect()
+}
+
+fn chars(text: &str, span: Span, limit: usize) -> Vec<Span> {
+    let end = span.end;
+    let start = span.start;
+    let mut pieces = text[span]
+        .char_indices()
+        .step_by(limit.max(1))
+        .map(|(offset, _)| start + offset)
+        .collect::<Vec<usize>>();
+
+    pieces.push(end);
+    pieces.windows(2).map(|it| it[0]..it[1]).collect()
+}
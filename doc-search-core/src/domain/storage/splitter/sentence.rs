@@ -0,0 +1,28 @@
+use crate::domain::storage::splitter::DocumentSplitter;
+use crate::domain::storage::splitter::segment::{self, Level};
+
+/// Packs whole paragraphs into parts of at most `chunk_size` characters.
+/// Paragraphs which do not fit are broken on sentences, then on words.
+pub struct SentenceSplitter {
+    chunk_size: usize,
+}
+
+impl SentenceSplitter {
+    pub fn new(chunk_size: usize) -> Self {
+        SentenceSplitter {
+            chunk_size: chunk_size.max(1),
+        }
+    }
+
+    pub(super) fn split_span(&self, content: &str, span: segment::Span) -> Vec<String> {
+        let mut units = Vec::new();
+        segment::refine(content, span, self.chunk_size, Level::Block, &mut units);
+        segment::pack(content, &units, self.chunk_size)
+    }
+}
+
+impl DocumentSplitter for SentenceSplitter {
+    fn split(&self, content: &str) -> Vec<String> {
+        self.split_span(content, 0..content.len())
+    }
+}
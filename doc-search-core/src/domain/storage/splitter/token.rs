@@ -0,0 +1,63 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::domain::storage::splitter::DocumentSplitter;
+use crate::domain::storage::splitter::segment::{self, Span};
+
+/// Divides content on parts of `token_limit` word tokens where consecutive
+/// parts share `token_limit * overlap_rate` tokens. Words are detected by
+/// unicode word boundaries, like the `standard` tokenizer does.
+pub struct TokenSplitter {
+    token_limit: usize,
+    overlap: usize,
+}
+
+impl TokenSplitter {
+    pub fn new(token_limit: usize, overlap_rate: f32) -> Self {
+        let token_limit = token_limit.max(1);
+        let overlap = (token_limit as f32 * overlap_rate.clamp(0.0, 1.0)) as usize;
+        TokenSplitter {
+            token_limit,
+            overlap: overlap.min(token_limit - 1),
+        }
+    }
+}
+
+impl DocumentSplitter for TokenSplitter {
+    fn split(&self, content: &str) -> Vec<String> {
+        let tokens = content
+            .unicode_word_indices()
+            .map(|(offset, word)| offset..offset + word.len())
+            .collect::<Vec<Span>>();
+
+        let mut parts = Vec::new();
+        if tokens.is_empty() {
+            segment::push_part(content, 0..content.len(), &mut parts);
+            return parts;
+        }
+
+        // Part spans up to the beginning of the next token, so punctuation
+        // trailing the last word stays within the part.
+        let step = self.token_limit - self.overlap;
+        let mut first_token = 0;
+        loop {
+            let last_token = (first_token + self.token_limit).min(tokens.len());
+            let start = match first_token {
+                0 => 0,
+                _ => tokens[first_token].start,
+            };
+            let end = tokens
+                .get(last_token)
+                .map(|it| it.start)
+                .unwrap_or(content.len());
+
+            segment::push_part(content, start..end, &mut parts);
+            if last_token == tokens.len() {
+                break;
+            }
+
+            first_token += step;
+        }
+
+        parts
+    }
+}
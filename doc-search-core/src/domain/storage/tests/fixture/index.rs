@@ -12,6 +12,7 @@ pub fn create_index_params() -> CreateIndexParams {
     CreateIndexParams {
         id: TEST_INDEX.to_string(),
         knn: None,
+        splitter: None,
     }
 }
 
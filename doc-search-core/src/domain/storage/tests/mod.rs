@@ -1,2 +1,6 @@
 pub mod fixture;
+mod test_bulk_report;
+mod test_document_assemble;
 mod test_document_split;
+mod test_document_splitter;
+mod test_document_upload;
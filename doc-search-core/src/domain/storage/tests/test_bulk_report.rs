@@ -0,0 +1,48 @@
+use rstest::rstest;
+
+use crate::domain::storage::models::{BulkStoreReport, StoredDocumentPartsInfo};
+use crate::domain::storage::tests::fixture::document::LARGE_DOCUMENT_ID;
+use crate::shared::kernel::{DocumentPartId, LargeDocumentId};
+
+fn build_stored_info() -> StoredDocumentPartsInfo {
+    StoredDocumentPartsInfo {
+        large_doc_id: LargeDocumentId(LARGE_DOCUMENT_ID.to_string()),
+        first_part_id: DocumentPartId(LARGE_DOCUMENT_ID.to_string()),
+        doc_parts_amount: 1,
+    }
+}
+
+#[rstest]
+fn test_bulk_report_merge_positions() {
+    let mut report = BulkStoreReport::default();
+    report.add_failed(1, "./invalid.txt".to_string(), "invalid form".to_string());
+    assert!(report.has_failures());
+
+    let mut stored = BulkStoreReport::default();
+    stored.add_stored(0, "./first.txt".to_string(), build_stored_info());
+    stored.add_failed(1, "./third.txt".to_string(), "storage error".to_string());
+    stored.add_stored(2, "./fourth.txt".to_string(), build_stored_info());
+
+    report.merge(stored, &[0, 2, 3]);
+
+    let succeeded = report
+        .succeeded
+        .iter()
+        .map(|it| (it.position, it.file_path.as_str()))
+        .collect::<Vec<(usize, &str)>>();
+    assert_eq!(succeeded, vec![(0, "./first.txt"), (3, "./fourth.txt")]);
+
+    let failed = report
+        .failed
+        .iter()
+        .map(|it| (it.position, it.file_path.as_str()))
+        .collect::<Vec<(usize, &str)>>();
+    assert_eq!(failed, vec![(1, "./invalid.txt"), (2, "./third.txt")]);
+}
+
+#[rstest]
+fn test_bulk_report_without_failures() {
+    let mut report = BulkStoreReport::default();
+    report.add_stored(0, "./first.txt".to_string(), build_stored_info());
+    assert!(!report.has_failures());
+}
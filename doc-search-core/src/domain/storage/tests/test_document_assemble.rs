@@ -0,0 +1,61 @@
+use rstest::rstest;
+
+use crate::domain::storage::StorageError;
+use crate::domain::storage::models::{DocumentPart, LargeDocument};
+use crate::domain::storage::splitter::{DocumentSplitter, TokenSplitter};
+use crate::domain::storage::tests::fixture::document::{
+    LARGE_DOCUMENT_CREATED_TIMESTAMP, build_document_part, build_large_document,
+};
+
+fn build_document_parts(contents: &[&str]) -> Vec<DocumentPart> {
+    contents
+        .iter()
+        .enumerate()
+        .map(|(position, content)| {
+            let mut doc_part = build_document_part(position + 1);
+            doc_part.content = content.to_string();
+            doc_part
+        })
+        .collect()
+}
+
+#[rstest]
+#[case(vec!["first part", "second part"], false, "first part\nsecond part")]
+#[case(vec!["one two", "two three", "three four."], true, "one two three four.")]
+#[case(vec!["I saw a", "a cat"], false, "I saw a\na cat")]
+#[case(vec!["some text", "extra words"], true, "some text\nextra words")]
+#[case(vec!["only part"], true, "only part")]
+fn test_assemble_document_parts(
+    #[case] contents: Vec<&str>,
+    #[case] merge_overlaps: bool,
+    #[case] expected: &str,
+) -> anyhow::Result<()> {
+    let mut doc_parts = build_document_parts(&contents);
+    doc_parts.reverse();
+
+    let large_doc = LargeDocument::assemble_document_parts(doc_parts, merge_overlaps)?;
+    assert_eq!(large_doc.content, expected);
+    assert_eq!(large_doc.file_path, "./test-document.docx");
+    assert_eq!(large_doc.created_at, LARGE_DOCUMENT_CREATED_TIMESTAMP);
+    Ok(())
+}
+
+#[rstest]
+fn test_assemble_token_split_document() -> anyhow::Result<()> {
+    let large_document = build_large_document();
+    let expected = large_document.content.clone();
+
+    let splitter = TokenSplitter::new(3, 0.4);
+    assert!(splitter.split(&expected).len() > 1);
+
+    let doc_parts = large_document.divide_large_document_with(&splitter)?;
+    let large_doc = LargeDocument::assemble_document_parts(doc_parts, true)?;
+    assert_eq!(large_doc.content, expected);
+    Ok(())
+}
+
+#[rstest]
+fn test_assemble_empty_document_parts() {
+    let result = LargeDocument::assemble_document_parts(Vec::new(), false);
+    assert!(matches!(result, Err(StorageError::DocumentNotFound(_))));
+}
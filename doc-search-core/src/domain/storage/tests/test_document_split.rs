@@ -1,6 +1,6 @@
 use rstest::rstest;
 
-use crate::domain::storage::models::LargeDocument;
+use crate::domain::storage::models::{DocumentPartEmbeddings, LargeDocument};
 use crate::domain::storage::tests::fixture::document::build_large_document;
 
 #[rstest]
@@ -16,3 +16,81 @@ fn test_build_searching_params(
     assert_eq!(document_parts.len(), expected_doc_parts);
     Ok(())
 }
+
+#[rstest]
+fn test_large_document_id_is_path_based() -> anyhow::Result<()> {
+    let first_parts = build_large_document().divide_large_document_on_parts(10)?;
+    let second_parts = build_large_document().divide_large_document_on_parts(10)?;
+    assert_eq!(
+        first_parts[0].large_doc_id.0,
+        second_parts[0].large_doc_id.0
+    );
+
+    let mut other_document = build_large_document();
+    other_document.file_path = "./other-document.docx".to_string();
+    let other_parts = other_document.divide_large_document_on_parts(10)?;
+    assert_ne!(first_parts[0].large_doc_id.0, other_parts[0].large_doc_id.0);
+    Ok(())
+}
+
+#[rstest]
+fn test_large_document_id_without_path_is_content_based() -> anyhow::Result<()> {
+    let mut first_document = build_large_document();
+    first_document.file_path = String::default();
+    let first_parts = first_document.divide_large_document_on_parts(10)?;
+
+    let mut second_document = build_large_document();
+    second_document.file_path = " ".to_string();
+    second_document.content = "there is another content".to_string();
+    let second_parts = second_document.divide_large_document_on_parts(10)?;
+    assert_ne!(first_parts[0].large_doc_id.0, second_parts[0].large_doc_id.0);
+
+    let mut same_document = build_large_document();
+    same_document.file_path = String::default();
+    let same_parts = same_document.divide_large_document_on_parts(10)?;
+    assert_eq!(first_parts[0].large_doc_id.0, same_parts[0].large_doc_id.0);
+    Ok(())
+}
+
+fn build_part_embeddings(chunks: &[&str], dimensions: &[usize]) -> DocumentPartEmbeddings {
+    DocumentPartEmbeddings {
+        chunked_text: chunks.iter().map(|it| it.to_string()).collect(),
+        embeddings: dimensions.iter().map(|dim| vec![0.5; *dim]).collect(),
+    }
+}
+
+#[rstest]
+fn test_divide_large_document_by_embeddings() -> anyhow::Result<()> {
+    let mut large_document = build_large_document();
+    large_document.embeddings = Some(vec![
+        build_part_embeddings(&["there is", "some content"], &[3, 3]),
+        build_part_embeddings(&["about project"], &[3]),
+    ]);
+
+    let document_parts = large_document.divide_large_document_on_parts(10)?;
+    assert_eq!(document_parts.len(), 2);
+    assert_eq!(document_parts[0].content, "there is\nsome content");
+    assert_eq!(document_parts[1].doc_part_id, 2);
+
+    let part_embeddings = document_parts[1].embeddings.as_ref().unwrap();
+    assert_eq!(
+        part_embeddings.chunked_text,
+        vec!["about project".to_string()]
+    );
+    Ok(())
+}
+
+#[rstest]
+#[case(Vec::new())]
+#[case(vec![build_part_embeddings(&[], &[])])]
+#[case(vec![build_part_embeddings(&["there is", "some content"], &[3])])]
+#[case(vec![build_part_embeddings(&["there is", "some content"], &[3, 2])])]
+#[case(vec![build_part_embeddings(&["there is"], &[0])])]
+fn test_divide_large_document_by_invalid_embeddings(
+    #[case] embeddings: Vec<DocumentPartEmbeddings>,
+) {
+    let mut large_document = build_large_document();
+    large_document.embeddings = Some(embeddings);
+    let result = large_document.divide_large_document_on_parts(10);
+    assert!(result.is_err());
+}
@@ -0,0 +1,121 @@
+use rstest::rstest;
+
+use crate::domain::storage::StorageError;
+use crate::domain::storage::models::SplitterParams;
+use crate::domain::storage::splitter::{CharacterSplitter, DocumentSplitter, MarkdownSplitter};
+use crate::domain::storage::splitter::{SentenceSplitter, TokenSplitter};
+use crate::domain::storage::tests::fixture::document::build_large_document;
+
+const CODE_BLOCK_CONTENT: &str = "Intro text.\n\n```\nfn a() {}\n\nfn b() {}\n```\n\nOutro.";
+const MARKDOWN_CONTENT: &str =
+    "# Title\n## Intro\nHello.\n## Usage\n```\n# not a heading\n```\nRun it.";
+
+#[rstest]
+#[case(
+    "First sentence. Second sentence.\n\nNext paragraph.",
+    20,
+    vec!["First sentence.", "Second sentence.", "Next paragraph."]
+)]
+#[case("One.\n\nTwo.", 100, vec!["One.\n\nTwo."])]
+#[case("abcdefghij", 4, vec!["abcd", "efgh", "ij"])]
+#[case(
+    CODE_BLOCK_CONTENT,
+    30,
+    vec!["Intro text.", "```\nfn a() {}\n\nfn b() {}\n```", "Outro."]
+)]
+#[case("  \n\n ", 10, vec![])]
+fn test_sentence_splitter(
+    #[case] content: &str,
+    #[case] chunk_size: usize,
+    #[case] expected: Vec<&str>,
+) {
+    let parts = SentenceSplitter::new(chunk_size).split(content);
+    assert_eq!(parts, expected);
+}
+
+#[rstest]
+#[case(
+    MARKDOWN_CONTENT,
+    100,
+    vec!["# Title\n## Intro\nHello.", "## Usage\n```\n# not a heading\n```\nRun it."]
+)]
+#[case(
+    "Preface.\n# Chapter\nFirst sentence. Second sentence.",
+    20,
+    vec!["Preface.", "# Chapter", "First sentence.", "Second sentence."]
+)]
+fn test_markdown_splitter(
+    #[case] content: &str,
+    #[case] chunk_size: usize,
+    #[case] expected: Vec<&str>,
+) {
+    let parts = MarkdownSplitter::new(chunk_size).split(content);
+    assert_eq!(parts, expected);
+}
+
+#[rstest]
+#[case(2, 0.0, vec!["one two", "three four", "five."])]
+#[case(2, 0.5, vec!["one two", "two three", "three four", "four five."])]
+#[case(10, 0.2, vec!["one two three four five."])]
+fn test_token_splitter(
+    #[case] token_limit: usize,
+    #[case] overlap_rate: f32,
+    #[case] expected: Vec<&str>,
+) {
+    let content = "one two three four five.";
+    let parts = TokenSplitter::new(token_limit, overlap_rate).split(content);
+    assert_eq!(parts, expected);
+}
+
+#[rstest]
+fn test_character_splitter() {
+    let content = "first block\n\nsecond block";
+    let parts = CharacterSplitter::new(12).split(content);
+    assert_eq!(parts, vec!["first block", "second block"]);
+}
+
+#[rstest]
+#[case(SplitterParams::Sentence { chunk_size: None }, true)]
+#[case(SplitterParams::Markdown { chunk_size: Some(0) }, false)]
+#[case(SplitterParams::Token { token_limit: 50, overlap_rate: 0.2 }, true)]
+#[case(SplitterParams::Token { token_limit: 0, overlap_rate: 0.2 }, false)]
+#[case(SplitterParams::Token { token_limit: 50, overlap_rate: 1.0 }, false)]
+fn test_splitter_params_validation(#[case] params: SplitterParams, #[case] is_valid: bool) {
+    let result = params.validate();
+    assert_eq!(result.is_ok(), is_valid);
+}
+
+#[rstest]
+fn test_divide_large_document_with_splitter() -> anyhow::Result<()> {
+    let splitter = SplitterParams::Token {
+        token_limit: 3,
+        overlap_rate: 0.0,
+    }
+    .build_splitter(1024);
+
+    let document_parts = build_large_document().divide_large_document_with(splitter.as_ref())?;
+    let contents = document_parts
+        .iter()
+        .map(|it| it.content.as_str())
+        .collect::<Vec<&str>>();
+
+    assert_eq!(
+        contents,
+        vec!["there is some", "huge content about", "current project"]
+    );
+    assert_eq!(document_parts[2].doc_part_id, 3);
+    Ok(())
+}
+
+#[rstest]
+fn test_divide_blank_document() {
+    let mut large_document = build_large_document();
+    large_document.content = " \n\n ".to_string();
+
+    let splitter = SentenceSplitter::new(10);
+    let result = large_document.divide_large_document_with(&splitter);
+    assert!(matches!(
+        result,
+        Err(StorageError::CantSplitLargeDocuments(_))
+    ));
+}
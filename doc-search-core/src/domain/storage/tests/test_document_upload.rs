@@ -0,0 +1,161 @@
+use rstest::rstest;
+use std::io::{Cursor, Write};
+use zip::write::SimpleFileOptions;
+
+use crate::domain::storage::StorageError;
+use crate::domain::storage::models::{DocumentFormat, UploadedFile, UploadedFileBuilder};
+use crate::domain::storage::tests::fixture::document::LARGE_DOCUMENT_CREATED_TIMESTAMP;
+
+const SAMPLE_PDF: &[u8] = include_bytes!("resources/sample.pdf");
+
+const DOCX_DOCUMENT_XML: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+  <w:body>
+    <w:p><w:r><w:t>First paragraph</w:t></w:r></w:p>
+    <w:p>
+      <w:r><w:t xml:space="preserve">Second </w:t></w:r>
+      <w:r><w:instrText>PAGE \* MERGEFORMAT</w:instrText></w:r>
+      <w:r><w:t>paragraph &amp; more</w:t></w:r>
+    </w:p>
+  </w:body>
+</w:document>"#;
+
+const ODT_CONTENT_XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<office:document-content xmlns:office="urn:oasis:names:tc:opendocument:xmlns:office:1.0" xmlns:text="urn:oasis:names:tc:opendocument:xmlns:text:1.0">
+  <office:body>
+    <office:text>
+      <text:h>Heading</text:h>
+      <text:p>Some<text:s/>text<text:tab/>with <text:span>span</text:span></text:p>
+    </office:text>
+  </office:body>
+</office:document-content>"#;
+
+fn build_zip_archive(entry: &str, content: &str) -> anyhow::Result<Vec<u8>> {
+    let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    writer.start_file(entry, SimpleFileOptions::default())?;
+    writer.write_all(content.as_bytes())?;
+    Ok(writer.finish()?.into_inner())
+}
+
+fn build_uploaded_file(file_name: &str, data: Vec<u8>) -> UploadedFile {
+    UploadedFileBuilder::default()
+        .file_name(file_name.to_string())
+        .file_path(format!("./{file_name}"))
+        .uploaded_at(LARGE_DOCUMENT_CREATED_TIMESTAMP)
+        .data(data)
+        .build()
+        .expect("build uploaded file failed")
+}
+
+#[rstest]
+#[case("notes.txt", None, Some(DocumentFormat::PlainText))]
+#[case("README.MD", None, Some(DocumentFormat::Markdown))]
+#[case("index.htm", None, Some(DocumentFormat::Html))]
+#[case(
+    "report.pdf",
+    Some("application/octet-stream"),
+    Some(DocumentFormat::Pdf)
+)]
+#[case("report.docx", None, Some(DocumentFormat::Docx))]
+#[case("report.odt", None, Some(DocumentFormat::Odt))]
+#[case("report", Some("application/pdf"), Some(DocumentFormat::Pdf))]
+#[case("page", Some("text/html; charset=utf-8"), Some(DocumentFormat::Html))]
+#[case("image.png", Some("image/png"), None)]
+#[case("archive", None, None)]
+fn test_detect_document_format(
+    #[case] file_name: &str,
+    #[case] content_type: Option<&str>,
+    #[case] expected: Option<DocumentFormat>,
+) {
+    let format = DocumentFormat::detect(file_name, content_type);
+    assert_eq!(format, expected);
+}
+
+#[rstest]
+#[case(
+    DocumentFormat::PlainText,
+    "  plain text content\n",
+    "plain text content"
+)]
+#[case(
+    DocumentFormat::Markdown,
+    "# Title\n\nSome *text*",
+    "# Title\n\nSome *text*"
+)]
+#[case(
+    DocumentFormat::Html,
+    "<html><head><title>T</title></head><body><p>Hello &amp; welcome</p></body></html>",
+    "Hello & welcome"
+)]
+fn test_extract_text_documents(
+    #[case] format: DocumentFormat,
+    #[case] data: &str,
+    #[case] expected: &str,
+) -> anyhow::Result<()> {
+    let text = format.extract_text(data.as_bytes())?;
+    assert_eq!(text, expected);
+    Ok(())
+}
+
+#[rstest]
+fn test_extract_docx_document() -> anyhow::Result<()> {
+    let data = build_zip_archive("word/document.xml", DOCX_DOCUMENT_XML)?;
+    let text = DocumentFormat::Docx.extract_text(&data)?;
+    assert_eq!(text, "First paragraph\nSecond paragraph & more");
+    Ok(())
+}
+
+#[rstest]
+fn test_extract_odt_document() -> anyhow::Result<()> {
+    let data = build_zip_archive("content.xml", ODT_CONTENT_XML)?;
+    let text = DocumentFormat::Odt.extract_text(&data)?;
+    assert_eq!(text, "Heading\nSome text\twith span");
+    Ok(())
+}
+
+#[rstest]
+fn test_extract_pdf_document() -> anyhow::Result<()> {
+    let text = DocumentFormat::Pdf.extract_text(SAMPLE_PDF)?;
+    assert_eq!(text, "Hello uploaded document");
+    Ok(())
+}
+
+#[rstest]
+fn test_extract_broken_pdf_document() {
+    let result = DocumentFormat::Pdf.extract_text(b"%PDF-1.7 garbage");
+    assert!(result.is_err());
+
+    let truncated = &SAMPLE_PDF[..SAMPLE_PDF.len() / 3];
+    let result = DocumentFormat::Pdf.extract_text(truncated);
+    assert!(result.is_err());
+}
+
+#[rstest]
+fn test_extract_broken_docx_document() {
+    let result = DocumentFormat::Docx.extract_text(b"not a zip archive");
+    assert!(result.is_err());
+}
+
+#[rstest]
+fn test_uploaded_file_into_large_document() -> anyhow::Result<()> {
+    let data = b"uploaded content".to_vec();
+    let file = build_uploaded_file("notes.txt", data);
+    let large_doc = file.extract_large_document()?;
+
+    assert_eq!(large_doc.file_name, "notes.txt");
+    assert_eq!(large_doc.file_path, "./notes.txt");
+    assert_eq!(large_doc.file_size, 16);
+    assert_eq!(large_doc.created_at, LARGE_DOCUMENT_CREATED_TIMESTAMP);
+    assert_eq!(large_doc.modified_at, LARGE_DOCUMENT_CREATED_TIMESTAMP);
+    assert_eq!(large_doc.content, "uploaded content");
+    Ok(())
+}
+
+#[rstest]
+#[case("image.png", b"\x89PNG".to_vec())]
+#[case("notes.txt", b"\xff\xfe\xfd".to_vec())]
+fn test_uploaded_file_validation_error(#[case] file_name: &str, #[case] data: Vec<u8>) {
+    let file = build_uploaded_file(file_name, data);
+    let result = file.extract_large_document();
+    assert!(matches!(result, Err(StorageError::ValidationError(_))));
+}
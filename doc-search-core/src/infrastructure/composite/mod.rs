@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod tests;
+
+mod storage;
+pub use storage::CompositeStorage;
+
+use std::sync::Arc;
+use tracing::instrument;
+
+use crate::domain::searcher::models::{Pagination, PaginationParams};
+use crate::domain::searcher::models::{SearchKindParams, SearchingParams};
+use crate::domain::searcher::models::{SuggestParams, Suggestions};
+use crate::domain::searcher::{IPaginator, ISearcher, SearchResult};
+
+/// Searcher routing semantic queries to a vector searcher while fulltext,
+/// retrieve, hybrid and similar queries, suggestions and cursor pagination
+/// go to the main one storing source documents.
+pub struct CompositeSearcher<Searcher, Semantic>
+where
+    Searcher: ISearcher + IPaginator + Send + Sync,
+    Semantic: ISearcher + Send + Sync,
+{
+    searcher: Arc<Searcher>,
+    semantic: Arc<Semantic>,
+}
+
+impl<Searcher, Semantic> CompositeSearcher<Searcher, Semantic>
+where
+    Searcher: ISearcher + IPaginator + Send + Sync,
+    Semantic: ISearcher + Send + Sync,
+{
+    pub fn new(searcher: Arc<Searcher>, semantic: Arc<Semantic>) -> Self {
+        CompositeSearcher { searcher, semantic }
+    }
+}
+
+#[async_trait::async_trait]
+impl<Searcher, Semantic> ISearcher for CompositeSearcher<Searcher, Semantic>
+where
+    Searcher: ISearcher + IPaginator + Send + Sync,
+    Semantic: ISearcher + Send + Sync,
+{
+    #[instrument(level = "info", skip(self))]
+    async fn search(&self, params: &SearchingParams) -> SearchResult<Pagination> {
+        match params.get_kind() {
+            SearchKindParams::Semantic(_) => self.semantic.search(params).await,
+            _ => self.searcher.search(params).await,
+        }
+    }
+
+    #[instrument(level = "info", skip(self))]
+    async fn suggest(&self, params: &SuggestParams) -> SearchResult<Suggestions> {
+        self.searcher.suggest(params).await
+    }
+}
+
+#[async_trait::async_trait]
+impl<Searcher, Semantic> IPaginator for CompositeSearcher<Searcher, Semantic>
+where
+    Searcher: ISearcher + IPaginator + Send + Sync,
+    Semantic: ISearcher + Send + Sync,
+{
+    #[instrument(level = "info", skip(self))]
+    async fn paginate(&self, params: &PaginationParams) -> SearchResult<Pagination> {
+        self.searcher.paginate(params).await
+    }
+
+    #[instrument(level = "info", skip(self))]
+    async fn release(&self, params: &PaginationParams) -> SearchResult<()> {
+        self.searcher.release(params).await
+    }
+}
@@ -0,0 +1,248 @@
+use std::sync::Arc;
+use tracing::instrument;
+
+use crate::domain::storage::models::UpdateDocumentParams;
+use crate::domain::storage::models::{AllDocumentParts, DocumentPart, DocumentPartVector};
+use crate::domain::storage::models::{CreateIndexParams, SplitterParams, StoredDocumentPartsInfo};
+use crate::domain::storage::{IDocumentPartStorage, IDocumentVectorStorage, IIndexStorage};
+use crate::domain::storage::{StorageError, StorageResult};
+use crate::shared::kernel::{DocumentPartId, IndexId, LargeDocumentId};
+
+/// Storage keeping documents in the main storage while embeddings of stored
+/// document parts are written to a vector storage, so semantic queries routed
+/// by `CompositeSearcher` to the vector searcher find stored documents.
+///
+/// Vectors are kept within indexes created with knn params only, documents
+/// of other indexes are stored by the main storage.
+pub struct CompositeStorage<Storage, Vectors>
+where
+    Storage: IIndexStorage + IDocumentPartStorage + Send + Sync,
+    Vectors: IIndexStorage + IDocumentVectorStorage + Send + Sync,
+{
+    storage: Arc<Storage>,
+    vectors: Arc<Vectors>,
+}
+
+impl<Storage, Vectors> CompositeStorage<Storage, Vectors>
+where
+    Storage: IIndexStorage + IDocumentPartStorage + Send + Sync,
+    Vectors: IIndexStorage + IDocumentVectorStorage + Send + Sync,
+{
+    pub fn new(storage: Arc<Storage>, vectors: Arc<Vectors>) -> Self {
+        CompositeStorage { storage, vectors }
+    }
+
+    async fn has_vector_index(&self, index_id: &IndexId) -> StorageResult<bool> {
+        match self.vectors.get_index(index_id).await {
+            Ok(_) => Ok(true),
+            Err(StorageError::IndexNotFound(_)) => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+
+    async fn store_vectors(
+        &self,
+        index_id: &IndexId,
+        stored_info: &StoredDocumentPartsInfo,
+        vectors: Vec<DocumentPartVector>,
+    ) -> StorageResult<()> {
+        if !self.has_vector_index(index_id).await? {
+            return Ok(());
+        }
+
+        // Vectors of the replaced document are dropped, since the new one
+        // may consist of less parts.
+        let large_doc_id = &stored_info.large_doc_id;
+        self.vectors
+            .delete_document_vectors(index_id, large_doc_id)
+            .await?;
+
+        if !vectors.is_empty() {
+            self.vectors
+                .store_document_vectors(index_id, vectors)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    fn split_indexes(index: &IndexId) -> impl Iterator<Item = IndexId> + '_ {
+        index.split(',').map(|it| IndexId(it.to_owned()))
+    }
+}
+
+#[async_trait::async_trait]
+impl<Storage, Vectors> IIndexStorage for CompositeStorage<Storage, Vectors>
+where
+    Storage: IIndexStorage + IDocumentPartStorage + Send + Sync,
+    Vectors: IIndexStorage + IDocumentVectorStorage + Send + Sync,
+{
+    #[instrument(level = "info", skip(self))]
+    async fn create_index(&self, params: &CreateIndexParams) -> StorageResult<IndexId> {
+        let index_id = self.storage.create_index(params).await?;
+        if params.knn.is_none() {
+            return Ok(index_id);
+        }
+
+        if let Err(err) = self.vectors.create_index(params).await {
+            if let Err(err) = self.storage.delete_index(&index_id).await {
+                tracing::error!(index=%index_id.0, err=?err, "failed to rollback created index");
+            }
+
+            return Err(err);
+        }
+
+        Ok(index_id)
+    }
+
+    #[instrument(level = "info", skip(self))]
+    async fn delete_index(&self, index_id: &IndexId) -> StorageResult<()> {
+        self.storage.delete_index(index_id).await?;
+        match self.vectors.delete_index(index_id).await {
+            Ok(()) | Err(StorageError::IndexNotFound(_)) => Ok(()),
+            Err(err) => Err(err),
+        }
+    }
+
+    #[instrument(level = "info", skip(self))]
+    async fn get_index(&self, index_id: &IndexId) -> StorageResult<IndexId> {
+        self.storage.get_index(index_id).await
+    }
+
+    #[instrument(level = "info", skip_all)]
+    async fn get_all_indexes(&self) -> StorageResult<Vec<IndexId>> {
+        self.storage.get_all_indexes().await
+    }
+
+    #[instrument(level = "info", skip(self))]
+    async fn get_index_splitter(
+        &self,
+        index_id: &IndexId,
+    ) -> StorageResult<Option<SplitterParams>> {
+        self.storage.get_index_splitter(index_id).await
+    }
+}
+
+#[async_trait::async_trait]
+impl<Storage, Vectors> IDocumentPartStorage for CompositeStorage<Storage, Vectors>
+where
+    Storage: IIndexStorage + IDocumentPartStorage + Send + Sync,
+    Vectors: IIndexStorage + IDocumentVectorStorage + Send + Sync,
+{
+    #[instrument(
+        level = "info",
+        skip_all,
+        fields(
+            index_id = index_id.0,
+            document_parts_amount = all_doc_parts.len(),
+        ),
+    )]
+    async fn store_document_parts(
+        &self,
+        index_id: &IndexId,
+        all_doc_parts: AllDocumentParts,
+    ) -> StorageResult<StoredDocumentPartsInfo> {
+        let vectors = all_doc_parts
+            .iter()
+            .filter_map(DocumentPartVector::from_document_part)
+            .collect::<Vec<DocumentPartVector>>();
+
+        let stored_info = self
+            .storage
+            .store_document_parts(index_id, all_doc_parts)
+            .await?;
+
+        self.store_vectors(index_id, &stored_info, vectors).await?;
+        Ok(stored_info)
+    }
+
+    #[instrument(
+        level = "info",
+        skip_all,
+        fields(
+            index_id = index_id.0,
+            document_parts_amount = all_doc_parts.len(),
+        ),
+    )]
+    async fn create_document_parts(
+        &self,
+        index_id: &IndexId,
+        all_doc_parts: AllDocumentParts,
+    ) -> StorageResult<StoredDocumentPartsInfo> {
+        let vectors = all_doc_parts
+            .iter()
+            .filter_map(DocumentPartVector::from_document_part)
+            .collect::<Vec<DocumentPartVector>>();
+
+        let stored_info = self
+            .storage
+            .create_document_parts(index_id, all_doc_parts)
+            .await?;
+
+        self.store_vectors(index_id, &stored_info, vectors).await?;
+        Ok(stored_info)
+    }
+
+    #[instrument(level = "info", skip(self))]
+    async fn get_document_parts(
+        &self,
+        index_id: &IndexId,
+        large_doc_id: &LargeDocumentId,
+    ) -> StorageResult<AllDocumentParts> {
+        self.storage
+            .get_document_parts(index_id, large_doc_id)
+            .await
+    }
+
+    #[instrument(level = "info", skip(self))]
+    async fn get_document_part(
+        &self,
+        index_id: &IndexId,
+        doc_part_id: &DocumentPartId,
+    ) -> StorageResult<DocumentPart> {
+        self.storage.get_document_part(index_id, doc_part_id).await
+    }
+
+    #[instrument(level = "info", skip(self))]
+    async fn update_document_parts(
+        &self,
+        index: &IndexId,
+        large_doc_id: &LargeDocumentId,
+        params: &UpdateDocumentParams,
+    ) -> StorageResult<()> {
+        self.storage
+            .update_document_parts(index, large_doc_id, params)
+            .await?;
+
+        for index_id in Self::split_indexes(index) {
+            if self.has_vector_index(&index_id).await? {
+                self.vectors
+                    .update_document_vectors(&index_id, large_doc_id, params)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[instrument(level = "info", skip(self))]
+    async fn delete_document_parts(
+        &self,
+        index: &IndexId,
+        large_doc_id: &LargeDocumentId,
+    ) -> StorageResult<()> {
+        self.storage
+            .delete_document_parts(index, large_doc_id)
+            .await?;
+
+        for index_id in Self::split_indexes(index) {
+            if self.has_vector_index(&index_id).await? {
+                self.vectors
+                    .delete_document_vectors(&index_id, large_doc_id)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,3 @@
+mod stub;
+mod test_searcher;
+mod test_storage;
@@ -0,0 +1,150 @@
+use anyhow::anyhow;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use crate::domain::searcher::models::{DocumentPartEntrailsBuilder, SearchingParams};
+use crate::domain::searcher::models::{FoundedDocumentBuilder, Pagination, SearchKindParams};
+use crate::domain::searcher::models::{SuggestParams, Suggestions};
+use crate::domain::searcher::{ISearcher, SearchError, SearchResult};
+use crate::domain::storage::models::UpdateDocumentParams;
+use crate::domain::storage::models::{CreateIndexParams, DocumentPartVector};
+use crate::domain::storage::{IDocumentVectorStorage, IIndexStorage};
+use crate::domain::storage::{StorageError, StorageResult};
+use crate::shared::kernel::vector::cosine_similarity;
+use crate::shared::kernel::{IndexId, LargeDocumentId};
+
+/// Vector storage keeping points in memory and searching them by cosine
+/// similarity the same way qdrant collections do.
+#[derive(Default)]
+pub struct VectorStorageStub {
+    collections: Mutex<HashMap<String, Vec<DocumentPartVector>>>,
+}
+
+#[async_trait::async_trait]
+impl IIndexStorage for VectorStorageStub {
+    async fn create_index(&self, index: &CreateIndexParams) -> StorageResult<IndexId> {
+        let mut collections = self.collections.lock().unwrap();
+        collections.insert(index.id.clone(), Vec::new());
+        Ok(IndexId(index.id.clone()))
+    }
+
+    async fn delete_index(&self, id: &IndexId) -> StorageResult<()> {
+        let mut collections = self.collections.lock().unwrap();
+        collections
+            .remove(&id.0)
+            .map(|_| ())
+            .ok_or_else(|| StorageError::IndexNotFound(anyhow!("there is no collection {}", id.0)))
+    }
+
+    async fn get_index(&self, id: &IndexId) -> StorageResult<IndexId> {
+        let collections = self.collections.lock().unwrap();
+        match collections.contains_key(&id.0) {
+            true => Ok(id.clone()),
+            false => Err(StorageError::IndexNotFound(anyhow!(
+                "no collection {}",
+                id.0
+            ))),
+        }
+    }
+
+    async fn get_all_indexes(&self) -> StorageResult<Vec<IndexId>> {
+        let collections = self.collections.lock().unwrap();
+        Ok(collections.keys().map(|it| IndexId(it.clone())).collect())
+    }
+}
+
+#[async_trait::async_trait]
+impl IDocumentVectorStorage for VectorStorageStub {
+    async fn store_document_vectors(
+        &self,
+        index: &IndexId,
+        vectors: Vec<DocumentPartVector>,
+    ) -> StorageResult<()> {
+        let mut collections = self.collections.lock().unwrap();
+        let points = collections.entry(index.0.clone()).or_default();
+        points.retain(|it| vectors.iter().all(|vector| vector.id.0 != it.id.0));
+        points.extend(vectors);
+        Ok(())
+    }
+
+    async fn update_document_vectors(
+        &self,
+        index: &IndexId,
+        large_doc_id: &LargeDocumentId,
+        params: &UpdateDocumentParams,
+    ) -> StorageResult<()> {
+        let mut collections = self.collections.lock().unwrap();
+        let points = collections.entry(index.0.clone()).or_default();
+        points
+            .iter_mut()
+            .filter(|it| it.document.large_doc_id.0 == large_doc_id.0)
+            .for_each(|it| it.document.apply_update(params));
+        Ok(())
+    }
+
+    async fn delete_document_vectors(
+        &self,
+        index: &IndexId,
+        large_doc_id: &LargeDocumentId,
+    ) -> StorageResult<()> {
+        let mut collections = self.collections.lock().unwrap();
+        let points = collections.entry(index.0.clone()).or_default();
+        points.retain(|it| it.document.large_doc_id.0 != large_doc_id.0);
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl ISearcher for VectorStorageStub {
+    async fn search(&self, params: &SearchingParams) -> SearchResult<Pagination> {
+        let SearchKindParams::Semantic(semantic) = params.get_kind() else {
+            return Err(SearchError::ValidationError(anyhow!("unsupported query")));
+        };
+
+        let tokens = semantic.tokens.clone().unwrap_or_default();
+        let min_score = semantic.min_score.unwrap_or_default() as f64;
+        let collections = self.collections.lock().unwrap();
+        let mut founded = Vec::new();
+        for index in params.get_indexes() {
+            for point in collections.get(index).into_iter().flatten() {
+                let score = cosine_similarity(&tokens, &point.vector);
+                if score < min_score {
+                    continue;
+                }
+
+                let document = &point.document;
+                let entrails = DocumentPartEntrailsBuilder::default()
+                    .large_doc_id(document.large_doc_id.clone())
+                    .doc_part_id(document.doc_part_id)
+                    .file_name(document.file_name.clone())
+                    .file_path(document.file_path.clone())
+                    .file_size(document.file_size)
+                    .created_at(document.created_at)
+                    .modified_at(document.modified_at)
+                    .content(None)
+                    .chunked_text(None)
+                    .embeddings(None)
+                    .metadata(None)
+                    .build()
+                    .map_err(|err| SearchError::InternalError(anyhow!(err)))?;
+
+                let founded_doc = FoundedDocumentBuilder::default()
+                    .id(point.id.0.clone())
+                    .index(index.clone())
+                    .score(Some(score))
+                    .highlight(Vec::default())
+                    .document(entrails)
+                    .build()
+                    .map_err(|err| SearchError::InternalError(anyhow!(err)))?;
+
+                founded.push(founded_doc);
+            }
+        }
+
+        Ok(Pagination::new(None, founded))
+    }
+
+    async fn suggest(&self, _params: &SuggestParams) -> SearchResult<Suggestions> {
+        Ok(Suggestions::default())
+    }
+}
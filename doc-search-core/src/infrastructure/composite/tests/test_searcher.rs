@@ -0,0 +1,99 @@
+use rstest::rstest;
+use std::sync::Arc;
+
+use crate::application::tests::fixture::search_params::*;
+use crate::application::tests::mock::storage::MockStorage;
+use crate::domain::searcher::models::{Pagination, PaginationParams};
+use crate::domain::searcher::models::{SearchKindParams, SearchingParams};
+use crate::domain::searcher::models::{SimilarMode, SimilarSource};
+use crate::domain::searcher::models::{SuggestParamsBuilder, Suggestions};
+use crate::domain::searcher::{IPaginator, ISearcher};
+use crate::infrastructure::composite::CompositeSearcher;
+use crate::shared::kernel::LargeDocumentId;
+
+const CURSOR: &str = "dksfsjvJHZVFDskjdbfsdfsdfdsg";
+
+fn build_searching_params(kind: SearchKindParams) -> SearchingParams {
+    let indexes = vec!["test-folder".to_string()];
+    SearchingParams::new(indexes, kind, build_result_params(), None)
+}
+
+#[rstest]
+#[case(SearchKindParams::FullText(build_with_query_fulltext_params()), false)]
+#[case(SearchKindParams::Retrieve(build_simple_retrieve_params()), false)]
+#[case(SearchKindParams::Hybrid(build_simple_hybrid_params()), false)]
+#[case(SearchKindParams::Similar(build_similar_params(
+    SimilarSource::LargeDocument(LargeDocumentId("c5cdd3bfad598ec7".to_string())),
+    SimilarMode::Vector,
+)), false)]
+#[case(SearchKindParams::Semantic(build_semantic_params_with_tokens()), true)]
+#[tokio::test]
+async fn test_composite_search_routing(
+    #[case] kind: SearchKindParams,
+    #[case] is_semantic: bool,
+) -> anyhow::Result<()> {
+    let mut searcher = MockStorage::new();
+    let mut semantic = MockStorage::new();
+
+    let (expected, unexpected) = match is_semantic {
+        true => (&mut semantic, &mut searcher),
+        false => (&mut searcher, &mut semantic),
+    };
+
+    expected
+        .expect_search()
+        .times(1)
+        .returning(|_| Ok(Pagination::new(None, Vec::default())));
+    unexpected.expect_search().never();
+
+    let composite = CompositeSearcher::new(Arc::new(searcher), Arc::new(semantic));
+    let params = build_searching_params(kind);
+    composite.search(&params).await?;
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_composite_paginate() -> anyhow::Result<()> {
+    let mut searcher = MockStorage::new();
+    let mut semantic = MockStorage::new();
+
+    searcher
+        .expect_paginate()
+        .times(1)
+        .returning(|_| Ok(Pagination::new(None, Vec::default())));
+    semantic.expect_paginate().never();
+
+    let composite = CompositeSearcher::new(Arc::new(searcher), Arc::new(semantic));
+    let params = PaginationParams {
+        cursor: CURSOR.to_string(),
+    };
+
+    let pagination = composite.paginate(&params).await?;
+    assert!(pagination.cursor.is_none());
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_composite_suggest() -> anyhow::Result<()> {
+    let mut searcher = MockStorage::new();
+    let mut semantic = MockStorage::new();
+
+    searcher
+        .expect_suggest()
+        .times(1)
+        .returning(|_| Ok(Suggestions::default()));
+    semantic.expect_suggest().never();
+
+    let composite = CompositeSearcher::new(Arc::new(searcher), Arc::new(semantic));
+    let params = SuggestParamsBuilder::default()
+        .indexes(vec!["test-folder".to_string()])
+        .query("war and pe".to_string())
+        .size(5)
+        .build()?;
+
+    let suggestions = composite.suggest(&params).await?;
+    assert!(suggestions.completions.is_empty());
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use rstest::rstest;
+use std::sync::Arc;
+
+use crate::ServiceConnect;
+use crate::application::tests::fixture::DEFAULT_INDEX_ID;
+use crate::application::tests::fixture::document::{build_large_document, build_short_document};
+use crate::application::tests::fixture::search_params::*;
+use crate::domain::searcher::ISearcher;
+use crate::domain::searcher::models::{SearchKindParams, SearchingParams};
+use crate::domain::storage::models::{CreateIndexParamsBuilder, KnnIndexParams};
+use crate::domain::storage::models::{DocumentPartEmbeddings, LargeDocument};
+use crate::domain::storage::{IDocumentPartStorage, IIndexStorage};
+use crate::infrastructure::composite::tests::stub::VectorStorageStub;
+use crate::infrastructure::composite::{CompositeSearcher, CompositeStorage};
+use crate::infrastructure::memory::{MemoryClient, MemoryConfig};
+use crate::shared::kernel::{IndexId, LargeDocumentId};
+
+const MAX_CONTENT_SIZE: usize = 500;
+
+async fn store_document(
+    storage: &CompositeStorage<MemoryClient, VectorStorageStub>,
+    document: LargeDocument,
+    vector: &[f64],
+) -> anyhow::Result<LargeDocumentId> {
+    let index_id = IndexId(DEFAULT_INDEX_ID.to_string());
+    let mut doc_parts = document.divide_large_document_on_parts(MAX_CONTENT_SIZE)?;
+    for doc_part in doc_parts.iter_mut() {
+        doc_part.embeddings = Some(DocumentPartEmbeddings {
+            chunked_text: vec![doc_part.content.clone()],
+            embeddings: vec![vector.to_vec()],
+        });
+    }
+
+    let stored_info = storage.store_document_parts(&index_id, doc_parts).await?;
+    Ok(stored_info.large_doc_id)
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_composite_store_and_semantic_search() -> anyhow::Result<()> {
+    let memory = Arc::new(MemoryClient::connect(&MemoryConfig::default()).await?);
+    let vectors = Arc::new(VectorStorageStub::default());
+    let storage = CompositeStorage::new(memory.clone(), vectors.clone());
+    let searcher = CompositeSearcher::new(memory, vectors);
+
+    let params = CreateIndexParamsBuilder::default()
+        .id(DEFAULT_INDEX_ID.to_string())
+        .knn(Some(KnnIndexParams::default()))
+        .build()?;
+    let index_id = storage.create_index(&params).await?;
+
+    let opposite = SEARCH_TOKENS.iter().map(|it| -it).collect::<Vec<f64>>();
+    let large_doc_id = store_document(&storage, build_short_document(), SEARCH_TOKENS).await?;
+    store_document(&storage, build_large_document(), &opposite).await?;
+
+    let indexes = vec![DEFAULT_INDEX_ID.to_string()];
+    let kind = SearchKindParams::Semantic(build_semantic_params_with_tokens());
+    let params = SearchingParams::new(indexes, kind, build_result_params(), None);
+    let pagination = searcher.search(&params).await?;
+    assert!(!pagination.founded.is_empty());
+    assert!(
+        pagination
+            .founded
+            .iter()
+            .all(|it| it.document.large_doc_id.0 == large_doc_id.0)
+    );
+
+    storage
+        .delete_document_parts(&index_id, &large_doc_id)
+        .await?;
+    let pagination = searcher.search(&params).await?;
+    assert!(pagination.founded.is_empty());
+    Ok(())
+}
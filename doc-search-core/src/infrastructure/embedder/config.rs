@@ -0,0 +1,28 @@
+use gset::Getset;
+use serde_derive::Deserialize;
+
+const DEFAULT_TIMEOUT: u64 = 30;
+
+#[derive(Clone, Debug, Deserialize, Getset)]
+pub struct EmbedderConfig {
+    /// Base address of OpenAI compatible api serving `/embeddings` endpoint.
+    #[getset(get, vis = "pub")]
+    address: String,
+    #[getset(get, vis = "pub")]
+    model: String,
+    #[serde(default)]
+    #[getset(get, vis = "pub")]
+    api_key: Option<String>,
+    /// Patterns of indexes storing embeddings computed by this model.
+    #[serde(default)]
+    #[getset(get, vis = "pub")]
+    indexes: Vec<String>,
+    /// Timeout of embeddings request in seconds.
+    #[serde(default = "default_timeout")]
+    #[getset(get_copy, vis = "pub")]
+    timeout: u64,
+}
+
+fn default_timeout() -> u64 {
+    DEFAULT_TIMEOUT
+}
@@ -0,0 +1,54 @@
+use anyhow::anyhow;
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::domain::embedder::{EmbedderError, EmbedderResult, IEmbedder};
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+/// Deterministic embedder hashing words of text into buckets of a vector,
+/// so texts sharing words have close embeddings. Intended for tests and
+/// local development without any model.
+#[derive(Clone)]
+pub struct HashingEmbedder {
+    dimension: usize,
+}
+
+impl HashingEmbedder {
+    pub fn new(dimension: usize) -> Self {
+        HashingEmbedder { dimension }
+    }
+}
+
+#[async_trait::async_trait]
+impl IEmbedder for HashingEmbedder {
+    async fn embed(&self, text: &str) -> EmbedderResult<Vec<f64>> {
+        if self.dimension == 0 {
+            let err = anyhow!("embeddings dimension must be greater than zero");
+            return Err(EmbedderError::ValidationError(err));
+        }
+
+        let mut embedding = vec![0.0; self.dimension];
+        for word in text.unicode_words() {
+            let hash = fnv_hash(&word.to_lowercase());
+            let bucket = (hash % self.dimension as u64) as usize;
+            let sign = if hash >> 63 == 0 { 1.0 } else { -1.0 };
+            embedding[bucket] += sign;
+        }
+
+        let norm = embedding.iter().map(|it| it * it).sum::<f64>().sqrt();
+        if norm == 0.0 {
+            let err = anyhow!("there are no words to embed");
+            return Err(EmbedderError::ValidationError(err));
+        }
+
+        Ok(embedding.into_iter().map(|it| it / norm).collect())
+    }
+}
+
+/// FNV-1a hash keeping embeddings stable between builds and platforms.
+fn fnv_hash(word: &str) -> u64 {
+    word.bytes().fold(FNV_OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+    })
+}
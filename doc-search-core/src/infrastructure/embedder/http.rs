@@ -0,0 +1,100 @@
+use anyhow::anyhow;
+use serde_derive::{Deserialize, Serialize};
+use std::time::Duration;
+use tracing::instrument;
+
+use crate::ServiceConnect;
+use crate::domain::embedder::{EmbedderError, EmbedderResult, IEmbedder};
+use crate::infrastructure::embedder::EmbedderConfig;
+
+const EMBEDDINGS_PATH: &str = "embeddings";
+
+/// Embedder requesting OpenAI compatible `/embeddings` endpoint.
+#[derive(Clone)]
+pub struct HttpEmbedder {
+    client: reqwest::Client,
+    url: String,
+    model: String,
+    api_key: Option<String>,
+}
+
+#[derive(Serialize)]
+struct EmbeddingsRequest<'a> {
+    model: &'a str,
+    input: [&'a str; 1],
+}
+
+#[derive(Deserialize)]
+struct EmbeddingsResponse {
+    data: Vec<EmbeddingsData>,
+}
+
+#[derive(Deserialize)]
+struct EmbeddingsData {
+    embedding: Vec<f64>,
+}
+
+#[async_trait::async_trait]
+impl ServiceConnect for HttpEmbedder {
+    type Config = EmbedderConfig;
+    type Client = HttpEmbedder;
+    type Error = EmbedderError;
+
+    async fn connect(config: &Self::Config) -> Result<Self::Client, Self::Error> {
+        // Hung embedder must not hang searching and ingestion using it.
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(config.timeout()))
+            .build()
+            .map_err(|err| EmbedderError::ConnectionError(anyhow!(err)))?;
+
+        let address = config.address().trim_end_matches('/');
+        tracing::info!(address=%address, model=%config.model(), "connected to embedder");
+        Ok(HttpEmbedder {
+            client,
+            url: format!("{address}/{EMBEDDINGS_PATH}"),
+            model: config.model().to_owned(),
+            api_key: config.api_key().clone(),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl IEmbedder for HttpEmbedder {
+    #[instrument(level = "info", skip(self))]
+    async fn embed(&self, text: &str) -> EmbedderResult<Vec<f64>> {
+        let body = EmbeddingsRequest {
+            model: &self.model,
+            input: [text],
+        };
+
+        let mut request = self.client.post(&self.url).json(&body);
+        if let Some(api_key) = self.api_key.as_ref() {
+            request = request.bearer_auth(api_key);
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|err| EmbedderError::ConnectionError(anyhow!(err)))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let msg = response.text().await.unwrap_or_default();
+            let err = anyhow!("embeddings service returned {status}: {msg}");
+            return Err(EmbedderError::ServiceError(err));
+        }
+
+        let response = response
+            .json::<EmbeddingsResponse>()
+            .await
+            .map_err(|err| EmbedderError::ServiceError(anyhow!(err)))?;
+
+        response
+            .data
+            .into_iter()
+            .next()
+            .map(|it| it.embedding)
+            .filter(|it| !it.is_empty())
+            .ok_or_else(|| EmbedderError::ServiceError(anyhow!("there are no embeddings")))
+    }
+}
@@ -0,0 +1,10 @@
+#[cfg(test)]
+mod tests;
+
+mod config;
+mod hashing;
+mod http;
+
+pub use config::EmbedderConfig;
+pub use hashing::HashingEmbedder;
+pub use http::HttpEmbedder;
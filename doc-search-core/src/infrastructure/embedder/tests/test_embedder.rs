@@ -0,0 +1,145 @@
+use rstest::rstest;
+use serde_json::{Value, json};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+
+use crate::ServiceConnect;
+use crate::domain::embedder::IEmbedder;
+use crate::infrastructure::embedder::{EmbedderConfig, HashingEmbedder, HttpEmbedder};
+
+const EMBEDDER_MODEL: &str = "text-embedding-model";
+const EMBEDDER_API_KEY: &str = "secret";
+
+/// Serves the single request by passed status and body, returns the body
+/// of accepted request.
+async fn run_stub_server(status: &'static str, body: Value) -> (String, JoinHandle<Value>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = format!("http://{}/v1/", listener.local_addr().unwrap());
+    let handle = tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let mut request = Vec::new();
+        let mut buffer = [0u8; 1024];
+        let (headers_end, content_length) = loop {
+            let read = stream.read(&mut buffer).await.unwrap();
+            request.extend_from_slice(&buffer[..read]);
+            let text = String::from_utf8_lossy(&request).to_string();
+            let Some(position) = text.find("\r\n\r\n") else {
+                continue;
+            };
+
+            let content_length = text[..position]
+                .lines()
+                .filter_map(|it| it.split_once(':'))
+                .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+                .map(|(_, value)| value.trim().parse::<usize>().unwrap())
+                .unwrap_or_default();
+
+            assert!(text.starts_with("POST /v1/embeddings"));
+            assert!(text.contains(&format!("Bearer {EMBEDDER_API_KEY}")));
+            break (position + 4, content_length);
+        };
+
+        while request.len() < headers_end + content_length {
+            let read = stream.read(&mut buffer).await.unwrap();
+            request.extend_from_slice(&buffer[..read]);
+        }
+
+        let body = body.to_string();
+        let response = format!(
+            "HTTP/1.1 {status}\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+            body.len()
+        );
+
+        stream.write_all(response.as_bytes()).await.unwrap();
+        serde_json::from_slice(&request[headers_end..]).unwrap()
+    });
+
+    (address, handle)
+}
+
+async fn connect_http_embedder(address: &str) -> anyhow::Result<HttpEmbedder> {
+    let config: EmbedderConfig = serde_json::from_value(json!({
+        "address": address,
+        "model": EMBEDDER_MODEL,
+        "api_key": EMBEDDER_API_KEY,
+    }))?;
+
+    Ok(HttpEmbedder::connect(&config).await?)
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_http_embedder() -> anyhow::Result<()> {
+    let response = json!({"data": [{"object": "embedding", "index": 0, "embedding": [0.1, -0.2]}]});
+    let (address, handle) = run_stub_server("200 OK", response).await;
+    let embedder = connect_http_embedder(&address).await?;
+
+    let embedding = embedder.embed("find something").await?;
+    assert_eq!(embedding, vec![0.1, -0.2]);
+
+    let request = handle.await?;
+    assert_eq!(request["model"], EMBEDDER_MODEL);
+    assert_eq!(request["input"], json!(["find something"]));
+    Ok(())
+}
+
+#[rstest]
+#[case("500 Internal Server Error", json!({"error": "model is not loaded"}))]
+#[case("200 OK", json!({"data": []}))]
+#[case("200 OK", json!({"unexpected": true}))]
+#[tokio::test]
+async fn test_http_embedder_failed(
+    #[case] status: &'static str,
+    #[case] response: Value,
+) -> anyhow::Result<()> {
+    let (address, handle) = run_stub_server(status, response).await;
+    let embedder = connect_http_embedder(&address).await?;
+
+    assert!(embedder.embed("find something").await.is_err());
+    handle.await?;
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_http_embedder_timeout() -> anyhow::Result<()> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let address = format!("http://{}/v1/", listener.local_addr()?);
+    let handle = tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        tokio::time::sleep(std::time::Duration::from_secs(5)).await;
+        drop(stream);
+    });
+
+    let config: EmbedderConfig = serde_json::from_value(json!({
+        "address": address,
+        "model": EMBEDDER_MODEL,
+        "timeout": 1,
+    }))?;
+
+    let embedder = HttpEmbedder::connect(&config).await?;
+    assert!(embedder.embed("find something").await.is_err());
+    handle.abort();
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_hashing_embedder() -> anyhow::Result<()> {
+    let embedder = HashingEmbedder::new(16);
+    let first = embedder.embed("Find something").await?;
+    let second = embedder.embed("find SOMETHING").await?;
+    assert_eq!(first.len(), 16);
+    assert_eq!(first, second);
+
+    let norm = first.iter().map(|it| it * it).sum::<f64>().sqrt();
+    assert!((norm - 1.0).abs() < 1e-9);
+
+    let other = embedder.embed("quite another text").await?;
+    assert_ne!(first, other);
+
+    assert!(embedder.embed(" ... ").await.is_err());
+    assert!(HashingEmbedder::new(0).embed("text").await.is_err());
+    Ok(())
+}
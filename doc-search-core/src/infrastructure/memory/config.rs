@@ -0,0 +1,24 @@
+use gset::Getset;
+use serde_derive::Deserialize;
+
+const DEFAULT_CURSOR_LIFETIME: u64 = 300;
+
+#[derive(Clone, Debug, Deserialize, Getset)]
+pub struct MemoryConfig {
+    /// Cursor context lifetime in seconds.
+    #[serde(default = "default_cursor_lifetime", alias = "scroll_lifetime")]
+    #[getset(get_copy, vis = "pub")]
+    cursor_lifetime: u64,
+}
+
+impl Default for MemoryConfig {
+    fn default() -> Self {
+        MemoryConfig {
+            cursor_lifetime: DEFAULT_CURSOR_LIFETIME,
+        }
+    }
+}
+
+fn default_cursor_lifetime() -> u64 {
+    DEFAULT_CURSOR_LIFETIME
+}
@@ -0,0 +1,48 @@
+use thiserror::Error;
+
+use crate::domain::searcher::SearchError;
+use crate::domain::storage::StorageError;
+
+pub type MemoryResult<T> = Result<T, MemoryError>;
+
+#[derive(Debug, Error)]
+pub enum MemoryError {
+    #[error("index has not been founded: {0}")]
+    IndexNotFound(anyhow::Error),
+    #[error("index already exists: {0}")]
+    IndexAlreadyExists(anyhow::Error),
+    #[error("document has not been found: {0}")]
+    DocumentNotFound(anyhow::Error),
+    #[error("cursor context has not been found: {0}")]
+    CursorNotFound(anyhow::Error),
+    #[error("validation error: {0}")]
+    ValidationError(anyhow::Error),
+    #[error("execution error: {0}")]
+    ExecutionError(anyhow::Error),
+}
+
+impl From<MemoryError> for StorageError {
+    fn from(err: MemoryError) -> Self {
+        match err {
+            MemoryError::IndexNotFound(err) => StorageError::IndexNotFound(err),
+            MemoryError::IndexAlreadyExists(err) => StorageError::IndexAlreadyExists(err),
+            MemoryError::DocumentNotFound(err) => StorageError::DocumentNotFound(err),
+            MemoryError::CursorNotFound(err) => StorageError::InternalError(err),
+            MemoryError::ValidationError(err) => StorageError::ValidationError(err),
+            MemoryError::ExecutionError(err) => StorageError::InternalError(err),
+        }
+    }
+}
+
+impl From<MemoryError> for SearchError {
+    fn from(err: MemoryError) -> Self {
+        match err {
+            MemoryError::IndexNotFound(err) => SearchError::IndexNotFound(err),
+            MemoryError::IndexAlreadyExists(err) => SearchError::InternalError(err),
+            MemoryError::DocumentNotFound(err) => SearchError::DocumentNotFound(err),
+            MemoryError::CursorNotFound(err) => SearchError::ValidationError(err),
+            MemoryError::ValidationError(err) => SearchError::ValidationError(err),
+            MemoryError::ExecutionError(err) => SearchError::InternalError(err),
+        }
+    }
+}
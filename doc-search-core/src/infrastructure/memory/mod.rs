@@ -0,0 +1,653 @@
+#[cfg(test)]
+mod tests;
+
+mod config;
+mod error;
+mod query;
+
+pub use config::MemoryConfig;
+pub use error::MemoryError;
+
+#[cfg(feature = "enable-unique-doc-id")]
+use crate::application::usecase::storage::gen_unique_document_id;
+use anyhow::{Context, anyhow};
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
+use tracing::instrument;
+
+use crate::ServiceConnect;
+use crate::domain::searcher::models::FoundedDocument;
+use crate::domain::searcher::models::TotalHits;
+use crate::domain::searcher::models::{DocumentPartEntrails, DocumentPartEntrailsBuilder};
+use crate::domain::searcher::models::{FoundedDocumentBuilder, Pagination, PaginationParams};
+use crate::domain::searcher::models::{ResultOrder, SearchKindParams, SearchingParams};
+use crate::domain::searcher::models::{SimilarSearchingParams, SimilarSource};
+use crate::domain::searcher::models::{SortField, SortParams};
+use crate::domain::searcher::models::{SuggestParams, Suggestions};
+use crate::domain::searcher::{IPaginator, ISearcher};
+use crate::domain::searcher::{SearchError, SearchResult};
+use crate::domain::storage::models::SplitterParams;
+use crate::domain::storage::models::StoredDocumentPartsInfoBuilder;
+use crate::domain::storage::models::UpdateDocumentParams;
+use crate::domain::storage::models::{AllDocumentParts, DocumentPart};
+use crate::domain::storage::models::{CreateIndexParams, StoredDocumentPartsInfo};
+use crate::domain::storage::{IDocumentPartStorage, IIndexStorage};
+use crate::domain::storage::{StorageError, StorageResult};
+use crate::infrastructure::memory::error::MemoryResult;
+use crate::shared::kernel::{DocumentPartId, IndexId, LargeDocumentId};
+
+type MemoryIndex = BTreeMap<String, DocumentPart>;
+
+const MAX_LIVE_CURSORS: usize = 1000;
+const MAX_CURSOR_ROWS: usize = 10_000;
+
+/// Storage and searcher backend keeping all document parts in process memory.
+/// Semantic and hybrid queries fall back to term matching because there are
+/// no embeddings to compare with.
+#[derive(Clone)]
+pub struct MemoryClient {
+    config: MemoryConfig,
+    indexes: Arc<RwLock<HashMap<String, MemoryIndex>>>,
+    splitters: Arc<RwLock<HashMap<String, SplitterParams>>>,
+    cursors: Arc<Mutex<HashMap<String, CursorContext>>>,
+}
+
+struct CursorContext {
+    expired_at: Instant,
+    page_size: usize,
+    founded: VecDeque<FoundedDocument>,
+    total: TotalHits,
+    max_score: Option<f64>,
+}
+
+impl MemoryClient {
+    pub fn new(config: MemoryConfig) -> Self {
+        MemoryClient {
+            config,
+            indexes: Arc::new(RwLock::new(HashMap::new())),
+            splitters: Arc::new(RwLock::new(HashMap::new())),
+            cursors: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ServiceConnect for MemoryClient {
+    type Config = MemoryConfig;
+    type Client = MemoryClient;
+    type Error = MemoryError;
+
+    async fn connect(config: &Self::Config) -> Result<Self::Client, Self::Error> {
+        tracing::info!("initialized in-memory storage");
+        Ok(MemoryClient::new(config.clone()))
+    }
+}
+
+#[async_trait::async_trait]
+impl IIndexStorage for MemoryClient {
+    #[instrument(level = "info", skip(self))]
+    async fn create_index(&self, params: &CreateIndexParams) -> StorageResult<IndexId> {
+        let index_id = &params.id;
+        if index_id.is_empty() {
+            let err = anyhow!("index name must not be empty");
+            return Err(StorageError::ValidationError(err));
+        }
+
+        let mut indexes = self.write_indexes()?;
+        if indexes.contains_key(index_id) {
+            let err = anyhow!("index {index_id} already exists");
+            return Err(MemoryError::IndexAlreadyExists(err).into());
+        }
+
+        indexes.insert(index_id.to_owned(), MemoryIndex::default());
+        let mut splitters = self.write_splitters()?;
+        match params.splitter.as_ref() {
+            Some(splitter) => splitters.insert(index_id.to_owned(), splitter.clone()),
+            None => splitters.remove(index_id),
+        };
+
+        Ok(IndexId(index_id.to_owned()))
+    }
+
+    #[instrument(level = "info", skip(self))]
+    async fn delete_index(&self, index_id: &IndexId) -> StorageResult<()> {
+        if self.write_indexes()?.remove(&index_id.0).is_none() {
+            let err = anyhow!("there is no index with such name: {index_id:?}");
+            return Err(StorageError::IndexNotFound(err));
+        }
+
+        self.write_splitters()?.remove(&index_id.0);
+        Ok(())
+    }
+
+    #[instrument(level = "info", skip(self))]
+    async fn get_index(&self, index_id: &IndexId) -> StorageResult<IndexId> {
+        if !self.read_indexes()?.contains_key(&index_id.0) {
+            let err = anyhow!("there is no index with such name: {index_id:?}");
+            return Err(StorageError::IndexNotFound(err));
+        }
+
+        Ok(index_id.to_owned())
+    }
+
+    #[instrument(level = "info", skip_all)]
+    async fn get_all_indexes(&self) -> StorageResult<Vec<IndexId>> {
+        let mut indexes = self
+            .read_indexes()?
+            .keys()
+            .map(|it| IndexId(it.to_owned()))
+            .collect::<Vec<IndexId>>();
+
+        indexes.sort_by(|a, b| a.0.cmp(&b.0));
+        Ok(indexes)
+    }
+
+    #[instrument(level = "info", skip(self))]
+    async fn get_index_splitter(
+        &self,
+        index_id: &IndexId,
+    ) -> StorageResult<Option<SplitterParams>> {
+        let _ = self.get_index(index_id).await?;
+        let splitters = self.read_splitters()?;
+        Ok(splitters.get(&index_id.0).cloned())
+    }
+}
+
+#[async_trait::async_trait]
+impl IDocumentPartStorage for MemoryClient {
+    #[instrument(
+        level = "info",
+        skip_all,
+        fields(
+            index_id = index_id.0,
+            document_parts_amount = all_doc_parts.len(),
+        ),
+    )]
+    async fn store_document_parts(
+        &self,
+        index_id: &IndexId,
+        all_doc_parts: AllDocumentParts,
+    ) -> StorageResult<StoredDocumentPartsInfo> {
+        self.insert_document_parts(index_id, all_doc_parts, false)
+    }
+
+    #[instrument(
+        level = "info",
+        skip_all,
+        fields(
+            index_id = index_id.0,
+            document_parts_amount = all_doc_parts.len(),
+        ),
+    )]
+    async fn create_document_parts(
+        &self,
+        index_id: &IndexId,
+        all_doc_parts: AllDocumentParts,
+    ) -> StorageResult<StoredDocumentPartsInfo> {
+        self.insert_document_parts(index_id, all_doc_parts, true)
+    }
+
+    #[instrument(level = "info", skip(self))]
+    async fn get_document_parts(
+        &self,
+        index: &IndexId,
+        large_doc_id: &LargeDocumentId,
+    ) -> StorageResult<AllDocumentParts> {
+        let indexes = self.read_indexes()?;
+        let mut all_doc_parts = Vec::new();
+        for index_id in index.split(',') {
+            let index = Self::get_index_ref(&indexes, &IndexId(index_id.to_owned()))?;
+            let doc_parts = index
+                .values()
+                .filter(|it| it.large_doc_id.0.eq(&large_doc_id.0))
+                .cloned();
+
+            all_doc_parts.extend(doc_parts);
+        }
+
+        all_doc_parts.sort_by_key(|it| it.doc_part_id);
+        Ok(all_doc_parts)
+    }
+
+    #[instrument(level = "info", skip(self))]
+    async fn get_document_part(
+        &self,
+        index: &IndexId,
+        doc_part_id: &DocumentPartId,
+    ) -> StorageResult<DocumentPart> {
+        let indexes = self.read_indexes()?;
+        let memory_index = Self::get_index_ref(&indexes, index)?;
+        memory_index.get(&doc_part_id.0).cloned().ok_or_else(|| {
+            let err = anyhow!("document [{doc_part_id:?}] not found in index: [{index:?}]");
+            StorageError::DocumentNotFound(err)
+        })
+    }
+
+    #[instrument(level = "info", skip(self))]
+    async fn update_document_parts(
+        &self,
+        index: &IndexId,
+        large_doc_id: &LargeDocumentId,
+        params: &UpdateDocumentParams,
+    ) -> StorageResult<()> {
+        let mut indexes = self.write_indexes()?;
+        let mut updated_parts = 0;
+        for index_id in index.split(',') {
+            let index = Self::get_index_mut(&mut indexes, &IndexId(index_id.to_owned()))?;
+            index
+                .values_mut()
+                .filter(|it| it.large_doc_id.0.eq(&large_doc_id.0))
+                .for_each(|it| {
+                    it.apply_update(params);
+                    updated_parts += 1;
+                });
+        }
+
+        if updated_parts == 0 {
+            let err = anyhow!("document [{large_doc_id:?}] not found in index: [{index:?}]");
+            return Err(StorageError::DocumentNotFound(err));
+        }
+
+        Ok(())
+    }
+
+    #[instrument(level = "info", skip(self))]
+    async fn delete_document_parts(
+        &self,
+        index: &IndexId,
+        large_doc_id: &LargeDocumentId,
+    ) -> StorageResult<()> {
+        let mut indexes = self.write_indexes()?;
+        for index_id in index.split(',') {
+            let index = Self::get_index_mut(&mut indexes, &IndexId(index_id.to_owned()))?;
+            index.retain(|_, it| it.large_doc_id.0.ne(&large_doc_id.0));
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl ISearcher for MemoryClient {
+    #[instrument(level = "info", skip(self))]
+    async fn search(&self, params: &SearchingParams) -> SearchResult<Pagination> {
+        let started_at = Instant::now();
+        let result = params.get_result();
+        let kind = params.get_kind();
+        let with_content = result.include_extra_fields.unwrap_or_default();
+
+        let syntax = query::parse_search_kind(kind)?;
+        let mut founded = Vec::new();
+        {
+            let indexes = self.read_indexes()?;
+            let similar = match kind {
+                SearchKindParams::Similar(it) => Some((it, Self::load_similar(&indexes, it)?)),
+                _ => None,
+            };
+
+            for (index_id, index) in
+                Self::get_search_indexes(&indexes, params.get_indexes(), |it| {
+                    params.is_index_allowed(it)
+                })?
+            {
+                for (id, doc) in index.iter() {
+                    let doc_match = match similar.as_ref() {
+                        Some((params, source)) => query::match_similar(params, source, doc),
+                        None => query::match_search_kind(kind, syntax.as_ref(), doc),
+                    };
+
+                    let Some(doc_match) = doc_match else {
+                        continue;
+                    };
+
+                    if let Some(filter) = params.get_filter()
+                        && !query::match_filter(filter, doc)?
+                    {
+                        continue;
+                    }
+
+                    let highlight = query::build_highlight(result, &doc_match.terms, &doc.content);
+                    let founded_doc = FoundedDocumentBuilder::default()
+                        .id(id.to_owned())
+                        .index(index_id.clone())
+                        .score(doc_match.score)
+                        .highlight(highlight)
+                        .document(Self::build_entrails(doc, with_content)?)
+                        .build()
+                        .context("failed to build founded document")
+                        .map_err(SearchError::InternalError)?;
+
+                    founded.push(founded_doc);
+                }
+            }
+        }
+
+        let knn_amount = match kind {
+            SearchKindParams::Semantic(it) => Some(it.knn_amount),
+            SearchKindParams::Hybrid(it) => Some(it.knn_amount),
+            SearchKindParams::Similar(it) => Some(it.knn_amount),
+            _ => None,
+        };
+
+        // Nearest neighbours are selected by score before requested sorting.
+        if let Some(knn_amount) = knn_amount {
+            let by_score = [SortParams::new(SortField::Score, ResultOrder::DESC)];
+            FoundedDocument::sort_by_params(&mut founded, &by_score);
+            founded.truncate(knn_amount as usize);
+        }
+
+        FoundedDocument::sort_by_params(&mut founded, &params.get_sort());
+        if result.is_grouped_by_document() {
+            founded = FoundedDocument::group_by_document(founded);
+        }
+
+        let total = TotalHits::exact(founded.len() as u64);
+        let max_score = founded.iter().filter_map(|it| it.score).reduce(f64::max);
+
+        let offset = result.offset.max(0) as usize;
+        let size = result.size.max(0) as usize;
+        let mut founded = founded
+            .into_iter()
+            .skip(offset)
+            .collect::<VecDeque<FoundedDocument>>();
+
+        if offset > 0 {
+            founded.truncate(size);
+            let paginated = Pagination::new(None, founded.into())
+                .with_total(Some(total), max_score)
+                .with_took(Some(started_at.elapsed().as_millis() as u64));
+
+            return Ok(paginated);
+        }
+
+        let page = founded.drain(..size.min(founded.len())).collect();
+        let cursor = match founded.is_empty() {
+            true => None,
+            false => {
+                founded.truncate(MAX_CURSOR_ROWS);
+                let cursor_context = CursorContext {
+                    expired_at: Instant::now() + self.cursor_lifetime(),
+                    page_size: size,
+                    founded,
+                    total: total.clone(),
+                    max_score,
+                };
+
+                Some(self.insert_cursor(cursor_context)?)
+            }
+        };
+
+        let paginated = Pagination::new(cursor, page)
+            .with_total(Some(total), max_score)
+            .with_took(Some(started_at.elapsed().as_millis() as u64));
+
+        Ok(paginated)
+    }
+
+    #[instrument(level = "info", skip(self))]
+    async fn suggest(&self, params: &SuggestParams) -> SearchResult<Suggestions> {
+        let indexes = self.read_indexes()?;
+        let search_indexes =
+            Self::get_search_indexes(&indexes, &params.indexes, |it| params.is_index_allowed(it))?;
+
+        let docs = search_indexes.into_iter().flat_map(|(_, it)| it.values());
+        Ok(query::build_suggestions(params, docs))
+    }
+}
+
+#[async_trait::async_trait]
+impl IPaginator for MemoryClient {
+    #[instrument(level = "info", skip(self))]
+    async fn paginate(&self, params: &PaginationParams) -> SearchResult<Pagination> {
+        let mut cursors = self.lock_cursors()?;
+        let now = Instant::now();
+        cursors.retain(|_, it| it.expired_at > now);
+
+        let Some(cursor_context) = cursors.get_mut(&params.cursor) else {
+            let err = anyhow!("cursor {} does not exist or expired", params.cursor);
+            return Err(SearchError::from(MemoryError::CursorNotFound(err)));
+        };
+
+        let page_size = cursor_context.page_size.min(cursor_context.founded.len());
+        let page = cursor_context
+            .founded
+            .drain(..page_size)
+            .collect::<Vec<FoundedDocument>>();
+
+        let total = Some(cursor_context.total.clone());
+        let max_score = cursor_context.max_score;
+        if cursor_context.founded.is_empty() {
+            cursors.remove(&params.cursor);
+            return Ok(Pagination::new(None, page).with_total(total, max_score));
+        }
+
+        // Every page is returned with a new cursor, so the same cursor always
+        // points to the same page.
+        cursor_context.expired_at = now + self.cursor_lifetime();
+        let cursor = uuid::Uuid::new_v4().to_string();
+        if let Some(cursor_context) = cursors.remove(&params.cursor) {
+            cursors.insert(cursor.clone(), cursor_context);
+        }
+
+        let paginated = Pagination::new(Some(cursor), page);
+        Ok(paginated.with_total(total, max_score))
+    }
+
+    #[instrument(level = "info", skip(self))]
+    async fn release(&self, params: &PaginationParams) -> SearchResult<()> {
+        if self.lock_cursors()?.remove(&params.cursor).is_none() {
+            let err = anyhow!("cursor {} does not exist or expired", params.cursor);
+            return Err(SearchError::from(MemoryError::CursorNotFound(err)));
+        }
+
+        Ok(())
+    }
+}
+
+impl MemoryClient {
+    /// Existence is checked under the same write lock parts are stored with,
+    /// so concurrently created document is stored only once.
+    fn insert_document_parts(
+        &self,
+        index_id: &IndexId,
+        all_doc_parts: AllDocumentParts,
+        create: bool,
+    ) -> StorageResult<StoredDocumentPartsInfo> {
+        let doc_parts_amount = all_doc_parts.len();
+        let large_doc_id = all_doc_parts
+            .first()
+            .map(|it| it.large_doc_id.clone())
+            .ok_or(anyhow!("missing large document id to store"))
+            .map_err(StorageError::InternalError)?;
+
+        let mut indexes = self.write_indexes()?;
+        let index = Self::get_index_mut(&mut indexes, index_id)?;
+        if create
+            && index
+                .values()
+                .any(|it| it.large_doc_id.0.eq(&large_doc_id.0))
+        {
+            let index_id = index_id.as_string();
+            let msg = format!("document {large_doc_id} already exists in index {index_id}");
+            return Err(StorageError::DocumentAlreadyExists(anyhow!(msg)));
+        }
+
+        index.retain(|_, it| it.large_doc_id.0.ne(&large_doc_id.0));
+
+        let mut stored_doc_ids = Vec::with_capacity(doc_parts_amount);
+        for doc in all_doc_parts.into_iter() {
+            #[cfg(not(feature = "enable-unique-doc-id"))]
+            let id = uuid::Uuid::new_v4().to_string();
+            #[cfg(feature = "enable-unique-doc-id")]
+            let id = gen_unique_document_id(index_id, &doc.large_doc_id, doc.doc_part_id);
+
+            index.insert(id.clone(), doc);
+            stored_doc_ids.push(id);
+        }
+
+        let first_doc_id = stored_doc_ids
+            .first()
+            .map(|it| DocumentPartId(it.clone()))
+            .ok_or(anyhow!("there is no any stored id's"))
+            .map_err(StorageError::InternalError)?;
+
+        let stored_doc_info = StoredDocumentPartsInfoBuilder::default()
+            .large_doc_id(large_doc_id)
+            .first_part_id(first_doc_id)
+            .doc_parts_amount(doc_parts_amount)
+            .build()
+            .context("failed to build stored document info")
+            .map_err(StorageError::InternalError)?;
+
+        Ok(stored_doc_info)
+    }
+
+    /// Expired cursors are pruned on every new one, while the oldest live
+    /// cursors are evicted once `MAX_LIVE_CURSORS` are kept.
+    fn insert_cursor(&self, cursor_context: CursorContext) -> SearchResult<String> {
+        let mut cursors = self.lock_cursors()?;
+        let now = Instant::now();
+        cursors.retain(|_, it| it.expired_at > now);
+        while cursors.len() >= MAX_LIVE_CURSORS {
+            let oldest = cursors
+                .iter()
+                .min_by_key(|(_, it)| it.expired_at)
+                .map(|(cursor, _)| cursor.clone());
+
+            match oldest {
+                Some(cursor) => cursors.remove(&cursor),
+                None => break,
+            };
+        }
+
+        let cursor = uuid::Uuid::new_v4().to_string();
+        cursors.insert(cursor.clone(), cursor_context);
+        Ok(cursor)
+    }
+
+    fn cursor_lifetime(&self) -> Duration {
+        Duration::from_secs(self.config.cursor_lifetime())
+    }
+
+    fn build_entrails(
+        doc: &DocumentPart,
+        with_content: bool,
+    ) -> SearchResult<DocumentPartEntrails> {
+        DocumentPartEntrailsBuilder::default()
+            .large_doc_id(doc.large_doc_id.clone())
+            .doc_part_id(doc.doc_part_id)
+            .file_name(doc.file_name.clone())
+            .file_path(doc.file_path.clone())
+            .file_size(doc.file_size)
+            .created_at(doc.created_at)
+            .modified_at(doc.modified_at)
+            .content(with_content.then(|| doc.content.clone()))
+            .chunked_text(None)
+            .embeddings(None)
+            .metadata(doc.metadata.clone())
+            .build()
+            .context("failed to build document part entrails")
+            .map_err(SearchError::InternalError)
+    }
+
+    fn get_index_ref<'a>(
+        indexes: &'a HashMap<String, MemoryIndex>,
+        index_id: &IndexId,
+    ) -> MemoryResult<&'a MemoryIndex> {
+        indexes.get(&index_id.0).ok_or_else(|| {
+            let err = anyhow!("there is no index with such name: {index_id:?}");
+            MemoryError::IndexNotFound(err)
+        })
+    }
+
+    fn get_index_mut<'a>(
+        indexes: &'a mut HashMap<String, MemoryIndex>,
+        index_id: &IndexId,
+    ) -> MemoryResult<&'a mut MemoryIndex> {
+        indexes.get_mut(&index_id.0).ok_or_else(|| {
+            let err = anyhow!("there is no index with such name: {index_id:?}");
+            MemoryError::IndexNotFound(err)
+        })
+    }
+
+    fn get_search_indexes<'a>(
+        indexes: &'a HashMap<String, MemoryIndex>,
+        requested: &[String],
+        is_allowed: impl Fn(&str) -> bool,
+    ) -> MemoryResult<Vec<(String, &'a MemoryIndex)>> {
+        if requested.first().is_some_and(|it| it == "*") {
+            let all_indexes = indexes
+                .iter()
+                .filter(|(id, _)| is_allowed(id))
+                .map(|(id, it)| (id.to_owned(), it))
+                .collect();
+            return Ok(all_indexes);
+        }
+
+        requested
+            .iter()
+            .map(|id| {
+                let index = Self::get_index_ref(indexes, &IndexId(id.to_owned()))?;
+                Ok((id.to_owned(), index))
+            })
+            .collect()
+    }
+
+    fn load_similar(
+        indexes: &HashMap<String, MemoryIndex>,
+        params: &SimilarSearchingParams,
+    ) -> MemoryResult<query::SimilarDocument> {
+        let index_id = IndexId(params.source_index.clone());
+        let index = Self::get_index_ref(indexes, &index_id)?;
+        let parts = match &params.source {
+            SimilarSource::DocumentPart(id) => index.get(&id.0).into_iter().collect(),
+            SimilarSource::LargeDocument(id) => index
+                .values()
+                .filter(|it| it.large_doc_id.0.eq(&id.0))
+                .collect::<Vec<&DocumentPart>>(),
+        };
+
+        query::SimilarDocument::from_parts(params, &parts)
+    }
+
+    fn read_indexes(
+        &self,
+    ) -> MemoryResult<std::sync::RwLockReadGuard<'_, HashMap<String, MemoryIndex>>> {
+        self.indexes
+            .read()
+            .map_err(|err| MemoryError::ExecutionError(anyhow!("indexes lock poisoned: {err}")))
+    }
+
+    fn write_indexes(
+        &self,
+    ) -> MemoryResult<std::sync::RwLockWriteGuard<'_, HashMap<String, MemoryIndex>>> {
+        self.indexes
+            .write()
+            .map_err(|err| MemoryError::ExecutionError(anyhow!("indexes lock poisoned: {err}")))
+    }
+
+    fn read_splitters(
+        &self,
+    ) -> MemoryResult<std::sync::RwLockReadGuard<'_, HashMap<String, SplitterParams>>> {
+        self.splitters
+            .read()
+            .map_err(|err| MemoryError::ExecutionError(anyhow!("splitters lock poisoned: {err}")))
+    }
+
+    fn write_splitters(
+        &self,
+    ) -> MemoryResult<std::sync::RwLockWriteGuard<'_, HashMap<String, SplitterParams>>> {
+        self.splitters
+            .write()
+            .map_err(|err| MemoryError::ExecutionError(anyhow!("splitters lock poisoned: {err}")))
+    }
+
+    fn lock_cursors(
+        &self,
+    ) -> MemoryResult<std::sync::MutexGuard<'_, HashMap<String, CursorContext>>> {
+        self.cursors
+            .lock()
+            .map_err(|err| MemoryError::ExecutionError(anyhow!("cursors lock poisoned: {err}")))
+    }
+}
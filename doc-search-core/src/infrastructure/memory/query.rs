@@ -0,0 +1,627 @@
+use anyhow::anyhow;
+use std::collections::{HashMap, HashSet};
+
+use crate::domain::searcher::SearchResult;
+use crate::domain::searcher::models::{FilterParams, ResultParams, SearchKindParams};
+use crate::domain::searcher::models::{SimilarMode, SimilarSearchingParams};
+use crate::domain::searcher::models::{SuggestField, SuggestParams, Suggestion, Suggestions};
+use crate::domain::searcher::query::parse_query;
+use crate::domain::searcher::query::{BoolQuery, QueryField, QueryNode, QueryTerm, QueryValue};
+use crate::domain::storage::models::{DocumentPart, FIRST_DOCUMENT_PART_ID};
+use crate::infrastructure::memory::error::{MemoryError, MemoryResult};
+use crate::shared::kernel::edit_distance;
+use crate::shared::kernel::vector::{cosine_similarity, mean_vector};
+
+const DEFAULT_FRAGMENT_SIZE: usize = 100;
+const DEFAULT_FRAGMENTS_AMOUNT: usize = 5;
+const MAX_CORRECTION_DISTANCE: usize = 2;
+
+/// Result of matching a document against searching kind params.
+pub struct DocumentMatch {
+    /// Share of distinct query terms founded in the document content.
+    pub score: Option<f64>,
+    pub terms: HashSet<String>,
+}
+
+/// Parses query string of fulltext searching params once per search, other
+/// searching kinds match their query as plain terms.
+pub fn parse_search_kind(kind: &SearchKindParams) -> SearchResult<Option<QueryNode>> {
+    match kind {
+        SearchKindParams::FullText(params) => params.query.as_deref().map(parse_query).transpose(),
+        _ => Ok(None),
+    }
+}
+
+pub fn match_search_kind(
+    kind: &SearchKindParams,
+    syntax: Option<&QueryNode>,
+    doc: &DocumentPart,
+) -> Option<DocumentMatch> {
+    match kind {
+        SearchKindParams::Retrieve(params) => {
+            let is_first_part = doc.doc_part_id == FIRST_DOCUMENT_PART_ID;
+            let is_same_path = params.path.as_ref().is_none_or(|it| it.eq(&doc.file_path));
+            (is_first_part && is_same_path).then(|| DocumentMatch {
+                score: None,
+                terms: HashSet::default(),
+            })
+        }
+        SearchKindParams::FullText(_) => match syntax {
+            None => Some(DocumentMatch {
+                score: None,
+                terms: HashSet::default(),
+            }),
+            Some(node) => match node.plain_text() {
+                Some(query) => match_terms(&query, &doc.content, None),
+                None => match_syntax(node, doc),
+            },
+        },
+        SearchKindParams::Semantic(params) => {
+            match_terms(&params.query, &doc.content, params.min_score)
+        }
+        SearchKindParams::Hybrid(params) => {
+            match_terms(&params.query, &doc.content, params.min_score)
+        }
+        // Similar documents are matched against loaded source document.
+        SearchKindParams::Similar(_) => None,
+    }
+}
+
+/// Source document of similar documents searching: content of its parts
+/// and mean vector of their stored embeddings.
+pub struct SimilarDocument {
+    pub large_doc_id: String,
+    pub content: String,
+    pub vector: Option<Vec<f64>>,
+}
+
+impl SimilarDocument {
+    pub fn from_parts(
+        params: &SimilarSearchingParams,
+        parts: &[&DocumentPart],
+    ) -> MemoryResult<Self> {
+        let Some(first_part) = parts.first() else {
+            let err = anyhow!("source document of similar searching does not exist");
+            return Err(MemoryError::DocumentNotFound(err));
+        };
+
+        let vectors = parts
+            .iter()
+            .filter_map(|it| it.embeddings.as_ref())
+            .flat_map(|it| it.embeddings.iter().cloned())
+            .collect::<Vec<Vec<f64>>>();
+
+        let vector = mean_vector(&vectors);
+        if params.mode == SimilarMode::Vector && vector.is_none() {
+            let err = anyhow!("source document has no stored embeddings");
+            return Err(MemoryError::ValidationError(err));
+        }
+
+        let content = parts
+            .iter()
+            .map(|it| it.content.as_str())
+            .collect::<Vec<&str>>()
+            .join(" ");
+
+        Ok(SimilarDocument {
+            large_doc_id: first_part.large_doc_id.0.clone(),
+            content,
+            vector,
+        })
+    }
+}
+
+/// Matches parts of other documents by share of the source content terms
+/// or by the closest chunk embedding to the source mean vector.
+pub fn match_similar(
+    params: &SimilarSearchingParams,
+    source: &SimilarDocument,
+    doc: &DocumentPart,
+) -> Option<DocumentMatch> {
+    if doc.large_doc_id.0.eq(&source.large_doc_id) {
+        return None;
+    }
+
+    let Some(vector) = source
+        .vector
+        .as_ref()
+        .filter(|_| params.mode == SimilarMode::Vector)
+    else {
+        return match_terms(&source.content, &doc.content, params.min_score);
+    };
+
+    let score = doc
+        .embeddings
+        .as_ref()?
+        .embeddings
+        .iter()
+        .map(|it| cosine_similarity(vector, it))
+        .reduce(f64::max)?;
+
+    let min_score = params.min_score.map_or(0.0, f64::from);
+    (score >= min_score).then(|| DocumentMatch {
+        score: Some(score),
+        terms: HashSet::default(),
+    })
+}
+
+pub fn match_filter(params: &FilterParams, doc: &DocumentPart) -> MemoryResult<bool> {
+    if params.doc_part_id.is_some_and(|it| it != doc.doc_part_id) {
+        return Ok(false);
+    }
+
+    let is_in_ranges = is_in_range(params.size_from, params.size_to, doc.file_size)
+        && is_in_range(params.created_from, params.created_to, doc.created_at)
+        && is_in_range(params.modified_from, params.modified_to, doc.modified_at);
+
+    if !is_in_ranges {
+        return Ok(false);
+    }
+
+    let metadata = doc.metadata.as_ref();
+    let is_same_pipeline = params
+        .pipeline_id
+        .is_none_or(|it| metadata.and_then(|meta| meta.pipeline_id) == Some(it));
+
+    let is_same_source = params
+        .source
+        .as_ref()
+        .is_none_or(|it| metadata.and_then(|meta| meta.source.as_ref()) == Some(it));
+
+    let is_same_semantic_source = params
+        .semantic_source
+        .as_ref()
+        .is_none_or(|it| metadata.and_then(|meta| meta.semantic_source.as_ref()) == Some(it));
+
+    if !(is_same_pipeline && is_same_source && is_same_semantic_source) {
+        return Ok(false);
+    }
+
+    if !params.match_class(metadata) {
+        return Ok(false);
+    }
+
+    let location = params
+        .location_filter()
+        .map_err(MemoryError::ValidationError)?;
+
+    Ok(location.is_none_or(|it| it.match_locations(metadata)))
+}
+
+/// Builds highlight fragments starting at founded query terms. Fragments
+/// are not overlapped and have the same size as opensearch ones by default.
+pub fn build_highlight(
+    params: &ResultParams,
+    terms: &HashSet<String>,
+    content: &str,
+) -> Vec<String> {
+    if terms.is_empty() {
+        return Vec::default();
+    }
+
+    let fragment_size = params
+        .highlight_item_size
+        .map_or(DEFAULT_FRAGMENT_SIZE, |it| it as usize);
+
+    let fragments_amount = params
+        .highlight_items
+        .map_or(DEFAULT_FRAGMENTS_AMOUNT, |it| it as usize);
+
+    let mut fragments = Vec::new();
+    let mut last_fragment_end = 0;
+    for (start, word) in split_words(content) {
+        if fragments.len() >= fragments_amount {
+            break;
+        }
+
+        if start < last_fragment_end || !terms.contains(&word.to_lowercase()) {
+            continue;
+        }
+
+        let fragment = content[start..]
+            .chars()
+            .take(fragment_size)
+            .collect::<String>();
+        last_fragment_end = start + fragment.len();
+        fragments.push(fragment.trim_end().to_string());
+    }
+
+    fragments
+}
+
+/// Builds completions of the last typed word by content terms and file names
+/// and a correction replacing words missed in content of passed documents.
+/// Scores are shares of documents containing suggested terms.
+pub fn build_suggestions<'a>(
+    params: &SuggestParams,
+    docs: impl Iterator<Item = &'a DocumentPart>,
+) -> Suggestions {
+    let mut docs_amount = 0;
+    let mut term_freqs = HashMap::<String, usize>::new();
+    let mut file_names = HashMap::<&str, usize>::new();
+    for doc in docs {
+        docs_amount += 1;
+        for term in tokenize(&doc.content) {
+            *term_freqs.entry(term).or_default() += 1;
+        }
+        *file_names.entry(doc.file_name.as_str()).or_default() += 1;
+    }
+
+    let file_name_terms = file_names
+        .keys()
+        .flat_map(|it| tokenize(it))
+        .collect::<HashSet<String>>();
+
+    if docs_amount == 0 {
+        return Suggestions::default();
+    }
+
+    let freq_ratio = |freq: usize| freq as f64 / docs_amount as f64;
+    let (head, last_word) = params.split_last_word();
+    let last_word = last_word.to_lowercase();
+
+    let mut completions = Vec::new();
+    if !last_word.is_empty() {
+        let content_completions = term_freqs
+            .iter()
+            .filter(|(term, _)| term.starts_with(&last_word))
+            .map(|(term, freq)| Suggestion {
+                text: format!("{head} {term}").trim_start().to_string(),
+                score: freq_ratio(*freq),
+                field: SuggestField::Content,
+            });
+
+        let head_terms = tokenize(head);
+        let file_name_completions = file_names
+            .iter()
+            .filter(|(file_name, _)| {
+                let file_name_terms = tokenize(file_name);
+                head_terms.is_subset(&file_name_terms)
+                    && file_name_terms.iter().any(|it| it.starts_with(&last_word))
+            })
+            .map(|(file_name, count)| Suggestion {
+                text: file_name.to_string(),
+                score: freq_ratio(*count),
+                field: SuggestField::FileName,
+            });
+
+        completions.extend(content_completions);
+        completions.extend(file_name_completions);
+    }
+
+    let words = split_words(&params.query)
+        .map(|(_, word)| word.to_lowercase())
+        .collect::<Vec<String>>();
+
+    let is_last_word_typed = !last_word.is_empty();
+    let mut is_corrected = false;
+    let mut corrected_freqs = Vec::with_capacity(words.len());
+    let mut corrected_words = Vec::with_capacity(words.len());
+    for (position, word) in words.iter().enumerate() {
+        if let Some(freq) = term_freqs.get(word) {
+            corrected_freqs.push(*freq);
+            corrected_words.push(word.clone());
+            continue;
+        }
+
+        // Words of file names and the last word being typed yet while it
+        // completes any term are not misspelled.
+        let is_typed = is_last_word_typed && position + 1 == words.len();
+        let is_completing = term_freqs.keys().any(|it| it.starts_with(word))
+            || file_name_terms.iter().any(|it| it.starts_with(word));
+
+        if file_name_terms.contains(word) || (is_typed && is_completing) {
+            corrected_words.push(word.clone());
+            continue;
+        }
+
+        match find_correction(word, &term_freqs) {
+            None => corrected_words.push(word.clone()),
+            Some((term, freq)) => {
+                is_corrected = true;
+                corrected_freqs.push(freq);
+                corrected_words.push(term.to_string());
+            }
+        }
+    }
+
+    let mut corrections = Vec::new();
+    if is_corrected {
+        let freqs_sum = corrected_freqs.iter().sum::<usize>() as f64;
+        corrections.push(Suggestion {
+            text: corrected_words.join(" "),
+            score: freqs_sum / corrected_freqs.len() as f64 / docs_amount as f64,
+            field: SuggestField::Content,
+        });
+    }
+
+    Suggestions::new(completions, corrections, params.size)
+}
+
+/// Finds the closest term to misspelled word, more frequent terms win ties.
+fn find_correction<'a>(
+    word: &str,
+    term_freqs: &'a HashMap<String, usize>,
+) -> Option<(&'a str, usize)> {
+    term_freqs
+        .iter()
+        .map(|(term, freq)| (edit_distance(word, term), term, *freq))
+        .filter(|(distance, _, _)| *distance <= MAX_CORRECTION_DISTANCE)
+        .min_by(|a, b| a.0.cmp(&b.0).then(b.2.cmp(&a.2)).then(a.1.cmp(b.1)))
+        .map(|(_, term, freq)| (term.as_str(), freq))
+}
+
+fn match_terms(query: &str, content: &str, min_score: Option<f32>) -> Option<DocumentMatch> {
+    let query_terms = tokenize(query);
+    if query_terms.is_empty() {
+        return None;
+    }
+
+    let content_terms = tokenize(content);
+    let founded_terms = query_terms
+        .intersection(&content_terms)
+        .cloned()
+        .collect::<HashSet<String>>();
+
+    let score = founded_terms.len() as f64 / query_terms.len() as f64;
+    let min_score = min_score.map_or(0.0, f64::from);
+    if founded_terms.is_empty() || score < min_score {
+        return None;
+    }
+
+    Some(DocumentMatch {
+        score: Some(score),
+        terms: founded_terms,
+    })
+}
+
+/// Matched and total amounts of positive query terms within syntax node.
+struct NodeMatch {
+    is_matched: bool,
+    matched: usize,
+    total: usize,
+}
+
+fn match_syntax(node: &QueryNode, doc: &DocumentPart) -> Option<DocumentMatch> {
+    let mut terms = HashSet::new();
+    let node_match = match_node(node, doc, &mut terms);
+    if !node_match.is_matched {
+        return None;
+    }
+
+    let score = match node_match.total {
+        0 => 1.0,
+        total => node_match.matched as f64 / total as f64,
+    };
+
+    Some(DocumentMatch {
+        score: Some(score),
+        terms,
+    })
+}
+
+fn match_node(node: &QueryNode, doc: &DocumentPart, terms: &mut HashSet<String>) -> NodeMatch {
+    match node {
+        QueryNode::Bool(query) => match_bool(query, doc, terms),
+        QueryNode::Term(term) => {
+            let is_matched = match_term(term, doc, terms);
+            NodeMatch {
+                is_matched,
+                matched: usize::from(is_matched),
+                total: 1,
+            }
+        }
+    }
+}
+
+fn match_bool(query: &BoolQuery, doc: &DocumentPart, terms: &mut HashSet<String>) -> NodeMatch {
+    let mut node_terms = HashSet::new();
+    let mut result = NodeMatch {
+        is_matched: true,
+        matched: 0,
+        total: 0,
+    };
+
+    for clause in &query.must {
+        let clause_match = match_node(clause, doc, &mut node_terms);
+        result.is_matched &= clause_match.is_matched;
+        result.matched += clause_match.matched;
+        result.total += clause_match.total;
+    }
+
+    let mut is_any_should_matched = false;
+    for clause in &query.should {
+        let clause_match = match_node(clause, doc, &mut node_terms);
+        is_any_should_matched |= clause_match.is_matched;
+        result.matched += clause_match.matched;
+        result.total += clause_match.total;
+    }
+
+    if query.must.is_empty() && !query.should.is_empty() {
+        result.is_matched &= is_any_should_matched;
+    }
+
+    let is_excluded = query
+        .must_not
+        .iter()
+        .any(|clause| match_node(clause, doc, &mut HashSet::new()).is_matched);
+
+    result.is_matched &= !is_excluded;
+    if result.is_matched {
+        terms.extend(node_terms);
+    }
+
+    result
+}
+
+/// Matches term like opensearch index mapping does: content, file name, path
+/// and summary are analyzed text, sources are keywords. Only content terms
+/// are collected for highlighting.
+fn match_term(term: &QueryTerm, doc: &DocumentPart, terms: &mut HashSet<String>) -> bool {
+    let metadata = doc.metadata.as_ref();
+    let value = &term.value;
+    match term.field {
+        QueryField::Content => match_text(value, &doc.content, terms),
+        QueryField::FileName => match_text(value, &doc.file_name, &mut HashSet::new()),
+        QueryField::FilePath => match_text(value, &doc.file_path, &mut HashSet::new()),
+        QueryField::Summary => metadata
+            .and_then(|meta| meta.summary.as_deref())
+            .is_some_and(|it| match_text(value, it, &mut HashSet::new())),
+        QueryField::Source => metadata
+            .and_then(|meta| meta.source.as_deref())
+            .is_some_and(|it| match_keyword(value, it)),
+        QueryField::SemanticSource => metadata
+            .and_then(|meta| meta.semantic_source.as_deref())
+            .is_some_and(|it| match_keyword(value, it)),
+        QueryField::CreatedAt => match_number(value, Some(doc.created_at)),
+        QueryField::ModifiedAt => match_number(value, Some(doc.modified_at)),
+        QueryField::FileSize => match_number(value, Some(i64::from(doc.file_size))),
+        QueryField::PipelineId => match_number(value, metadata.and_then(|meta| meta.pipeline_id)),
+    }
+}
+
+fn match_text(value: &QueryValue, text: &str, terms: &mut HashSet<String>) -> bool {
+    let text_terms = split_words(text)
+        .map(|(_, word)| word.to_lowercase())
+        .collect::<Vec<String>>();
+
+    let founded_terms = match value {
+        QueryValue::Word(word) => tokenize(word)
+            .into_iter()
+            .filter(|it| text_terms.contains(it))
+            .collect::<Vec<String>>(),
+        QueryValue::Phrase(phrase) => {
+            let phrase_terms = split_words(phrase)
+                .map(|(_, word)| word.to_lowercase())
+                .collect::<Vec<String>>();
+
+            let is_founded = !phrase_terms.is_empty()
+                && text_terms
+                    .windows(phrase_terms.len())
+                    .any(|window| window == phrase_terms.as_slice());
+
+            match is_founded {
+                true => phrase_terms,
+                false => Vec::default(),
+            }
+        }
+        QueryValue::Wildcard(pattern) => {
+            let pattern = parse_wildcard(pattern);
+            text_terms
+                .into_iter()
+                .filter(|it| is_wildcard_matched(&pattern, it))
+                .collect()
+        }
+        QueryValue::Fuzzy { word, distance } => {
+            let word = word.to_lowercase();
+            text_terms
+                .into_iter()
+                .filter(|it| edit_distance(&word, it) <= usize::from(*distance))
+                .collect()
+        }
+        QueryValue::Range(_) => Vec::default(),
+    };
+
+    let is_founded = !founded_terms.is_empty();
+    terms.extend(founded_terms);
+    is_founded
+}
+
+fn match_keyword(value: &QueryValue, keyword: &str) -> bool {
+    match value {
+        QueryValue::Word(word) | QueryValue::Phrase(word) => word.eq(keyword),
+        QueryValue::Wildcard(pattern) => {
+            is_wildcard_matched(&parse_wildcard(pattern), &keyword.to_lowercase())
+        }
+        QueryValue::Fuzzy { word, distance } => edit_distance(word, keyword) <= *distance as usize,
+        QueryValue::Range(_) => false,
+    }
+}
+
+fn match_number(value: &QueryValue, number: Option<i64>) -> bool {
+    match value {
+        QueryValue::Range(range) => number.is_some_and(|it| range.contains(it)),
+        _ => false,
+    }
+}
+
+enum WildcardItem {
+    AnyChars,
+    AnyChar,
+    Char(char),
+}
+
+fn parse_wildcard(pattern: &str) -> Vec<WildcardItem> {
+    let mut items = Vec::new();
+    let mut chars = pattern.chars().flat_map(char::to_lowercase);
+    while let Some(ch) = chars.next() {
+        let item = match ch {
+            '*' => WildcardItem::AnyChars,
+            '?' => WildcardItem::AnyChar,
+            '\\' => match chars.next() {
+                Some(escaped) => WildcardItem::Char(escaped),
+                None => break,
+            },
+            _ => WildcardItem::Char(ch),
+        };
+
+        items.push(item);
+    }
+
+    items
+}
+
+fn is_wildcard_matched(pattern: &[WildcardItem], text: &str) -> bool {
+    let text = text.chars().collect::<Vec<char>>();
+    let (mut pattern_pos, mut text_pos) = (0, 0);
+    let mut any_chars_pos = None;
+    while text_pos < text.len() {
+        match pattern.get(pattern_pos) {
+            Some(WildcardItem::AnyChars) => {
+                any_chars_pos = Some((pattern_pos, text_pos));
+                pattern_pos += 1;
+                continue;
+            }
+            Some(WildcardItem::AnyChar) => {
+                pattern_pos += 1;
+                text_pos += 1;
+                continue;
+            }
+            Some(WildcardItem::Char(ch)) if text[text_pos].eq(ch) => {
+                pattern_pos += 1;
+                text_pos += 1;
+                continue;
+            }
+            _ => {}
+        }
+
+        // Lets the last `*` consume one more char and retries the rest.
+        let Some((any_pattern_pos, any_text_pos)) = any_chars_pos else {
+            return false;
+        };
+
+        any_chars_pos = Some((any_pattern_pos, any_text_pos + 1));
+        pattern_pos = any_pattern_pos + 1;
+        text_pos = any_text_pos + 1;
+    }
+
+    pattern[pattern_pos..]
+        .iter()
+        .all(|it| matches!(it, WildcardItem::AnyChars))
+}
+
+fn tokenize(text: &str) -> HashSet<String> {
+    split_words(text)
+        .map(|(_, word)| word.to_lowercase())
+        .collect()
+}
+
+fn split_words(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    text.split(|ch: char| !ch.is_alphanumeric())
+        .filter(|it| !it.is_empty())
+        .map(move |it| (it.as_ptr() as usize - text.as_ptr() as usize, it))
+}
+
+fn is_in_range<T: PartialOrd>(from: Option<T>, to: Option<T>, value: T) -> bool {
+    from.is_none_or(|it| value >= it) && to.is_none_or(|it| value <= it)
+}
@@ -0,0 +1,86 @@
+use crate::ServiceConnect;
+use crate::application::tests::fixture::DEFAULT_INDEX_ID;
+use crate::domain::searcher::models::{FilterParams, FilterParamsBuilder, FullTextSearchingParams};
+use crate::domain::searcher::models::{ResultOrder, ResultParams, ResultParamsBuilder};
+use crate::domain::searcher::models::{SearchKindParams, SearchingParams};
+use crate::domain::searcher::models::{SuggestParams, SuggestParamsBuilder};
+use crate::domain::storage::models::{CreateIndexParamsBuilder, LargeDocument};
+use crate::domain::storage::{IDocumentPartStorage, IIndexStorage};
+use crate::infrastructure::memory::{MemoryClient, MemoryConfig};
+use crate::shared::kernel::IndexId;
+
+pub const MAX_CONTENT_SIZE: usize = 500;
+
+pub async fn init_test_client() -> anyhow::Result<MemoryClient> {
+    let client = MemoryClient::connect(&MemoryConfig::default()).await?;
+    let create_index_params = CreateIndexParamsBuilder::default()
+        .id(DEFAULT_INDEX_ID.to_string())
+        .knn(None)
+        .build()?;
+
+    client.create_index(&create_index_params).await?;
+    Ok(client)
+}
+
+pub async fn store_documents(
+    client: &MemoryClient,
+    documents: Vec<LargeDocument>,
+) -> anyhow::Result<usize> {
+    let index_id = IndexId(DEFAULT_INDEX_ID.to_string());
+    let mut stored_parts_amount = 0;
+    for document in documents {
+        let doc_parts = document.divide_large_document_on_parts(MAX_CONTENT_SIZE)?;
+        let stored_info = client.store_document_parts(&index_id, doc_parts).await?;
+        stored_parts_amount += stored_info.doc_parts_amount;
+    }
+
+    Ok(stored_parts_amount)
+}
+
+pub fn build_result_params(size: i64, offset: i64, order: ResultOrder) -> ResultParams {
+    ResultParamsBuilder::default()
+        .order(order)
+        .size(size)
+        .offset(offset)
+        .include_extra_fields(Some(true))
+        .highlight_items(None)
+        .highlight_item_size(None)
+        .build()
+        .expect("failed building result params builder")
+}
+
+pub fn build_empty_filter_params() -> FilterParamsBuilder {
+    let mut builder = FilterParamsBuilder::default();
+    builder
+        .doc_part_id(None)
+        .size_from(None)
+        .size_to(None)
+        .created_from(None)
+        .created_to(None)
+        .modified_from(None)
+        .modified_to(None);
+
+    builder
+}
+
+pub fn build_fulltext_params(
+    query: Option<&str>,
+    result: ResultParams,
+    filter: Option<FilterParams>,
+) -> SearchingParams {
+    let params = FullTextSearchingParams {
+        query: query.map(String::from),
+    };
+
+    let indexes = vec![DEFAULT_INDEX_ID.to_string()];
+    SearchingParams::new(indexes, SearchKindParams::FullText(params), result, filter)
+}
+
+pub fn build_suggest_params(query: &str) -> SuggestParams {
+    SuggestParamsBuilder::default()
+        .indexes(vec![DEFAULT_INDEX_ID.to_string()])
+        .query(query.to_string())
+        .size(5)
+        .build()
+        .expect("failed to build suggest params")
+}
@@ -0,0 +1,465 @@
+use rstest::rstest;
+
+use crate::application::tests::fixture::DOC_FILE_TIMESTAMP;
+use crate::application::tests::fixture::document::{build_large_document, build_short_document};
+use crate::application::tests::fixture::search_params::build_similar_params;
+use crate::application::tests::fixture::search_params::build_simple_semantic_params;
+use crate::application::tests::fixture::{DEFAULT_INDEX_ID, DOCUMENT_CLASS_NAME, DOCUMENT_SOURCE};
+use crate::domain::searcher::models::RetrieveIndexDocumentsParams;
+use crate::domain::searcher::models::{FilterParams, PaginationParams, ResultOrder};
+use crate::domain::searcher::models::{SearchKindParams, SearchingParams, TotalHits};
+use crate::domain::searcher::models::{SimilarMode, SimilarSource};
+use crate::domain::searcher::models::{SortField, SortParams};
+use crate::domain::searcher::{IPaginator, ISearcher, SearchError};
+use crate::domain::storage::IDocumentPartStorage;
+use crate::domain::storage::models::DocumentPartEmbeddings;
+use crate::infrastructure::memory::MAX_LIVE_CURSORS;
+use crate::infrastructure::memory::tests::fixture::*;
+use crate::shared::kernel::{DocumentPartId, IndexId, LargeDocumentId};
+
+#[rstest]
+#[case(Some("Petersburg"), true)]
+#[case(Some("petersburg nonexistentword"), true)]
+#[case(Some("nonexistentword"), false)]
+#[case(None, true)]
+#[tokio::test]
+async fn test_memory_fulltext_search(
+    #[case] query: Option<&str>,
+    #[case] is_founded: bool,
+) -> anyhow::Result<()> {
+    let client = init_test_client().await?;
+    store_documents(&client, vec![build_short_document()]).await?;
+
+    let result = build_result_params(10, 0, ResultOrder::DESC);
+    let params = build_fulltext_params(query, result, None);
+    let pagination = client.search(&params).await?;
+    assert_eq!(!pagination.founded.is_empty(), is_founded);
+
+    if let (Some(_), Some(founded)) = (query, pagination.founded.first()) {
+        assert!(
+            founded
+                .highlight
+                .iter()
+                .all(|it| it.starts_with("Petersburg"))
+        );
+        assert!(founded.document.content.is_some());
+    }
+
+    Ok(())
+}
+
+#[rstest]
+#[case("\"War and Peace\" AND Tolstoy", true)]
+#[case("\"Peace and War\"", false)]
+#[case("Tolstoy -file_name:test*", false)]
+#[case("+Petersburg NOT (draft OR report)", true)]
+#[case("Peterzburg~1 && Pier?e", true)]
+#[case("Peters*", true)]
+#[case("file_name:test* AND file_path:short", true)]
+#[case("file_size:[1000 TO 2000] created_at:<1700000000", true)]
+#[case("+file_size:>2000 Petersburg", false)]
+#[case("source:source", false)]
+#[tokio::test]
+async fn test_memory_fulltext_syntax_search(
+    #[case] query: &str,
+    #[case] is_founded: bool,
+) -> anyhow::Result<()> {
+    let client = init_test_client().await?;
+    store_documents(&client, vec![build_short_document()]).await?;
+
+    let result = build_result_params(10, 0, ResultOrder::DESC);
+    let params = build_fulltext_params(Some(query), result, None);
+    let pagination = client.search(&params).await?;
+    assert_eq!(!pagination.founded.is_empty(), is_founded);
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_memory_fulltext_syntax_error() -> anyhow::Result<()> {
+    let client = init_test_client().await?;
+    let result = build_result_params(10, 0, ResultOrder::DESC);
+    let params = build_fulltext_params(Some("author:smith"), result, None);
+    let search_result = client.search(&params).await;
+    assert!(matches!(
+        search_result,
+        Err(SearchError::ValidationError(_))
+    ));
+    Ok(())
+}
+
+#[rstest]
+#[case("Peters", Some("petersburg"), None)]
+#[case("war and pe", Some("war and peace"), None)]
+#[case("test docu", Some("test-document.docx"), None)]
+#[case("Pierre did not let anyome", None, Some("pierre did not let anyone"))]
+#[case("Petersbrug ", None, Some("petersburg"))]
+#[tokio::test]
+async fn test_memory_suggest(
+    #[case] query: &str,
+    #[case] completion: Option<&str>,
+    #[case] correction: Option<&str>,
+) -> anyhow::Result<()> {
+    let client = init_test_client().await?;
+    store_documents(&client, vec![build_short_document()]).await?;
+
+    let params = build_suggest_params(query);
+    let suggestions = client.suggest(&params).await?;
+    assert!(suggestions.completions.len() <= params.size);
+    if let Some(completion) = completion {
+        assert!(
+            suggestions
+                .completions
+                .iter()
+                .any(|it| it.text == completion)
+        );
+    }
+
+    let corrections = suggestions
+        .corrections
+        .iter()
+        .map(|it| it.text.as_str())
+        .collect::<Vec<&str>>();
+
+    assert_eq!(corrections.first().copied(), correction);
+    Ok(())
+}
+
+#[rstest]
+#[case(ResultOrder::ASC)]
+#[case(ResultOrder::DESC)]
+#[tokio::test]
+async fn test_memory_search_ordering(#[case] order: ResultOrder) -> anyhow::Result<()> {
+    let client = init_test_client().await?;
+    let mut short_document = build_short_document();
+    short_document.created_at += 100;
+    store_documents(&client, vec![build_large_document(), short_document]).await?;
+
+    let is_ascending = matches!(order, ResultOrder::ASC);
+    let result = build_result_params(100, 0, order);
+    let params = build_fulltext_params(None, result, None);
+    let pagination = client.search(&params).await?;
+
+    let timestamps = pagination
+        .founded
+        .iter()
+        .map(|it| it.document.created_at)
+        .collect::<Vec<i64>>();
+
+    let mut expected = timestamps.clone();
+    expected.sort();
+    if !is_ascending {
+        expected.reverse();
+    }
+
+    assert_eq!(timestamps, expected);
+    Ok(())
+}
+
+#[rstest]
+#[case(ResultOrder::ASC, DOC_FILE_TIMESTAMP)]
+#[case(ResultOrder::DESC, DOC_FILE_TIMESTAMP + 100)]
+#[tokio::test]
+async fn test_memory_search_sorting(
+    #[case] order: ResultOrder,
+    #[case] expected_modified_at: i64,
+) -> anyhow::Result<()> {
+    let client = init_test_client().await?;
+    let mut short_document = build_short_document();
+    short_document.modified_at += 100;
+    store_documents(&client, vec![build_large_document(), short_document]).await?;
+
+    let mut result = build_result_params(100, 0, ResultOrder::DESC);
+    result.sort = vec![
+        SortParams::new(SortField::ModifiedAt, order),
+        SortParams::new(SortField::PipelineId, ResultOrder::DESC),
+    ];
+
+    let params = build_fulltext_params(None, result, None);
+    let pagination = client.search(&params).await?;
+    let founded = pagination
+        .founded
+        .first()
+        .expect("expected founded document");
+    assert_eq!(founded.document.modified_at, expected_modified_at);
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_memory_cursor_pagination() -> anyhow::Result<()> {
+    let client = init_test_client().await?;
+    let stored_parts_amount = store_documents(&client, vec![build_large_document()]).await?;
+
+    let result = build_result_params(2, 0, ResultOrder::ASC);
+    let params = build_fulltext_params(None, result, None);
+    let mut pagination = client.search(&params).await?;
+    let mut founded_amount = pagination.founded.len();
+    let expected_total = Some(TotalHits::exact(stored_parts_amount as u64));
+    assert_eq!(pagination.total, expected_total);
+    assert!(pagination.took.is_some());
+
+    while let Some(cursor) = pagination.cursor.take() {
+        let params = PaginationParams { cursor };
+        pagination = client.paginate(&params).await?;
+        assert_ne!(pagination.cursor.as_ref(), Some(&params.cursor));
+        assert!(client.paginate(&params).await.is_err());
+        founded_amount += pagination.founded.len();
+        assert_eq!(pagination.total, expected_total);
+    }
+
+    assert_eq!(founded_amount, stored_parts_amount);
+
+    let params = PaginationParams {
+        cursor: "unknown-cursor".to_string(),
+    };
+    let result = client.paginate(&params).await;
+    assert!(matches!(result, Err(SearchError::ValidationError(_))));
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_memory_cursors_limited() -> anyhow::Result<()> {
+    let client = init_test_client().await?;
+    store_documents(&client, vec![build_large_document()]).await?;
+
+    let result = build_result_params(1, 0, ResultOrder::ASC);
+    let params = build_fulltext_params(None, result, None);
+    let first_cursor = client.search(&params).await?.cursor;
+    assert!(first_cursor.is_some());
+
+    for _ in 0..MAX_LIVE_CURSORS {
+        let pagination = client.search(&params).await?;
+        assert!(pagination.cursor.is_some());
+    }
+
+    assert_eq!(client.cursors.lock().unwrap().len(), MAX_LIVE_CURSORS);
+
+    let params = PaginationParams {
+        cursor: first_cursor.unwrap(),
+    };
+    let result = client.paginate(&params).await;
+    assert!(matches!(result, Err(SearchError::ValidationError(_))));
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_memory_search_grouped_by_document() -> anyhow::Result<()> {
+    let client = init_test_client().await?;
+    let documents = vec![build_large_document(), build_short_document()];
+    let stored_parts_amount = store_documents(&client, documents).await?;
+
+    let mut result = build_result_params(10, 0, ResultOrder::DESC);
+    result.group_by_document = Some(true);
+    let params = build_fulltext_params(None, result, None);
+    let pagination = client.search(&params).await?;
+    assert_eq!(pagination.founded.len(), 2);
+
+    let matched_parts = pagination
+        .founded
+        .iter()
+        .filter_map(|it| it.grouped.as_ref())
+        .map(|it| it.matched_parts)
+        .sum::<usize>();
+
+    assert_eq!(matched_parts, stored_parts_amount);
+    Ok(())
+}
+
+#[rstest]
+#[case(build_empty_filter_params().source(Some(DOCUMENT_SOURCE.to_string())).build()?, true)]
+#[case(build_empty_filter_params().source(Some("unknown".to_string())).build()?, false)]
+#[case(build_empty_filter_params().size_to(Some(1)).build()?, false)]
+#[case(build_empty_filter_params().doc_part_id(Some(1)).build()?, true)]
+#[case(build_empty_filter_params().doc_class(Some(DOCUMENT_CLASS_NAME.to_string())).build()?, true)]
+#[case(build_empty_filter_params().doc_class_probability(Some(0.5)).build()?, true)]
+#[case(build_empty_filter_params().location_coords(Some(vec![0.01, 0.01])).build()?, true)]
+#[case(build_empty_filter_params().location_coords(Some(vec![20.0, 20.0])).build()?, false)]
+#[tokio::test]
+async fn test_memory_filtered_search(
+    #[case] filter: FilterParams,
+    #[case] is_founded: bool,
+) -> anyhow::Result<()> {
+    let client = init_test_client().await?;
+    store_documents(&client, vec![build_large_document()]).await?;
+
+    let result = build_result_params(10, 0, ResultOrder::DESC);
+    let params = build_fulltext_params(None, result, Some(filter));
+    let pagination = client.search(&params).await?;
+    assert_eq!(!pagination.founded.is_empty(), is_founded);
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_memory_retrieve_documents() -> anyhow::Result<()> {
+    let client = init_test_client().await?;
+    let documents = vec![build_large_document(), build_short_document()];
+    store_documents(&client, documents).await?;
+
+    let result = build_result_params(10, 0, ResultOrder::DESC);
+    let params = RetrieveIndexDocumentsParams { path: None };
+    let indexes = vec![DEFAULT_INDEX_ID.to_string()];
+    let kind = SearchKindParams::Retrieve(params);
+    let params = SearchingParams::new(indexes, kind, result, None);
+
+    let pagination = client.search(&params).await?;
+    assert_eq!(pagination.founded.len(), 2);
+    assert!(
+        pagination
+            .founded
+            .iter()
+            .all(|it| it.document.doc_part_id == 1)
+    );
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_memory_semantic_search() -> anyhow::Result<()> {
+    let client = init_test_client().await?;
+    store_documents(&client, vec![build_large_document()]).await?;
+
+    let mut semantic_params = build_simple_semantic_params();
+    semantic_params.query = "Petersburg".to_string();
+    let knn_amount = semantic_params.knn_amount as usize;
+
+    let result = build_result_params(100, 0, ResultOrder::DESC);
+    let indexes = vec![DEFAULT_INDEX_ID.to_string()];
+    let kind = SearchKindParams::Semantic(semantic_params);
+    let params = SearchingParams::new(indexes, kind, result, None);
+
+    let pagination = client.search(&params).await?;
+    assert!(!pagination.founded.is_empty());
+    assert!(pagination.founded.len() <= knn_amount);
+    assert!(pagination.founded.iter().all(|it| it.score.is_some()));
+    Ok(())
+}
+
+#[rstest]
+#[case(false)]
+#[case(true)]
+#[tokio::test]
+async fn test_memory_similar_search(#[case] by_first_part: bool) -> anyhow::Result<()> {
+    let client = init_test_client().await?;
+    let index_id = IndexId(DEFAULT_INDEX_ID.to_string());
+    let doc_parts = build_short_document().divide_large_document_on_parts(MAX_CONTENT_SIZE)?;
+    let stored_info = client.store_document_parts(&index_id, doc_parts).await?;
+    store_documents(&client, vec![build_large_document()]).await?;
+
+    let source = match by_first_part {
+        true => SimilarSource::DocumentPart(stored_info.first_part_id.clone()),
+        false => SimilarSource::LargeDocument(stored_info.large_doc_id.clone()),
+    };
+
+    let result = build_result_params(100, 0, ResultOrder::DESC);
+    let indexes = vec![DEFAULT_INDEX_ID.to_string()];
+    let kind = SearchKindParams::Similar(build_similar_params(source, SimilarMode::Lexical));
+    let params = SearchingParams::new(indexes, kind, result, None);
+
+    let pagination = client.search(&params).await?;
+    assert!(!pagination.founded.is_empty());
+    assert!(
+        pagination
+            .founded
+            .iter()
+            .all(|it| it.document.large_doc_id.0 != stored_info.large_doc_id.0)
+    );
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_memory_similar_vector_search() -> anyhow::Result<()> {
+    let client = init_test_client().await?;
+    let index_id = IndexId(DEFAULT_INDEX_ID.to_string());
+    let mut stored_infos = Vec::new();
+    for (document, vector) in [
+        (build_short_document(), vec![1.0, 0.0]),
+        (build_large_document(), vec![0.8, 0.6]),
+    ] {
+        let mut doc_parts = document.divide_large_document_on_parts(MAX_CONTENT_SIZE)?;
+        for doc_part in doc_parts.iter_mut() {
+            doc_part.embeddings = Some(DocumentPartEmbeddings {
+                chunked_text: vec![doc_part.content.clone()],
+                embeddings: vec![vector.clone()],
+            });
+        }
+
+        stored_infos.push(client.store_document_parts(&index_id, doc_parts).await?);
+    }
+
+    let source = SimilarSource::LargeDocument(stored_infos[0].large_doc_id.clone());
+    let result = build_result_params(100, 0, ResultOrder::DESC);
+    let indexes = vec![DEFAULT_INDEX_ID.to_string()];
+    let kind = SearchKindParams::Similar(build_similar_params(source, SimilarMode::Vector));
+    let params = SearchingParams::new(indexes, kind, result, None);
+
+    let pagination = client.search(&params).await?;
+    assert_eq!(pagination.founded.len(), stored_infos[1].doc_parts_amount);
+    assert!(
+        pagination
+            .founded
+            .iter()
+            .all(|it| it.score.is_some_and(|score| (score - 0.8).abs() < 1e-9))
+    );
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_memory_similar_search_errors() -> anyhow::Result<()> {
+    let client = init_test_client().await?;
+    store_documents(&client, vec![build_short_document()]).await?;
+
+    let result = build_result_params(10, 0, ResultOrder::DESC);
+    let indexes = vec![DEFAULT_INDEX_ID.to_string()];
+    let source = SimilarSource::DocumentPart(DocumentPartId("unknown".to_string()));
+    let kind = SearchKindParams::Similar(build_similar_params(source, SimilarMode::Lexical));
+    let params = SearchingParams::new(indexes.clone(), kind, result.clone(), None);
+    let search_result = client.search(&params).await;
+    assert!(matches!(
+        search_result,
+        Err(SearchError::DocumentNotFound(_))
+    ));
+
+    let retrieved = client
+        .search(&build_fulltext_params(None, result.clone(), None))
+        .await?;
+    let large_doc_id = retrieved.founded[0].document.large_doc_id.0.clone();
+    let source = SimilarSource::LargeDocument(LargeDocumentId(large_doc_id));
+    let kind = SearchKindParams::Similar(build_similar_params(source, SimilarMode::Vector));
+    let params = SearchingParams::new(indexes, kind, result, None);
+    let search_result = client.search(&params).await;
+    assert!(matches!(
+        search_result,
+        Err(SearchError::ValidationError(_))
+    ));
+    Ok(())
+}
+
+#[rstest]
+#[case(None, 2)]
+#[case(Some(vec![DEFAULT_INDEX_ID.to_string()]), 2)]
+#[case(Some(vec!["other-*".to_string()]), 0)]
+#[case(Some(vec![]), 0)]
+#[tokio::test]
+async fn test_memory_wildcard_search_restricted(
+    #[case] allowed_indexes: Option<Vec<String>>,
+    #[case] expected_founded: usize,
+) -> anyhow::Result<()> {
+    let client = init_test_client().await?;
+    let documents = vec![build_large_document(), build_short_document()];
+    store_documents(&client, documents).await?;
+
+    let result = build_result_params(10, 0, ResultOrder::DESC);
+    let kind = SearchKindParams::Retrieve(RetrieveIndexDocumentsParams { path: None });
+    let params = SearchingParams::new(vec!["*".to_string()], kind, result, None)
+        .with_allowed_indexes(allowed_indexes);
+
+    let pagination = client.search(&params).await?;
+    assert_eq!(pagination.founded.len(), expected_founded);
+    Ok(())
+}
@@ -0,0 +1,219 @@
+use rstest::rstest;
+
+use crate::application::tests::fixture::document::{build_large_document, build_short_document};
+use crate::application::tests::fixture::{DEFAULT_INDEX_ID, DOC_FILE_PATH};
+use crate::domain::storage::models::UpdateDocumentParamsBuilder;
+use crate::domain::storage::models::{CreateIndexParamsBuilder, LargeDocument, SplitterParams};
+use crate::domain::storage::{IDocumentPartStorage, IIndexStorage, StorageError};
+use crate::infrastructure::memory::tests::fixture::{MAX_CONTENT_SIZE, init_test_client};
+use crate::shared::kernel::{IndexId, LargeDocumentId};
+
+#[rstest]
+#[case("second-index", true)]
+#[case(DEFAULT_INDEX_ID, false)]
+#[case("", false)]
+#[tokio::test]
+async fn test_memory_create_index(
+    #[case] index_id: &str,
+    #[case] is_created: bool,
+) -> anyhow::Result<()> {
+    let client = init_test_client().await?;
+    let params = CreateIndexParamsBuilder::default()
+        .id(index_id.to_string())
+        .knn(None)
+        .build()?;
+
+    let result = client.create_index(&params).await;
+    assert_eq!(result.is_ok(), is_created);
+
+    let indexes = client.get_all_indexes().await?;
+    let expected_amount = if is_created { 2 } else { 1 };
+    assert_eq!(indexes.len(), expected_amount);
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_memory_delete_index() -> anyhow::Result<()> {
+    let client = init_test_client().await?;
+    let index_id = IndexId(DEFAULT_INDEX_ID.to_string());
+
+    client.delete_index(&index_id).await?;
+    let result = client.get_index(&index_id).await;
+    assert!(matches!(result, Err(StorageError::IndexNotFound(_))));
+
+    let result = client.delete_index(&index_id).await;
+    assert!(matches!(result, Err(StorageError::IndexNotFound(_))));
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_memory_index_splitter() -> anyhow::Result<()> {
+    let client = init_test_client().await?;
+    let splitter = SplitterParams::Markdown {
+        chunk_size: Some(256),
+    };
+    let params = CreateIndexParamsBuilder::default()
+        .id("splitter-index".to_string())
+        .knn(None)
+        .splitter(Some(splitter.clone()))
+        .build()?;
+
+    let index_id = client.create_index(&params).await?;
+    let stored_splitter = client.get_index_splitter(&index_id).await?;
+    assert_eq!(stored_splitter, Some(splitter));
+
+    let default_index_id = IndexId(DEFAULT_INDEX_ID.to_string());
+    let default_splitter = client.get_index_splitter(&default_index_id).await?;
+    assert_eq!(default_splitter, None);
+
+    client.delete_index(&index_id).await?;
+    let result = client.get_index_splitter(&index_id).await;
+    assert!(matches!(result, Err(StorageError::IndexNotFound(_))));
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_memory_store_document_parts(
+    build_large_document: LargeDocument,
+) -> anyhow::Result<()> {
+    let client = init_test_client().await?;
+    let index_id = IndexId(DEFAULT_INDEX_ID.to_string());
+    let doc_parts = build_large_document.divide_large_document_on_parts(MAX_CONTENT_SIZE)?;
+    let doc_parts_amount = doc_parts.len();
+
+    let stored_info = client.store_document_parts(&index_id, doc_parts).await?;
+    assert_eq!(stored_info.doc_parts_amount, doc_parts_amount);
+
+    let first_part = client
+        .get_document_part(&index_id, &stored_info.first_part_id)
+        .await?;
+    assert_eq!(first_part.doc_part_id, 1);
+
+    let large_doc_id = stored_info.large_doc_id;
+    let all_parts = client.get_document_parts(&index_id, &large_doc_id).await?;
+    assert_eq!(all_parts.len(), doc_parts_amount);
+    assert!(
+        all_parts
+            .windows(2)
+            .all(|it| it[0].doc_part_id < it[1].doc_part_id)
+    );
+
+    client
+        .delete_document_parts(&index_id, &large_doc_id)
+        .await?;
+    let all_parts = client.get_document_parts(&index_id, &large_doc_id).await?;
+    assert!(all_parts.is_empty());
+
+    let result = client
+        .get_document_part(&index_id, &stored_info.first_part_id)
+        .await;
+    assert!(matches!(result, Err(StorageError::DocumentNotFound(_))));
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_memory_store_replaces_document_parts(
+    build_large_document: LargeDocument,
+    build_short_document: LargeDocument,
+) -> anyhow::Result<()> {
+    let client = init_test_client().await?;
+    let index_id = IndexId(DEFAULT_INDEX_ID.to_string());
+    let doc_parts = build_large_document.divide_large_document_on_parts(MAX_CONTENT_SIZE)?;
+    let stored_info = client.store_document_parts(&index_id, doc_parts).await?;
+
+    let mut short_document = build_short_document;
+    short_document.file_path = DOC_FILE_PATH.to_string();
+    let doc_parts = short_document.divide_large_document_on_parts(MAX_CONTENT_SIZE)?;
+    let replaced_info = client.store_document_parts(&index_id, doc_parts).await?;
+    assert_eq!(replaced_info.large_doc_id.0, stored_info.large_doc_id.0);
+
+    let all_parts = client
+        .get_document_parts(&index_id, &replaced_info.large_doc_id)
+        .await?;
+    assert_eq!(all_parts.len(), replaced_info.doc_parts_amount);
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_memory_create_existing_document_parts(
+    build_large_document: LargeDocument,
+) -> anyhow::Result<()> {
+    let client = init_test_client().await?;
+    let index_id = IndexId(DEFAULT_INDEX_ID.to_string());
+    let doc_parts = build_large_document.divide_large_document_on_parts(MAX_CONTENT_SIZE)?;
+    let stored_info = client
+        .create_document_parts(&index_id, doc_parts.clone())
+        .await?;
+
+    let result = client.create_document_parts(&index_id, doc_parts).await;
+    assert!(matches!(
+        result,
+        Err(StorageError::DocumentAlreadyExists(_))
+    ));
+
+    let all_parts = client
+        .get_document_parts(&index_id, &stored_info.large_doc_id)
+        .await?;
+    assert_eq!(all_parts.len(), stored_info.doc_parts_amount);
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_memory_store_into_unknown_index(
+    build_large_document: LargeDocument,
+) -> anyhow::Result<()> {
+    let client = init_test_client().await?;
+    let index_id = IndexId("unknown-index".to_string());
+    let doc_parts = build_large_document.divide_large_document_on_parts(MAX_CONTENT_SIZE)?;
+
+    let result = client.store_document_parts(&index_id, doc_parts).await;
+    assert!(matches!(result, Err(StorageError::IndexNotFound(_))));
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_memory_update_document_parts(
+    build_large_document: LargeDocument,
+) -> anyhow::Result<()> {
+    let client = init_test_client().await?;
+    let index_id = IndexId(DEFAULT_INDEX_ID.to_string());
+    let doc_parts = build_large_document.divide_large_document_on_parts(MAX_CONTENT_SIZE)?;
+    let stored_info = client.store_document_parts(&index_id, doc_parts).await?;
+    let large_doc_id = stored_info.large_doc_id;
+    let stored_parts = client.get_document_parts(&index_id, &large_doc_id).await?;
+
+    let params = UpdateDocumentParamsBuilder::default()
+        .file_name("renamed-document.docx".to_string())
+        .file_path("./renamed-document.docx".to_string())
+        .file_size(2048)
+        .modified_at(1)
+        .metadata(None)
+        .build()?;
+
+    client
+        .update_document_parts(&index_id, &large_doc_id, &params)
+        .await?;
+
+    let updated_parts = client.get_document_parts(&index_id, &large_doc_id).await?;
+    assert_eq!(updated_parts.len(), stored_parts.len());
+    for (updated, stored) in updated_parts.iter().zip(stored_parts.iter()) {
+        assert_eq!(updated.file_name, params.file_name);
+        assert_eq!(updated.created_at, stored.created_at);
+        assert_eq!(updated.content, stored.content);
+        assert!(updated.metadata.is_none());
+    }
+
+    let unknown_doc_id = LargeDocumentId("unknown-document".to_string());
+    let result = client
+        .update_document_parts(&index_id, &unknown_doc_id, &params)
+        .await;
+    assert!(matches!(result, Err(StorageError::DocumentNotFound(_))));
+    Ok(())
+}
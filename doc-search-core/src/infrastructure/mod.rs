@@ -1 +1,6 @@
+pub mod composite;
+pub mod embedder;
+pub mod memory;
 pub mod osearch;
+pub mod qdrant;
+pub mod tantivy;
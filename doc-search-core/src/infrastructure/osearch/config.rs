@@ -1,6 +1,8 @@
 use gset::Getset;
 use serde_derive::Deserialize;
 
+const PLACEHOLDER_CURSOR_SECRET: &str = "change-me";
+
 #[derive(Clone, Debug, Deserialize, Getset)]
 pub struct OSearchConfig {
     #[getset(get, vis = "pub")]
@@ -9,12 +11,34 @@ pub struct OSearchConfig {
     username: String,
     #[getset(get, vis = "pub")]
     password: String,
+    #[serde(default)]
+    #[getset(get, vis = "pub")]
+    cursor_secret: Option<String>,
     #[getset(get, vis = "pub")]
     semantic: OSearchKnnConfig,
     #[getset(get, vis = "pub")]
     cluster: OSearchClusterConfig,
 }
 
+impl OSearchConfig {
+    /// Cursors signed by empty or shipped placeholder secret can be forged,
+    /// so such secret is rejected at startup instead of being used.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        let Some(secret) = self.cursor_secret.as_deref() else {
+            return Ok(());
+        };
+
+        let secret = secret.trim();
+        if secret.is_empty() || secret.eq(PLACEHOLDER_CURSOR_SECRET) {
+            anyhow::bail!(
+                "storage.opensearch.cursor_secret must be set to a non placeholder value"
+            );
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Getset)]
 pub struct OSearchClusterConfig {
     #[getset(get_copy, vis = "pub")]
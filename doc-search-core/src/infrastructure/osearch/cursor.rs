@@ -0,0 +1,171 @@
+use anyhow::anyhow;
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use hmac::{Hmac, Mac};
+use serde_derive::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::Sha256;
+use std::collections::HashMap;
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use crate::domain::searcher::models::SearchingParams;
+use crate::infrastructure::osearch::error::{OSearchError, OSearchResult};
+
+type HmacSha256 = Hmac<Sha256>;
+
+const SIGNATURE_SEPARATOR: char = '.';
+const MAX_CACHED_QUERIES: usize = 1000;
+
+/// State of cursor passed to client within the cursor itself, so the next
+/// page is loaded by any replica and nothing is kept between requests.
+/// Query is rebuilt by searching params for every page, except similar one
+/// resolved by loading the source document and one built by embedding tokens
+/// dropped from params to keep cursor compact. Such query is kept by replica
+/// created the cursor under `query_id`.
+#[derive(Deserialize, Serialize)]
+pub struct CursorContext {
+    pub params: SearchingParams,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub query_id: Option<String>,
+    pub pit_id: Option<String>,
+    pub search_after: Option<Value>,
+    pub offset: usize,
+    pub size: usize,
+    pub expired_at: u64,
+}
+
+impl CursorContext {
+    pub fn new(params: SearchingParams, pit_id: Option<String>, size: usize) -> Self {
+        CursorContext {
+            params: params.without_tokens(),
+            query_id: None,
+            pit_id,
+            search_after: None,
+            offset: 0,
+            size,
+            expired_at: 0,
+        }
+    }
+
+    pub fn prolong(&mut self, lifetime: Duration) {
+        self.expired_at = unix_timestamp() + lifetime.as_secs();
+    }
+
+    fn is_expired(&self) -> bool {
+        self.expired_at <= unix_timestamp()
+    }
+}
+
+/// Encodes cursor context as base64 json signed by HMAC-SHA256, so passed
+/// back cursor can not be forged to search within not allowed indexes.
+pub struct CursorCodec {
+    mac: HmacSha256,
+}
+
+impl CursorCodec {
+    pub fn new(secret: &str) -> Self {
+        let mac =
+            HmacSha256::new_from_slice(secret.as_bytes()).expect("hmac accepts keys of any length");
+
+        CursorCodec { mac }
+    }
+
+    pub fn encode(&self, context: &CursorContext) -> OSearchResult<String> {
+        let payload = serde_json::to_vec(context)
+            .map_err(|err| OSearchError::ExecutionError(anyhow!(err)))?;
+
+        let signature = self.sign(&payload);
+        let payload = URL_SAFE_NO_PAD.encode(payload);
+        let signature = URL_SAFE_NO_PAD.encode(signature);
+        Ok(format!("{payload}{SIGNATURE_SEPARATOR}{signature}"))
+    }
+
+    pub fn decode(&self, cursor: &str) -> OSearchResult<CursorContext> {
+        let not_found = || {
+            let err = anyhow!("cursor {cursor} does not exist or expired");
+            OSearchError::CursorNotFound(err)
+        };
+
+        let (payload, signature) = cursor
+            .split_once(SIGNATURE_SEPARATOR)
+            .ok_or_else(not_found)?;
+
+        let payload = URL_SAFE_NO_PAD.decode(payload).map_err(|_| not_found())?;
+        let signature = URL_SAFE_NO_PAD.decode(signature).map_err(|_| not_found())?;
+        let mut mac = self.mac.clone();
+        mac.update(&payload);
+        mac.verify_slice(&signature).map_err(|_| not_found())?;
+
+        let context = serde_json::from_slice::<CursorContext>(&payload).map_err(|_| not_found())?;
+        if context.is_expired() {
+            return Err(not_found());
+        }
+
+        Ok(context)
+    }
+
+    fn sign(&self, payload: &[u8]) -> Vec<u8> {
+        let mut mac = self.mac.clone();
+        mac.update(payload);
+        mac.finalize().into_bytes().to_vec()
+    }
+}
+
+/// Queries of cursors which can not be rebuilt by cursor params. Expired
+/// queries are pruned by every insert and the oldest ones are evicted once
+/// `MAX_CACHED_QUERIES` are kept.
+#[derive(Default)]
+pub struct QueryCache {
+    queries: Mutex<HashMap<String, CachedQuery>>,
+}
+
+struct CachedQuery {
+    query: Value,
+    expired_at: Instant,
+}
+
+impl QueryCache {
+    pub fn insert(&self, query: Value, lifetime: Duration) -> String {
+        let mut queries = self.queries.lock().unwrap_or_else(PoisonError::into_inner);
+        let now = Instant::now();
+        queries.retain(|_, it| it.expired_at > now);
+        while queries.len() >= MAX_CACHED_QUERIES {
+            let oldest = queries
+                .iter()
+                .min_by_key(|(_, it)| it.expired_at)
+                .map(|(query_id, _)| query_id.clone());
+
+            match oldest {
+                Some(query_id) => queries.remove(&query_id),
+                None => break,
+            };
+        }
+
+        let query_id = uuid::Uuid::new_v4().to_string();
+        let expired_at = now + lifetime;
+        queries.insert(query_id.clone(), CachedQuery { query, expired_at });
+        query_id
+    }
+
+    /// Returns not expired query prolonging it by passed lifetime.
+    pub fn get(&self, query_id: &str, lifetime: Duration) -> Option<Value> {
+        let mut queries = self.queries.lock().unwrap_or_else(PoisonError::into_inner);
+        let now = Instant::now();
+        let cached = queries.get_mut(query_id).filter(|it| it.expired_at > now)?;
+        cached.expired_at = now + lifetime;
+        Some(cached.query.clone())
+    }
+
+    pub fn remove(&self, query_id: &str) {
+        let mut queries = self.queries.lock().unwrap_or_else(PoisonError::into_inner);
+        queries.remove(query_id);
+    }
+}
+
+fn unix_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
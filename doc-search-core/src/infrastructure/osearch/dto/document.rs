@@ -2,7 +2,9 @@ use anyhow::Context;
 use derive_builder::Builder;
 use serde_derive::{Deserialize, Serialize};
 
-use crate::domain::searcher::models::{DocumentPartEntrails, DocumentPartEntrailsBuilder};
+use crate::domain::searcher::models::{
+    DocumentPartEntrails, DocumentPartEntrailsBuilder, Embeddings,
+};
 use crate::domain::storage::models::DocumentPart;
 use crate::infrastructure::osearch::dto::metadata::SourceDocumentMetadata;
 use crate::infrastructure::osearch::error::OSearchError;
@@ -19,6 +21,17 @@ pub struct SourceDocument {
     pub modified_at: i64,
     pub content: Option<String>,
     pub metadata: Option<SourceDocumentMetadata>,
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chunked_text: Option<Vec<String>>,
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub embeddings: Option<Vec<SourceEmbeddings>>,
+}
+
+#[derive(Clone, Deserialize, Serialize)]
+pub struct SourceEmbeddings {
+    pub knn: Vec<f64>,
 }
 
 impl TryFrom<SourceDocument> for DocumentPartEntrails {
@@ -30,6 +43,12 @@ impl TryFrom<SourceDocument> for DocumentPartEntrails {
             None => None,
         };
 
+        let embeddings = src_doc.embeddings.map(|it| {
+            it.into_iter()
+                .map(|emb| Embeddings::from(emb.knn))
+                .collect()
+        });
+
         DocumentPartEntrailsBuilder::default()
             .large_doc_id(LargeDocumentId(src_doc.large_doc_id))
             .doc_part_id(src_doc.doc_part_id)
@@ -39,8 +58,8 @@ impl TryFrom<SourceDocument> for DocumentPartEntrails {
             .created_at(src_doc.created_at)
             .modified_at(src_doc.modified_at)
             .content(src_doc.content)
-            .chunked_text(None)
-            .embeddings(None)
+            .chunked_text(src_doc.chunked_text)
+            .embeddings(embeddings)
             .metadata(metadata)
             .build()
             .context("failed to build document part entrails")
@@ -57,6 +76,18 @@ impl TryFrom<DocumentPart> for SourceDocument {
             None => None,
         };
 
+        let (chunked_text, embeddings) = match doc_part.embeddings {
+            None => (None, None),
+            Some(part_embeddings) => {
+                let embeddings = part_embeddings
+                    .embeddings
+                    .into_iter()
+                    .map(|knn| SourceEmbeddings { knn })
+                    .collect();
+                (Some(part_embeddings.chunked_text), Some(embeddings))
+            }
+        };
+
         SourceDocumentBuilder::default()
             .large_doc_id(doc_part.large_doc_id.0)
             .doc_part_id(doc_part.doc_part_id)
@@ -67,6 +98,8 @@ impl TryFrom<DocumentPart> for SourceDocument {
             .modified_at(doc_part.modified_at)
             .content(Some(doc_part.content))
             .metadata(metadata)
+            .chunked_text(chunked_text)
+            .embeddings(embeddings)
             .build()
             .context("failed to build document part")
             .map_err(OSearchError::ValidationError)
@@ -14,6 +14,7 @@ pub struct FoundedDocumentInfo {
     _score: Option<f64>,
     _source: SourceDocument,
     highlight: Option<HighlightContent>,
+    inner_hits: Option<CollapsedInnerHits>,
 }
 
 #[derive(Deserialize)]
@@ -21,10 +22,51 @@ struct HighlightContent {
     content: Vec<String>,
 }
 
+/// Inner hits of collapsed hit, named `matched_parts` by the search query.
+#[derive(Deserialize)]
+struct CollapsedInnerHits {
+    matched_parts: InnerHitsResult,
+}
+
+#[derive(Deserialize)]
+struct InnerHitsResult {
+    hits: InnerHitsList,
+}
+
+#[derive(Deserialize)]
+struct InnerHitsList {
+    total: InnerHitsTotal,
+    hits: Vec<FoundedDocumentInfo>,
+}
+
+#[derive(Deserialize)]
+struct InnerHitsTotal {
+    value: usize,
+}
+
 impl TryFrom<FoundedDocumentInfo> for FoundedDocument {
     type Error = OSearchError;
 
-    fn try_from(doc_info: FoundedDocumentInfo) -> Result<Self, Self::Error> {
+    fn try_from(mut doc_info: FoundedDocumentInfo) -> Result<Self, Self::Error> {
+        if let Some(inner_hits) = doc_info.inner_hits.take() {
+            let matched_parts = inner_hits.matched_parts.hits;
+            let parts = matched_parts
+                .hits
+                .into_iter()
+                .map(FoundedDocument::try_from)
+                .collect::<Result<Vec<FoundedDocument>, OSearchError>>()?;
+
+            // Inner hits are limited by size, so amount of matched parts
+            // is taken from their total.
+            if let Some(mut founded) = FoundedDocument::collapse_parts(parts) {
+                if let Some(grouped) = founded.grouped.as_mut() {
+                    grouped.matched_parts = matched_parts.total.value;
+                }
+
+                return Ok(founded);
+            }
+        }
+
         let highlight = doc_info.highlight.map(|it| it.content).unwrap_or_default();
         let document = doc_info._source.try_into()?;
         FoundedDocumentBuilder::default()
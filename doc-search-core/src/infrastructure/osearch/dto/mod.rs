@@ -8,10 +8,15 @@ mod index;
 pub use index::IndexInformation;
 
 mod metadata;
-mod params;
+pub use metadata::SourceDocumentMetadata;
 
+mod params;
 pub use params::{FullTextQueryParams, FullTextQueryParamsBuilder};
 pub use params::{HybridQueryParams, HybridQueryParamsBuilder};
 pub use params::{RetrieveAllDocPartsQueryParams, RetrieveAllDocPartsQueryParamsBuilder};
 pub use params::{RetrieveIndexDocsQueryParams, RetrieveIndexDocsQueryParamsBuilder};
 pub use params::{SemanticQueryParams, SemanticQueryParamsBuilder};
+pub use params::{SimilarQueryParams, SimilarQueryParamsBuilder};
+
+mod splitter;
+pub use splitter::SplitterSettings;
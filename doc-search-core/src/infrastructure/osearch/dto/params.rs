@@ -1,7 +1,9 @@
 use derive_builder::Builder;
 use gset::Getset;
 
-use crate::domain::searcher::models::{FilterParams, ResultParams};
+use crate::domain::searcher::models::SimilarMode;
+use crate::domain::searcher::models::{FilterParams, HybridScoringParams, ResultParams};
+use crate::domain::searcher::query::QueryNode;
 
 #[derive(Debug, Builder, Getset)]
 pub struct RetrieveAllDocPartsQueryParams {
@@ -11,6 +13,9 @@ pub struct RetrieveAllDocPartsQueryParams {
     with_sorting: bool,
     #[getset(get_copy, vis = "pub")]
     only_first_part: bool,
+    #[builder(default)]
+    #[getset(get, vis = "pub")]
+    exclude_ids: Vec<String>,
 }
 
 #[derive(Debug, Builder, Getset)]
@@ -37,7 +42,7 @@ impl RetrieveIndexDocsQueryParams {
 #[derive(Debug, Builder, Getset)]
 pub struct FullTextQueryParams {
     #[getset(get, vis = "pub")]
-    query: Option<String>,
+    query: Option<QueryNode>,
     #[getset(get, vis = "pub")]
     result: ResultParams,
     #[getset(get, vis = "pub")]
@@ -98,6 +103,12 @@ pub struct HybridQueryParams {
     result: ResultParams,
     #[getset(get, vis = "pub")]
     filter: Option<FilterParams>,
+    #[builder(default)]
+    #[getset(get, vis = "pub")]
+    scoring: Option<HybridScoringParams>,
+    #[builder(default)]
+    #[getset(get_copy, vis = "pub")]
+    pagination_depth: Option<usize>,
 }
 
 impl HybridQueryParams {
@@ -110,3 +121,34 @@ impl HybridQueryParams {
         &["content"]
     }
 }
+
+#[derive(Debug, Builder, Getset)]
+pub struct SimilarQueryParams {
+    #[getset(get, vis = "pub")]
+    large_doc_id: String,
+    #[getset(get, vis = "pub")]
+    like: Vec<String>,
+    #[getset(get, vis = "pub")]
+    vector: Option<Vec<f64>>,
+    #[getset(get_copy, vis = "pub")]
+    mode: SimilarMode,
+    #[getset(get_copy, vis = "pub")]
+    knn_amount: u16,
+    #[getset(get_copy, vis = "pub")]
+    min_score: Option<f32>,
+    #[getset(get, vis = "pub")]
+    result: ResultParams,
+    #[getset(get, vis = "pub")]
+    filter: Option<FilterParams>,
+}
+
+impl SimilarQueryParams {
+    pub fn get_excluded_params(&self) -> &[&str] {
+        let include_extra_fields = self.result.include_extra_fields.unwrap_or_default();
+        if include_extra_fields {
+            return &["chunked_text", "embeddings"];
+        };
+
+        &["content", "chunked_text", "embeddings"]
+    }
+}
@@ -0,0 +1,47 @@
+use serde_derive::{Deserialize, Serialize};
+
+use crate::domain::storage::models::SplitterParams;
+
+/// Splitter params kept within `_meta` of the index mappings.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum SplitterSettings {
+    Character { chunk_size: Option<usize> },
+    Sentence { chunk_size: Option<usize> },
+    Markdown { chunk_size: Option<usize> },
+    Token { token_limit: u32, overlap_rate: f32 },
+}
+
+impl From<&SplitterParams> for SplitterSettings {
+    fn from(params: &SplitterParams) -> Self {
+        match params.clone() {
+            SplitterParams::Character { chunk_size } => SplitterSettings::Character { chunk_size },
+            SplitterParams::Sentence { chunk_size } => SplitterSettings::Sentence { chunk_size },
+            SplitterParams::Markdown { chunk_size } => SplitterSettings::Markdown { chunk_size },
+            SplitterParams::Token {
+                token_limit,
+                overlap_rate,
+            } => SplitterSettings::Token {
+                token_limit,
+                overlap_rate,
+            },
+        }
+    }
+}
+
+impl From<SplitterSettings> for SplitterParams {
+    fn from(settings: SplitterSettings) -> Self {
+        match settings {
+            SplitterSettings::Character { chunk_size } => SplitterParams::Character { chunk_size },
+            SplitterSettings::Sentence { chunk_size } => SplitterParams::Sentence { chunk_size },
+            SplitterSettings::Markdown { chunk_size } => SplitterParams::Markdown { chunk_size },
+            SplitterSettings::Token {
+                token_limit,
+                overlap_rate,
+            } => SplitterParams::Token {
+                token_limit,
+                overlap_rate,
+            },
+        }
+    }
+}
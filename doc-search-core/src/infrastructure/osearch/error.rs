@@ -24,6 +24,8 @@ pub enum OSearchError {
     DocumentNotFound(anyhow::Error),
     #[error("document already exists: {0}")]
     DocumentAlreadyExists(anyhow::Error),
+    #[error("cursor context has not been found: {0}")]
+    CursorNotFound(anyhow::Error),
     #[error("validation error: {0}")]
     ValidationError(anyhow::Error),
     #[error("failed to build query: {0}")]
@@ -43,6 +45,7 @@ impl From<OSearchError> for StorageError {
             OSearchError::IndexNotFound(err) => StorageError::IndexNotFound(err),
             OSearchError::DocumentNotFound(err) => StorageError::DocumentNotFound(err),
             OSearchError::DocumentAlreadyExists(err) => StorageError::DocumentAlreadyExists(err),
+            OSearchError::CursorNotFound(err) => StorageError::InternalError(err),
             OSearchError::ValidationError(err) => StorageError::ValidationError(err),
             OSearchError::BuildQueryError(err) => StorageError::InternalError(err),
             OSearchError::ExecutionError(err) => StorageError::InternalError(err),
@@ -57,8 +60,9 @@ impl From<OSearchError> for SearchError {
         match err {
             OSearchError::AuthenticationFailed(err) => SearchError::AuthenticationFailed(err),
             OSearchError::IndexNotFound(err) => SearchError::IndexNotFound(err),
-            OSearchError::DocumentNotFound(err) => SearchError::InternalError(err),
+            OSearchError::DocumentNotFound(err) => SearchError::DocumentNotFound(err),
             OSearchError::DocumentAlreadyExists(err) => SearchError::InternalError(err),
+            OSearchError::CursorNotFound(err) => SearchError::ValidationError(err),
             OSearchError::ValidationError(err) => SearchError::ValidationError(err),
             OSearchError::BuildQueryError(err) => SearchError::InternalError(err),
             OSearchError::ExecutionError(err) => SearchError::ServiceError(err),
@@ -2,12 +2,18 @@ use anyhow::Context;
 use serde::Deserialize;
 use serde_json::Value;
 
-use crate::domain::searcher::models::{FoundedDocument, Pagination, PaginationBuilder};
+use crate::domain::searcher::models::{
+    FacetBucket, Facets, FoundedDocument, Pagination, PaginationBuilder,
+};
+use crate::domain::searcher::models::{SuggestField, Suggestion, Suggestions};
+use crate::domain::searcher::models::{TotalHits, TotalHitsRelation};
 use crate::domain::searcher::{SearchError, SearchResult};
 use crate::domain::storage::StorageResult;
-use crate::domain::storage::models::{AllDocumentParts, DocumentPart};
-use crate::infrastructure::osearch::dto::FoundedDocumentInfo;
+use crate::domain::storage::models::{AllDocumentParts, DocumentPart, SplitterParams};
+use crate::infrastructure::osearch::dto::{FoundedDocumentInfo, SourceDocument, SplitterSettings};
 use crate::infrastructure::osearch::error::{OSearchError, OSearchResult};
+use crate::infrastructure::osearch::query::{NESTED_VALUES_AGG_NAME, REVERSE_NESTED_AGG_NAME};
+use crate::infrastructure::osearch::query::{SUGGEST_PHRASE_NAME, SUGGEST_TERM_NAME};
 
 pub fn extract_retrieved_document_parts(object: Value) -> StorageResult<AllDocumentParts> {
     let founded_hits = object[&"hits"][&"hits"].as_array();
@@ -25,13 +31,20 @@ pub fn extract_retrieved_document_parts(object: Value) -> StorageResult<AllDocum
 }
 
 pub fn extract_founded_document_parts(object: Value) -> SearchResult<Pagination> {
-    let scroll_id = object[&"_scroll_id"].as_str().map(String::from);
+    let facets = extract_facets(&object);
+    let total = extract_total_hits(&object);
+    let max_score = object[&"hits"][&"max_score"].as_f64();
+    let took = object[&"took"].as_u64();
     let founded_hits = object[&"hits"][&"hits"].as_array();
     let Some(hits) = founded_hits else {
         tracing::warn!("returned empty array of founded documents");
         let paginated_result = PaginationBuilder::default()
             .founded(Vec::default())
-            .scroll_id(scroll_id)
+            .cursor(None)
+            .facets(facets)
+            .total(total)
+            .max_score(max_score)
+            .took(took)
             .build()
             .context("failed to build pagination result")
             .map_err(SearchError::InternalError)?;
@@ -45,8 +58,12 @@ pub fn extract_founded_document_parts(object: Value) -> SearchResult<Pagination>
         .collect::<Vec<FoundedDocument>>();
 
     let documents = PaginationBuilder::default()
-        .scroll_id(scroll_id)
+        .cursor(None)
         .founded(documents)
+        .facets(facets)
+        .total(total)
+        .max_score(max_score)
+        .took(took)
         .build()
         .context("failed to build pagination result")
         .map_err(SearchError::InternalError)?;
@@ -54,6 +71,267 @@ pub fn extract_founded_document_parts(object: Value) -> SearchResult<Pagination>
     Ok(documents)
 }
 
+/// Source documents of hits are loaded with content and stored embeddings,
+/// so hits failed to deserialize are skipped.
+pub fn extract_source_documents(object: &Value) -> Vec<SourceDocument> {
+    let Some(hits) = object[&"hits"][&"hits"].as_array() else {
+        return Vec::default();
+    };
+
+    hits.iter()
+        .filter_map(|it| SourceDocument::deserialize(&it[&"_source"]).ok())
+        .collect()
+}
+
+/// Total hits are counted up to 10000 by default and reported with `gte`
+/// relation beyond it, unless `track_total_hits` is passed within query.
+pub fn extract_total_hits(object: &Value) -> Option<TotalHits> {
+    let total = &object[&"hits"][&"total"];
+    let value = total[&"value"].as_u64()?;
+    let relation = match total[&"relation"].as_str() {
+        Some("gte") => TotalHitsRelation::LowerBound,
+        _ => TotalHitsRelation::Exact,
+    };
+
+    Some(TotalHits { value, relation })
+}
+
+/// Extracts completions from highlights of hits matched by the typed prefix
+/// and corrections from phrase suggester, falling back to the query with
+/// words corrected by term suggester. Completion scores are relative to
+/// the best hit score.
+pub fn extract_suggestions(object: &Value, size: usize) -> Suggestions {
+    let max_score = object[&"hits"][&"max_score"]
+        .as_f64()
+        .filter(|it| *it > 0.0)
+        .unwrap_or(1.0);
+
+    let mut completions = Vec::new();
+    let hits = object[&"hits"][&"hits"].as_array();
+    for hit in hits.into_iter().flatten() {
+        let score = hit[&"_score"].as_f64().unwrap_or_default() / max_score;
+        let highlight = &hit[&"highlight"];
+        if highlight[&"file_name.suggest"].is_array()
+            && let Some(file_name) = hit[&"_source"][&"file_name"].as_str()
+        {
+            completions.push(Suggestion {
+                text: file_name.to_string(),
+                score,
+                field: SuggestField::FileName,
+            });
+        }
+
+        let fragments = highlight[&"content.suggest"].as_array();
+        let phrases = fragments
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+            .filter_map(extract_highlighted_phrase);
+
+        for text in phrases {
+            completions.push(Suggestion {
+                text,
+                score,
+                field: SuggestField::Content,
+            });
+        }
+    }
+
+    let suggest = &object[&"suggest"];
+    let mut corrections = suggest[&SUGGEST_PHRASE_NAME]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .flat_map(|it| it[&"options"].as_array().into_iter().flatten())
+        .filter_map(|option| {
+            Some(Suggestion {
+                text: option[&"text"].as_str()?.to_string(),
+                score: option[&"score"].as_f64()?,
+                field: SuggestField::Content,
+            })
+        })
+        .collect::<Vec<Suggestion>>();
+
+    if corrections.is_empty()
+        && let Some(correction) = extract_term_correction(&suggest[&SUGGEST_TERM_NAME])
+    {
+        corrections.push(correction);
+    }
+
+    Suggestions::new(completions, corrections, size)
+}
+
+/// Returns the first run of adjacent highlighted words of fragment.
+fn extract_highlighted_phrase(fragment: &str) -> Option<String> {
+    let mut words = Vec::new();
+    let mut rest = fragment;
+    while let Some(start) = rest.find("<em>") {
+        if !words.is_empty() && !rest[..start].trim().is_empty() {
+            break;
+        }
+
+        let word_with_rest = &rest[start + "<em>".len()..];
+        let end = word_with_rest.find("</em>")?;
+        words.push(word_with_rest[..end].to_lowercase());
+        rest = &word_with_rest[end + "</em>".len()..];
+    }
+
+    (!words.is_empty()).then(|| words.join(" "))
+}
+
+fn extract_term_correction(entries: &Value) -> Option<Suggestion> {
+    let mut words = Vec::new();
+    let mut scores = Vec::new();
+    for entry in entries.as_array()? {
+        let option = entry[&"options"].as_array().and_then(|it| it.first());
+        match option {
+            None => words.push(entry[&"text"].as_str()?.to_string()),
+            Some(option) => {
+                words.push(option[&"text"].as_str()?.to_string());
+                scores.push(option[&"score"].as_f64().unwrap_or_default());
+            }
+        }
+    }
+
+    if scores.is_empty() {
+        return None;
+    }
+
+    Some(Suggestion {
+        text: words.join(" "),
+        score: scores.iter().sum::<f64>() / scores.len() as f64,
+        field: SuggestField::Content,
+    })
+}
+
+/// Opensearch may refresh point in time id on every search request, so
+/// the returned one must be passed to the next page request.
+pub fn extract_pit_id(object: &Value) -> Option<String> {
+    object[&"pit_id"].as_str().map(String::from)
+}
+
+/// Sort values of the last hit are the `search_after` of the next page.
+pub fn extract_search_after(object: &Value) -> Option<Value> {
+    object[&"hits"][&"hits"]
+        .as_array()
+        .and_then(|hits| hits.last())
+        .map(|hit| hit[&"sort"].clone())
+        .filter(|sort| !sort.is_null())
+}
+
+pub fn extract_hits_amount(object: &Value) -> usize {
+    object[&"hits"][&"hits"].as_array().map_or(0, Vec::len)
+}
+
+/// Extracts splitter params from `_meta` of get mapping response. Response
+/// is keyed by concrete index name, so the first index is taken.
+pub fn extract_index_splitter(object: &Value) -> anyhow::Result<Option<SplitterParams>> {
+    let Some(mapping) = object.as_object().and_then(|it| it.values().next()) else {
+        return Ok(None);
+    };
+
+    let settings = &mapping[&"mappings"][&"_meta"][&"splitter"];
+    if settings.is_null() {
+        return Ok(None);
+    }
+
+    let settings = SplitterSettings::deserialize(settings).context("invalid splitter settings")?;
+    Ok(Some(settings.into()))
+}
+
+/// Bulk API responds with success status even if some items were rejected,
+/// so failures are collected from per-item results as `(id, reason)` pairs.
+pub fn extract_bulk_failures(object: &Value) -> Vec<(String, String)> {
+    if !object[&"errors"].as_bool().unwrap_or(false) {
+        return Vec::default();
+    }
+
+    let Some(items) = object[&"items"].as_array() else {
+        return Vec::default();
+    };
+
+    items
+        .iter()
+        .filter_map(|item| item.as_object()?.values().next())
+        .filter(|result| !result[&"error"].is_null())
+        .map(|result| {
+            let id = result[&"_id"].as_str().unwrap_or_default().to_string();
+            let error = &result[&"error"];
+            let reason = match (error[&"type"].as_str(), error[&"reason"].as_str()) {
+                (Some(kind), Some(reason)) => format!("{kind}: {reason}"),
+                _ => error.to_string(),
+            };
+            (id, reason)
+        })
+        .collect()
+}
+
+/// Ids of documents the bulk request has created, skipping overwritten ones,
+/// so rollback never deletes documents existed before the request.
+pub fn extract_bulk_created_ids(object: &Value) -> Vec<String> {
+    let Some(items) = object[&"items"].as_array() else {
+        return Vec::default();
+    };
+
+    items
+        .iter()
+        .filter_map(|item| item.as_object()?.values().next())
+        .filter(|result| result[&"result"].as_str() == Some("created"))
+        .filter_map(|result| result[&"_id"].as_str().map(String::from))
+        .collect()
+}
+
+pub fn has_bulk_conflicts(object: &Value) -> bool {
+    let Some(items) = object[&"items"].as_array() else {
+        return false;
+    };
+
+    items
+        .iter()
+        .filter_map(|item| item.as_object()?.values().next())
+        .any(|result| result[&"status"].as_u64() == Some(409))
+}
+
+fn extract_facets(object: &Value) -> Option<Facets> {
+    let aggregations = object[&"aggregations"].as_object()?;
+    let facets = aggregations
+        .iter()
+        .map(|(name, aggregation)| (name.to_owned(), extract_facet_buckets(aggregation)))
+        .collect::<Facets>();
+
+    Some(facets)
+}
+
+fn extract_facet_buckets(aggregation: &Value) -> Vec<FacetBucket> {
+    let buckets = aggregation[&"buckets"]
+        .as_array()
+        .or_else(|| aggregation[NESTED_VALUES_AGG_NAME][&"buckets"].as_array());
+
+    let Some(buckets) = buckets else {
+        tracing::warn!("returned aggregation without buckets");
+        return Vec::default();
+    };
+
+    buckets.iter().filter_map(extract_facet_bucket).collect()
+}
+
+fn extract_facet_bucket(bucket: &Value) -> Option<FacetBucket> {
+    let key = match bucket[&"key_as_string"].as_str() {
+        Some(key) => key.to_owned(),
+        None => match &bucket[&"key"] {
+            Value::String(key) => key.to_owned(),
+            Value::Null => return None,
+            key => key.to_string(),
+        },
+    };
+
+    let count = bucket[REVERSE_NESTED_AGG_NAME][&"doc_count"]
+        .as_u64()
+        .or_else(|| bucket[&"doc_count"].as_u64())?;
+
+    Some(FacetBucket { key, count })
+}
+
 fn extract_document<T>(value: &Value) -> OSearchResult<T>
 where
     T: TryFrom<FoundedDocumentInfo, Error = OSearchError>,
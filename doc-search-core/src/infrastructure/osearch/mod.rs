@@ -2,6 +2,7 @@
 mod tests;
 
 mod config;
+mod cursor;
 mod dto;
 mod error;
 mod extractor;
@@ -20,32 +21,59 @@ use opensearch::http::headers::HeaderMap;
 use opensearch::http::request::JsonBody;
 use opensearch::http::transport::{SingleNodeConnectionPool, TransportBuilder};
 use opensearch::http::{Method, Url};
-use opensearch::indices::{IndicesCreateParts, IndicesDeleteParts};
+use opensearch::indices::{IndicesCreateParts, IndicesDeleteParts, IndicesGetMappingParts};
 use opensearch::ingest::IngestPutPipelineParts;
-use opensearch::{DeleteByQueryParts, OpenSearch};
+use opensearch::{DeleteByQueryParts, OpenSearch, UpdateByQueryParts};
 use serde_derive::Deserialize;
 use serde_json::{Value, json};
 use std::sync::Arc;
+use std::time::Duration;
 use tracing::instrument;
 
 use crate::ServiceConnect;
+use crate::domain::searcher::models::SimilarSearchingParams;
 use crate::domain::searcher::models::{
-    Pagination, PaginationParams, SearchKindParams, SearchingParams,
+    HybridScoringParams, Pagination, PaginationParams, SearchKindParams, SearchingParams,
 };
+use crate::domain::searcher::models::{SuggestParams, Suggestions};
 use crate::domain::searcher::{IPaginator, ISearcher};
 use crate::domain::searcher::{SearchError, SearchResult};
+use crate::domain::storage::models::SplitterParams;
 use crate::domain::storage::models::{AllDocumentParts, DocumentPart};
-use crate::domain::storage::models::{CreateIndexParams, KnnIndexParams};
+use crate::domain::storage::models::{CreateIndexParams, KnnIndexParams, UpdateDocumentParams};
 use crate::domain::storage::models::{StoredDocumentPartsInfo, StoredDocumentPartsInfoBuilder};
 use crate::domain::storage::{IDocumentPartStorage, IIndexStorage};
 use crate::domain::storage::{StorageError, StorageResult};
 use crate::infrastructure::osearch::config::OSearchKnnConfig;
+use crate::infrastructure::osearch::cursor::{CursorCodec, CursorContext, QueryCache};
 use crate::infrastructure::osearch::dto::RetrieveAllDocPartsQueryParamsBuilder;
 use crate::infrastructure::osearch::dto::{FoundedDocumentInfo, IndexInformation, SourceDocument};
+use crate::infrastructure::osearch::query::build_suggest_query;
+use crate::infrastructure::osearch::query::build_update_doc_parts_script;
+use crate::infrastructure::osearch::query::{CURSOR_KEEP_ALIVE, build_cursor_page_query};
 use crate::infrastructure::osearch::query::{QueryBuildHelper, build_search_query};
+use crate::infrastructure::osearch::query::{
+    SIMILAR_MAX_SOURCE_PARTS, SIMILAR_SOURCE_PARTS_SIZE, build_similar_search_query,
+    build_similar_source_query,
+};
 use crate::shared::kernel::{DocumentPartId, IndexId, LargeDocumentId};
 
-const SCROLL_LIFETIME: &str = "5m";
+#[derive(Clone, Copy, PartialEq)]
+enum BulkOperation {
+    Index,
+    Create,
+}
+
+impl BulkOperation {
+    fn as_str(&self) -> &'static str {
+        match self {
+            BulkOperation::Index => "index",
+            BulkOperation::Create => "create",
+        }
+    }
+}
+
+const CURSOR_LIFETIME: Duration = Duration::from_secs(5 * 60);
 const EXECUTE_TIMEOUT: &str = "1m";
 const RESPONSE_FORMAT: &str = "json";
 
@@ -53,6 +81,8 @@ const RESPONSE_FORMAT: &str = "json";
 pub struct OSearchClient {
     config: OSearchConfig,
     client: Arc<OpenSearch>,
+    cursors: Arc<CursorCodec>,
+    queries: Arc<QueryCache>,
 }
 
 #[async_trait::async_trait]
@@ -77,9 +107,17 @@ impl ServiceConnect for OSearchClient {
         tracing::info!(address=%config.address(), "connected to opensearch");
         let client = OpenSearch::new(transport);
         let arc_client = Arc::new(client);
+        let cursor_secret = config.cursor_secret().clone().unwrap_or_else(|| {
+            tracing::warn!("cursor secret is not set, cursors are valid only within this replica");
+            let random = [uuid::Uuid::new_v4(), uuid::Uuid::new_v4()];
+            random.map(|it| it.simple().to_string()).concat()
+        });
+
         Ok(OSearchClient {
             config: config.clone(),
             client: arc_client,
+            cursors: Arc::new(CursorCodec::new(&cursor_secret)),
+            queries: Arc::new(QueryCache::default()),
         })
     }
 }
@@ -90,7 +128,8 @@ impl IIndexStorage for OSearchClient {
     async fn create_index(&self, params: &CreateIndexParams) -> StorageResult<IndexId> {
         let index_id = &params.id;
         let knn_params = params.knn.as_ref();
-        let folder_schema = schema::build_index_mappings(&self.config, knn_params);
+        let splitter_params = params.splitter.as_ref();
+        let folder_schema = schema::build_index_mappings(&self.config, knn_params, splitter_params);
 
         let response = self
             .client
@@ -181,6 +220,30 @@ impl IIndexStorage for OSearchClient {
 
         Ok(indexes)
     }
+
+    #[instrument(level = "info", skip(self))]
+    async fn get_index_splitter(
+        &self,
+        index_id: &IndexId,
+    ) -> StorageResult<Option<SplitterParams>> {
+        let response = self
+            .client
+            .indices()
+            .get_mapping(IndicesGetMappingParts::Index(&[index_id.as_string()]))
+            .send()
+            .await?;
+
+        if !response.status_code().is_success() {
+            let err = error::OSearchError::from_response(response).await;
+            return Err(StorageError::from(err));
+        }
+
+        let mappings = response.json::<Value>().await?;
+        let splitter =
+            extractor::extract_index_splitter(&mappings).map_err(StorageError::InternalError)?;
+
+        Ok(splitter)
+    }
 }
 
 #[async_trait::async_trait]
@@ -198,64 +261,27 @@ impl IDocumentPartStorage for OSearchClient {
         index_id: &IndexId,
         all_doc_parts: AllDocumentParts,
     ) -> StorageResult<StoredDocumentPartsInfo> {
-        let doc_parts_amount = all_doc_parts.len();
-        let large_doc_id = all_doc_parts
-            .first()
-            .as_ref()
-            .map(|it| it.large_doc_id.clone())
-            .ok_or(anyhow!("missing large document id to store"))
-            .map_err(StorageError::InternalError)?;
-
-        let mut stored_doc_ids = Vec::with_capacity(doc_parts_amount);
-        let mut operations: Vec<JsonBody<Value>> = Vec::with_capacity(doc_parts_amount * 2);
-
-        for doc in all_doc_parts.into_iter() {
-            #[cfg(not(feature = "enable-unique-doc-id"))]
-            let id = uuid::Uuid::new_v4().to_string();
-            #[cfg(feature = "enable-unique-doc-id")]
-            let id = gen_unique_document_id(index_id, &doc.large_doc_id, doc.doc_part_id);
-
-            let doc_header = json!({"index": {"_id": id}}).into();
-            operations.push(doc_header);
-
-            let srd_doc: SourceDocument = doc.try_into()?;
-            let doc_body = serde_json::to_value(srd_doc)
-                .context("failed to serialize document to json")
-                .map_err(StorageError::ValidationError)?
-                .into();
-
-            stored_doc_ids.push(id);
-            operations.push(doc_body);
-        }
-
-        let response = self
-            .client
-            .bulk(opensearch::BulkParts::Index(index_id.as_string()))
-            .pipeline(schema::INGEST_PIPELINE_NAME)
-            .body(operations)
-            .send()
-            .await?;
-
-        if !response.status_code().is_success() {
-            let err = error::OSearchError::from_response(response).await;
-            return Err(StorageError::from(err));
-        }
-
-        let first_doc_id = stored_doc_ids
-            .first()
-            .map(|it| DocumentPartId(it.clone()))
-            .ok_or(anyhow!("there is no any stored id's"))
-            .map_err(StorageError::InternalError)?;
-
-        let stored_doc_info = StoredDocumentPartsInfoBuilder::default()
-            .large_doc_id(large_doc_id)
-            .first_part_id(first_doc_id.clone())
-            .doc_parts_amount(doc_parts_amount)
-            .build()
-            .context("failed to build stored document info")
-            .map_err(StorageError::InternalError)?;
+        self.bulk_document_parts(index_id, all_doc_parts, BulkOperation::Index)
+            .await
+    }
 
-        Ok(stored_doc_info)
+    #[instrument(
+        level = "info",
+        skip_all,
+        fields(
+            index_id = index_id.0,
+            document_parts_amount = all_doc_parts.len(),
+        ),
+    )]
+    async fn create_document_parts(
+        &self,
+        index_id: &IndexId,
+        all_doc_parts: AllDocumentParts,
+    ) -> StorageResult<StoredDocumentPartsInfo> {
+        // Part ids are derived from the large document id, so bulk `create`
+        // itself rejects the document stored before or concurrently.
+        self.bulk_document_parts(index_id, all_doc_parts, BulkOperation::Create)
+            .await
     }
 
     #[instrument(level = "info", skip(self))]
@@ -274,7 +300,7 @@ impl IDocumentPartStorage for OSearchClient {
 
         let query = query_params.build_query();
         let indexes = index.as_string().split(',').collect::<Vec<&str>>();
-        let search_parts = Self::build_search_parts(&indexes);
+        let search_parts = Self::build_search_parts(&indexes, None);
 
         let request = self.client.search(search_parts).pretty(true);
 
@@ -320,6 +346,53 @@ impl IDocumentPartStorage for OSearchClient {
         Ok(document)
     }
 
+    #[instrument(level = "info", skip(self))]
+    async fn update_document_parts(
+        &self,
+        index: &IndexId,
+        large_doc_id: &LargeDocumentId,
+        params: &UpdateDocumentParams,
+    ) -> StorageResult<()> {
+        let query_params = RetrieveAllDocPartsQueryParamsBuilder::default()
+            .large_doc_id(large_doc_id.to_string())
+            .only_first_part(false)
+            .with_sorting(false)
+            .build()
+            .context("failed to build query params")
+            .map_err(StorageError::InternalError)?;
+
+        let mut query = query_params.build_query();
+        query["script"] = build_update_doc_parts_script(params)?;
+
+        // Skipping the default ingest pipeline keeps stored chunks and
+        // embeddings as they are, only document fields are rewritten.
+        let indexes = index.as_string().split(',').collect::<Vec<&str>>();
+        let response = self
+            .client
+            .update_by_query(UpdateByQueryParts::Index(&indexes))
+            .pipeline(schema::NONE_PIPELINE_NAME)
+            .refresh(true)
+            .body(query)
+            .send()
+            .await?;
+
+        if !response.status_code().is_success() {
+            let err = error::OSearchError::from_response(response).await;
+            return Err(StorageError::from(err));
+        }
+
+        let updated_parts = response.json::<Value>().await?["updated"]
+            .as_u64()
+            .unwrap_or_default();
+
+        if updated_parts == 0 {
+            let err = anyhow!("document [{large_doc_id:?}] not found in index: [{index:?}]");
+            return Err(StorageError::DocumentNotFound(err));
+        }
+
+        Ok(())
+    }
+
     #[instrument(level = "info", skip(self))]
     async fn delete_document_parts(
         &self,
@@ -362,25 +435,210 @@ impl ISearcher for OSearchClient {
             .map(String::as_str)
             .collect::<Vec<&str>>();
 
-        let search_parts = Self::build_search_parts(&indexes);
-        let query = build_search_query(params, self.config.semantic())?;
+        let allowed_indexes = params
+            .get_allowed_indexes()
+            .map(|it| it.iter().map(String::as_str).collect::<Vec<&str>>());
+
+        if params.is_wildcard() && allowed_indexes.as_ref().is_some_and(Vec::is_empty) {
+            tracing::debug!("there are no allowed indexes to search");
+            return Ok(Pagination::new(None, Vec::default()));
+        }
+
+        let query = self.build_query(params).await?;
         let query_str = serde_json::to_string_pretty(&query);
         tracing::debug!(query=?query_str, "search query");
-        let request = self
+
+        let result = params.get_result();
+        let size = result.size.max(0) as usize;
+
+        // Empty page never exhausts results, so it is returned without cursor
+        // like any page requested by offset.
+        if result.offset > 0 || size == 0 {
+            let offset = result.offset as usize;
+            let page_query = build_cursor_page_query(&query, None, None, offset, size);
+            let search_parts = Self::build_search_parts(&indexes, allowed_indexes.as_deref());
+            let response_data = self.send_search_query(search_parts, &page_query).await?;
+            return extractor::extract_founded_document_parts(response_data);
+        }
+
+        let search_indexes = Self::resolve_search_indexes(&indexes, allowed_indexes.as_deref());
+
+        // Point in time does not support field collapsing and hybrid query,
+        // so grouped and hybrid results are paginated by offset.
+        let is_offset_paginated = result.is_grouped_by_document()
+            || matches!(params.get_kind(), SearchKindParams::Hybrid(_));
+
+        let pit_id = match is_offset_paginated {
+            true => None,
+            false => Some(self.open_point_in_time(search_indexes).await?),
+        };
+
+        let mut cursor_context = CursorContext::new(params.clone(), pit_id, size);
+        if params.has_tokens() || matches!(params.get_kind(), SearchKindParams::Similar(_)) {
+            let query_id = self.queries.insert(query.clone(), CURSOR_LIFETIME);
+            cursor_context.query_id = Some(query_id);
+        }
+
+        self.next_cursor_page(&query, cursor_context).await
+    }
+
+    #[instrument(level = "info", skip(self))]
+    async fn suggest(&self, params: &SuggestParams) -> SearchResult<Suggestions> {
+        let indexes = params
+            .indexes
+            .iter()
+            .map(String::as_str)
+            .collect::<Vec<&str>>();
+
+        let allowed_indexes = params
+            .allowed_indexes
+            .as_ref()
+            .map(|it| it.iter().map(String::as_str).collect::<Vec<&str>>());
+
+        if params.is_wildcard() && allowed_indexes.as_ref().is_some_and(Vec::is_empty) {
+            tracing::debug!("there are no allowed indexes to suggest from");
+            return Ok(Suggestions::default());
+        }
+
+        let query = build_suggest_query(params);
+        let search_parts = Self::build_search_parts(&indexes, allowed_indexes.as_deref());
+        let response_data = self.send_search_query(search_parts, &query).await?;
+        Ok(extractor::extract_suggestions(&response_data, params.size))
+    }
+}
+
+#[async_trait::async_trait]
+impl IPaginator for OSearchClient {
+    #[instrument(level = "info", skip(self))]
+    async fn paginate(&self, params: &PaginationParams) -> SearchResult<Pagination> {
+        let mut cursor_context = self.cursors.decode(&params.cursor)?;
+        let query = self.load_cursor_query(&mut cursor_context).await?;
+
+        self.next_cursor_page(&query, cursor_context).await
+    }
+
+    #[instrument(level = "info", skip(self))]
+    async fn release(&self, params: &PaginationParams) -> SearchResult<()> {
+        let cursor_context = self.cursors.decode(&params.cursor)?;
+        if let Some(query_id) = cursor_context.query_id.as_deref() {
+            self.queries.remove(query_id);
+        }
+
+        if let Some(pit_id) = cursor_context.pit_id.as_deref() {
+            self.delete_point_in_time(pit_id).await?;
+        }
+
+        Ok(())
+    }
+}
+
+impl OSearchClient {
+    async fn build_query(&self, params: &SearchingParams) -> SearchResult<Value> {
+        let query = match params.get_kind() {
+            SearchKindParams::Similar(similar) => {
+                let source = self.load_similar_source(similar).await?;
+                build_similar_search_query(params, &source)?
+            }
+            _ => build_search_query(params, self.config.semantic())?,
+        };
+
+        Ok(query)
+    }
+
+    /// Query of cursor is rebuilt by its params, except one kept by replica
+    /// created the cursor. Resolved similar query is rebuilt by replica which
+    /// does not keep it, while query built by embedding tokens can not be
+    /// rebuilt, so such cursor is continued only by replica created it.
+    async fn load_cursor_query(&self, cursor_context: &mut CursorContext) -> SearchResult<Value> {
+        let Some(query_id) = cursor_context.query_id.as_deref() else {
+            return self.build_query(&cursor_context.params).await;
+        };
+
+        if let Some(query) = self.queries.get(query_id, CURSOR_LIFETIME) {
+            return Ok(query);
+        }
+
+        let SearchKindParams::Similar(_) = cursor_context.params.get_kind() else {
+            let err = anyhow!("query {query_id} of cursor does not exist or expired");
+            return Err(error::OSearchError::CursorNotFound(err).into());
+        };
+
+        let query = self.build_query(&cursor_context.params).await?;
+        let query_id = self.queries.insert(query.clone(), CURSOR_LIFETIME);
+        cursor_context.query_id = Some(query_id);
+        Ok(query)
+    }
+
+    /// Loads the next page of cursor context. Every full page is returned
+    /// with a new cursor pointing after it, the last one releases context.
+    async fn next_cursor_page(
+        &self,
+        query: &Value,
+        mut cursor_context: CursorContext,
+    ) -> SearchResult<Pagination> {
+        let page_query = build_cursor_page_query(
+            query,
+            cursor_context.pit_id.as_deref(),
+            cursor_context.search_after.as_ref(),
+            cursor_context.offset,
+            cursor_context.size,
+        );
+
+        let params = &cursor_context.params;
+        let indexes = params
+            .get_indexes()
+            .iter()
+            .map(String::as_str)
+            .collect::<Vec<&str>>();
+
+        let allowed_indexes = params
+            .get_allowed_indexes()
+            .map(|it| it.iter().map(String::as_str).collect::<Vec<&str>>());
+
+        // Point in time is bound to indexes, so request must not contain them.
+        let search_parts = match cursor_context.pit_id {
+            Some(_) => opensearch::SearchParts::None,
+            None => Self::build_search_parts(&indexes, allowed_indexes.as_deref()),
+        };
+
+        let response_data = self.send_search_query(search_parts, &page_query).await?;
+        let hits_amount = extractor::extract_hits_amount(&response_data);
+        if let Some(pit_id) = extractor::extract_pit_id(&response_data) {
+            cursor_context.pit_id = Some(pit_id);
+        }
+
+        cursor_context.search_after = extractor::extract_search_after(&response_data);
+        cursor_context.offset += hits_amount;
+
+        let mut paginated = extractor::extract_founded_document_parts(response_data)?;
+        if cursor_context.size == 0 || hits_amount < cursor_context.size {
+            if let Some(query_id) = cursor_context.query_id.as_deref() {
+                self.queries.remove(query_id);
+            }
+
+            if let Some(pit_id) = cursor_context.pit_id.as_deref()
+                && let Err(err) = self.delete_point_in_time(pit_id).await
+            {
+                tracing::warn!(err=?err, "failed to release exhausted cursor");
+            }
+
+            return Ok(paginated);
+        }
+
+        cursor_context.prolong(CURSOR_LIFETIME);
+        paginated.cursor = Some(self.cursors.encode(&cursor_context)?);
+        Ok(paginated)
+    }
+
+    async fn send_search_query(
+        &self,
+        search_parts: opensearch::SearchParts<'_>,
+        query: &Value,
+    ) -> SearchResult<Value> {
+        let response = self
             .client
             .search(search_parts)
             .pretty(true)
-            .size(params.get_result().size);
-
-        let request_builder = match params.get_result().offset > 0 {
-            true => request.from(params.get_result().offset),
-            false => match params.get_kind() {
-                SearchKindParams::Hybrid(_) => request.from(params.get_result().offset),
-                _ => request.scroll(SCROLL_LIFETIME),
-            },
-        };
-
-        let response = request_builder
             .body(query)
             .send()
             .await
@@ -392,43 +650,88 @@ impl ISearcher for OSearchClient {
             return Err(SearchError::InternalError(anyhow!(err)));
         }
 
-        let response_data = response
+        response
             .json::<Value>()
             .await
             .context("failed to parse search result")
-            .map_err(SearchError::InternalError)?;
+            .map_err(SearchError::InternalError)
+    }
 
-        let paginated = extractor::extract_founded_document_parts(response_data)?;
-        Ok(paginated)
+    async fn load_similar_source(
+        &self,
+        params: &SimilarSearchingParams,
+    ) -> SearchResult<Vec<SourceDocument>> {
+        let indexes = [params.source_index.as_str()];
+        let mut source = Vec::new();
+        loop {
+            let search_after = source.last().map(|it: &SourceDocument| it.doc_part_id);
+            let query = build_similar_source_query(&params.source, search_after);
+            let search_parts = opensearch::SearchParts::Index(&indexes);
+            let response_data = self.send_search_query(search_parts, &query).await?;
+            let hits_amount = extractor::extract_hits_amount(&response_data);
+            source.extend(extractor::extract_source_documents(&response_data));
+            if hits_amount < SIMILAR_SOURCE_PARTS_SIZE || source.len() >= SIMILAR_MAX_SOURCE_PARTS {
+                source.truncate(SIMILAR_MAX_SOURCE_PARTS);
+                return Ok(source);
+            }
+        }
     }
-}
 
-#[async_trait::async_trait]
-impl IPaginator for OSearchClient {
-    #[instrument(level = "info", skip(self))]
-    async fn paginate(&self, params: &PaginationParams) -> SearchResult<Pagination> {
+    async fn open_point_in_time(&self, indexes: &[&str]) -> SearchResult<String> {
+        let url = format!("/{}/_search/point_in_time", indexes.join(","));
         let response = self
             .client
-            .scroll(opensearch::ScrollParts::ScrollId(&params.scroll_id))
-            .pretty(true)
-            .send()
+            .transport()
+            .send(
+                Method::Post,
+                &url,
+                HeaderMap::new(),
+                Some(&[("keep_alive", CURSOR_KEEP_ALIVE)]),
+                Option::<()>::None,
+                None,
+            )
             .await
-            .context("pagination failed")
+            .context("failed to open point in time")
             .map_err(SearchError::InternalError)?;
 
         if !response.status_code().is_success() {
             let err = error::OSearchError::from_response(response).await;
-            return Err(SearchError::InternalError(anyhow!(err)));
-        };
+            return Err(SearchError::from(err));
+        }
 
         let response_data = response
             .json::<Value>()
             .await
-            .context("pagination error response")
+            .context("failed to parse point in time response")
             .map_err(SearchError::InternalError)?;
 
-        let paginated = extractor::extract_founded_document_parts(response_data)?;
-        Ok(paginated)
+        extractor::extract_pit_id(&response_data)
+            .ok_or_else(|| SearchError::InternalError(anyhow!("point in time id is missing")))
+    }
+
+    async fn delete_point_in_time(&self, pit_id: &str) -> SearchResult<()> {
+        let body = JsonBody::new(json!({"pit_id": [pit_id]}));
+        let response = self
+            .client
+            .transport()
+            .send(
+                Method::Delete,
+                "/_search/point_in_time",
+                HeaderMap::new(),
+                Option::<&Value>::None,
+                Some(body),
+                None,
+            )
+            .await
+            .context("failed to delete point in time")
+            .map_err(SearchError::InternalError)?;
+
+        if !response.status_code().is_success() {
+            let err = error::OSearchError::from_response(response).await;
+            return Err(SearchError::from(err));
+        }
+
+        Ok(())
     }
 }
 
@@ -473,7 +776,9 @@ impl OSearchClient {
         }
 
         let url = format!("/_search/pipeline/{}", schema::HYBRID_SEARCH_PIPELINE_NAME);
-        let hs_schema = schema::build_hybrid_search_schema(self.config.semantic());
+        let model_id = self.config.semantic().model_id();
+        let scoring = HybridScoringParams::default();
+        let hs_schema = schema::build_hybrid_search_schema(model_id, &scoring);
         let schema_bytes = serde_json::to_vec(&hs_schema)
             .context("response deserialization error")
             .map_err(StorageError::InternalError)?;
@@ -577,11 +882,205 @@ impl OSearchClient {
         Ok(())
     }
 
-    fn build_search_parts<'a>(indexes: &'a [&'a str]) -> opensearch::SearchParts<'a> {
-        match indexes.first() {
-            Some(&"*") => opensearch::SearchParts::None,
-            _ => opensearch::SearchParts::Index(indexes),
+    async fn bulk_document_parts(
+        &self,
+        index_id: &IndexId,
+        all_doc_parts: AllDocumentParts,
+        operation: BulkOperation,
+    ) -> StorageResult<StoredDocumentPartsInfo> {
+        let doc_parts_amount = all_doc_parts.len();
+        let large_doc_id = all_doc_parts
+            .first()
+            .as_ref()
+            .map(|it| it.large_doc_id.clone())
+            .ok_or(anyhow!("missing large document id to store"))
+            .map_err(StorageError::InternalError)?;
+
+        // Parts embedded by an external service already carry chunks and
+        // embeddings, so they must not pass through the ingest pipeline.
+        let pipeline = match all_doc_parts.iter().all(|it| it.embeddings.is_some()) {
+            true => schema::NONE_PIPELINE_NAME,
+            false => schema::INGEST_PIPELINE_NAME,
+        };
+
+        let mut stored_doc_ids = Vec::with_capacity(doc_parts_amount);
+        let mut operations: Vec<JsonBody<Value>> = Vec::with_capacity(doc_parts_amount * 2);
+
+        for doc in all_doc_parts.into_iter() {
+            let id = Self::gen_document_part_id(index_id, &doc);
+            let doc_header = json!({(operation.as_str()): {"_id": id}}).into();
+            operations.push(doc_header);
+
+            let srd_doc: SourceDocument = doc.try_into()?;
+            let doc_body = serde_json::to_value(srd_doc)
+                .context("failed to serialize document to json")
+                .map_err(StorageError::ValidationError)?
+                .into();
+
+            stored_doc_ids.push(id);
+            operations.push(doc_body);
+        }
+
+        let response = self
+            .client
+            .bulk(opensearch::BulkParts::Index(index_id.as_string()))
+            .pipeline(pipeline)
+            .body(operations)
+            .send()
+            .await?;
+
+        if !response.status_code().is_success() {
+            let err = error::OSearchError::from_response(response).await;
+            return Err(StorageError::from(err));
+        }
+
+        let response_data = response.json::<Value>().await?;
+        let failures = extractor::extract_bulk_failures(&response_data);
+        if !failures.is_empty() {
+            let created_ids = extractor::extract_bulk_created_ids(&response_data);
+            let created_ids = created_ids.iter().collect::<Vec<&String>>();
+            self.rollback_document_parts(index_id, &created_ids).await;
+
+            if extractor::has_bulk_conflicts(&response_data) {
+                let index_id = index_id.as_string();
+                let msg = format!("document {large_doc_id} already exists in index {index_id}");
+                return Err(StorageError::DocumentAlreadyExists(anyhow!(msg)));
+            }
+
+            let failed_amount = failures.len();
+            let reasons = failures
+                .iter()
+                .map(|(id, reason)| format!("{id}: {reason}"))
+                .collect::<Vec<String>>()
+                .join("; ");
+
+            let msg =
+                format!("failed to store {failed_amount}/{doc_parts_amount} parts: {reasons}");
+            return Err(StorageError::InternalError(anyhow!(msg)));
+        }
+
+        // Parts left over from a previous version of the same document are
+        // dropped only after the new ones were indexed, so a failed bulk
+        // request never leaves the document without any parts.
+        if operation == BulkOperation::Index {
+            self.delete_stale_document_parts(index_id, &large_doc_id, &stored_doc_ids)
+                .await?;
+        }
+
+        let first_doc_id = stored_doc_ids
+            .first()
+            .map(|it| DocumentPartId(it.clone()))
+            .ok_or(anyhow!("there is no any stored id's"))
+            .map_err(StorageError::InternalError)?;
+
+        let stored_doc_info = StoredDocumentPartsInfoBuilder::default()
+            .large_doc_id(large_doc_id)
+            .first_part_id(first_doc_id.clone())
+            .doc_parts_amount(doc_parts_amount)
+            .build()
+            .context("failed to build stored document info")
+            .map_err(StorageError::InternalError)?;
+
+        Ok(stored_doc_info)
+    }
+
+    async fn delete_stale_document_parts(
+        &self,
+        index_id: &IndexId,
+        large_doc_id: &LargeDocumentId,
+        stored_doc_ids: &[String],
+    ) -> StorageResult<()> {
+        let stale_parts_params = RetrieveAllDocPartsQueryParamsBuilder::default()
+            .large_doc_id(large_doc_id.to_string())
+            .only_first_part(false)
+            .with_sorting(false)
+            .exclude_ids(stored_doc_ids.to_vec())
+            .build()
+            .context("failed to build query params")
+            .map_err(StorageError::InternalError)?;
+
+        let response = self
+            .client
+            .delete_by_query(DeleteByQueryParts::Index(&[index_id.as_string()]))
+            .body(stale_parts_params.build_query())
+            .send()
+            .await?;
+
+        if !response.status_code().is_success() {
+            let err = error::OSearchError::from_response(response).await;
+            return Err(StorageError::from(err));
+        }
+
+        Ok(())
+    }
+
+    /// Drops parts created by partially failed bulk request, so no part of
+    /// the new version is left in place of missing parts of the previous one.
+    /// Failures are only logged because the bulk error is already returned
+    /// to caller.
+    async fn rollback_document_parts(&self, index_id: &IndexId, doc_part_ids: &[&String]) {
+        if doc_part_ids.is_empty() {
+            return;
         }
+
+        let operations = doc_part_ids
+            .iter()
+            .map(|id| json!({"delete": {"_id": id}}).into())
+            .collect::<Vec<JsonBody<Value>>>();
+
+        let result = self
+            .client
+            .bulk(opensearch::BulkParts::Index(index_id.as_string()))
+            .body(operations)
+            .send()
+            .await;
+
+        match result {
+            Ok(response) if response.status_code().is_success() => {}
+            Ok(response) => {
+                let err = error::OSearchError::from_response(response).await;
+                tracing::warn!(err=?err, "failed to rollback stored document parts");
+            }
+            Err(err) => {
+                tracing::warn!(err=?err, "failed to rollback stored document parts");
+            }
+        }
+    }
+
+    fn build_search_parts<'a>(
+        indexes: &'a [&'a str],
+        allowed_indexes: Option<&'a [&'a str]>,
+    ) -> opensearch::SearchParts<'a> {
+        match (indexes.first(), allowed_indexes) {
+            (Some(&"*"), None) => opensearch::SearchParts::None,
+            _ => opensearch::SearchParts::Index(Self::resolve_search_indexes(
+                indexes,
+                allowed_indexes,
+            )),
+        }
+    }
+
+    fn resolve_search_indexes<'a>(
+        indexes: &'a [&'a str],
+        allowed_indexes: Option<&'a [&'a str]>,
+    ) -> &'a [&'a str] {
+        match (indexes.first(), allowed_indexes) {
+            (Some(&"*"), Some(patterns)) => patterns,
+            _ => indexes,
+        }
+    }
+
+    /// Part ids are the same for every version of the large document, so
+    /// replacing overwrites its parts and creating conflicts with them.
+    #[cfg(not(feature = "enable-unique-doc-id"))]
+    fn gen_document_part_id(index_id: &IndexId, doc: &DocumentPart) -> String {
+        let name = format!("{}/{}/{}", index_id.0, doc.large_doc_id, doc.doc_part_id);
+        uuid::Uuid::new_v5(&uuid::Uuid::NAMESPACE_OID, name.as_bytes()).to_string()
+    }
+
+    #[cfg(feature = "enable-unique-doc-id")]
+    fn gen_document_part_id(index_id: &IndexId, doc: &DocumentPart) -> String {
+        gen_unique_document_id(index_id, &doc.large_doc_id, doc.doc_part_id)
     }
 
     #[cfg(feature = "enable-unique-doc-id")]
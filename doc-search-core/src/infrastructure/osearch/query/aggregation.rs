@@ -0,0 +1,89 @@
+use serde_json::{Map, Value, json};
+
+use crate::domain::searcher::models::{AggregationParams, FacetKind};
+
+pub const NESTED_VALUES_AGG_NAME: &str = "values";
+pub const REVERSE_NESTED_AGG_NAME: &str = "documents";
+
+const FILE_EXTENSION_SCRIPT: &str = "\
+    def names = doc['file_name.keyword']; \
+    if (names.size() == 0) { return null; } \
+    def name = names.value; \
+    int index = name.lastIndexOf('.'); \
+    return index < 0 ? '' : name.substring(index + 1).toLowerCase();";
+
+pub fn build_aggregations_query(params: &AggregationParams) -> Value {
+    let aggregations = params
+        .facets
+        .iter()
+        .map(|kind| (kind.to_string(), build_facet_query(kind, params)))
+        .collect::<Map<String, Value>>();
+
+    Value::Object(aggregations)
+}
+
+fn build_facet_query(kind: &FacetKind, params: &AggregationParams) -> Value {
+    let size = params.size;
+    match kind {
+        FacetKind::Source => build_terms_query("metadata.source", size),
+        FacetKind::SemanticSource => build_terms_query("metadata.semantic_source", size),
+        FacetKind::Classes => {
+            build_nested_terms_query("metadata.classes", "metadata.classes.name", size)
+        }
+        FacetKind::Subjects => {
+            build_nested_terms_query("metadata.subjects", "metadata.subjects.name.keyword", size)
+        }
+        FacetKind::Groups => {
+            build_nested_terms_query("metadata.groups", "metadata.groups.name.keyword", size)
+        }
+        FacetKind::FileExtension => json!({
+            "terms": {
+                "size": size,
+                "script": {
+                    "lang": "painless",
+                    "source": FILE_EXTENSION_SCRIPT,
+                }
+            }
+        }),
+        FacetKind::CreatedAt => json!({
+            "date_histogram": {
+                "field": "created_at",
+                "calendar_interval": params.interval.to_string(),
+                "format": "epoch_second",
+                "min_doc_count": 1,
+            }
+        }),
+    }
+}
+
+fn build_terms_query(field: &str, size: u32) -> Value {
+    json!({
+        "terms": {
+            "field": field,
+            "size": size,
+        }
+    })
+}
+
+// Terms over nested objects count the nested objects themselves, so each
+// bucket joins back to the root document to report document counts.
+fn build_nested_terms_query(path: &str, field: &str, size: u32) -> Value {
+    json!({
+        "nested": {
+            "path": path,
+        },
+        "aggs": {
+            NESTED_VALUES_AGG_NAME: {
+                "terms": {
+                    "field": field,
+                    "size": size,
+                },
+                "aggs": {
+                    REVERSE_NESTED_AGG_NAME: {
+                        "reverse_nested": {}
+                    }
+                }
+            }
+        }
+    })
+}
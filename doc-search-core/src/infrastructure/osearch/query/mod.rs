@@ -1,23 +1,292 @@
-use anyhow::Context;
+mod aggregation;
+mod syntax;
+
+pub use aggregation::{NESTED_VALUES_AGG_NAME, REVERSE_NESTED_AGG_NAME};
+
+use aggregation::build_aggregations_query;
+use anyhow::{Context, anyhow};
 use serde_json::{Value, json};
+use syntax::build_syntax_query;
 
-use super::schema::HYBRID_SEARCH_PIPELINE_NAME;
+use super::schema::{HYBRID_SEARCH_PIPELINE_NAME, build_hybrid_search_schema};
+use crate::domain::searcher::SearchError;
+use crate::domain::searcher::models::SuggestParams;
 use crate::domain::searcher::models::{
-    FilterParams, ResultOrder, ResultParams, SearchKindParams, SearchingParams,
+    FilterParams, ResultOrder, ResultParams, SearchKindParams, SearchingParams, SortParams,
 };
+use crate::domain::searcher::models::{SimilarMode, SimilarSource};
+use crate::domain::searcher::query::{QueryNode, parse_query};
+use crate::domain::storage::models::UpdateDocumentParams;
 use crate::infrastructure::osearch::config::OSearchKnnConfig;
 use crate::infrastructure::osearch::dto::{
     FullTextQueryParams, FullTextQueryParamsBuilder, HybridQueryParams, HybridQueryParamsBuilder,
     RetrieveAllDocPartsQueryParams, RetrieveIndexDocsQueryParams,
     RetrieveIndexDocsQueryParamsBuilder, SemanticQueryParams, SemanticQueryParamsBuilder,
+    SimilarQueryParams, SimilarQueryParamsBuilder,
 };
+use crate::infrastructure::osearch::dto::{SourceDocument, SourceDocumentMetadata};
 use crate::infrastructure::osearch::error::{OSearchError, OSearchResult};
+use crate::shared::kernel::vector::mean_vector;
 
 const HYBRID_PAGINATION_DEPTH: usize = 20;
+pub const CURSOR_KEEP_ALIVE: &str = "5m";
+const SHARD_DOC_FIELD: &str = "_shard_doc";
+
+pub const SUGGEST_PHRASE_NAME: &str = "phrase_correction";
+pub const SUGGEST_TERM_NAME: &str = "term_correction";
+const SUGGEST_HITS_FACTOR: usize = 4;
+const SUGGEST_FRAGMENT_SIZE: usize = 60;
+const SUGGEST_MAX_ERRORS: usize = 2;
+
+const COLLAPSED_PARTS_NAME: &str = "matched_parts";
+const COLLAPSED_PARTS_SIZE: usize = 100;
+
+pub const SIMILAR_SOURCE_PARTS_SIZE: usize = 100;
+pub const SIMILAR_MAX_SOURCE_PARTS: usize = 1000;
+pub const SIMILAR_MAX_LIKE_LENGTH: usize = 10_000;
+const SIMILAR_MAX_QUERY_TERMS: usize = 25;
 
 pub fn build_search_query(
     params: &SearchingParams,
     config: &OSearchKnnConfig,
+) -> OSearchResult<Value> {
+    let query = build_search_kind_query(params, config)?;
+    Ok(complete_search_query(params, query))
+}
+
+/// Builds the query loading parts of the source document of similar
+/// documents searching with their content and stored embeddings. Parts are
+/// loaded by pages of `SIMILAR_SOURCE_PARTS_SIZE`, the next page continues
+/// after `doc_part_id` of the previous page last part.
+pub fn build_similar_source_query(source: &SimilarSource, search_after: Option<usize>) -> Value {
+    let query = match source {
+        SimilarSource::LargeDocument(id) => json!({"term": {"large_doc_id": id.0}}),
+        SimilarSource::DocumentPart(id) => json!({"ids": {"values": [id.0]}}),
+    };
+
+    let mut query = json!({
+        "size": SIMILAR_SOURCE_PARTS_SIZE,
+        "_source": {
+            "exclude": ["chunked_text"],
+        },
+        "query": query,
+        "sort": [{"doc_part_id": {"order": "asc"}}],
+    });
+
+    if let Some(doc_part_id) = search_after {
+        query["search_after"] = json!([doc_part_id]);
+    }
+
+    query
+}
+
+/// Builds similar documents searching query over parts of the source
+/// document: lexical mode passes their content to `more_like_this` query,
+/// vector mode searches nearest neighbours of their mean embedding. Content
+/// passed to `more_like_this` is cut by `SIMILAR_MAX_LIKE_LENGTH` chars.
+pub fn build_similar_search_query(
+    params: &SearchingParams,
+    source: &[SourceDocument],
+) -> OSearchResult<Value> {
+    let SearchKindParams::Similar(similar) = params.get_kind() else {
+        let err = anyhow!("searching params are not of similar kind");
+        return Err(OSearchError::BuildQueryError(err));
+    };
+
+    let Some(source_part) = source.first() else {
+        let err = anyhow!("source document of similar searching does not exist");
+        return Err(OSearchError::DocumentNotFound(err));
+    };
+
+    let like = build_similar_like(source);
+
+    let vectors = source
+        .iter()
+        .filter_map(|it| it.embeddings.as_ref())
+        .flatten()
+        .map(|it| it.knn.clone())
+        .collect::<Vec<Vec<f64>>>();
+
+    let vector = mean_vector(&vectors);
+    match similar.mode {
+        SimilarMode::Lexical if like.is_empty() => {
+            let err = anyhow!("source document has no stored content");
+            return Err(OSearchError::ValidationError(err));
+        }
+        SimilarMode::Vector if vector.is_none() => {
+            let err = anyhow!("source document has no stored embeddings");
+            return Err(OSearchError::ValidationError(err));
+        }
+        _ => {}
+    }
+
+    let query_params = SimilarQueryParamsBuilder::default()
+        .large_doc_id(source_part.large_doc_id.clone())
+        .like(like)
+        .vector(vector)
+        .mode(similar.mode)
+        .knn_amount(similar.knn_amount)
+        .min_score(similar.min_score)
+        .result(params.get_result().to_owned())
+        .filter(params.get_filter().cloned())
+        .build()
+        .context("failed to build similar query params")
+        .map_err(OSearchError::BuildQueryError)?;
+
+    Ok(complete_search_query(params, query_params.build_query()))
+}
+
+fn build_similar_like(source: &[SourceDocument]) -> Vec<String> {
+    let mut remaining = SIMILAR_MAX_LIKE_LENGTH;
+    source
+        .iter()
+        .filter_map(|it| it.content.as_deref())
+        .map_while(|content| {
+            if remaining == 0 {
+                return None;
+            }
+
+            let text = content.chars().take(remaining).collect::<String>();
+            remaining -= text.chars().count();
+            Some(text)
+        })
+        .collect()
+}
+
+fn complete_search_query(params: &SearchingParams, mut query: Value) -> Value {
+    if let Some(aggregations) = params.get_aggregations() {
+        query["aggs"] = build_aggregations_query(aggregations);
+    }
+
+    if params.get_result().is_grouped_by_document() {
+        query["collapse"] = build_collapse_query(&query);
+    }
+
+    if params.get_result().is_total_hits_tracked() {
+        query["track_total_hits"] = json!(true);
+    }
+
+    query
+}
+
+/// Builds the page query of cursor from the original search query. Point in
+/// time pages continue after sort values of the previous page last hit,
+/// other ones are shifted by offset with hybrid pagination depth covering it.
+pub fn build_cursor_page_query(
+    query: &Value,
+    pit_id: Option<&str>,
+    search_after: Option<&Value>,
+    offset: usize,
+    size: usize,
+) -> Value {
+    let mut query = query.clone();
+    query["size"] = json!(size);
+
+    let Some(pit_id) = pit_id else {
+        query["from"] = json!(offset);
+        if let Some(depth) = query.pointer_mut("/query/hybrid/pagination_depth") {
+            let window = (offset + size) as u64;
+            *depth = json!(depth.as_u64().unwrap_or_default().max(window));
+        }
+
+        return query;
+    };
+
+    query["pit"] = json!({
+        "id": pit_id,
+        "keep_alive": CURSOR_KEEP_ALIVE,
+    });
+
+    // search_after requires explicit sort, relevance one is used by default.
+    // Hits with equal sort values are ordered by `_shard_doc`, otherwise they
+    // may be skipped or repeated between pages.
+    if query["sort"].as_array().is_none_or(Vec::is_empty) {
+        query["sort"] = json!([{"_score": {"order": "desc"}}]);
+    }
+
+    if let Some(sort) = query["sort"].as_array_mut()
+        && !sort.iter().any(|it| it.get(SHARD_DOC_FIELD).is_some())
+    {
+        sort.push(json!({SHARD_DOC_FIELD: "asc"}));
+    }
+
+    if let Some(values) = search_after {
+        query["search_after"] = values.clone();
+    }
+
+    query
+}
+
+/// Builds search-as-you-type query: hits matched by prefix of the last word
+/// over `search_as_you_type` sub-fields carry completions within highlights,
+/// while term and phrase suggesters correct misspelled words of content.
+pub fn build_suggest_query(params: &SuggestParams) -> Value {
+    let query = params.query.as_str();
+    let size = params.size;
+    json!({
+        "size": size * SUGGEST_HITS_FACTOR,
+        "_source": ["file_name"],
+        "query": {
+            "multi_match": {
+                "query": query,
+                "type": "bool_prefix",
+                "fields": [
+                    "file_name.suggest",
+                    "file_name.suggest._2gram",
+                    "file_name.suggest._3gram",
+                    "content.suggest",
+                    "content.suggest._2gram",
+                    "content.suggest._3gram",
+                ]
+            }
+        },
+        "highlight": {
+            "pre_tags": ["<em>"],
+            "post_tags": ["</em>"],
+            "highlight_query": {
+                "multi_match": {
+                    "query": query,
+                    "type": "phrase_prefix",
+                    "fields": ["file_name.suggest", "content.suggest"],
+                }
+            },
+            "fields": {
+                "file_name.suggest": {
+                    "number_of_fragments": 0,
+                },
+                "content.suggest": {
+                    "fragment_size": SUGGEST_FRAGMENT_SIZE,
+                    "number_of_fragments": 1,
+                },
+            }
+        },
+        "suggest": {
+            "text": query,
+            SUGGEST_TERM_NAME: {
+                "term": {
+                    "field": "content",
+                    "suggest_mode": "missing",
+                    "size": 1,
+                }
+            },
+            SUGGEST_PHRASE_NAME: {
+                "phrase": {
+                    "field": "content",
+                    "size": size,
+                    "max_errors": SUGGEST_MAX_ERRORS,
+                    "direct_generator": [{
+                        "field": "content",
+                        "suggest_mode": "always",
+                    }]
+                }
+            },
+        }
+    })
+}
+
+fn build_search_kind_query(
+    params: &SearchingParams,
+    config: &OSearchKnnConfig,
 ) -> OSearchResult<Value> {
     let default_model_id = config.model_id().clone();
     let result = params.get_result();
@@ -36,8 +305,9 @@ pub fn build_search_query(
             Ok(query_params.build_query())
         }
         SearchKindParams::FullText(params) => {
+            let query = parse_fulltext_query(params.query.as_deref())?;
             let query_params = FullTextQueryParamsBuilder::default()
-                .query(params.query.clone())
+                .query(query)
                 .result(result.to_owned())
                 .filter(filter.cloned())
                 .build()
@@ -69,6 +339,8 @@ pub fn build_search_query(
                 .model_id(model_id)
                 .knn_amount(params.knn_amount)
                 .min_score(params.min_score)
+                .scoring(params.scoring.clone())
+                .pagination_depth(params.pagination_depth)
                 .result(result.to_owned())
                 .filter(filter.cloned())
                 .build()
@@ -77,9 +349,53 @@ pub fn build_search_query(
 
             Ok(query_params.build_query())
         }
+        SearchKindParams::Similar(_) => {
+            let err = anyhow!("similar searching requires loaded source document");
+            Err(OSearchError::BuildQueryError(err))
+        }
+    }
+}
+
+fn parse_fulltext_query(query: Option<&str>) -> OSearchResult<Option<QueryNode>> {
+    let Some(query) = query else {
+        return Ok(None);
+    };
+
+    match parse_query(query) {
+        Ok(node) => Ok(Some(node)),
+        Err(SearchError::ValidationError(err)) => Err(OSearchError::ValidationError(err)),
+        Err(err) => Err(OSearchError::BuildQueryError(err.into())),
     }
 }
 
+const UPDATE_DOC_PARTS_SCRIPT: &str = "\
+    ctx._source.file_name = params.file_name; \
+    ctx._source.file_path = params.file_path; \
+    ctx._source.file_size = params.file_size; \
+    ctx._source.modified_at = params.modified_at; \
+    ctx._source.metadata = params.metadata;";
+
+pub fn build_update_doc_parts_script(params: &UpdateDocumentParams) -> OSearchResult<Value> {
+    let metadata = params
+        .metadata
+        .clone()
+        .map(SourceDocumentMetadata::try_from)
+        .transpose()
+        .map_err(OSearchError::ValidationError)?;
+
+    Ok(json!({
+        "lang": "painless",
+        "source": UPDATE_DOC_PARTS_SCRIPT,
+        "params": {
+            "file_name": params.file_name,
+            "file_path": params.file_path,
+            "file_size": params.file_size,
+            "modified_at": params.modified_at,
+            "metadata": metadata,
+        }
+    }))
+}
+
 pub trait QueryBuildHelper {
     fn build_query(&self) -> Value;
 }
@@ -117,6 +433,16 @@ impl QueryBuildHelper for RetrieveAllDocPartsQueryParams {
             }
         });
 
+        if !self.exclude_ids().is_empty() {
+            query["query"]["bool"]["must_not"] = json!([
+                {
+                    "ids": {
+                        "values": self.exclude_ids(),
+                    }
+                }
+            ]);
+        }
+
         if self.with_sorting() {
             query[&"sort"] = json!({
                 "doc_part_id": {
@@ -154,7 +480,7 @@ impl QueryBuildHelper for RetrieveIndexDocsQueryParams {
         };
 
         let result = self.result();
-        let sort = build_sort_query(&result.order);
+        let sort = build_sort_query(&result.date_sort());
         let exclude = self.get_excluded_params();
         let filter = build_filter_query(self.filter());
 
@@ -177,13 +503,13 @@ impl QueryBuildHelper for FullTextQueryParams {
     fn build_query(&self) -> Value {
         let must = match self.query() {
             None => json!([{"match_all": {}}]),
-            Some(value) => json!([{"match": {"content": value} }]),
+            Some(node) => json!([build_syntax_query(node)]),
         };
 
         let result = self.result();
         let highlight = build_highlight_query(result);
         let exclude = self.get_excluded_params();
-        let sort = build_sort_query(&result.order);
+        let sort = build_sort_query(&result.relevance_sort());
         let filter = build_filter_query(self.filter());
 
         json!({
@@ -237,6 +563,10 @@ impl QueryBuildHelper for SemanticQueryParams {
             }
         });
 
+        if !self.result().sort.is_empty() {
+            base_value["sort"] = build_sort_query(&self.result().sort);
+        }
+
         if let Some(min_score) = self.min_score() {
             base_value["min_score"] = json!(min_score);
         }
@@ -255,6 +585,14 @@ impl QueryBuildHelper for HybridQueryParams {
         let exclude = self.get_excluded_params();
         let filter = build_filter_query(self.filter());
         let highlight = build_highlight_query(self.result());
+        let pagination_depth = self.pagination_depth().unwrap_or(HYBRID_PAGINATION_DEPTH);
+
+        // Scoring passed within request is applied by temporary search
+        // pipeline instead of the one created while initialization.
+        let search_pipeline = match self.scoring() {
+            None => json!(HYBRID_SEARCH_PIPELINE_NAME),
+            Some(scoring) => build_hybrid_search_schema(model_id, scoring),
+        };
 
         let multi_match_query = json!({
             "query": query,
@@ -276,11 +614,11 @@ impl QueryBuildHelper for HybridQueryParams {
                 "exclude": exclude
             },
             "size": size,
-            "search_pipeline": HYBRID_SEARCH_PIPELINE_NAME,
+            "search_pipeline": search_pipeline,
             "highlight": highlight,
             "query": {
                 "hybrid": {
-                    "pagination_depth": HYBRID_PAGINATION_DEPTH,
+                    "pagination_depth": pagination_depth,
                     "queries": [
                         {
                             "neural": {
@@ -309,6 +647,80 @@ impl QueryBuildHelper for HybridQueryParams {
             },
         });
 
+        if !self.result().sort.is_empty() {
+            base_value["sort"] = build_sort_query(&self.result().sort);
+        }
+
+        if let Some(min_score) = self.min_score() {
+            base_value["min_score"] = json!(min_score);
+        }
+
+        base_value
+    }
+}
+
+impl QueryBuildHelper for SimilarQueryParams {
+    fn build_query(&self) -> Value {
+        let must = match (self.mode(), self.vector()) {
+            (SimilarMode::Vector, Some(vector)) => json!([
+                {
+                    "nested": {
+                        "path": "embeddings",
+                        "score_mode": "max",
+                        "query": {
+                            "knn": {
+                                "embeddings.knn": {
+                                    "vector": vector,
+                                    "k": self.knn_amount(),
+                                }
+                            }
+                        }
+                    }
+                }
+            ]),
+            _ => json!([
+                {
+                    "more_like_this": {
+                        "fields": ["content"],
+                        "like": self.like(),
+                        "min_term_freq": 1,
+                        "min_doc_freq": 1,
+                        "max_query_terms": SIMILAR_MAX_QUERY_TERMS,
+                    }
+                }
+            ]),
+        };
+
+        let size = self.result().size;
+        let exclude = self.get_excluded_params();
+        let filter = build_filter_query(self.filter());
+        let highlight = build_highlight_query(self.result());
+
+        let mut base_value = json!({
+            "_source": {
+                "exclude": exclude,
+            },
+            "size": size,
+            "highlight": highlight,
+            "query": {
+                "bool": {
+                    "must": must,
+                    "must_not": [
+                        {
+                            "term": {
+                                "large_doc_id": self.large_doc_id(),
+                            }
+                        }
+                    ],
+                    "filter": filter,
+                }
+            }
+        });
+
+        if !self.result().sort.is_empty() {
+            base_value["sort"] = build_sort_query(&self.result().sort);
+        }
+
         if let Some(min_score) = self.min_score() {
             base_value["min_score"] = json!(min_score);
         }
@@ -426,6 +838,46 @@ fn build_filter_query(filter: &Option<FilterParams>) -> Value {
                     }));
                 }
 
+                if let Some(modified_from) = params.modified_from {
+                    filter_params.push(json!({
+                        "range": {
+                            "modified_at": {
+                                "gte": modified_from,
+                            }
+                        }
+                    }));
+                }
+
+                if let Some(modified_to) = params.modified_to {
+                    filter_params.push(json!({
+                        "range": {
+                            "modified_at": {
+                                "lte": modified_to,
+                            }
+                        }
+                    }));
+                }
+
+                if let Some(doc_part_id) = params.doc_part_id {
+                    filter_params.push(json!({
+                        "term": {
+                            "doc_part_id": doc_part_id,
+                        }
+                    }));
+                }
+
+                if let Some(pipeline_id) = params.pipeline_id {
+                    filter_params.push(json!({
+                        "term": {
+                            "metadata.pipeline_id": pipeline_id,
+                        }
+                    }));
+                }
+
+                if params.doc_class.is_some() || params.doc_class_probability.is_some() {
+                    filter_params.push(build_doc_class_filter_query(params));
+                }
+
                 return Value::from(filter_params);
             }
 
@@ -434,6 +886,41 @@ fn build_filter_query(filter: &Option<FilterParams>) -> Value {
     }
 }
 
+fn build_doc_class_filter_query(params: &FilterParams) -> Value {
+    let mut must = Vec::with_capacity(2);
+    if let Some(doc_class) = params.doc_class.as_ref() {
+        must.push(json!({
+            "term": {
+                "metadata.classes.name": doc_class,
+            }
+        }));
+    }
+
+    if let Some(probability) = params.doc_class_probability {
+        must.push(json!({
+            "range": {
+                "metadata.classes.probability": {
+                    "gte": probability,
+                }
+            }
+        }));
+    }
+
+    // Both conditions are checked against the same nested class object,
+    // so a document does not pass with a matching name on one class and
+    // a high enough probability on another.
+    json!({
+        "nested": {
+            "path": "metadata.classes",
+            "query": {
+                "bool": {
+                    "must": must,
+                }
+            }
+        }
+    })
+}
+
 fn build_highlight_query(params: &ResultParams) -> Value {
     let mut base_value = json!({
         "fields": {
@@ -455,17 +942,41 @@ fn build_highlight_query(params: &ResultParams) -> Value {
     base_value
 }
 
-fn build_sort_query(order: &ResultOrder) -> Value {
-    let order = match order {
-        ResultOrder::ASC => "asc",
-        ResultOrder::DESC => "desc",
-    };
+/// Collapses hits by large document. Matched parts of each document are
+/// returned as inner hits with the same source and highlight settings.
+fn build_collapse_query(query: &Value) -> Value {
+    let mut inner_hits = json!({
+        "name": COLLAPSED_PARTS_NAME,
+        "size": COLLAPSED_PARTS_SIZE,
+        "_source": query["_source"],
+    });
 
-    json!([
-        {
-            "created_at": {
-                "order": order
-            }
-        }
-    ])
+    if !query["highlight"].is_null() {
+        inner_hits["highlight"] = query["highlight"].clone();
+    }
+
+    json!({
+        "field": "large_doc_id",
+        "inner_hits": inner_hits,
+    })
+}
+
+fn build_sort_query(sort: &[SortParams]) -> Value {
+    let sort = sort
+        .iter()
+        .map(|it| {
+            let order = match it.order {
+                ResultOrder::ASC => "asc",
+                ResultOrder::DESC => "desc",
+            };
+
+            json!({
+                it.field.to_string(): {
+                    "order": order
+                }
+            })
+        })
+        .collect::<Vec<Value>>();
+
+    Value::Array(sort)
 }
@@ -0,0 +1,88 @@
+use serde_json::{Map, Value, json};
+use std::ops::Bound;
+
+use crate::domain::searcher::query::{QueryField, QueryNode, QueryRange, QueryTerm, QueryValue};
+
+pub fn build_syntax_query(node: &QueryNode) -> Value {
+    if let Some(text) = node.plain_text() {
+        return json!({"match": {"content": text}});
+    }
+
+    match node {
+        QueryNode::Term(term) => build_term_query(term),
+        QueryNode::Bool(query) => {
+            let build_clauses = |nodes: &[QueryNode]| -> Vec<Value> {
+                nodes.iter().map(build_syntax_query).collect()
+            };
+
+            let mut bool_query = json!({
+                "must": build_clauses(&query.must),
+                "should": build_clauses(&query.should),
+                "must_not": build_clauses(&query.must_not),
+            });
+
+            if query.must.is_empty() && !query.should.is_empty() {
+                bool_query["minimum_should_match"] = json!(1);
+            }
+
+            json!({"bool": bool_query})
+        }
+    }
+}
+
+fn build_term_query(term: &QueryTerm) -> Value {
+    let field = field_name(term.field);
+    match &term.value {
+        QueryValue::Word(word) => json!({"match": {field: word}}),
+        QueryValue::Phrase(phrase) => json!({"match_phrase": {field: phrase}}),
+        QueryValue::Fuzzy { word, distance } => json!({
+            "match": {
+                field: {
+                    "query": word,
+                    "fuzziness": distance,
+                }
+            }
+        }),
+        QueryValue::Wildcard(pattern) => json!({
+            "wildcard": {
+                field: {
+                    "value": pattern,
+                    "case_insensitive": true,
+                }
+            }
+        }),
+        QueryValue::Range(range) => json!({"range": {field: build_range_bounds(range)}}),
+    }
+}
+
+fn build_range_bounds(range: &QueryRange) -> Value {
+    let mut bounds = Map::new();
+    match range.from {
+        Bound::Included(from) => bounds.insert("gte".to_string(), json!(from)),
+        Bound::Excluded(from) => bounds.insert("gt".to_string(), json!(from)),
+        Bound::Unbounded => None,
+    };
+
+    match range.to {
+        Bound::Included(to) => bounds.insert("lte".to_string(), json!(to)),
+        Bound::Excluded(to) => bounds.insert("lt".to_string(), json!(to)),
+        Bound::Unbounded => None,
+    };
+
+    Value::Object(bounds)
+}
+
+fn field_name(field: QueryField) -> &'static str {
+    match field {
+        QueryField::Content => "content",
+        QueryField::FileName => "file_name",
+        QueryField::FilePath => "file_path",
+        QueryField::Source => "metadata.source",
+        QueryField::SemanticSource => "metadata.semantic_source",
+        QueryField::Summary => "metadata.summary",
+        QueryField::CreatedAt => "created_at",
+        QueryField::ModifiedAt => "modified_at",
+        QueryField::FileSize => "file_size",
+        QueryField::PipelineId => "metadata.pipeline_id",
+    }
+}
@@ -1,23 +1,25 @@
 use serde_json::{Value, json};
 
-use crate::domain::storage::models::{KnnIndexParams, KnnIndexParamsBuilder};
+use crate::domain::searcher::models::HybridScoringParams;
+use crate::domain::storage::models::{KnnIndexParams, KnnIndexParamsBuilder, SplitterParams};
 use crate::infrastructure::osearch::OSearchConfig;
-use crate::infrastructure::osearch::config::OSearchKnnConfig;
+use crate::infrastructure::osearch::dto::SplitterSettings;
 
 pub const INGEST_PIPELINE_NAME: &str = "embeddings-ingest-pipeline";
 pub const HYBRID_SEARCH_PIPELINE_NAME: &str = "hybrid-search-pipeline";
-const NORMALIZATION_TECHNIQUE: &str = "min_max";
-const COMBINATION_TECHNIQUE: &str = "arithmetic_mean";
+pub const NONE_PIPELINE_NAME: &str = "_none";
 const TOKENIZER_KIND: &str = "standard";
 const ALGO_PARAM_EF_SEARCH: u32 = 100;
 
-pub fn build_hybrid_search_schema(config: &OSearchKnnConfig) -> Value {
+/// Builds hybrid search pipeline which normalizes and combines scores of
+/// semantic and lexical sub-queries. Weights follow the order of sub-queries.
+pub fn build_hybrid_search_schema(model_id: &str, scoring: &HybridScoringParams) -> Value {
     let schema_query = json!({
         "description": "Post processor for hybrid searching",
         "request_processors": [
             {
                 "neural_query_enricher" : {
-                    "default_model_id": config.model_id(),
+                    "default_model_id": model_id,
                 }
             }
         ],
@@ -26,14 +28,14 @@ pub fn build_hybrid_search_schema(config: &OSearchKnnConfig) -> Value {
             {
                 "normalization-processor": {
                     "normalization": {
-                        "technique": NORMALIZATION_TECHNIQUE,
+                        "technique": scoring.normalization.to_string(),
                     },
                     "combination": {
-                        "technique": COMBINATION_TECHNIQUE,
+                        "technique": scoring.combination.to_string(),
                         "parameters": {
                             "weights": [
-                                0.3,
-                                0.7
+                                scoring.semantic_weight,
+                                scoring.lexical_weight
                             ]
                         }
                     }
@@ -86,7 +88,11 @@ pub fn builder_ingest_schema(config: &OSearchConfig, params: Option<&KnnIndexPar
     schema_query
 }
 
-pub fn build_index_mappings(config: &OSearchConfig, params: Option<&KnnIndexParams>) -> Value {
+pub fn build_index_mappings(
+    config: &OSearchConfig,
+    params: Option<&KnnIndexParams>,
+    splitter: Option<&SplitterParams>,
+) -> Value {
     let semantic_config = config.semantic();
     let knn_default_params = KnnIndexParamsBuilder::default()
         .knn_dimension(semantic_config.knn_dimension())
@@ -97,7 +103,7 @@ pub fn build_index_mappings(config: &OSearchConfig, params: Option<&KnnIndexPara
 
     let knn_params = params.unwrap_or(&knn_default_params);
     let cluster_config = config.cluster();
-    let schema_query = json!({
+    let mut schema_query = json!({
         "settings": {
             "index": {
                 "knn": true,
@@ -118,7 +124,8 @@ pub fn build_index_mappings(config: &OSearchConfig, params: Option<&KnnIndexPara
                 "file_name": {
                     "type": "text",
                     "fields": {
-                      "keyword": { "type": "keyword", "ignore_above": 256 }
+                      "keyword": { "type": "keyword", "ignore_above": 256 },
+                      "suggest": { "type": "search_as_you_type" }
                     }
                   },
                 "file_path": {
@@ -131,7 +138,10 @@ pub fn build_index_mappings(config: &OSearchConfig, params: Option<&KnnIndexPara
                     "type": "keyword"
                 },
                 "content": {
-                    "type": "text"
+                    "type": "text",
+                    "fields": {
+                        "suggest": { "type": "search_as_you_type" }
+                    }
                 },
                 "created_at": {
                     "type": "date",
@@ -240,5 +250,10 @@ pub fn build_index_mappings(config: &OSearchConfig, params: Option<&KnnIndexPara
         }
     });
 
+    if let Some(splitter) = splitter {
+        let settings = SplitterSettings::from(splitter);
+        schema_query["mappings"]["_meta"] = json!({ "splitter": settings });
+    }
+
     schema_query
 }
@@ -3,4 +3,4 @@ pub mod search;
 pub const INDEX_ID: &str = "test-folder";
 pub const DOCUMENT_ID: &str = "29346839246dsf987a1173sfa7sd781h";
 pub const DOCUMENT_PART_ID: &str = "kwejbrw46dsf987a1173sfa7sd781h";
-pub const SCROLL_ID: &str = "dksfsjvJHZVFDskjdbfsdfsdfdsg";
+pub const PIT_ID: &str = "dksfsjvJHZVFDskjdbfsdfsdfdsg";
@@ -2,6 +2,7 @@ use rstest::fixture;
 use serde_json::Value;
 
 const SEARCHING_RESULT: &[u8] = include_bytes!("../../resources/searching-result.json");
+const SUGGEST_RESULT: &[u8] = include_bytes!("../../resources/suggest-result.json");
 
 #[fixture]
 pub fn build_full_search_result() -> Value {
@@ -9,9 +10,9 @@ pub fn build_full_search_result() -> Value {
 }
 
 #[fixture]
-pub fn build_search_result_without_scroll_id() -> Value {
+pub fn build_search_result_without_pit_id() -> Value {
     let mut searching_result = build_full_search_result();
-    searching_result["_scroll_id"] = Value::Null;
+    searching_result["pit_id"] = Value::Null;
     searching_result
 }
 
@@ -29,3 +30,8 @@ pub fn build_search_result_without_metadata() -> Value {
 
     searching_result
 }
+
+#[fixture]
+pub fn build_suggest_result() -> Value {
+    serde_json::from_slice(SUGGEST_RESULT).expect("failed to load suggest result fixture data")
+}
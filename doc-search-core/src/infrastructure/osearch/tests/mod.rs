@@ -3,5 +3,7 @@ pub use common::fixture;
 #[allow(unused_imports)]
 pub use common::mock;
 
+mod test_cursor;
+mod test_document;
 mod test_extractor;
 mod test_query;
@@ -0,0 +1,122 @@
+use rstest::*;
+use serde_json::json;
+use std::time::Duration;
+
+use crate::application::tests::fixture::search_params::*;
+use crate::domain::searcher::models::{SearchKindParams, SearchingParams};
+use crate::infrastructure::osearch::cursor::{CursorCodec, CursorContext, QueryCache};
+use crate::infrastructure::osearch::tests::fixture::{INDEX_ID, PIT_ID};
+
+const CURSOR_SECRET: &str = "cursor-secret";
+const CURSOR_LIFETIME: Duration = Duration::from_secs(60);
+const KNN_DIMENSION: usize = 384;
+const MAX_SEMANTIC_CURSOR_LEN: usize = 2048;
+
+fn build_cursor_context() -> CursorContext {
+    let params = SearchingParams::new(
+        vec![INDEX_ID.to_string()],
+        SearchKindParams::FullText(build_with_query_fulltext_params()),
+        build_result_params(),
+        Some(build_filter_params()),
+    );
+
+    let mut context = CursorContext::new(params, Some(PIT_ID.to_string()), 10);
+    context.search_after = Some(json!([1750957215, 7]));
+    context.offset = 10;
+    context.prolong(CURSOR_LIFETIME);
+    context
+}
+
+#[rstest]
+fn test_cursor_codec_round_trip() -> anyhow::Result<()> {
+    let codec = CursorCodec::new(CURSOR_SECRET);
+    let cursor = codec.encode(&build_cursor_context())?;
+
+    let context = codec.decode(&cursor)?;
+    assert_eq!(context.pit_id.as_deref(), Some(PIT_ID));
+    assert_eq!(context.search_after, Some(json!([1750957215, 7])));
+    assert_eq!(context.offset, 10);
+    assert_eq!(context.size, 10);
+    assert_eq!(context.params.get_indexes(), &vec![INDEX_ID.to_string()]);
+    assert!(matches!(
+        context.params.get_kind(),
+        SearchKindParams::FullText(_)
+    ));
+
+    Ok(())
+}
+
+#[rstest]
+fn test_cursor_codec_drops_semantic_tokens() -> anyhow::Result<()> {
+    let mut semantic = build_semantic_params_with_tokens();
+    semantic.tokens = Some(vec![-0.0123456789; KNN_DIMENSION]);
+    let params = SearchingParams::new(
+        vec![INDEX_ID.to_string()],
+        SearchKindParams::Semantic(semantic),
+        build_result_params(),
+        Some(build_filter_params()),
+    );
+    assert!(params.has_tokens());
+
+    let codec = CursorCodec::new(CURSOR_SECRET);
+    let mut context = CursorContext::new(params, Some(PIT_ID.to_string()), 10);
+    context.query_id = Some(uuid::Uuid::new_v4().to_string());
+    context.prolong(CURSOR_LIFETIME);
+
+    let cursor = codec.encode(&context)?;
+    assert!(cursor.len() < MAX_SEMANTIC_CURSOR_LEN);
+
+    let context = codec.decode(&cursor)?;
+    assert!(!context.params.has_tokens());
+    assert!(context.query_id.is_some());
+    Ok(())
+}
+
+#[rstest]
+fn test_query_cache_expires_queries() -> anyhow::Result<()> {
+    let cache = QueryCache::default();
+    let query = json!({"query": {"knn": {"embeddings.knn": {"vector": [0.1, 0.2]}}}});
+
+    let query_id = cache.insert(query.clone(), CURSOR_LIFETIME);
+    assert_eq!(cache.get(&query_id, CURSOR_LIFETIME), Some(query.clone()));
+
+    cache.remove(&query_id);
+    assert!(cache.get(&query_id, CURSOR_LIFETIME).is_none());
+
+    let query_id = cache.insert(query, Duration::ZERO);
+    assert!(cache.get(&query_id, CURSOR_LIFETIME).is_none());
+    Ok(())
+}
+
+#[rstest]
+fn test_cursor_codec_rejects_forged_cursor() -> anyhow::Result<()> {
+    let codec = CursorCodec::new(CURSOR_SECRET);
+    let cursor = codec.encode(&build_cursor_context())?;
+
+    let other_codec = CursorCodec::new("another-secret");
+    assert!(other_codec.decode(&cursor).is_err());
+
+    let mut forged_context = build_cursor_context();
+    forged_context.offset = 0;
+    let forged_cursor = codec.encode(&forged_context)?;
+
+    let (payload, signature) = cursor.split_once('.').unwrap();
+    let (forged_payload, _) = forged_cursor.split_once('.').unwrap();
+    let forged_cursor = format!("{forged_payload}.{signature}");
+    assert!(codec.decode(&forged_cursor).is_err());
+    assert!(codec.decode(payload).is_err());
+    assert!(codec.decode("unknown-cursor").is_err());
+
+    Ok(())
+}
+
+#[rstest]
+fn test_cursor_codec_rejects_expired_cursor() -> anyhow::Result<()> {
+    let codec = CursorCodec::new(CURSOR_SECRET);
+    let mut context = build_cursor_context();
+    context.prolong(Duration::ZERO);
+
+    let cursor = codec.encode(&context)?;
+    assert!(codec.decode(&cursor).is_err());
+    Ok(())
+}
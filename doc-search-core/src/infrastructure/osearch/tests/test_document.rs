@@ -0,0 +1,29 @@
+use rstest::rstest;
+
+use crate::domain::storage::models::DocumentPartEmbeddings;
+use crate::domain::storage::tests::fixture::document::build_document_part;
+use crate::infrastructure::osearch::dto::SourceDocument;
+
+#[rstest]
+fn test_source_document_with_embeddings() -> anyhow::Result<()> {
+    let mut doc_part = build_document_part(1);
+    doc_part.embeddings = Some(DocumentPartEmbeddings {
+        chunked_text: vec!["there is some".to_string(), "huge content".to_string()],
+        embeddings: vec![vec![0.1, 0.2], vec![0.3, 0.4]],
+    });
+
+    let src_doc = SourceDocument::try_from(doc_part)?;
+    let value = serde_json::to_value(src_doc)?;
+    assert_eq!(value["chunked_text"][1], "huge content");
+    assert_eq!(value["embeddings"][0]["knn"][1], 0.2);
+    Ok(())
+}
+
+#[rstest]
+fn test_source_document_without_embeddings() -> anyhow::Result<()> {
+    let src_doc = SourceDocument::try_from(build_document_part(1))?;
+    let value = serde_json::to_value(src_doc)?;
+    assert!(value.get("chunked_text").is_none());
+    assert!(value.get("embeddings").is_none());
+    Ok(())
+}
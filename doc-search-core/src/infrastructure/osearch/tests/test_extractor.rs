@@ -1,26 +1,37 @@
 use anyhow;
 use rstest::rstest;
-use serde_json::Value;
+use serde_json::{Value, json};
 
+use crate::domain::searcher::models::FacetBucket;
+use crate::domain::searcher::models::SuggestField;
+use crate::domain::searcher::models::{TotalHits, TotalHitsRelation};
+use crate::infrastructure::osearch::extractor::extract_bulk_failures;
 use crate::infrastructure::osearch::extractor::extract_founded_document_parts;
+use crate::infrastructure::osearch::extractor::extract_suggestions;
+use crate::infrastructure::osearch::extractor::{extract_bulk_created_ids, has_bulk_conflicts};
+use crate::infrastructure::osearch::extractor::{extract_pit_id, extract_search_after};
 use crate::infrastructure::osearch::tests::fixture::search::*;
 use crate::infrastructure::osearch::tests::fixture::{
-    DOCUMENT_ID, DOCUMENT_PART_ID, INDEX_ID, SCROLL_ID,
+    DOCUMENT_ID, DOCUMENT_PART_ID, INDEX_ID, PIT_ID,
 };
 
 #[rstest]
-#[case(build_full_search_result(), Some(SCROLL_ID.to_string()))]
-#[case(build_search_result_without_metadata(), Some(SCROLL_ID.to_string()))]
-#[case(build_search_result_without_scroll_id(), None)]
+#[case(build_full_search_result(), Some(PIT_ID.to_string()))]
+#[case(build_search_result_without_metadata(), Some(PIT_ID.to_string()))]
+#[case(build_search_result_without_pit_id(), None)]
 fn test_extract_founded_docs(
     #[case] founded: Value,
-    #[case] expected_scroll_id: Option<String>,
+    #[case] expected_pit_id: Option<String>,
 ) -> anyhow::Result<()> {
     println!("{}", serde_json::to_string_pretty(&founded)?);
-    let extracted_docs = extract_founded_document_parts(founded)?;
+    assert_eq!(expected_pit_id, extract_pit_id(&founded));
+    assert_eq!(
+        Some(json!([0.7, 1750957215])),
+        extract_search_after(&founded)
+    );
 
-    let scroll_id_opt = extracted_docs.scroll_id;
-    assert_eq!(expected_scroll_id, scroll_id_opt);
+    let extracted_docs = extract_founded_document_parts(founded)?;
+    assert_eq!(None, extracted_docs.cursor);
     assert_eq!(1, extracted_docs.founded.len());
 
     let root_document = extracted_docs.founded.first().expect("expected document");
@@ -30,3 +41,189 @@ fn test_extract_founded_docs(
 
     Ok(())
 }
+
+#[rstest]
+fn test_extract_founded_docs_total(
+    #[from(build_full_search_result)] mut founded: Value,
+) -> anyhow::Result<()> {
+    let extracted_docs = extract_founded_document_parts(founded.clone())?;
+    assert_eq!(Some(TotalHits::exact(1)), extracted_docs.total);
+    assert_eq!(Some(0.7), extracted_docs.max_score);
+    assert_eq!(Some(12), extracted_docs.took);
+
+    founded["hits"]["total"] = json!({"value": 10000, "relation": "gte"});
+    founded["hits"]["max_score"] = Value::Null;
+    let extracted_docs = extract_founded_document_parts(founded)?;
+    let expected_total = TotalHits {
+        value: 10000,
+        relation: TotalHitsRelation::LowerBound,
+    };
+
+    assert_eq!(Some(expected_total), extracted_docs.total);
+    assert_eq!(None, extracted_docs.max_score);
+
+    Ok(())
+}
+
+#[rstest]
+fn test_extract_founded_docs_facets(
+    #[from(build_full_search_result)] mut founded: Value,
+) -> anyhow::Result<()> {
+    let extracted_docs = extract_founded_document_parts(founded.clone())?;
+    assert_eq!(None, extracted_docs.facets);
+
+    founded["aggregations"] = json!({
+        "source": {
+            "doc_count_error_upper_bound": 0,
+            "sum_other_doc_count": 0,
+            "buckets": [{"key": "upload", "doc_count": 4}]
+        },
+        "classes": {
+            "doc_count": 7,
+            "values": {
+                "buckets": [{"key": "report", "doc_count": 5, "documents": {"doc_count": 3}}]
+            }
+        },
+        "created_at": {
+            "buckets": [{"key_as_string": "1750957115", "key": 1750957115000_u64, "doc_count": 2}]
+        }
+    });
+
+    let extracted_docs = extract_founded_document_parts(founded)?;
+    let facets = extracted_docs.facets.expect("expected extracted facets");
+
+    let bucket = |key: &str, count: u64| FacetBucket {
+        key: key.to_string(),
+        count,
+    };
+
+    assert_eq!(facets["source"], vec![bucket("upload", 4)]);
+    assert_eq!(facets["classes"], vec![bucket("report", 3)]);
+    assert_eq!(facets["created_at"], vec![bucket("1750957115", 2)]);
+
+    Ok(())
+}
+
+#[rstest]
+fn test_extract_founded_docs_grouped(
+    #[from(build_full_search_result)] mut founded: Value,
+) -> anyhow::Result<()> {
+    let hit = founded["hits"]["hits"][0].clone();
+    let mut best_part = hit.clone();
+    best_part["_id"] = json!("best-part-id");
+    best_part["_score"] = json!(1.5);
+    best_part["highlight"] = json!({"content": ["best highlight"]});
+
+    founded["hits"]["hits"][0]["_score"] = Value::Null;
+    founded["hits"]["hits"][0]["inner_hits"] = json!({
+        "matched_parts": {
+            "hits": {
+                "total": {"value": 5, "relation": "eq"},
+                "max_score": 1.5,
+                "hits": [best_part, hit],
+            }
+        }
+    });
+
+    let extracted_docs = extract_founded_document_parts(founded)?;
+    let root_document = extracted_docs.founded.first().expect("expected document");
+    assert_eq!("best-part-id", root_document.id);
+    assert_eq!(Some(1.5), root_document.score);
+    assert_eq!(
+        root_document.highlight,
+        vec!["best highlight", "There is some highlight"]
+    );
+
+    let grouped = root_document
+        .grouped
+        .as_ref()
+        .expect("expected grouped parts");
+    assert_eq!(grouped.matched_parts, 5);
+    assert!(grouped.score.is_some_and(|it| (it - 2.2).abs() < 1e-9));
+
+    Ok(())
+}
+
+#[rstest]
+#[case(json!({"took": 3, "errors": false, "items": [{"index": {"_id": "1", "status": 201}}]}), 0)]
+#[case(json!({"took": 3, "errors": true, "items": [
+    {"index": {"_id": "1", "status": 201}},
+    {"index": {"_id": "2", "status": 400, "error": {
+        "type": "mapper_parsing_exception",
+        "reason": "failed to parse field [created_at]"
+    }}},
+    {"index": {"_id": "3", "status": 429, "error": {
+        "type": "es_rejected_execution_exception",
+        "reason": "rejected execution"
+    }}}
+]}), 2)]
+fn test_extract_bulk_failures(#[case] response: Value, #[case] expected_failures: usize) {
+    let failures = extract_bulk_failures(&response);
+    assert_eq!(expected_failures, failures.len());
+
+    if let Some((id, reason)) = failures.first() {
+        assert_eq!("2", id);
+        assert_eq!(
+            "mapper_parsing_exception: failed to parse field [created_at]",
+            reason
+        );
+    }
+}
+
+#[rstest]
+fn test_extract_bulk_created_ids() {
+    let response = json!({"took": 3, "errors": true, "items": [
+        {"create": {"_id": "1", "status": 201, "result": "created"}},
+        {"index": {"_id": "2", "status": 200, "result": "updated"}},
+        {"create": {"_id": "3", "status": 409, "error": {
+            "type": "version_conflict_engine_exception",
+            "reason": "document already exists"
+        }}}
+    ]});
+
+    assert_eq!(vec!["1".to_string()], extract_bulk_created_ids(&response));
+    assert!(has_bulk_conflicts(&response));
+
+    let response = json!({"took": 3, "errors": false, "items": [
+        {"index": {"_id": "1", "status": 201, "result": "created"}}
+    ]});
+    assert!(!has_bulk_conflicts(&response));
+}
+
+#[rstest]
+fn test_extract_suggestions(
+    #[from(build_suggest_result)] mut suggested: Value,
+) -> anyhow::Result<()> {
+    let suggestions = extract_suggestions(&suggested, 5);
+    let completions = suggestions
+        .completions
+        .iter()
+        .map(|it| (it.text.as_str(), it.score, it.field))
+        .collect::<Vec<(&str, f64, SuggestField)>>();
+
+    let expected = vec![
+        ("war-and-peace.txt", 1.0, SuggestField::FileName),
+        ("war and peace", 1.0, SuggestField::Content),
+        ("war and peasants", 0.5, SuggestField::Content),
+    ];
+    assert_eq!(expected, completions);
+
+    let corrections = suggestions
+        .corrections
+        .iter()
+        .map(|it| (it.text.as_str(), it.score))
+        .collect::<Vec<(&str, f64)>>();
+    assert_eq!(vec![("war and peace", 0.42)], corrections);
+
+    let suggestions = extract_suggestions(&suggested, 1);
+    assert_eq!(1, suggestions.completions.len());
+
+    suggested["suggest"]["phrase_correction"][0]["options"] = json!([]);
+    suggested["suggest"]["term_correction"][2]["options"] =
+        json!([{"text": "peace", "score": 0.5}]);
+    let suggestions = extract_suggestions(&suggested, 5);
+    let correction = suggestions.corrections.first().map(|it| it.text.as_str());
+    assert_eq!(Some("war and peace"), correction);
+
+    Ok(())
+}
@@ -3,16 +3,45 @@ use rstest::*;
 use serde_json::{Value, json};
 
 use crate::application::tests::fixture::search_params::*;
+use crate::application::tests::fixture::{DOC_FILE_NAME, DOC_FILE_PATH, DOC_FILE_SIZE};
+use crate::application::tests::fixture::{DOC_FILE_TIMESTAMP, FIRST_DOC_PART_ID, LARGE_DOC_ID};
+use crate::domain::searcher::models::SuggestParamsBuilder;
+use crate::domain::searcher::models::{
+    AggregationParamsBuilder, FacetKind, HistogramInterval, SearchKindParams, SearchingParams,
+};
+use crate::domain::searcher::models::{FilterParams, FilterParamsBuilder};
 use crate::domain::searcher::models::{
     FullTextSearchingParams, HybridSearchingParams, RetrieveIndexDocumentsParams,
     SemanticSearchingParams,
 };
+use crate::domain::searcher::models::{
+    HybridScoringParamsBuilder, ScoreCombination, ScoreNormalization,
+};
+use crate::domain::searcher::models::{ResultOrder, SortField, SortParams};
+use crate::domain::searcher::models::{SimilarMode, SimilarSource};
+use crate::domain::searcher::query::parse_query;
 use crate::domain::searcher::tests::fixture::params::build_filter_searching_params;
+use crate::domain::searcher::tests::fixture::{
+    CURRENT_TIMESTAMP, DOCUMENT_CLASS_FILTER_PARAMS, DOCUMENT_CLASS_PROBABILITY_FILTER_PARAMS,
+    PIPELINE_ID_FILTER_PARAMS,
+};
+use crate::domain::storage::models::UpdateDocumentParamsBuilder;
+use crate::infrastructure::osearch::OSearchClient;
+use crate::infrastructure::osearch::config::OSearchKnnConfig;
+use crate::infrastructure::osearch::dto::SourceDocument;
 use crate::infrastructure::osearch::dto::{
-    FullTextQueryParamsBuilder, HybridQueryParamsBuilder, RetrieveIndexDocsQueryParamsBuilder,
-    SemanticQueryParamsBuilder,
+    FullTextQueryParamsBuilder, HybridQueryParamsBuilder, RetrieveAllDocPartsQueryParamsBuilder,
+    RetrieveIndexDocsQueryParamsBuilder, SemanticQueryParamsBuilder,
 };
-use crate::infrastructure::osearch::query::QueryBuildHelper;
+use crate::infrastructure::osearch::error::OSearchError;
+use crate::infrastructure::osearch::query::{
+    QueryBuildHelper, build_cursor_page_query, build_search_query, build_similar_search_query,
+    build_similar_source_query, build_suggest_query, build_update_doc_parts_script,
+};
+use crate::infrastructure::osearch::query::{
+    SIMILAR_MAX_LIKE_LENGTH, SUGGEST_PHRASE_NAME, SUGGEST_TERM_NAME,
+};
+use crate::shared::kernel::{DocumentPartId, LargeDocumentId};
 
 const RETRIEVE_FULL_PARAMS: &[u8] = include_bytes!("resources/retrieve-full-query.json");
 const RETRIEVE_SIMPLE_PARAMS: &[u8] = include_bytes!("resources/retrieve-simple-query.json");
@@ -91,7 +120,7 @@ fn test_build_simple_fulltext_params_query(
 ) -> anyhow::Result<()> {
     let result = build_result_params();
     let query_params = FullTextQueryParamsBuilder::default()
-        .query(params.query.clone())
+        .query(params.query.as_deref().map(parse_query).transpose()?)
         .result(result.to_owned())
         .filter(None)
         .build()
@@ -112,7 +141,7 @@ fn test_build_full_fulltext_params_query(
     let result = build_result_params();
     let filter = build_filter_searching_params();
     let query_params = FullTextQueryParamsBuilder::default()
-        .query(params.query.clone())
+        .query(params.query.as_deref().map(parse_query).transpose()?)
         .result(result.to_owned())
         .filter(Some(filter))
         .build()
@@ -240,3 +269,606 @@ fn test_build_full_hybrid_params_query(
 
     Ok(())
 }
+
+#[rstest]
+fn test_build_hybrid_params_query_with_scoring(
+    #[from(build_simple_hybrid_params)] params: HybridSearchingParams,
+) -> anyhow::Result<()> {
+    let scoring = HybridScoringParamsBuilder::default()
+        .normalization(ScoreNormalization::L2)
+        .combination(ScoreCombination::GeometricMean)
+        .semantic_weight(0.6)
+        .lexical_weight(0.4)
+        .build()
+        .context("failed to build hybrid scoring params")?;
+
+    let query_params = HybridQueryParamsBuilder::default()
+        .query(params.query.clone())
+        .model_id(params.model_id.unwrap_or_default())
+        .knn_amount(params.knn_amount)
+        .min_score(params.min_score)
+        .scoring(Some(scoring))
+        .pagination_depth(Some(50))
+        .result(build_result_params())
+        .filter(None)
+        .build()
+        .context("failed to build hybrid query params")?;
+
+    let query = query_params.build_query();
+    assert_eq!(query["query"]["hybrid"]["pagination_depth"], json!(50));
+
+    let processor = &query["search_pipeline"]["phase_results_processors"][0];
+    let expected_processor = json!({
+        "normalization-processor": {
+            "normalization": {"technique": "l2"},
+            "combination": {
+                "technique": "geometric_mean",
+                "parameters": {"weights": [0.6_f32, 0.4_f32]}
+            }
+        }
+    });
+
+    assert_eq!(processor, &expected_processor);
+    Ok(())
+}
+
+#[rstest]
+fn test_build_stale_doc_parts_query() -> anyhow::Result<()> {
+    let query_params = RetrieveAllDocPartsQueryParamsBuilder::default()
+        .large_doc_id(LARGE_DOC_ID.to_string())
+        .only_first_part(false)
+        .with_sorting(false)
+        .exclude_ids(vec![FIRST_DOC_PART_ID.to_string()])
+        .build()
+        .context("failed to build all doc parts query params")?;
+
+    let query = query_params.build_query();
+    let comparable_query = json!({
+        "query": {
+            "bool": {
+                "must": [{"match": {"large_doc_id": LARGE_DOC_ID}}],
+                "must_not": [{"ids": {"values": [FIRST_DOC_PART_ID]}}],
+            }
+        }
+    });
+
+    assert_eq!(query, comparable_query);
+    Ok(())
+}
+
+#[rstest]
+fn test_build_update_doc_parts_script() -> anyhow::Result<()> {
+    let params = UpdateDocumentParamsBuilder::default()
+        .file_name(DOC_FILE_NAME.to_string())
+        .file_path(DOC_FILE_PATH.to_string())
+        .file_size(DOC_FILE_SIZE)
+        .modified_at(DOC_FILE_TIMESTAMP)
+        .metadata(None)
+        .build()?;
+
+    let script = build_update_doc_parts_script(&params)?;
+    assert_eq!(script["lang"], json!("painless"));
+    assert_eq!(
+        script["params"],
+        json!({
+            "file_name": DOC_FILE_NAME,
+            "file_path": DOC_FILE_PATH,
+            "file_size": DOC_FILE_SIZE,
+            "modified_at": DOC_FILE_TIMESTAMP,
+            "metadata": null,
+        })
+    );
+
+    Ok(())
+}
+
+fn build_empty_filter_params() -> FilterParams {
+    FilterParamsBuilder::default()
+        .doc_part_id(None)
+        .size_from(None)
+        .size_to(None)
+        .created_from(None)
+        .created_to(None)
+        .modified_from(None)
+        .modified_to(None)
+        .build()
+        .expect("failed to build empty filter params")
+}
+
+#[rstest]
+#[case::modified_from(
+    FilterParams { modified_from: Some(CURRENT_TIMESTAMP), ..build_empty_filter_params() },
+    json!([{"range": {"modified_at": {"gte": CURRENT_TIMESTAMP}}}]),
+)]
+#[case::modified_to(
+    FilterParams { modified_to: Some(CURRENT_TIMESTAMP), ..build_empty_filter_params() },
+    json!([{"range": {"modified_at": {"lte": CURRENT_TIMESTAMP}}}]),
+)]
+#[case::doc_part_id(
+    FilterParams { doc_part_id: Some(2), ..build_empty_filter_params() },
+    json!([{"term": {"doc_part_id": 2}}]),
+)]
+#[case::pipeline_id(
+    FilterParams { pipeline_id: Some(PIPELINE_ID_FILTER_PARAMS), ..build_empty_filter_params() },
+    json!([{"term": {"metadata.pipeline_id": PIPELINE_ID_FILTER_PARAMS}}]),
+)]
+#[case::doc_class(
+    FilterParams {
+        doc_class: Some(DOCUMENT_CLASS_FILTER_PARAMS.to_string()),
+        ..build_empty_filter_params()
+    },
+    json!([{
+        "nested": {
+            "path": "metadata.classes",
+            "query": {
+                "bool": {
+                    "must": [{"term": {"metadata.classes.name": DOCUMENT_CLASS_FILTER_PARAMS}}]
+                }
+            }
+        }
+    }]),
+)]
+#[case::doc_class_probability(
+    FilterParams {
+        doc_class_probability: Some(DOCUMENT_CLASS_PROBABILITY_FILTER_PARAMS),
+        ..build_empty_filter_params()
+    },
+    json!([{
+        "nested": {
+            "path": "metadata.classes",
+            "query": {
+                "bool": {
+                    "must": [{
+                        "range": {
+                            "metadata.classes.probability": {
+                                "gte": DOCUMENT_CLASS_PROBABILITY_FILTER_PARAMS
+                            }
+                        }
+                    }]
+                }
+            }
+        }
+    }]),
+)]
+#[case::doc_class_with_probability(
+    FilterParams {
+        doc_class: Some(DOCUMENT_CLASS_FILTER_PARAMS.to_string()),
+        doc_class_probability: Some(DOCUMENT_CLASS_PROBABILITY_FILTER_PARAMS),
+        ..build_empty_filter_params()
+    },
+    json!([{
+        "nested": {
+            "path": "metadata.classes",
+            "query": {
+                "bool": {
+                    "must": [
+                        {"term": {"metadata.classes.name": DOCUMENT_CLASS_FILTER_PARAMS}},
+                        {
+                            "range": {
+                                "metadata.classes.probability": {
+                                    "gte": DOCUMENT_CLASS_PROBABILITY_FILTER_PARAMS
+                                }
+                            }
+                        }
+                    ]
+                }
+            }
+        }
+    }]),
+)]
+fn test_build_filter_query_per_field(
+    #[case] filter: FilterParams,
+    #[case] expected_filter: Value,
+) -> anyhow::Result<()> {
+    let query_params = RetrieveIndexDocsQueryParamsBuilder::default()
+        .path(None)
+        .result(build_result_params())
+        .filter(Some(filter))
+        .build()
+        .context("failed to build retrieve query params")?;
+
+    let query = query_params.build_query();
+    assert_eq!(query["query"]["bool"]["filter"], expected_filter);
+
+    Ok(())
+}
+
+#[rstest]
+fn test_build_search_query_with_aggregations(
+    #[from(build_simple_retrieve_params)] params: RetrieveIndexDocumentsParams,
+) -> anyhow::Result<()> {
+    let knn_config = serde_json::from_value::<OSearchKnnConfig>(json!({
+        "model_id": "model-id",
+        "knn_dimension": 384,
+        "token_limit": 700,
+        "overlap_rate": 0.2,
+        "knn_ef_searcher": null,
+    }))?;
+
+    let aggregations = AggregationParamsBuilder::default()
+        .facets(vec![
+            FacetKind::Source,
+            FacetKind::Classes,
+            FacetKind::CreatedAt,
+        ])
+        .size(5)
+        .interval(HistogramInterval::Week)
+        .build()
+        .context("failed to build aggregation params")?;
+
+    let kind = SearchKindParams::Retrieve(params);
+    let searching_params = SearchingParams::new(vec![], kind, build_result_params(), None)
+        .with_aggregations(Some(aggregations));
+
+    let query = build_search_query(&searching_params, &knn_config)?;
+    let expected_aggs = json!({
+        "source": {
+            "terms": {"field": "metadata.source", "size": 5}
+        },
+        "classes": {
+            "nested": {"path": "metadata.classes"},
+            "aggs": {
+                "values": {
+                    "terms": {"field": "metadata.classes.name", "size": 5},
+                    "aggs": {"documents": {"reverse_nested": {}}}
+                }
+            }
+        },
+        "created_at": {
+            "date_histogram": {
+                "field": "created_at",
+                "calendar_interval": "week",
+                "format": "epoch_second",
+                "min_doc_count": 1
+            }
+        }
+    });
+
+    assert_eq!(query["aggs"], expected_aggs);
+
+    let searching_params = SearchingParams::new(
+        vec![],
+        SearchKindParams::Retrieve(build_simple_retrieve_params()),
+        build_result_params(),
+        None,
+    );
+
+    let query = build_search_query(&searching_params, &knn_config)?;
+    assert_eq!(query.get("aggs"), None);
+
+    Ok(())
+}
+
+#[rstest]
+fn test_build_search_query_grouped_by_document(
+    #[from(build_with_query_fulltext_params)] params: FullTextSearchingParams,
+) -> anyhow::Result<()> {
+    let knn_config = serde_json::from_value::<OSearchKnnConfig>(json!({
+        "model_id": "model-id",
+        "knn_dimension": 384,
+        "token_limit": 700,
+        "overlap_rate": 0.2,
+        "knn_ef_searcher": null,
+    }))?;
+
+    let mut result = build_result_params();
+    result.group_by_document = Some(true);
+    let kind = SearchKindParams::FullText(params);
+    let searching_params = SearchingParams::new(vec![], kind, result, None);
+
+    let query = build_search_query(&searching_params, &knn_config)?;
+    let collapse = &query["collapse"];
+    assert_eq!(collapse["field"], json!("large_doc_id"));
+    assert_eq!(collapse["inner_hits"]["name"], json!("matched_parts"));
+    assert_eq!(collapse["inner_hits"]["_source"], query["_source"]);
+    assert_eq!(collapse["inner_hits"]["highlight"], query["highlight"]);
+
+    let kind = SearchKindParams::FullText(build_with_query_fulltext_params());
+    let searching_params = SearchingParams::new(vec![], kind, build_result_params(), None);
+    let query = build_search_query(&searching_params, &knn_config)?;
+    assert_eq!(query.get("collapse"), None);
+
+    Ok(())
+}
+
+#[rstest]
+fn test_build_search_query_with_total_hits_tracked(
+    #[from(build_with_query_fulltext_params)] params: FullTextSearchingParams,
+) -> anyhow::Result<()> {
+    let knn_config = serde_json::from_value::<OSearchKnnConfig>(json!({
+        "model_id": "model-id",
+        "knn_dimension": 384,
+        "token_limit": 700,
+        "overlap_rate": 0.2,
+        "knn_ef_searcher": null,
+    }))?;
+
+    let mut result = build_result_params();
+    result.track_total_hits = Some(true);
+    let kind = SearchKindParams::FullText(params);
+    let searching_params = SearchingParams::new(vec![], kind, result, None);
+    let query = build_search_query(&searching_params, &knn_config)?;
+    assert_eq!(query["track_total_hits"], json!(true));
+
+    let kind = SearchKindParams::FullText(build_with_query_fulltext_params());
+    let searching_params = SearchingParams::new(vec![], kind, build_result_params(), None);
+    let query = build_search_query(&searching_params, &knn_config)?;
+    assert_eq!(query.get("track_total_hits"), None);
+
+    Ok(())
+}
+
+#[rstest]
+fn test_build_search_query_with_syntax() -> anyhow::Result<()> {
+    let knn_config = serde_json::from_value::<OSearchKnnConfig>(json!({
+        "model_id": "model-id",
+        "knn_dimension": 384,
+        "token_limit": 700,
+        "overlap_rate": 0.2,
+        "knn_ef_searcher": null,
+    }))?;
+
+    let mut params = build_with_query_fulltext_params();
+    params.query = Some(
+        "\"exact phrase\" AND source:smith -draft~1 file_name:report* file_size:[* TO 1024}"
+            .to_string(),
+    );
+
+    let kind = SearchKindParams::FullText(params);
+    let searching_params = SearchingParams::new(vec![], kind, build_result_params(), None);
+    let query = build_search_query(&searching_params, &knn_config)?;
+    let expected = json!([{
+        "bool": {
+            "must": [
+                {"match_phrase": {"content": "exact phrase"}},
+                {"match": {"metadata.source": "smith"}},
+            ],
+            "should": [
+                {"wildcard": {"file_name": {"value": "report*", "case_insensitive": true}}},
+                {"range": {"file_size": {"lt": 1024}}},
+            ],
+            "must_not": [
+                {"match": {"content": {"query": "draft", "fuzziness": 1}}},
+            ],
+        }
+    }]);
+    assert_eq!(query["query"]["bool"]["must"], expected);
+
+    let mut params = build_with_query_fulltext_params();
+    params.query = Some("\"exact phrase AND".to_string());
+    let kind = SearchKindParams::FullText(params);
+    let searching_params = SearchingParams::new(vec![], kind, build_result_params(), None);
+    let result = build_search_query(&searching_params, &knn_config);
+    assert!(matches!(result, Err(OSearchError::ValidationError(_))));
+
+    Ok(())
+}
+
+#[rstest]
+fn test_build_search_query_with_sort(
+    #[from(build_simple_semantic_params)] params: SemanticSearchingParams,
+) -> anyhow::Result<()> {
+    let knn_config = serde_json::from_value::<OSearchKnnConfig>(json!({
+        "model_id": "model-id",
+        "knn_dimension": 384,
+        "token_limit": 700,
+        "overlap_rate": 0.2,
+        "knn_ef_searcher": null,
+    }))?;
+
+    let kind = SearchKindParams::Semantic(params);
+    let searching_params = SearchingParams::new(vec![], kind, build_result_params(), None);
+    let query = build_search_query(&searching_params, &knn_config)?;
+    assert_eq!(query.get("sort"), None);
+
+    let mut result = build_result_params();
+    result.sort = vec![
+        SortParams::new(SortField::FileName, ResultOrder::ASC),
+        SortParams::new(SortField::PipelineId, ResultOrder::DESC),
+    ];
+
+    let expected_sort = json!([
+        {"file_name.keyword": {"order": "asc"}},
+        {"metadata.pipeline_id": {"order": "desc"}},
+    ]);
+
+    let kind = SearchKindParams::Semantic(build_simple_semantic_params());
+    let searching_params = SearchingParams::new(vec![], kind, result.clone(), None);
+    let query = build_search_query(&searching_params, &knn_config)?;
+    assert_eq!(query["sort"], expected_sort);
+
+    let kind = SearchKindParams::FullText(build_with_query_fulltext_params());
+    let searching_params = SearchingParams::new(vec![], kind, result, None);
+    let query = build_search_query(&searching_params, &knn_config)?;
+    assert_eq!(query["sort"], expected_sort);
+
+    Ok(())
+}
+
+#[rstest]
+#[case(vec!["*"], None, "/_search")]
+#[case(vec!["*"], Some(vec!["team-*", "public"]), "/team-*,public/_search")]
+#[case(vec!["index-1", "index-2"], Some(vec!["team-*"]), "/index-1,index-2/_search")]
+fn test_build_search_parts(
+    #[case] indexes: Vec<&str>,
+    #[case] allowed_indexes: Option<Vec<&str>>,
+    #[case] expected_url: &str,
+) -> anyhow::Result<()> {
+    let search_parts = OSearchClient::build_search_parts(&indexes, allowed_indexes.as_deref());
+    assert_eq!(search_parts.url(), expected_url);
+    Ok(())
+}
+
+#[rstest]
+fn test_build_cursor_page_query() -> anyhow::Result<()> {
+    let query = json!({
+        "query": {"match_all": {}},
+        "sort": [{"created_at": {"order": "desc"}}],
+    });
+
+    let search_after = json!([1750957215, "part-id"]);
+    let page_query = build_cursor_page_query(&query, Some("pit-id"), Some(&search_after), 10, 5);
+    assert_eq!(
+        page_query["pit"],
+        json!({"id": "pit-id", "keep_alive": "5m"})
+    );
+    assert_eq!(page_query["search_after"], search_after);
+    let expected_sort = json!([{"created_at": {"order": "desc"}}, {"_shard_doc": "asc"}]);
+    assert_eq!(page_query["sort"], expected_sort);
+    assert_eq!(page_query["size"], json!(5));
+    assert_eq!(page_query.get("from"), None);
+
+    let query = json!({"query": {"match_all": {}}});
+    let page_query = build_cursor_page_query(&query, Some("pit-id"), None, 0, 5);
+    let expected_sort = json!([{"_score": {"order": "desc"}}, {"_shard_doc": "asc"}]);
+    assert_eq!(page_query["sort"], expected_sort);
+    assert_eq!(page_query.get("search_after"), None);
+
+    let page_query = build_cursor_page_query(&page_query, Some("pit-id"), None, 0, 5);
+    assert_eq!(page_query["sort"], expected_sort);
+
+    let query = json!({"query": {"hybrid": {"pagination_depth": 20, "queries": []}}});
+    let page_query = build_cursor_page_query(&query, None, None, 20, 10);
+    assert_eq!(page_query["query"]["hybrid"]["pagination_depth"], json!(30));
+    assert_eq!(page_query["from"], json!(20));
+    assert_eq!(page_query.get("pit"), None);
+    assert_eq!(page_query.get("sort"), None);
+
+    Ok(())
+}
+
+#[rstest]
+fn test_build_suggest_query() -> anyhow::Result<()> {
+    let params = SuggestParamsBuilder::default()
+        .indexes(vec!["test-folder".to_string()])
+        .query("war and pe".to_string())
+        .size(5)
+        .build()?;
+
+    let query = build_suggest_query(&params);
+    assert_eq!(query["size"], json!(20));
+    assert_eq!(query["query"]["multi_match"]["type"], json!("bool_prefix"));
+    assert_eq!(query["query"]["multi_match"]["query"], json!("war and pe"));
+
+    let highlight_fields = query["highlight"]["fields"]
+        .as_object()
+        .context("expected highlight fields")?;
+    assert!(highlight_fields.contains_key("file_name.suggest"));
+    assert!(highlight_fields.contains_key("content.suggest"));
+
+    let suggest = &query["suggest"];
+    assert_eq!(suggest["text"], json!("war and pe"));
+    assert_eq!(
+        suggest[SUGGEST_TERM_NAME]["term"]["field"],
+        json!("content")
+    );
+    assert_eq!(suggest[SUGGEST_PHRASE_NAME]["phrase"]["size"], json!(5));
+
+    Ok(())
+}
+
+#[rstest]
+fn test_build_similar_source_query() -> anyhow::Result<()> {
+    let source = SimilarSource::LargeDocument(LargeDocumentId(LARGE_DOC_ID.to_string()));
+    let query = build_similar_source_query(&source, None);
+    assert_eq!(query["query"]["term"]["large_doc_id"], json!(LARGE_DOC_ID));
+    assert_eq!(query["_source"]["exclude"], json!(["chunked_text"]));
+    assert_eq!(query.get("search_after"), None);
+
+    let query = build_similar_source_query(&source, Some(100));
+    assert_eq!(query["search_after"], json!([100]));
+    assert_eq!(query["sort"], json!([{"doc_part_id": {"order": "asc"}}]));
+
+    let source = SimilarSource::DocumentPart(DocumentPartId(FIRST_DOC_PART_ID.to_string()));
+    let query = build_similar_source_query(&source, None);
+    assert_eq!(query["query"]["ids"]["values"], json!([FIRST_DOC_PART_ID]));
+
+    Ok(())
+}
+
+fn build_similar_source_documents(
+    embeddings: Option<Value>,
+) -> anyhow::Result<Vec<SourceDocument>> {
+    let source_doc = serde_json::from_value::<SourceDocument>(json!({
+        "large_doc_id": LARGE_DOC_ID,
+        "doc_part_id": 1,
+        "file_name": DOC_FILE_NAME,
+        "file_path": DOC_FILE_PATH,
+        "file_size": DOC_FILE_SIZE,
+        "created_at": DOC_FILE_TIMESTAMP,
+        "modified_at": DOC_FILE_TIMESTAMP,
+        "content": "War and Peace",
+        "metadata": null,
+        "embeddings": embeddings,
+    }))?;
+
+    Ok(vec![source_doc])
+}
+
+#[rstest]
+fn test_build_similar_search_query() -> anyhow::Result<()> {
+    let embeddings = json!([{"knn": [1.0, 0.0]}, {"knn": [0.0, 1.0]}]);
+    let source_docs = build_similar_source_documents(Some(embeddings))?;
+    let source = SimilarSource::LargeDocument(LargeDocumentId(LARGE_DOC_ID.to_string()));
+
+    let kind =
+        SearchKindParams::Similar(build_similar_params(source.clone(), SimilarMode::Lexical));
+    let params = SearchingParams::new(vec![], kind, build_result_params(), None);
+    let query = build_similar_search_query(&params, &source_docs)?;
+    let bool_query = &query["query"]["bool"];
+    let more_like_this = &bool_query["must"][0]["more_like_this"];
+    assert_eq!(more_like_this["fields"], json!(["content"]));
+    assert_eq!(more_like_this["like"], json!(["War and Peace"]));
+    assert_eq!(
+        bool_query["must_not"][0]["term"]["large_doc_id"],
+        json!(LARGE_DOC_ID)
+    );
+
+    let kind = SearchKindParams::Similar(build_similar_params(source, SimilarMode::Vector));
+    let params = SearchingParams::new(vec![], kind, build_result_params(), None);
+    let query = build_similar_search_query(&params, &source_docs)?;
+    let knn_query = &query["query"]["bool"]["must"][0]["nested"]["query"]["knn"]["embeddings.knn"];
+    assert_eq!(knn_query["vector"], json!([0.5, 0.5]));
+    assert_eq!(knn_query["k"], json!(KNN_AMOUNT));
+
+    Ok(())
+}
+
+#[rstest]
+fn test_build_similar_search_query_bounds_like() -> anyhow::Result<()> {
+    let mut source_docs = Vec::new();
+    for _ in 0..3 {
+        let mut source_part = build_similar_source_documents(None)?.remove(0);
+        source_part.content = Some("war ".repeat(SIMILAR_MAX_LIKE_LENGTH / 8));
+        source_docs.push(source_part);
+    }
+
+    let source = SimilarSource::LargeDocument(LargeDocumentId(LARGE_DOC_ID.to_string()));
+    let kind = SearchKindParams::Similar(build_similar_params(source, SimilarMode::Lexical));
+    let params = SearchingParams::new(vec![], kind, build_result_params(), None);
+    let query = build_similar_search_query(&params, &source_docs)?;
+    let like = &query["query"]["bool"]["must"][0]["more_like_this"]["like"];
+    let like = serde_json::from_value::<Vec<String>>(like.clone())?;
+    assert_eq!(like.len(), 2);
+    assert_eq!(like.concat().chars().count(), SIMILAR_MAX_LIKE_LENGTH);
+
+    Ok(())
+}
+
+#[rstest]
+fn test_build_similar_search_query_errors() -> anyhow::Result<()> {
+    let source = SimilarSource::LargeDocument(LargeDocumentId(LARGE_DOC_ID.to_string()));
+    let kind =
+        SearchKindParams::Similar(build_similar_params(source.clone(), SimilarMode::Lexical));
+    let params = SearchingParams::new(vec![], kind, build_result_params(), None);
+    let result = build_similar_search_query(&params, &[]);
+    assert!(matches!(result, Err(OSearchError::DocumentNotFound(_))));
+
+    let source_docs = build_similar_source_documents(None)?;
+    let kind = SearchKindParams::Similar(build_similar_params(source, SimilarMode::Vector));
+    let params = SearchingParams::new(vec![], kind, build_result_params(), None);
+    let result = build_similar_search_query(&params, &source_docs);
+    assert!(matches!(result, Err(OSearchError::ValidationError(_))));
+
+    Ok(())
+}
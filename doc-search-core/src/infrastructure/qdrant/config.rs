@@ -0,0 +1,11 @@
+use gset::Getset;
+use serde_derive::Deserialize;
+
+#[derive(Clone, Debug, Deserialize, Getset)]
+pub struct QdrantConfig {
+    #[getset(get, vis = "pub")]
+    address: String,
+    #[serde(default)]
+    #[getset(get, vis = "pub")]
+    api_key: Option<String>,
+}
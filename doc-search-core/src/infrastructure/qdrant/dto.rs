@@ -0,0 +1,232 @@
+use anyhow::Context;
+use qdrant_client::Payload;
+use qdrant_client::qdrant::Value as QdrantValue;
+use serde_derive::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::domain::searcher::models::{DocumentPartEntrails, DocumentPartEntrailsBuilder};
+use crate::domain::storage::models::{DocumentPart, UpdateDocumentParams};
+use crate::infrastructure::qdrant::error::{QdrantError, QdrantResult};
+use crate::shared::kernel::LargeDocumentId;
+use crate::shared::kernel::metadata::{
+    DocumentClass, DocumentGroup, DocumentIcon, DocumentLocation, DocumentMetadata,
+    DocumentReference, DocumentSubject, PipelineLabel,
+};
+
+#[derive(Deserialize, Serialize)]
+pub struct PointPayload {
+    pub large_doc_id: String,
+    pub doc_part_id: usize,
+    pub file_name: String,
+    pub file_path: String,
+    pub file_size: u32,
+    pub created_at: i64,
+    pub modified_at: i64,
+    pub content: String,
+    pub metadata: Option<PayloadMetadata>,
+}
+
+/// Document level fields of points rewritten once the document is updated,
+/// while part content is kept as it is.
+#[derive(Serialize)]
+pub struct PayloadUpdate {
+    pub file_name: String,
+    pub file_path: String,
+    pub file_size: u32,
+    pub modified_at: i64,
+    pub metadata: Option<PayloadMetadata>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct PayloadMetadata {
+    pub pipeline_id: Option<i64>,
+    pub photo: Option<String>,
+    pub source: Option<String>,
+    pub semantic_source: Option<String>,
+    pub summary: Option<String>,
+    pub locations: Vec<PayloadLocation>,
+    pub subjects: Vec<String>,
+    pub classes: Vec<PayloadClass>,
+    pub icons: Vec<String>,
+    pub groups: Vec<String>,
+    pub pipelines: Vec<String>,
+    pub references: Vec<String>,
+}
+
+/// Coords are stored as `{lon, lat}` object to be usable by qdrant geo filters.
+#[derive(Deserialize, Serialize)]
+pub struct PayloadLocation {
+    pub name: String,
+    pub coords: GeoCoords,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct GeoCoords {
+    pub lon: f64,
+    pub lat: f64,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct PayloadClass {
+    pub name: String,
+    pub probability: f64,
+}
+
+impl PointPayload {
+    pub fn into_payload(self) -> QdrantResult<Payload> {
+        let value = serde_json::to_value(self)
+            .context("failed to serialize point payload")
+            .map_err(QdrantError::PayloadError)?;
+
+        Payload::try_from(value)
+            .context("point payload must be json object")
+            .map_err(QdrantError::PayloadError)
+    }
+
+    pub fn from_payload(payload: HashMap<String, QdrantValue>) -> QdrantResult<Self> {
+        let value = serde_json::Value::from(Payload::from(payload));
+        serde_json::from_value::<PointPayload>(value)
+            .context("failed to deserialize point payload")
+            .map_err(QdrantError::PayloadError)
+    }
+
+    pub fn into_entrails(self, with_content: bool) -> QdrantResult<DocumentPartEntrails> {
+        DocumentPartEntrailsBuilder::default()
+            .large_doc_id(LargeDocumentId(self.large_doc_id))
+            .doc_part_id(self.doc_part_id)
+            .file_name(self.file_name)
+            .file_path(self.file_path)
+            .file_size(self.file_size)
+            .created_at(self.created_at)
+            .modified_at(self.modified_at)
+            .content(with_content.then_some(self.content))
+            .chunked_text(None)
+            .embeddings(None)
+            .metadata(self.metadata.map(DocumentMetadata::from))
+            .build()
+            .context("failed to build document part entrails")
+            .map_err(QdrantError::PayloadError)
+    }
+}
+
+impl PayloadUpdate {
+    pub fn into_payload(self) -> QdrantResult<Payload> {
+        let value = serde_json::to_value(self)
+            .context("failed to serialize point payload update")
+            .map_err(QdrantError::PayloadError)?;
+
+        Payload::try_from(value)
+            .context("point payload update must be json object")
+            .map_err(QdrantError::PayloadError)
+    }
+}
+
+impl From<&UpdateDocumentParams> for PayloadUpdate {
+    fn from(params: &UpdateDocumentParams) -> Self {
+        PayloadUpdate {
+            file_name: params.file_name.clone(),
+            file_path: params.file_path.clone(),
+            file_size: params.file_size,
+            modified_at: params.modified_at,
+            metadata: params.metadata.clone().map(PayloadMetadata::from),
+        }
+    }
+}
+
+impl From<&DocumentPart> for PointPayload {
+    fn from(doc_part: &DocumentPart) -> Self {
+        PointPayload {
+            large_doc_id: doc_part.large_doc_id.0.clone(),
+            doc_part_id: doc_part.doc_part_id,
+            file_name: doc_part.file_name.clone(),
+            file_path: doc_part.file_path.clone(),
+            file_size: doc_part.file_size,
+            created_at: doc_part.created_at,
+            modified_at: doc_part.modified_at,
+            content: doc_part.content.clone(),
+            metadata: doc_part.metadata.clone().map(PayloadMetadata::from),
+        }
+    }
+}
+
+impl From<DocumentMetadata> for PayloadMetadata {
+    fn from(metadata: DocumentMetadata) -> Self {
+        let locations = metadata
+            .locations
+            .into_iter()
+            .map(|it| PayloadLocation {
+                name: it.name,
+                coords: GeoCoords {
+                    lon: it.longitude,
+                    lat: it.latitude,
+                },
+            })
+            .collect();
+
+        let classes = metadata
+            .classes
+            .into_iter()
+            .map(|it| PayloadClass {
+                name: it.name,
+                probability: it.probability,
+            })
+            .collect();
+
+        PayloadMetadata {
+            pipeline_id: metadata.pipeline_id,
+            photo: metadata.photo,
+            source: metadata.source,
+            semantic_source: metadata.semantic_source,
+            summary: metadata.summary,
+            locations,
+            subjects: metadata.subjects.into_iter().map(|it| it.0).collect(),
+            classes,
+            icons: metadata.icons.into_iter().map(|it| it.0).collect(),
+            groups: metadata.groups.into_iter().map(|it| it.0).collect(),
+            pipelines: metadata.pipelines.into_iter().map(|it| it.0).collect(),
+            references: metadata.references.into_iter().map(|it| it.0).collect(),
+        }
+    }
+}
+
+impl From<PayloadMetadata> for DocumentMetadata {
+    fn from(metadata: PayloadMetadata) -> Self {
+        let locations = metadata
+            .locations
+            .into_iter()
+            .map(|it| DocumentLocation {
+                name: it.name,
+                latitude: it.coords.lat,
+                longitude: it.coords.lon,
+            })
+            .collect();
+
+        let classes = metadata
+            .classes
+            .into_iter()
+            .map(|it| DocumentClass {
+                name: it.name,
+                probability: it.probability,
+            })
+            .collect();
+
+        DocumentMetadata {
+            pipeline_id: metadata.pipeline_id,
+            photo: metadata.photo,
+            source: metadata.source,
+            semantic_source: metadata.semantic_source,
+            summary: metadata.summary,
+            locations,
+            subjects: metadata.subjects.into_iter().map(DocumentSubject).collect(),
+            classes,
+            icons: metadata.icons.into_iter().map(DocumentIcon).collect(),
+            groups: metadata.groups.into_iter().map(DocumentGroup).collect(),
+            pipelines: metadata.pipelines.into_iter().map(PipelineLabel).collect(),
+            references: metadata
+                .references
+                .into_iter()
+                .map(DocumentReference)
+                .collect(),
+        }
+    }
+}
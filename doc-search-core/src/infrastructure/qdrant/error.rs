@@ -0,0 +1,51 @@
+use anyhow::anyhow;
+use thiserror::Error;
+
+use crate::domain::searcher::SearchError;
+use crate::domain::storage::StorageError;
+
+pub type QdrantResult<T> = Result<T, QdrantError>;
+
+#[derive(Debug, Error)]
+pub enum QdrantError {
+    #[error("collection has not been founded: {0}")]
+    CollectionNotFound(anyhow::Error),
+    #[error("unsupported searching kind: {0}")]
+    UnsupportedQuery(anyhow::Error),
+    #[error("failed to convert payload: {0}")]
+    PayloadError(anyhow::Error),
+    #[error("validation error: {0}")]
+    ValidationError(anyhow::Error),
+    #[error("execution error: {0}")]
+    ExecutionError(anyhow::Error),
+}
+
+impl From<qdrant_client::QdrantError> for QdrantError {
+    fn from(err: qdrant_client::QdrantError) -> Self {
+        QdrantError::ExecutionError(anyhow!(err))
+    }
+}
+
+impl From<QdrantError> for StorageError {
+    fn from(err: QdrantError) -> Self {
+        match err {
+            QdrantError::CollectionNotFound(err) => StorageError::IndexNotFound(err),
+            QdrantError::UnsupportedQuery(err) => StorageError::ValidationError(err),
+            QdrantError::PayloadError(err) => StorageError::InternalError(err),
+            QdrantError::ValidationError(err) => StorageError::ValidationError(err),
+            QdrantError::ExecutionError(err) => StorageError::InternalError(err),
+        }
+    }
+}
+
+impl From<QdrantError> for SearchError {
+    fn from(err: QdrantError) -> Self {
+        match err {
+            QdrantError::CollectionNotFound(err) => SearchError::IndexNotFound(err),
+            QdrantError::UnsupportedQuery(err) => SearchError::ValidationError(err),
+            QdrantError::PayloadError(err) => SearchError::InternalError(err),
+            QdrantError::ValidationError(err) => SearchError::ValidationError(err),
+            QdrantError::ExecutionError(err) => SearchError::ServiceError(err),
+        }
+    }
+}
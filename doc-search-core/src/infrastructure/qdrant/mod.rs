@@ -0,0 +1,315 @@
+#[cfg(test)]
+mod tests;
+
+mod config;
+mod dto;
+mod error;
+mod query;
+
+pub use config::QdrantConfig;
+pub use error::QdrantError;
+
+use anyhow::{Context, anyhow};
+use qdrant_client::Qdrant;
+use qdrant_client::qdrant::point_id::PointIdOptions;
+use qdrant_client::qdrant::{Condition, CreateCollectionBuilder, DeletePointsBuilder, Distance};
+use qdrant_client::qdrant::{Filter, PointId, PointStruct, QueryPointsBuilder, ScoredPoint};
+use qdrant_client::qdrant::{SetPayloadPointsBuilder, UpsertPointsBuilder, VectorParamsBuilder};
+use std::sync::Arc;
+use tracing::instrument;
+
+use crate::ServiceConnect;
+use crate::domain::searcher::models::{FoundedDocument, FoundedDocumentBuilder, Pagination};
+use crate::domain::searcher::models::{SearchKindParams, SearchingParams};
+use crate::domain::searcher::models::{SuggestParams, Suggestions};
+use crate::domain::searcher::{ISearcher, SearchError, SearchResult};
+use crate::domain::storage::models::{CreateIndexParams, DocumentPartVector, UpdateDocumentParams};
+use crate::domain::storage::{IDocumentVectorStorage, IIndexStorage, StorageError, StorageResult};
+use crate::infrastructure::qdrant::dto::{PayloadUpdate, PointPayload};
+use crate::infrastructure::qdrant::error::QdrantResult;
+use crate::shared::kernel::{IndexId, LargeDocumentId};
+
+/// Vector storage and semantic searcher over qdrant collections. Each index
+/// is stored as collection of document part points with payload.
+#[derive(Clone)]
+pub struct QdrantClient {
+    client: Arc<Qdrant>,
+}
+
+#[async_trait::async_trait]
+impl ServiceConnect for QdrantClient {
+    type Config = QdrantConfig;
+    type Client = QdrantClient;
+    type Error = QdrantError;
+
+    async fn connect(config: &Self::Config) -> Result<Self::Client, Self::Error> {
+        let client = Qdrant::from_url(config.address())
+            .api_key(config.api_key().clone())
+            .build()?;
+
+        tracing::info!(address=%config.address(), "connected to qdrant");
+        Ok(QdrantClient {
+            client: Arc::new(client),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl IIndexStorage for QdrantClient {
+    #[instrument(level = "info", skip(self))]
+    async fn create_index(&self, params: &CreateIndexParams) -> StorageResult<IndexId> {
+        let Some(knn) = params.knn.as_ref() else {
+            let err = anyhow!("knn params are required to create qdrant collection");
+            return Err(StorageError::ValidationError(err));
+        };
+
+        let vector_params = VectorParamsBuilder::new(knn.knn_dimension as u64, Distance::Cosine);
+        let request = CreateCollectionBuilder::new(&params.id).vectors_config(vector_params);
+        self.client
+            .create_collection(request)
+            .await
+            .map_err(QdrantError::from)?;
+
+        Ok(IndexId(params.id.to_owned()))
+    }
+
+    #[instrument(level = "info", skip(self))]
+    async fn delete_index(&self, index_id: &IndexId) -> StorageResult<()> {
+        self.check_collection_exists(index_id).await?;
+        self.client
+            .delete_collection(index_id.as_string())
+            .await
+            .map_err(QdrantError::from)?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "info", skip(self))]
+    async fn get_index(&self, index_id: &IndexId) -> StorageResult<IndexId> {
+        self.check_collection_exists(index_id).await?;
+        Ok(index_id.to_owned())
+    }
+
+    #[instrument(level = "info", skip_all)]
+    async fn get_all_indexes(&self) -> StorageResult<Vec<IndexId>> {
+        let mut indexes = self
+            .get_collections()
+            .await?
+            .into_iter()
+            .map(IndexId)
+            .collect::<Vec<IndexId>>();
+
+        indexes.sort_by(|a, b| a.0.cmp(&b.0));
+        Ok(indexes)
+    }
+}
+
+#[async_trait::async_trait]
+impl ISearcher for QdrantClient {
+    #[instrument(level = "info", skip(self))]
+    async fn search(&self, params: &SearchingParams) -> SearchResult<Pagination> {
+        let SearchKindParams::Semantic(semantic) = params.get_kind() else {
+            let msg = format!("{} searching is not supported by qdrant", params.get_kind());
+            return Err(QdrantError::UnsupportedQuery(anyhow!(msg)).into());
+        };
+
+        let Some(tokens) = semantic.tokens.as_ref() else {
+            let err = anyhow!("qdrant semantic searching requires query tokens");
+            return Err(SearchError::ValidationError(err));
+        };
+
+        let filter = params.get_filter().map(query::build_filter).transpose()?;
+        let result = params.get_result();
+        let offset = result.offset.max(0) as u64;
+        let size = result.size.max(0) as u64;
+        let limit = (offset + size).min(semantic.knn_amount as u64);
+        let vector = tokens.iter().map(|it| *it as f32).collect::<Vec<f32>>();
+        let with_content = result.include_extra_fields.unwrap_or_default();
+
+        let collections = match params.is_wildcard() {
+            true => self
+                .get_collections()
+                .await?
+                .into_iter()
+                .filter(|it| params.is_index_allowed(it))
+                .collect(),
+            false => params.get_indexes().to_vec(),
+        };
+
+        let mut founded = Vec::new();
+        let mut took = 0.0;
+        for collection in collections {
+            let mut request = QueryPointsBuilder::new(&collection)
+                .query(vector.clone())
+                .limit(limit)
+                .with_payload(true);
+
+            if let Some(filter) = filter.clone() {
+                request = request.filter(filter);
+            }
+
+            if let Some(min_score) = semantic.min_score {
+                request = request.score_threshold(min_score);
+            }
+
+            let response = self
+                .client
+                .query(request)
+                .await
+                .map_err(QdrantError::from)?;
+
+            took += response.time;
+            for point in response.result {
+                founded.push(Self::build_founded_document(
+                    &collection,
+                    point,
+                    with_content,
+                )?);
+            }
+        }
+
+        founded.sort_by(|a, b| {
+            b.score
+                .partial_cmp(&a.score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        // Qdrant does not count matched points, so only the best score is known.
+        let max_score = founded.first().and_then(|it| it.score);
+        let founded = founded
+            .into_iter()
+            .take(limit as usize)
+            .skip(offset as usize)
+            .collect();
+
+        let paginated = Pagination::new(None, founded)
+            .with_total(None, max_score)
+            .with_took(Some((took * 1000.0) as u64));
+
+        Ok(paginated)
+    }
+
+    #[instrument(level = "info", skip(self))]
+    async fn suggest(&self, _params: &SuggestParams) -> SearchResult<Suggestions> {
+        let msg = "suggestions are not supported by qdrant";
+        Err(QdrantError::UnsupportedQuery(anyhow!(msg)).into())
+    }
+}
+
+#[async_trait::async_trait]
+impl IDocumentVectorStorage for QdrantClient {
+    /// Stores document parts with their embeddings as qdrant points. Points
+    /// with the same ids are overwritten.
+    #[instrument(level = "info", skip_all, fields(index_id = index_id.0, amount = vectors.len()))]
+    async fn store_document_vectors(
+        &self,
+        index_id: &IndexId,
+        vectors: Vec<DocumentPartVector>,
+    ) -> StorageResult<()> {
+        let points = vectors
+            .into_iter()
+            .map(|it| {
+                let payload = PointPayload::from(&it.document).into_payload()?;
+                let vector = it
+                    .vector
+                    .into_iter()
+                    .map(|v| v as f32)
+                    .collect::<Vec<f32>>();
+                Ok(PointStruct::new(it.id.0, vector, payload))
+            })
+            .collect::<QdrantResult<Vec<PointStruct>>>()?;
+
+        let request = UpsertPointsBuilder::new(index_id.as_string(), points).wait(true);
+        self.client
+            .upsert_points(request)
+            .await
+            .map_err(QdrantError::from)?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "info", skip(self))]
+    async fn update_document_vectors(
+        &self,
+        index_id: &IndexId,
+        large_doc_id: &LargeDocumentId,
+        params: &UpdateDocumentParams,
+    ) -> StorageResult<()> {
+        let payload = PayloadUpdate::from(params).into_payload()?;
+        let condition = Condition::matches("large_doc_id", large_doc_id.0.clone());
+        let request = SetPayloadPointsBuilder::new(index_id.as_string(), payload)
+            .points_selector(Filter::must([condition]))
+            .wait(true);
+
+        self.client
+            .set_payload(request)
+            .await
+            .map_err(QdrantError::from)?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "info", skip(self))]
+    async fn delete_document_vectors(
+        &self,
+        index_id: &IndexId,
+        large_doc_id: &LargeDocumentId,
+    ) -> StorageResult<()> {
+        let condition = Condition::matches("large_doc_id", large_doc_id.0.clone());
+        let request = DeletePointsBuilder::new(index_id.as_string())
+            .points(Filter::must([condition]))
+            .wait(true);
+
+        self.client
+            .delete_points(request)
+            .await
+            .map_err(QdrantError::from)?;
+
+        Ok(())
+    }
+}
+
+impl QdrantClient {
+    async fn check_collection_exists(&self, index_id: &IndexId) -> QdrantResult<()> {
+        let is_exists = self.client.collection_exists(index_id.as_string()).await?;
+        if !is_exists {
+            let err = anyhow!("there is no collection with such name: {index_id:?}");
+            return Err(QdrantError::CollectionNotFound(err));
+        }
+
+        Ok(())
+    }
+
+    async fn get_collections(&self) -> QdrantResult<Vec<String>> {
+        let response = self.client.list_collections().await?;
+        let collections = response.collections.into_iter().map(|it| it.name).collect();
+
+        Ok(collections)
+    }
+
+    fn build_founded_document(
+        collection: &str,
+        point: ScoredPoint,
+        with_content: bool,
+    ) -> SearchResult<FoundedDocument> {
+        let id = point.id.map(Self::point_id_to_string).unwrap_or_default();
+        let payload = PointPayload::from_payload(point.payload)?;
+        FoundedDocumentBuilder::default()
+            .id(id)
+            .index(collection.to_owned())
+            .score(Some(point.score as f64))
+            .highlight(Vec::default())
+            .document(payload.into_entrails(with_content)?)
+            .build()
+            .context("failed to build founded document")
+            .map_err(SearchError::InternalError)
+    }
+
+    fn point_id_to_string(point_id: PointId) -> String {
+        match point_id.point_id_options {
+            Some(PointIdOptions::Uuid(id)) => id,
+            Some(PointIdOptions::Num(id)) => id.to_string(),
+            None => String::default(),
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use qdrant_client::qdrant::{Condition, Filter, GeoPoint, GeoRadius, Range};
+
+use crate::domain::searcher::models::FilterParams;
+use crate::infrastructure::qdrant::error::{QdrantError, QdrantResult};
+
+pub const LOCATION_COORDS_KEY: &str = "metadata.locations[].coords";
+pub const CLASSES_KEY: &str = "metadata.classes";
+
+pub fn build_filter(params: &FilterParams) -> QdrantResult<Filter> {
+    let mut conditions = Vec::new();
+
+    if let Some(doc_part_id) = params.doc_part_id {
+        conditions.push(Condition::matches("doc_part_id", doc_part_id as i64));
+    }
+
+    if let Some(pipeline_id) = params.pipeline_id {
+        conditions.push(Condition::matches("metadata.pipeline_id", pipeline_id));
+    }
+
+    if let Some(source) = params.source.as_ref() {
+        conditions.push(Condition::matches("metadata.source", source.to_owned()));
+    }
+
+    if let Some(semantic_source) = params.semantic_source.as_ref() {
+        let condition = Condition::matches("metadata.semantic_source", semantic_source.to_owned());
+        conditions.push(condition);
+    }
+
+    let size_range = build_range(
+        params.size_from.map(f64::from),
+        params.size_to.map(f64::from),
+    );
+    let created_range = build_range(
+        params.created_from.map(|it| it as f64),
+        params.created_to.map(|it| it as f64),
+    );
+    let modified_range = build_range(
+        params.modified_from.map(|it| it as f64),
+        params.modified_to.map(|it| it as f64),
+    );
+
+    conditions.extend(size_range.map(|it| Condition::range("file_size", it)));
+    conditions.extend(created_range.map(|it| Condition::range("created_at", it)));
+    conditions.extend(modified_range.map(|it| Condition::range("modified_at", it)));
+
+    if params.doc_class.is_some() || params.doc_class_probability.is_some() {
+        let mut class_conditions = Vec::new();
+        if let Some(doc_class) = params.doc_class.as_ref() {
+            class_conditions.push(Condition::matches("name", doc_class.to_owned()));
+        }
+
+        if let Some(probability) = params.doc_class_probability {
+            let range = Range {
+                gte: Some(probability),
+                ..Default::default()
+            };
+            class_conditions.push(Condition::range("probability", range));
+        }
+
+        conditions.push(Condition::nested(
+            CLASSES_KEY,
+            Filter::must(class_conditions),
+        ));
+    }
+
+    let location = params
+        .location_filter()
+        .map_err(QdrantError::ValidationError)?;
+
+    if let Some(location) = location {
+        let geo_radius = GeoRadius {
+            center: Some(GeoPoint {
+                lon: location.longitude,
+                lat: location.latitude,
+            }),
+            radius: location.radius as f32,
+        };
+
+        conditions.push(Condition::geo_radius(LOCATION_COORDS_KEY, geo_radius));
+    }
+
+    Ok(Filter::must(conditions))
+}
+
+fn build_range(from: Option<f64>, to: Option<f64>) -> Option<Range> {
+    if from.is_none() && to.is_none() {
+        return None;
+    }
+
+    Some(Range {
+        gte: from,
+        lte: to,
+        ..Default::default()
+    })
+}
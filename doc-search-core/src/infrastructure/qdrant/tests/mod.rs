@@ -0,0 +1,2 @@
+mod test_dto;
+mod test_query;
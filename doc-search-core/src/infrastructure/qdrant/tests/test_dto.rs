@@ -0,0 +1,29 @@
+use rstest::rstest;
+
+use crate::application::tests::fixture::document::build_large_document;
+use crate::domain::storage::models::LargeDocument;
+use crate::infrastructure::qdrant::dto::PointPayload;
+
+const MAX_CONTENT_SIZE: usize = 500;
+
+#[rstest]
+fn test_point_payload_conversion(
+    #[from(build_large_document)] document: LargeDocument,
+) -> anyhow::Result<()> {
+    let doc_parts = document.divide_large_document_on_parts(MAX_CONTENT_SIZE)?;
+    let doc_part = doc_parts.first().expect("there is no document parts");
+
+    let payload = PointPayload::from(doc_part).into_payload()?;
+    let restored = PointPayload::from_payload(payload.into())?;
+    let entrails = restored.into_entrails(true)?;
+
+    assert_eq!(entrails.large_doc_id.0, doc_part.large_doc_id.0);
+    assert_eq!(entrails.doc_part_id, doc_part.doc_part_id);
+    assert_eq!(entrails.content.as_ref(), Some(&doc_part.content));
+
+    let metadata = entrails.metadata.expect("metadata has been lost");
+    let expected = doc_part.metadata.as_ref().expect("fixture has metadata");
+    assert_eq!(metadata.locations.len(), expected.locations.len());
+    assert_eq!(metadata.classes.len(), expected.classes.len());
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use qdrant_client::qdrant::condition::ConditionOneOf;
+use qdrant_client::qdrant::{Condition, Filter};
+use rstest::rstest;
+
+use crate::domain::searcher::models::{FilterParams, FilterParamsBuilder};
+use crate::domain::searcher::tests::fixture::params::build_filter_searching_params;
+use crate::infrastructure::qdrant::query::{CLASSES_KEY, LOCATION_COORDS_KEY, build_filter};
+
+fn extract_condition_keys(filter: &Filter) -> Vec<String> {
+    filter
+        .must
+        .iter()
+        .filter_map(|it: &Condition| match it.condition_one_of.as_ref()? {
+            ConditionOneOf::Field(field) => Some(field.key.clone()),
+            ConditionOneOf::Nested(nested) => Some(nested.key.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[rstest]
+fn test_build_full_filter(
+    #[from(build_filter_searching_params)] params: FilterParams,
+) -> anyhow::Result<()> {
+    let filter = build_filter(&params)?;
+    let keys = extract_condition_keys(&filter);
+    let expected_keys = vec![
+        "doc_part_id",
+        "metadata.pipeline_id",
+        "metadata.source",
+        "metadata.semantic_source",
+        "file_size",
+        "created_at",
+        "modified_at",
+        CLASSES_KEY,
+        LOCATION_COORDS_KEY,
+    ];
+
+    assert_eq!(keys, expected_keys);
+    Ok(())
+}
+
+#[rstest]
+fn test_build_empty_filter() -> anyhow::Result<()> {
+    let params = FilterParamsBuilder::default()
+        .doc_part_id(None)
+        .size_from(None)
+        .size_to(None)
+        .created_from(None)
+        .created_to(None)
+        .modified_from(None)
+        .modified_to(None)
+        .build()?;
+
+    let filter = build_filter(&params)?;
+    assert!(filter.must.is_empty());
+    Ok(())
+}
+
+#[rstest]
+#[case(Some(vec![0.1]), None)]
+#[case(Some(vec![0.1, 0.2]), Some("5 parsecs"))]
+fn test_build_invalid_location_filter(
+    #[from(build_filter_searching_params)] mut params: FilterParams,
+    #[case] coords: Option<Vec<f64>>,
+    #[case] distance: Option<&str>,
+) {
+    params.location_coords = coords;
+    params.distance = distance.map(String::from);
+    assert!(build_filter(&params).is_err());
+}
@@ -0,0 +1,10 @@
+use gset::Getset;
+use serde_derive::Deserialize;
+
+#[derive(Clone, Debug, Deserialize, Getset)]
+pub struct TantivyConfig {
+    #[getset(get, vis = "pub")]
+    data_dir: String,
+    #[getset(get_copy, vis = "pub")]
+    writer_memory_size: usize,
+}
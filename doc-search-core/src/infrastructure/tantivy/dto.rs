@@ -0,0 +1,305 @@
+use anyhow::{Context, anyhow};
+use serde_derive::{Deserialize, Serialize};
+use tantivy::TantivyDocument;
+use tantivy::schema::{Field, Value};
+
+use crate::domain::searcher::models::{DocumentPartEntrails, DocumentPartEntrailsBuilder};
+use crate::domain::storage::models::{DocumentPart, DocumentPartBuilder, SplitterParams};
+use crate::infrastructure::tantivy::error::{TantivyError, TantivyResult};
+use crate::infrastructure::tantivy::schema::IndexFields;
+use crate::shared::kernel::LargeDocumentId;
+use crate::shared::kernel::metadata::{
+    DocumentClass, DocumentGroup, DocumentIcon, DocumentLocation, DocumentMetadata,
+    DocumentReference, DocumentSubject, PipelineLabel,
+};
+
+pub struct StoredDocumentPart {
+    pub id: String,
+    pub document: DocumentPart,
+}
+
+impl StoredDocumentPart {
+    pub fn into_document(self, fields: &IndexFields) -> TantivyResult<TantivyDocument> {
+        let doc_part = self.document;
+        let mut document = TantivyDocument::default();
+        document.add_text(fields.id, &self.id);
+        document.add_text(fields.large_doc_id, &doc_part.large_doc_id.0);
+        document.add_u64(fields.doc_part_id, doc_part.doc_part_id as u64);
+        document.add_text(fields.file_name, &doc_part.file_name);
+        document.add_text(fields.file_path, &doc_part.file_path);
+        document.add_u64(fields.file_size, doc_part.file_size as u64);
+        document.add_i64(fields.created_at, doc_part.created_at);
+        document.add_i64(fields.modified_at, doc_part.modified_at);
+        document.add_text(fields.content, &doc_part.content);
+
+        let Some(metadata) = doc_part.metadata else {
+            return Ok(document);
+        };
+
+        if let Some(pipeline_id) = metadata.pipeline_id {
+            document.add_i64(fields.pipeline_id, pipeline_id);
+        }
+
+        if let Some(source) = metadata.source.as_ref() {
+            document.add_text(fields.source, source);
+        }
+
+        if let Some(semantic_source) = metadata.semantic_source.as_ref() {
+            document.add_text(fields.semantic_source, semantic_source);
+        }
+
+        for class in metadata.classes.iter() {
+            document.add_text(fields.doc_class, &class.name);
+        }
+
+        let stored_metadata = StoredMetadata::from(metadata);
+        let metadata_json = serde_json::to_string(&stored_metadata)
+            .context("failed to serialize document metadata")
+            .map_err(TantivyError::ValidationError)?;
+
+        document.add_text(fields.metadata, metadata_json);
+        Ok(document)
+    }
+
+    pub fn from_document(fields: &IndexFields, document: &TantivyDocument) -> TantivyResult<Self> {
+        let metadata = match document
+            .get_first(fields.metadata)
+            .and_then(|it| it.as_str())
+        {
+            None => None,
+            Some(value) => serde_json::from_str::<StoredMetadata>(value)
+                .map(DocumentMetadata::from)
+                .ok(),
+        };
+
+        let doc_part = DocumentPartBuilder::default()
+            .large_doc_id(LargeDocumentId(extract_text(
+                document,
+                fields.large_doc_id,
+            )?))
+            .doc_part_id(extract_u64(document, fields.doc_part_id)? as usize)
+            .file_name(extract_text(document, fields.file_name)?)
+            .file_path(extract_text(document, fields.file_path)?)
+            .file_size(extract_u64(document, fields.file_size)? as u32)
+            .created_at(extract_i64(document, fields.created_at)?)
+            .modified_at(extract_i64(document, fields.modified_at)?)
+            .content(extract_text(document, fields.content)?)
+            .metadata(metadata)
+            .build()
+            .context("failed to build stored document part")
+            .map_err(TantivyError::ValidationError)?;
+
+        Ok(StoredDocumentPart {
+            id: extract_text(document, fields.id)?,
+            document: doc_part,
+        })
+    }
+
+    pub fn into_entrails(self, with_content: bool) -> TantivyResult<DocumentPartEntrails> {
+        let doc_part = self.document;
+        let content = with_content.then_some(doc_part.content);
+        DocumentPartEntrailsBuilder::default()
+            .large_doc_id(doc_part.large_doc_id)
+            .doc_part_id(doc_part.doc_part_id)
+            .file_name(doc_part.file_name)
+            .file_path(doc_part.file_path)
+            .file_size(doc_part.file_size)
+            .created_at(doc_part.created_at)
+            .modified_at(doc_part.modified_at)
+            .content(content)
+            .chunked_text(None)
+            .embeddings(None)
+            .metadata(doc_part.metadata)
+            .build()
+            .context("failed to build document part entrails")
+            .map_err(TantivyError::ValidationError)
+    }
+}
+
+fn extract_text(document: &TantivyDocument, field: Field) -> TantivyResult<String> {
+    document
+        .get_first(field)
+        .and_then(|it| it.as_str())
+        .map(String::from)
+        .ok_or_else(|| TantivyError::ValidationError(anyhow!("missing text field {field:?}")))
+}
+
+fn extract_u64(document: &TantivyDocument, field: Field) -> TantivyResult<u64> {
+    document
+        .get_first(field)
+        .and_then(|it| it.as_u64())
+        .ok_or_else(|| TantivyError::ValidationError(anyhow!("missing u64 field {field:?}")))
+}
+
+fn extract_i64(document: &TantivyDocument, field: Field) -> TantivyResult<i64> {
+    document
+        .get_first(field)
+        .and_then(|it| it.as_i64())
+        .ok_or_else(|| TantivyError::ValidationError(anyhow!("missing i64 field {field:?}")))
+}
+
+#[derive(Deserialize, Serialize)]
+struct StoredMetadata {
+    pipeline_id: Option<i64>,
+    photo: Option<String>,
+    source: Option<String>,
+    semantic_source: Option<String>,
+    summary: Option<String>,
+    locations: Vec<StoredLocation>,
+    subjects: Vec<String>,
+    classes: Vec<StoredClass>,
+    icons: Vec<String>,
+    groups: Vec<String>,
+    pipelines: Vec<String>,
+    references: Vec<String>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct StoredLocation {
+    name: String,
+    latitude: f64,
+    longitude: f64,
+}
+
+#[derive(Deserialize, Serialize)]
+struct StoredClass {
+    name: String,
+    probability: f64,
+}
+
+impl From<DocumentMetadata> for StoredMetadata {
+    fn from(metadata: DocumentMetadata) -> Self {
+        let locations = metadata
+            .locations
+            .into_iter()
+            .map(|it| StoredLocation {
+                name: it.name,
+                latitude: it.latitude,
+                longitude: it.longitude,
+            })
+            .collect();
+
+        let classes = metadata
+            .classes
+            .into_iter()
+            .map(|it| StoredClass {
+                name: it.name,
+                probability: it.probability,
+            })
+            .collect();
+
+        StoredMetadata {
+            pipeline_id: metadata.pipeline_id,
+            photo: metadata.photo,
+            source: metadata.source,
+            semantic_source: metadata.semantic_source,
+            summary: metadata.summary,
+            locations,
+            subjects: metadata.subjects.into_iter().map(|it| it.0).collect(),
+            classes,
+            icons: metadata.icons.into_iter().map(|it| it.0).collect(),
+            groups: metadata.groups.into_iter().map(|it| it.0).collect(),
+            pipelines: metadata.pipelines.into_iter().map(|it| it.0).collect(),
+            references: metadata.references.into_iter().map(|it| it.0).collect(),
+        }
+    }
+}
+
+impl From<StoredMetadata> for DocumentMetadata {
+    fn from(metadata: StoredMetadata) -> Self {
+        let locations = metadata
+            .locations
+            .into_iter()
+            .map(|it| DocumentLocation {
+                name: it.name,
+                latitude: it.latitude,
+                longitude: it.longitude,
+            })
+            .collect();
+
+        let classes = metadata
+            .classes
+            .into_iter()
+            .map(|it| DocumentClass {
+                name: it.name,
+                probability: it.probability,
+            })
+            .collect();
+
+        DocumentMetadata {
+            pipeline_id: metadata.pipeline_id,
+            photo: metadata.photo,
+            source: metadata.source,
+            semantic_source: metadata.semantic_source,
+            summary: metadata.summary,
+            locations,
+            subjects: metadata.subjects.into_iter().map(DocumentSubject).collect(),
+            classes,
+            icons: metadata.icons.into_iter().map(DocumentIcon).collect(),
+            groups: metadata.groups.into_iter().map(DocumentGroup).collect(),
+            pipelines: metadata.pipelines.into_iter().map(PipelineLabel).collect(),
+            references: metadata
+                .references
+                .into_iter()
+                .map(DocumentReference)
+                .collect(),
+        }
+    }
+}
+
+/// Splitter params persisted next to index files, since tantivy index
+/// meta does not keep any custom settings.
+#[derive(Deserialize, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum StoredSplitterParams {
+    Character { chunk_size: Option<usize> },
+    Sentence { chunk_size: Option<usize> },
+    Markdown { chunk_size: Option<usize> },
+    Token { token_limit: u32, overlap_rate: f32 },
+}
+
+impl From<SplitterParams> for StoredSplitterParams {
+    fn from(params: SplitterParams) -> Self {
+        match params {
+            SplitterParams::Character { chunk_size } => {
+                StoredSplitterParams::Character { chunk_size }
+            }
+            SplitterParams::Sentence { chunk_size } => {
+                StoredSplitterParams::Sentence { chunk_size }
+            }
+            SplitterParams::Markdown { chunk_size } => {
+                StoredSplitterParams::Markdown { chunk_size }
+            }
+            SplitterParams::Token {
+                token_limit,
+                overlap_rate,
+            } => StoredSplitterParams::Token {
+                token_limit,
+                overlap_rate,
+            },
+        }
+    }
+}
+
+impl From<StoredSplitterParams> for SplitterParams {
+    fn from(params: StoredSplitterParams) -> Self {
+        match params {
+            StoredSplitterParams::Character { chunk_size } => {
+                SplitterParams::Character { chunk_size }
+            }
+            StoredSplitterParams::Sentence { chunk_size } => {
+                SplitterParams::Sentence { chunk_size }
+            }
+            StoredSplitterParams::Markdown { chunk_size } => {
+                SplitterParams::Markdown { chunk_size }
+            }
+            StoredSplitterParams::Token {
+                token_limit,
+                overlap_rate,
+            } => SplitterParams::Token {
+                token_limit,
+                overlap_rate,
+            },
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use anyhow::anyhow;
+use thiserror::Error;
+
+use crate::domain::searcher::SearchError;
+use crate::domain::storage::StorageError;
+
+pub type TantivyResult<T> = Result<T, TantivyError>;
+
+#[derive(Debug, Error)]
+pub enum TantivyError {
+    #[error("index has not been founded: {0}")]
+    IndexNotFound(anyhow::Error),
+    #[error("index already exists: {0}")]
+    IndexAlreadyExists(anyhow::Error),
+    #[error("document has not been found: {0}")]
+    DocumentNotFound(anyhow::Error),
+    #[error("document already exists: {0}")]
+    DocumentAlreadyExists(anyhow::Error),
+    #[error("cursor context has not been found: {0}")]
+    CursorNotFound(anyhow::Error),
+    #[error("unsupported searching kind: {0}")]
+    UnsupportedQuery(anyhow::Error),
+    #[error("validation error: {0}")]
+    ValidationError(anyhow::Error),
+    #[error("execution error: {0}")]
+    ExecutionError(anyhow::Error),
+    #[error("io error: {0}")]
+    IoError(anyhow::Error),
+}
+
+impl From<tantivy::TantivyError> for TantivyError {
+    fn from(err: tantivy::TantivyError) -> Self {
+        TantivyError::ExecutionError(anyhow!(err))
+    }
+}
+
+impl From<std::io::Error> for TantivyError {
+    fn from(err: std::io::Error) -> Self {
+        TantivyError::IoError(anyhow!(err))
+    }
+}
+
+impl From<TantivyError> for StorageError {
+    fn from(err: TantivyError) -> Self {
+        match err {
+            TantivyError::IndexNotFound(err) => StorageError::IndexNotFound(err),
+            TantivyError::IndexAlreadyExists(err) => StorageError::IndexAlreadyExists(err),
+            TantivyError::DocumentNotFound(err) => StorageError::DocumentNotFound(err),
+            TantivyError::DocumentAlreadyExists(err) => StorageError::DocumentAlreadyExists(err),
+            TantivyError::CursorNotFound(err) => StorageError::InternalError(err),
+            TantivyError::UnsupportedQuery(err) => StorageError::ValidationError(err),
+            TantivyError::ValidationError(err) => StorageError::ValidationError(err),
+            TantivyError::ExecutionError(err) => StorageError::InternalError(err),
+            TantivyError::IoError(err) => StorageError::InternalError(err),
+        }
+    }
+}
+
+impl From<TantivyError> for SearchError {
+    fn from(err: TantivyError) -> Self {
+        match err {
+            TantivyError::IndexNotFound(err) => SearchError::IndexNotFound(err),
+            TantivyError::IndexAlreadyExists(err) => SearchError::InternalError(err),
+            TantivyError::DocumentNotFound(err) => SearchError::DocumentNotFound(err),
+            TantivyError::DocumentAlreadyExists(err) => SearchError::InternalError(err),
+            TantivyError::CursorNotFound(err) => SearchError::ValidationError(err),
+            TantivyError::UnsupportedQuery(err) => SearchError::ValidationError(err),
+            TantivyError::ValidationError(err) => SearchError::ValidationError(err),
+            TantivyError::ExecutionError(err) => SearchError::ServiceError(err),
+            TantivyError::IoError(err) => SearchError::InternalError(err),
+        }
+    }
+}
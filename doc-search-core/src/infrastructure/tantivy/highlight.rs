@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use tantivy::query::Query;
+use tantivy::schema::Field;
+use tantivy::snippet::SnippetGenerator;
+use tantivy::{DocAddress, Searcher, TantivyDocument};
+
+use crate::domain::searcher::models::FoundedDocument;
+use crate::infrastructure::tantivy::error::TantivyResult;
+
+/// Builds highlights of the returned page only: matched documents of all
+/// searched indexes are remembered by address while snippets are generated
+/// once the page is cut out of sorted and grouped results.
+pub struct PageHighlighter {
+    fragment_size: usize,
+    sources: Vec<(Searcher, Box<dyn Query>, Field)>,
+    addresses: HashMap<(String, String), (usize, DocAddress)>,
+    grouped_parts: HashMap<(String, String), Vec<String>>,
+}
+
+impl PageHighlighter {
+    pub fn new(fragment_size: usize) -> Self {
+        PageHighlighter {
+            fragment_size,
+            sources: Vec::new(),
+            addresses: HashMap::new(),
+            grouped_parts: HashMap::new(),
+        }
+    }
+
+    /// Registers searcher and query of the next searched index, so matched
+    /// documents added after it are highlighted by them.
+    pub fn add_source(&mut self, searcher: Searcher, query: Box<dyn Query>, field: Field) {
+        self.sources.push((searcher, query, field));
+    }
+
+    pub fn add_document(&mut self, index: &str, id: &str, address: DocAddress) {
+        let position = self.sources.len().saturating_sub(1);
+        let key = (index.to_owned(), id.to_owned());
+        self.addresses.insert(key, (position, address));
+    }
+
+    /// Remembers parts of large documents before they are collapsed into a
+    /// single result, since the result is highlighted by all of its parts.
+    pub fn add_grouped_parts(&mut self, founded: &[FoundedDocument]) {
+        for doc in founded.iter() {
+            let key = (doc.index.clone(), doc.document.large_doc_id.0.clone());
+            self.grouped_parts
+                .entry(key)
+                .or_default()
+                .push(doc.id.clone());
+        }
+    }
+
+    pub fn highlight(&self, page: &mut [FoundedDocument]) -> TantivyResult<()> {
+        let mut generators = HashMap::new();
+        for doc in page.iter_mut() {
+            let mut part_ids = vec![doc.id.clone()];
+            let group_key = (doc.index.clone(), doc.document.large_doc_id.0.clone());
+            if let Some(ids) = self.grouped_parts.get(&group_key) {
+                part_ids.extend(ids.iter().filter(|it| **it != doc.id).cloned());
+            }
+
+            for part_id in part_ids {
+                let address_key = (doc.index.clone(), part_id);
+                let Some(&(position, address)) = self.addresses.get(&address_key) else {
+                    continue;
+                };
+
+                let (searcher, query, field) = &self.sources[position];
+                let generator = match generators.entry(position) {
+                    Entry::Occupied(it) => it.into_mut(),
+                    Entry::Vacant(it) => {
+                        let mut generator =
+                            SnippetGenerator::create(searcher, query.as_ref(), *field)?;
+                        generator.set_max_num_chars(self.fragment_size);
+                        it.insert(generator)
+                    }
+                };
+
+                let document = searcher.doc::<TantivyDocument>(address)?;
+                let snippet = generator.snippet_from_doc(&document);
+                if snippet.is_empty() {
+                    continue;
+                }
+
+                let fragment = snippet.fragment().to_string();
+                if !doc.highlight.contains(&fragment) {
+                    doc.highlight.push(fragment);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,163 @@
+use anyhow::anyhow;
+use std::path::Path;
+use std::sync::Mutex;
+use tantivy::collector::Count;
+use tantivy::query::TermQuery;
+use tantivy::schema::IndexRecordOption;
+use tantivy::{Index, IndexReader, IndexWriter, ReloadPolicy, TantivyDocument, Term};
+
+use crate::domain::storage::models::SplitterParams;
+use crate::infrastructure::tantivy::dto::StoredSplitterParams;
+use crate::infrastructure::tantivy::error::{TantivyError, TantivyResult};
+use crate::infrastructure::tantivy::schema::{self, IndexFields};
+
+const WRITER_THREADS_NUM: usize = 1;
+const SPLITTER_FILE_NAME: &str = "splitter.json";
+
+pub struct IndexHandle {
+    index: Index,
+    reader: IndexReader,
+    writer: Mutex<IndexWriter>,
+    fields: IndexFields,
+    splitter: Option<SplitterParams>,
+}
+
+impl IndexHandle {
+    pub fn create(
+        path: &Path,
+        writer_memory_size: usize,
+        splitter: Option<SplitterParams>,
+    ) -> TantivyResult<Self> {
+        std::fs::create_dir_all(path)?;
+        let index = Index::create_in_dir(path, schema::build_index_schema())?;
+        if let Some(params) = splitter.clone() {
+            let data = serde_json::to_vec(&StoredSplitterParams::from(params))
+                .map_err(|err| TantivyError::ExecutionError(anyhow!(err)))?;
+            std::fs::write(path.join(SPLITTER_FILE_NAME), data)?;
+        }
+
+        Self::from_index(index, writer_memory_size, splitter)
+    }
+
+    pub fn open(path: &Path, writer_memory_size: usize) -> TantivyResult<Self> {
+        let index = Index::open_in_dir(path)?;
+        let splitter_path = path.join(SPLITTER_FILE_NAME);
+        let splitter = match splitter_path.exists() {
+            false => None,
+            true => {
+                let data = std::fs::read(splitter_path)?;
+                let params = serde_json::from_slice::<StoredSplitterParams>(&data)
+                    .map_err(|err| TantivyError::ValidationError(anyhow!(err)))?;
+                Some(params.into())
+            }
+        };
+
+        Self::from_index(index, writer_memory_size, splitter)
+    }
+
+    pub fn index(&self) -> &Index {
+        &self.index
+    }
+
+    pub fn reader(&self) -> &IndexReader {
+        &self.reader
+    }
+
+    pub fn fields(&self) -> &IndexFields {
+        &self.fields
+    }
+
+    pub fn splitter(&self) -> Option<&SplitterParams> {
+        self.splitter.as_ref()
+    }
+
+    /// Drops every document matching `term` and adds `documents` within a
+    /// single commit, so readers never observe a partially replaced state.
+    /// Deletion is rolled back if any document fails to be added.
+    pub fn replace_documents(
+        &self,
+        term: Term,
+        documents: Vec<TantivyDocument>,
+    ) -> TantivyResult<()> {
+        let mut writer = self.lock_writer()?;
+        writer.delete_term(term);
+        for document in documents {
+            if let Err(err) = writer.add_document(document) {
+                writer.rollback()?;
+                return Err(err.into());
+            }
+        }
+
+        self.commit(&mut writer)
+    }
+
+    /// Adds `documents` only if no live document matches `term`. Reader is
+    /// reloaded by every commit under the writer lock, so the check can not
+    /// miss document added concurrently.
+    pub fn create_documents(
+        &self,
+        term: Term,
+        documents: Vec<TantivyDocument>,
+    ) -> TantivyResult<()> {
+        let mut writer = self.lock_writer()?;
+        let query = TermQuery::new(term.clone(), IndexRecordOption::Basic);
+        if self.reader.searcher().search(&query, &Count)? > 0 {
+            let err = anyhow!("document {:?} already exists", term.value().as_str());
+            return Err(TantivyError::DocumentAlreadyExists(err));
+        }
+
+        for document in documents {
+            if let Err(err) = writer.add_document(document) {
+                writer.rollback()?;
+                return Err(err.into());
+            }
+        }
+
+        self.commit(&mut writer)
+    }
+
+    pub fn delete_documents(&self, term: Term) -> TantivyResult<()> {
+        let mut writer = self.lock_writer()?;
+        writer.delete_term(term);
+        self.commit(&mut writer)
+    }
+
+    fn from_index(
+        index: Index,
+        writer_memory_size: usize,
+        splitter: Option<SplitterParams>,
+    ) -> TantivyResult<Self> {
+        let fields = IndexFields::from_schema(&index.schema())?;
+        let writer = index.writer_with_num_threads(WRITER_THREADS_NUM, writer_memory_size)?;
+        let reader = index
+            .reader_builder()
+            .reload_policy(ReloadPolicy::Manual)
+            .try_into()?;
+
+        Ok(IndexHandle {
+            index,
+            reader,
+            writer: Mutex::new(writer),
+            fields,
+            splitter,
+        })
+    }
+
+    fn lock_writer(&self) -> TantivyResult<std::sync::MutexGuard<'_, IndexWriter>> {
+        self.writer
+            .lock()
+            .map_err(|err| TantivyError::ExecutionError(anyhow!("writer lock poisoned: {err}")))
+    }
+
+    /// Staged operations are rolled back once commit fails, otherwise the
+    /// next commit of any caller would apply them.
+    fn commit(&self, writer: &mut IndexWriter) -> TantivyResult<()> {
+        if let Err(err) = writer.commit() {
+            writer.rollback()?;
+            return Err(err.into());
+        }
+
+        self.reader.reload()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,855 @@
+#[cfg(test)]
+mod tests;
+
+mod config;
+mod dto;
+mod error;
+mod highlight;
+mod index;
+mod query;
+mod schema;
+mod suggest;
+
+pub use config::TantivyConfig;
+pub use error::TantivyError;
+
+#[cfg(feature = "enable-unique-doc-id")]
+use crate::application::usecase::storage::gen_unique_document_id;
+use anyhow::{Context, anyhow};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
+use tantivy::collector::{Count, TopDocs};
+use tantivy::query::TermQuery;
+use tantivy::schema::IndexRecordOption;
+use tantivy::{DocAddress, Order, TantivyDocument, Term};
+use tracing::instrument;
+
+use crate::ServiceConnect;
+use crate::domain::searcher::models::{
+    FoundedDocument, FoundedDocumentBuilder, Pagination, PaginationParams, ResultOrder,
+    SearchKindParams, SearchingParams, SimilarMode, SimilarSearchingParams, SimilarSource,
+    SortField, SuggestParams, Suggestions, TotalHits, TotalHitsRelation,
+};
+use crate::domain::searcher::{IPaginator, ISearcher};
+use crate::domain::searcher::{SearchError, SearchResult};
+use crate::domain::storage::models::SplitterParams;
+use crate::domain::storage::models::StoredDocumentPartsInfoBuilder;
+use crate::domain::storage::models::UpdateDocumentParams;
+use crate::domain::storage::models::{AllDocumentParts, DocumentPart};
+use crate::domain::storage::models::{CreateIndexParams, StoredDocumentPartsInfo};
+use crate::domain::storage::{IDocumentPartStorage, IIndexStorage};
+use crate::domain::storage::{StorageError, StorageResult};
+use crate::infrastructure::tantivy::dto::StoredDocumentPart;
+use crate::infrastructure::tantivy::error::TantivyResult;
+use crate::infrastructure::tantivy::highlight::PageHighlighter;
+use crate::infrastructure::tantivy::index::IndexHandle;
+use crate::infrastructure::tantivy::schema::CREATED_AT_FIELD;
+use crate::infrastructure::tantivy::suggest::SuggestCollector;
+use crate::shared::kernel::{DocumentPartId, IndexId, LargeDocumentId};
+
+const CURSOR_LIFETIME: Duration = Duration::from_secs(5 * 60);
+const MAX_RESULT_WINDOW: usize = 10_000;
+const MAX_LIVE_CURSORS: usize = 1000;
+const DEFAULT_FRAGMENT_SIZE: usize = 100;
+
+type IndexHandles = HashMap<String, Arc<IndexHandle>>;
+
+#[derive(Clone)]
+pub struct TantivyClient {
+    config: TantivyConfig,
+    indexes: Arc<RwLock<IndexHandles>>,
+    cursors: Arc<Mutex<HashMap<String, CursorContext>>>,
+}
+
+/// Searching is re-executed for every next page, so cursor keeps params
+/// with offset of the next page instead of matched documents.
+struct CursorContext {
+    expired_at: Instant,
+    params: SearchingParams,
+    offset: usize,
+    page_size: usize,
+    total: TotalHits,
+    max_score: Option<f64>,
+}
+
+struct SearchPage {
+    founded: Vec<FoundedDocument>,
+    has_more: bool,
+    total: TotalHits,
+    max_score: Option<f64>,
+}
+
+#[async_trait::async_trait]
+impl ServiceConnect for TantivyClient {
+    type Config = TantivyConfig;
+    type Client = TantivyClient;
+    type Error = TantivyError;
+
+    async fn connect(config: &Self::Config) -> Result<Self::Client, Self::Error> {
+        let data_dir = Path::new(config.data_dir());
+        std::fs::create_dir_all(data_dir)?;
+
+        let mut indexes = HashMap::new();
+        for entry in std::fs::read_dir(data_dir)? {
+            let entry = entry?;
+            if !entry.file_type()?.is_dir() {
+                continue;
+            }
+
+            let index_name = entry.file_name().to_string_lossy().to_string();
+            let handle = IndexHandle::open(&entry.path(), config.writer_memory_size())?;
+            indexes.insert(index_name, Arc::new(handle));
+        }
+
+        tracing::info!(data_dir=%config.data_dir(), indexes=indexes.len(), "opened tantivy indexes");
+        Ok(TantivyClient {
+            config: config.clone(),
+            indexes: Arc::new(RwLock::new(indexes)),
+            cursors: Arc::new(Mutex::new(HashMap::new())),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl IIndexStorage for TantivyClient {
+    #[instrument(level = "info", skip(self))]
+    async fn create_index(&self, params: &CreateIndexParams) -> StorageResult<IndexId> {
+        let index_id = params.id.clone();
+        let splitter = params.splitter.clone();
+        Self::validate_index_name(&index_id)?;
+
+        self.run_blocking(move |client| {
+            let mut indexes = client.write_indexes()?;
+            if indexes.contains_key(&index_id) {
+                let err = anyhow!("index {index_id} already exists");
+                return Err(TantivyError::IndexAlreadyExists(err));
+            }
+
+            let path = client.build_index_path(&index_id);
+            let memory_size = client.config.writer_memory_size();
+            let handle = IndexHandle::create(&path, memory_size, splitter)?;
+            indexes.insert(index_id.clone(), Arc::new(handle));
+            Ok(IndexId(index_id))
+        })
+        .await
+        .map_err(StorageError::from)
+    }
+
+    #[instrument(level = "info", skip(self))]
+    async fn delete_index(&self, index_id: &IndexId) -> StorageResult<()> {
+        let removed = self.write_indexes()?.remove(&index_id.0);
+        if removed.is_none() {
+            let err = anyhow!("there is no index with such name: {index_id:?}");
+            return Err(StorageError::IndexNotFound(err));
+        }
+
+        drop(removed);
+        let path = self.build_index_path(index_id);
+        self.run_blocking(move |_| {
+            std::fs::remove_dir_all(path)
+                .context("failed to remove index directory")
+                .map_err(StorageError::InternalError)
+        })
+        .await
+    }
+
+    #[instrument(level = "info", skip(self))]
+    async fn get_index(&self, index_id: &IndexId) -> StorageResult<IndexId> {
+        let _ = self.get_index_handle(index_id)?;
+        Ok(index_id.to_owned())
+    }
+
+    #[instrument(level = "info", skip_all)]
+    async fn get_all_indexes(&self) -> StorageResult<Vec<IndexId>> {
+        let mut indexes = self
+            .read_indexes()?
+            .keys()
+            .map(|it| IndexId(it.to_owned()))
+            .collect::<Vec<IndexId>>();
+
+        indexes.sort_by(|a, b| a.0.cmp(&b.0));
+        Ok(indexes)
+    }
+
+    #[instrument(level = "info", skip(self))]
+    async fn get_index_splitter(
+        &self,
+        index_id: &IndexId,
+    ) -> StorageResult<Option<SplitterParams>> {
+        let handle = self.get_index_handle(index_id)?;
+        Ok(handle.splitter().cloned())
+    }
+}
+
+#[async_trait::async_trait]
+impl IDocumentPartStorage for TantivyClient {
+    #[instrument(
+        level = "info",
+        skip_all,
+        fields(
+            index_id = index_id.0,
+            document_parts_amount = all_doc_parts.len(),
+        ),
+    )]
+    async fn store_document_parts(
+        &self,
+        index_id: &IndexId,
+        all_doc_parts: AllDocumentParts,
+    ) -> StorageResult<StoredDocumentPartsInfo> {
+        let index_id = index_id.clone();
+        self.run_blocking(move |client| client.store_parts(&index_id, all_doc_parts, false))
+            .await
+    }
+
+    #[instrument(
+        level = "info",
+        skip_all,
+        fields(
+            index_id = index_id.0,
+            document_parts_amount = all_doc_parts.len(),
+        ),
+    )]
+    async fn create_document_parts(
+        &self,
+        index_id: &IndexId,
+        all_doc_parts: AllDocumentParts,
+    ) -> StorageResult<StoredDocumentPartsInfo> {
+        let index_id = index_id.clone();
+        self.run_blocking(move |client| client.store_parts(&index_id, all_doc_parts, true))
+            .await
+    }
+
+    #[instrument(level = "info", skip(self))]
+    async fn get_document_parts(
+        &self,
+        index: &IndexId,
+        large_doc_id: &LargeDocumentId,
+    ) -> StorageResult<AllDocumentParts> {
+        let index = index.clone();
+        let large_doc_id = large_doc_id.clone();
+        self.run_blocking(move |client| {
+            let mut all_doc_parts = Vec::new();
+            for index_id in index.split(',') {
+                let handle = client.get_index_handle(&IndexId(index_id.to_owned()))?;
+                let stored_docs = Self::load_stored_parts(&handle, &large_doc_id)?;
+                all_doc_parts.extend(stored_docs.into_iter().map(|it| it.document));
+            }
+
+            Ok(all_doc_parts)
+        })
+        .await
+    }
+
+    #[instrument(level = "info", skip(self))]
+    async fn get_document_part(
+        &self,
+        index: &IndexId,
+        doc_part_id: &DocumentPartId,
+    ) -> StorageResult<DocumentPart> {
+        let handle = self.get_index_handle(index)?;
+        let part_id = doc_part_id.clone();
+        let stored_doc = self
+            .run_blocking(move |_| Self::load_stored_part(&handle, &part_id))
+            .await?;
+
+        let Some(stored_doc) = stored_doc else {
+            let err = anyhow!("document [{doc_part_id:?}] not found in index: [{index:?}]");
+            return Err(StorageError::DocumentNotFound(err));
+        };
+
+        Ok(stored_doc.document)
+    }
+
+    #[instrument(level = "info", skip(self))]
+    async fn update_document_parts(
+        &self,
+        index: &IndexId,
+        large_doc_id: &LargeDocumentId,
+        params: &UpdateDocumentParams,
+    ) -> StorageResult<()> {
+        let indexes = index.clone();
+        let doc_id = large_doc_id.clone();
+        let params = params.clone();
+        let updated_parts = self
+            .run_blocking(move |client| {
+                let mut updated_parts = 0;
+                for index_id in indexes.split(',') {
+                    let handle = client.get_index_handle(&IndexId(index_id.to_owned()))?;
+                    let fields = handle.fields();
+                    let stored_docs = Self::load_stored_parts(&handle, &doc_id)?;
+                    if stored_docs.is_empty() {
+                        continue;
+                    }
+
+                    let mut documents = Vec::with_capacity(stored_docs.len());
+                    for mut stored_doc in stored_docs.into_iter() {
+                        stored_doc.document.apply_update(&params);
+                        documents.push(stored_doc.into_document(fields)?);
+                    }
+
+                    updated_parts += documents.len();
+                    let term = Term::from_field_text(fields.large_doc_id, &doc_id.0);
+                    handle.replace_documents(term, documents)?;
+                }
+
+                Ok::<usize, StorageError>(updated_parts)
+            })
+            .await?;
+
+        if updated_parts == 0 {
+            let err = anyhow!("document [{large_doc_id:?}] not found in index: [{index:?}]");
+            return Err(StorageError::DocumentNotFound(err));
+        }
+
+        Ok(())
+    }
+
+    #[instrument(level = "info", skip(self))]
+    async fn delete_document_parts(
+        &self,
+        index: &IndexId,
+        large_doc_id: &LargeDocumentId,
+    ) -> StorageResult<()> {
+        let index = index.clone();
+        let large_doc_id = large_doc_id.clone();
+        self.run_blocking(move |client| {
+            for index_id in index.split(',') {
+                let handle = client.get_index_handle(&IndexId(index_id.to_owned()))?;
+                let term = Term::from_field_text(handle.fields().large_doc_id, &large_doc_id.0);
+                handle.delete_documents(term)?;
+            }
+
+            Ok(())
+        })
+        .await
+    }
+}
+
+#[async_trait::async_trait]
+impl ISearcher for TantivyClient {
+    #[instrument(level = "info", skip(self))]
+    async fn search(&self, params: &SearchingParams) -> SearchResult<Pagination> {
+        let started_at = Instant::now();
+        let result = params.get_result();
+        let offset = result.offset.max(0) as usize;
+        let size = result.size.max(0) as usize;
+
+        let searching = params.clone();
+        let page = self
+            .run_blocking(move |client| client.search_page(&searching, offset, size))
+            .await?;
+
+        // Only the first page is continued by cursor, while deeper pages
+        // are requested by offset.
+        let cursor = match offset == 0 && page.has_more {
+            false => None,
+            true => {
+                let cursor_context = CursorContext {
+                    expired_at: Instant::now() + CURSOR_LIFETIME,
+                    params: params.clone(),
+                    offset: size,
+                    page_size: size,
+                    total: page.total.clone(),
+                    max_score: page.max_score,
+                };
+
+                let mut cursors = self.lock_cursors()?;
+                Some(Self::insert_cursor(&mut cursors, cursor_context))
+            }
+        };
+
+        let paginated = Pagination::new(cursor, page.founded)
+            .with_total(Some(page.total), page.max_score)
+            .with_took(Some(started_at.elapsed().as_millis() as u64));
+
+        Ok(paginated)
+    }
+
+    #[instrument(level = "info", skip(self))]
+    async fn suggest(&self, params: &SuggestParams) -> SearchResult<Suggestions> {
+        let handles = self.get_handles(&params.indexes, |it| params.is_index_allowed(it))?;
+        let mut collector = SuggestCollector::new(params);
+        let collector = self
+            .run_blocking(move |_| {
+                for (_, handle) in handles.iter() {
+                    collector.collect(handle)?;
+                }
+
+                Ok::<_, TantivyError>(collector)
+            })
+            .await?;
+
+        Ok(collector.build_suggestions(params))
+    }
+}
+
+#[async_trait::async_trait]
+impl IPaginator for TantivyClient {
+    #[instrument(level = "info", skip(self))]
+    async fn paginate(&self, params: &PaginationParams) -> SearchResult<Pagination> {
+        let (searching, offset, page_size, total, max_score) = {
+            let mut cursors = self.lock_cursors()?;
+            let now = Instant::now();
+            cursors.retain(|_, it| it.expired_at > now);
+
+            let Some(cursor_context) = cursors.get(&params.cursor) else {
+                let err = anyhow!("cursor {} does not exist or expired", params.cursor);
+                return Err(SearchError::from(TantivyError::CursorNotFound(err)));
+            };
+
+            (
+                cursor_context.params.clone(),
+                cursor_context.offset,
+                cursor_context.page_size,
+                cursor_context.total.clone(),
+                cursor_context.max_score,
+            )
+        };
+
+        let page = self
+            .run_blocking(move |client| client.search_page(&searching, offset, page_size))
+            .await?;
+
+        // Every page is returned with a new cursor, so the same cursor always
+        // points to the same page.
+        let mut cursors = self.lock_cursors()?;
+        let cursor_context = cursors.remove(&params.cursor);
+        let cursor = match cursor_context {
+            Some(mut cursor_context) if page.has_more => {
+                cursor_context.offset = offset + page_size;
+                cursor_context.expired_at = Instant::now() + CURSOR_LIFETIME;
+                Some(Self::insert_cursor(&mut cursors, cursor_context))
+            }
+            _ => None,
+        };
+
+        let paginated = Pagination::new(cursor, page.founded);
+        Ok(paginated.with_total(Some(total), max_score))
+    }
+
+    #[instrument(level = "info", skip(self))]
+    async fn release(&self, params: &PaginationParams) -> SearchResult<()> {
+        if self.lock_cursors()?.remove(&params.cursor).is_none() {
+            let err = anyhow!("cursor {} does not exist or expired", params.cursor);
+            return Err(SearchError::from(TantivyError::CursorNotFound(err)));
+        }
+
+        Ok(())
+    }
+}
+
+impl TantivyClient {
+    /// Runs index I/O, searching and commits on a blocking thread instead
+    /// of the async executor.
+    async fn run_blocking<T, E, F>(&self, func: F) -> Result<T, E>
+    where
+        F: FnOnce(TantivyClient) -> Result<T, E> + Send + 'static,
+        T: Send + 'static,
+        E: From<TantivyError> + Send + 'static,
+    {
+        let client = self.clone();
+        tokio::task::spawn_blocking(move || func(client))
+            .await
+            .map_err(|err| E::from(TantivyError::ExecutionError(anyhow!(err))))?
+    }
+
+    fn store_parts(
+        &self,
+        index_id: &IndexId,
+        all_doc_parts: AllDocumentParts,
+        create: bool,
+    ) -> StorageResult<StoredDocumentPartsInfo> {
+        let handle = self.get_index_handle(index_id)?;
+        let fields = handle.fields();
+
+        let doc_parts_amount = all_doc_parts.len();
+        let large_doc_id = all_doc_parts
+            .first()
+            .map(|it| it.large_doc_id.clone())
+            .ok_or(anyhow!("missing large document id to store"))
+            .map_err(StorageError::InternalError)?;
+
+        let mut stored_doc_ids = Vec::with_capacity(doc_parts_amount);
+        let mut documents = Vec::with_capacity(doc_parts_amount);
+        for doc in all_doc_parts.into_iter() {
+            #[cfg(not(feature = "enable-unique-doc-id"))]
+            let id = uuid::Uuid::new_v4().to_string();
+            #[cfg(feature = "enable-unique-doc-id")]
+            let id = gen_unique_document_id(index_id, &doc.large_doc_id, doc.doc_part_id);
+
+            let stored_doc = StoredDocumentPart {
+                id: id.clone(),
+                document: doc,
+            };
+
+            stored_doc_ids.push(id);
+            documents.push(stored_doc.into_document(fields)?);
+        }
+
+        let large_doc_term = Term::from_field_text(fields.large_doc_id, &large_doc_id.0);
+        match create {
+            true => handle.create_documents(large_doc_term, documents)?,
+            false => handle.replace_documents(large_doc_term, documents)?,
+        }
+
+        let first_doc_id = stored_doc_ids
+            .first()
+            .map(|it| DocumentPartId(it.clone()))
+            .ok_or(anyhow!("there is no any stored id's"))
+            .map_err(StorageError::InternalError)?;
+
+        let stored_doc_info = StoredDocumentPartsInfoBuilder::default()
+            .large_doc_id(large_doc_id)
+            .first_part_id(first_doc_id)
+            .doc_parts_amount(doc_parts_amount)
+            .build()
+            .context("failed to build stored document info")
+            .map_err(StorageError::InternalError)?;
+
+        Ok(stored_doc_info)
+    }
+
+    fn search_page(
+        &self,
+        params: &SearchingParams,
+        offset: usize,
+        size: usize,
+    ) -> SearchResult<SearchPage> {
+        let handles = self.get_search_handles(params)?;
+        let similar = match params.get_kind() {
+            SearchKindParams::Similar(it) if it.mode == SimilarMode::Lexical => {
+                Some(self.load_similar_document(it)?)
+            }
+            _ => None,
+        };
+
+        let result = params.get_result();
+        let filter = params.get_filter();
+        let with_post_filter = query::has_post_filter(filter);
+        let with_grouping = result.is_grouped_by_document();
+
+        // Top documents are collected by the first sort key when index can
+        // order by it, otherwise all matched documents are sorted in process.
+        let sort = params.get_sort();
+        let primary_key = sort.first().map(|it| (it.field, it.order.clone()));
+        let by_created_at = match primary_key {
+            Some((SortField::CreatedAt, ResultOrder::ASC)) => Some(Order::Asc),
+            Some((SortField::CreatedAt, ResultOrder::DESC)) => Some(Order::Desc),
+            _ => None,
+        };
+
+        let is_collected_sorted = by_created_at.is_some()
+            || matches!(primary_key, Some((SortField::Score, ResultOrder::DESC)));
+
+        // A single document past the page is collected to find out whether
+        // there is a next page to continue.
+        let window = match with_post_filter || with_grouping || !is_collected_sorted {
+            true => MAX_RESULT_WINDOW,
+            false => (offset + size + 1).min(MAX_RESULT_WINDOW),
+        };
+
+        let with_content = result.include_extra_fields.unwrap_or_default();
+        let with_highlight =
+            matches!(params.get_kind(), SearchKindParams::FullText(it) if it.query.is_some());
+
+        let fragment_size = result
+            .highlight_item_size
+            .map_or(DEFAULT_FRAGMENT_SIZE, |it| it as usize);
+
+        let mut highlighter = PageHighlighter::new(fragment_size);
+        let mut founded = Vec::new();
+        let mut matched_amount = 0;
+        let mut is_window_exceeded = false;
+        for (index_id, handle) in handles.iter() {
+            let fields = handle.fields();
+            let query =
+                query::build_search_query(params, handle.index(), fields, similar.as_ref())?;
+            let searcher = handle.reader().searcher();
+            let (hits, count) = match by_created_at.clone() {
+                Some(order) => {
+                    let collector = TopDocs::with_limit(window)
+                        .order_by_fast_field::<i64>(CREATED_AT_FIELD, order);
+
+                    let (hits, count) = searcher
+                        .search(query.as_ref(), &(collector, Count))
+                        .map_err(TantivyError::from)?;
+
+                    let hits = hits
+                        .into_iter()
+                        .map(|(_, address)| (None, address))
+                        .collect::<Vec<(Option<f64>, DocAddress)>>();
+
+                    (hits, count)
+                }
+                None => {
+                    let (hits, count) = searcher
+                        .search(query.as_ref(), &(TopDocs::with_limit(window), Count))
+                        .map_err(TantivyError::from)?;
+
+                    let hits = hits
+                        .into_iter()
+                        .map(|(score, address)| (Some(f64::from(score)), address))
+                        .collect::<Vec<(Option<f64>, DocAddress)>>();
+
+                    (hits, count)
+                }
+            };
+
+            matched_amount += count;
+            is_window_exceeded |= count > window;
+
+            for (score, address) in hits.iter() {
+                let document = searcher
+                    .doc::<TantivyDocument>(*address)
+                    .map_err(TantivyError::from)?;
+
+                let stored_doc = StoredDocumentPart::from_document(fields, &document)?;
+                if let Some(filter) = filter.filter(|_| with_post_filter) {
+                    let metadata = stored_doc.document.metadata.as_ref();
+                    if !query::match_post_filter(filter, metadata) {
+                        continue;
+                    }
+                }
+
+                let founded_doc = FoundedDocumentBuilder::default()
+                    .id(stored_doc.id.clone())
+                    .index(index_id.to_owned())
+                    .score(*score)
+                    .highlight(Vec::new())
+                    .document(stored_doc.into_entrails(with_content)?)
+                    .build()
+                    .context("failed to build founded document")
+                    .map_err(SearchError::InternalError)?;
+
+                if with_highlight {
+                    highlighter.add_document(index_id, &founded_doc.id, *address);
+                }
+
+                founded.push(founded_doc);
+            }
+
+            if with_highlight {
+                highlighter.add_source(searcher, query, fields.content);
+            }
+        }
+
+        FoundedDocument::sort_by_params(&mut founded, &sort);
+        if with_grouping {
+            if with_highlight {
+                highlighter.add_grouped_parts(&founded);
+            }
+
+            founded = FoundedDocument::group_by_document(founded);
+        }
+
+        // Post filtered and grouped documents are counted within collected
+        // window only, so the amount is a lower bound once window is exceeded.
+        let total = match with_post_filter || with_grouping {
+            false => TotalHits::exact(matched_amount as u64),
+            true => TotalHits {
+                value: founded.len() as u64,
+                relation: match is_window_exceeded {
+                    true => TotalHitsRelation::LowerBound,
+                    false => TotalHitsRelation::Exact,
+                },
+            },
+        };
+
+        let max_score = founded.iter().filter_map(|it| it.score).reduce(f64::max);
+        let mut page = founded
+            .into_iter()
+            .skip(offset)
+            .take(size.saturating_add(1))
+            .collect::<Vec<FoundedDocument>>();
+
+        let has_more = page.len() > size;
+        page.truncate(size);
+        if with_highlight {
+            highlighter.highlight(&mut page)?;
+        }
+
+        Ok(SearchPage {
+            founded: page,
+            has_more,
+            total,
+            max_score,
+        })
+    }
+
+    fn validate_index_name(index_id: &str) -> StorageResult<()> {
+        let is_valid = !index_id.is_empty()
+            && !index_id.starts_with(['.', '-', '_'])
+            && index_id
+                .chars()
+                .all(|ch| ch.is_ascii_lowercase() || ch.is_ascii_digit() || "-_".contains(ch));
+
+        if !is_valid {
+            let err = anyhow!("invalid index name: {index_id}");
+            return Err(StorageError::ValidationError(err));
+        }
+
+        Ok(())
+    }
+
+    fn build_index_path(&self, index_id: &str) -> PathBuf {
+        Path::new(self.config.data_dir()).join(index_id)
+    }
+
+    fn get_index_handle(&self, index_id: &IndexId) -> TantivyResult<Arc<IndexHandle>> {
+        self.read_indexes()?
+            .get(&index_id.0)
+            .cloned()
+            .ok_or_else(|| {
+                let err = anyhow!("there is no index with such name: {index_id:?}");
+                TantivyError::IndexNotFound(err)
+            })
+    }
+
+    fn load_stored_part(
+        handle: &IndexHandle,
+        doc_part_id: &DocumentPartId,
+    ) -> TantivyResult<Option<StoredDocumentPart>> {
+        let fields = handle.fields();
+        let term = Term::from_field_text(fields.id, &doc_part_id.0);
+        let query = TermQuery::new(term, IndexRecordOption::Basic);
+
+        let searcher = handle.reader().searcher();
+        let hits = searcher.search(&query, &TopDocs::with_limit(1))?;
+        let Some((_, address)) = hits.first() else {
+            return Ok(None);
+        };
+
+        let document = searcher.doc::<TantivyDocument>(*address)?;
+        let stored_doc = StoredDocumentPart::from_document(fields, &document)?;
+        Ok(Some(stored_doc))
+    }
+
+    fn load_similar_document(
+        &self,
+        params: &SimilarSearchingParams,
+    ) -> TantivyResult<query::SimilarDocument> {
+        let index_id = IndexId(params.source_index.clone());
+        let handle = self.get_index_handle(&index_id)?;
+        let stored_docs = match &params.source {
+            SimilarSource::LargeDocument(id) => Self::load_stored_parts(&handle, id)?,
+            SimilarSource::DocumentPart(id) => {
+                Self::load_stored_part(&handle, id)?.into_iter().collect()
+            }
+        };
+
+        let Some(first_doc) = stored_docs.first() else {
+            let err = anyhow!("source document of similar searching does not exist");
+            return Err(TantivyError::DocumentNotFound(err));
+        };
+
+        Ok(query::SimilarDocument {
+            large_doc_id: first_doc.document.large_doc_id.clone(),
+            contents: stored_docs
+                .into_iter()
+                .map(|it| it.document.content)
+                .collect(),
+        })
+    }
+
+    fn load_stored_parts(
+        handle: &IndexHandle,
+        large_doc_id: &LargeDocumentId,
+    ) -> TantivyResult<Vec<StoredDocumentPart>> {
+        let fields = handle.fields();
+        let term = Term::from_field_text(fields.large_doc_id, &large_doc_id.0);
+        let query = TermQuery::new(term, IndexRecordOption::Basic);
+        let collector = TopDocs::with_limit(MAX_RESULT_WINDOW)
+            .order_by_fast_field::<u64>(schema::DOC_PART_ID_FIELD, Order::Asc);
+
+        let searcher = handle.reader().searcher();
+        let hits = searcher.search(&query, &collector)?;
+
+        let mut stored_docs = Vec::with_capacity(hits.len());
+        for (_, address) in hits {
+            let document = searcher.doc::<TantivyDocument>(address)?;
+            stored_docs.push(StoredDocumentPart::from_document(fields, &document)?);
+        }
+
+        Ok(stored_docs)
+    }
+
+    fn get_search_handles(
+        &self,
+        params: &SearchingParams,
+    ) -> TantivyResult<Vec<(String, Arc<IndexHandle>)>> {
+        self.get_handles(params.get_indexes(), |it| params.is_index_allowed(it))
+    }
+
+    fn get_handles(
+        &self,
+        requested: &[String],
+        is_allowed: impl Fn(&str) -> bool,
+    ) -> TantivyResult<Vec<(String, Arc<IndexHandle>)>> {
+        if requested.first().is_some_and(|it| it == "*") {
+            let handles = self
+                .read_indexes()?
+                .iter()
+                .filter(|(id, _)| is_allowed(id))
+                .map(|(id, handle)| (id.to_owned(), handle.clone()))
+                .collect();
+
+            return Ok(handles);
+        }
+
+        requested
+            .iter()
+            .map(|id| {
+                let handle = self.get_index_handle(&IndexId(id.to_owned()))?;
+                Ok((id.to_owned(), handle))
+            })
+            .collect()
+    }
+
+    fn read_indexes(&self) -> TantivyResult<std::sync::RwLockReadGuard<'_, IndexHandles>> {
+        self.indexes
+            .read()
+            .map_err(|err| TantivyError::ExecutionError(anyhow!("indexes lock poisoned: {err}")))
+    }
+
+    fn write_indexes(&self) -> TantivyResult<std::sync::RwLockWriteGuard<'_, IndexHandles>> {
+        self.indexes
+            .write()
+            .map_err(|err| TantivyError::ExecutionError(anyhow!("indexes lock poisoned: {err}")))
+    }
+
+    /// Expired cursors are pruned on every new one, while the oldest live
+    /// cursors are evicted once `MAX_LIVE_CURSORS` are kept. Rows are not
+    /// kept by cursors and pages past `MAX_RESULT_WINDOW` are never continued.
+    fn insert_cursor(
+        cursors: &mut HashMap<String, CursorContext>,
+        cursor_context: CursorContext,
+    ) -> String {
+        let now = Instant::now();
+        cursors.retain(|_, it| it.expired_at > now);
+        while cursors.len() >= MAX_LIVE_CURSORS {
+            let oldest = cursors
+                .iter()
+                .min_by_key(|(_, it)| it.expired_at)
+                .map(|(cursor, _)| cursor.clone());
+
+            match oldest {
+                Some(cursor) => cursors.remove(&cursor),
+                None => break,
+            };
+        }
+
+        let cursor = uuid::Uuid::new_v4().to_string();
+        cursors.insert(cursor.clone(), cursor_context);
+        cursor
+    }
+
+    fn lock_cursors(
+        &self,
+    ) -> TantivyResult<std::sync::MutexGuard<'_, HashMap<String, CursorContext>>> {
+        self.cursors
+            .lock()
+            .map_err(|err| TantivyError::ExecutionError(anyhow!("cursors lock poisoned: {err}")))
+    }
+}
@@ -0,0 +1,377 @@
+use anyhow::anyhow;
+use std::ops::Bound;
+use tantivy::query::{AllQuery, BooleanQuery, EmptyQuery, FuzzyTermQuery, Occur, PhraseQuery};
+use tantivy::query::{MoreLikeThisQuery, Query, RangeQuery, RegexQuery, TermQuery};
+use tantivy::schema::{Field, IndexRecordOption, OwnedValue};
+use tantivy::tokenizer::TokenStream;
+use tantivy::{Index, Term};
+
+use crate::domain::searcher::SearchError;
+use crate::domain::searcher::models::SimilarMode;
+use crate::domain::searcher::models::{FilterParams, SearchKindParams, SearchingParams};
+use crate::domain::searcher::query::parse_query;
+use crate::domain::searcher::query::{QueryField, QueryNode, QueryRange, QueryTerm, QueryValue};
+use crate::infrastructure::tantivy::error::{TantivyError, TantivyResult};
+use crate::infrastructure::tantivy::schema::IndexFields;
+use crate::shared::kernel::LargeDocumentId;
+use crate::shared::kernel::metadata::DocumentMetadata;
+
+const SIMILAR_MAX_QUERY_TERMS: usize = 25;
+
+/// Source document of lexical similar documents searching loaded from its
+/// index before queries of searched indexes are built.
+pub struct SimilarDocument {
+    pub large_doc_id: LargeDocumentId,
+    pub contents: Vec<String>,
+}
+
+pub fn build_search_query(
+    params: &SearchingParams,
+    index: &Index,
+    fields: &IndexFields,
+    similar: Option<&SimilarDocument>,
+) -> TantivyResult<Box<dyn Query>> {
+    let must_query: Box<dyn Query> = match params.get_kind() {
+        SearchKindParams::Retrieve(params) => {
+            let first_part_query = build_term_query(Term::from_field_u64(fields.doc_part_id, 1));
+            match params.path.as_ref() {
+                None => first_part_query,
+                Some(path) => {
+                    let path_query =
+                        build_term_query(Term::from_field_text(fields.file_path, path));
+                    Box::new(BooleanQuery::intersection(vec![
+                        first_part_query,
+                        path_query,
+                    ]))
+                }
+            }
+        }
+        SearchKindParams::FullText(params) => match params.query.as_ref() {
+            None => Box::new(AllQuery),
+            Some(query) => {
+                let node = parse_query(query).map_err(|err| match err {
+                    SearchError::ValidationError(err) => TantivyError::ValidationError(err),
+                    err => TantivyError::ExecutionError(err.into()),
+                })?;
+
+                SyntaxQueryBuilder { index, fields }.build_node_query(&node)?
+            }
+        },
+        SearchKindParams::Similar(params) if params.mode == SimilarMode::Lexical => {
+            let Some(source) = similar else {
+                let err = anyhow!("similar searching requires loaded source document");
+                return Err(TantivyError::ExecutionError(err));
+            };
+
+            build_similar_query(source, fields)
+        }
+        SearchKindParams::Similar(params) => {
+            let msg = format!(
+                "{} similar searching is not supported by tantivy",
+                params.mode
+            );
+            return Err(TantivyError::UnsupportedQuery(anyhow!(msg)));
+        }
+        kind => {
+            let msg = format!("{kind} searching is not supported by tantivy");
+            return Err(TantivyError::UnsupportedQuery(anyhow!(msg)));
+        }
+    };
+
+    let mut queries = vec![(Occur::Must, must_query)];
+    if let Some(filter) = params.get_filter() {
+        let filter_queries = build_filter_query(filter, fields)?
+            .into_iter()
+            .map(|it| (Occur::Must, it));
+
+        queries.extend(filter_queries);
+    }
+
+    Ok(Box::new(BooleanQuery::new(queries)))
+}
+
+/// Matches documents sharing the most significant content terms of the
+/// source document, excluding its own parts.
+fn build_similar_query(source: &SimilarDocument, fields: &IndexFields) -> Box<dyn Query> {
+    let contents = source
+        .contents
+        .iter()
+        .map(|it| OwnedValue::from(it.as_str()))
+        .collect();
+
+    let like_query = MoreLikeThisQuery::builder()
+        .with_min_doc_frequency(1)
+        .with_min_term_frequency(1)
+        .with_max_query_terms(SIMILAR_MAX_QUERY_TERMS)
+        .with_document_fields(vec![(fields.content, contents)]);
+
+    let source_term = Term::from_field_text(fields.large_doc_id, &source.large_doc_id.0);
+    Box::new(BooleanQuery::new(vec![
+        (Occur::Must, Box::new(like_query)),
+        (Occur::MustNot, build_term_query(source_term)),
+    ]))
+}
+
+/// Translates query syntax tree onto index fields: `content` and `file_name`
+/// are tokenized by field analyzer, paths and sources are matched as keywords.
+struct SyntaxQueryBuilder<'a> {
+    index: &'a Index,
+    fields: &'a IndexFields,
+}
+
+impl SyntaxQueryBuilder<'_> {
+    fn build_node_query(&self, node: &QueryNode) -> TantivyResult<Box<dyn Query>> {
+        let query = match node {
+            QueryNode::Term(term) => return self.build_term_query(term),
+            QueryNode::Bool(query) => query,
+        };
+
+        let mut clauses = Vec::new();
+        for (occur, nodes) in [
+            (Occur::Must, &query.must),
+            (Occur::Should, &query.should),
+            (Occur::MustNot, &query.must_not),
+        ] {
+            for node in nodes {
+                clauses.push((occur, self.build_node_query(node)?));
+            }
+        }
+
+        // Boolean query of excluding clauses only matches nothing.
+        if query.must.is_empty() && query.should.is_empty() {
+            clauses.push((Occur::Must, Box::new(AllQuery)));
+        }
+
+        Ok(Box::new(BooleanQuery::new(clauses)))
+    }
+
+    fn build_term_query(&self, term: &QueryTerm) -> TantivyResult<Box<dyn Query>> {
+        let fields = self.fields;
+        match term.field {
+            QueryField::Content => self.build_text_query(fields.content, &term.value),
+            QueryField::FileName => self.build_text_query(fields.file_name, &term.value),
+            QueryField::FilePath => build_keyword_query(fields.file_path, &term.value),
+            QueryField::Source => build_keyword_query(fields.source, &term.value),
+            QueryField::SemanticSource => build_keyword_query(fields.semantic_source, &term.value),
+            QueryField::CreatedAt => build_numeric_query(&term.value, |value| {
+                Term::from_field_i64(fields.created_at, value)
+            }),
+            QueryField::ModifiedAt => build_numeric_query(&term.value, |value| {
+                Term::from_field_i64(fields.modified_at, value)
+            }),
+            QueryField::PipelineId => build_numeric_query(&term.value, |value| {
+                Term::from_field_i64(fields.pipeline_id, value)
+            }),
+            QueryField::FileSize => build_numeric_query(&term.value, |value| {
+                Term::from_field_u64(fields.file_size, value.max(0) as u64)
+            }),
+            QueryField::Summary => {
+                let msg = format!("field {} is not indexed by tantivy", term.field);
+                Err(TantivyError::UnsupportedQuery(anyhow!(msg)))
+            }
+        }
+    }
+
+    fn build_text_query(&self, field: Field, value: &QueryValue) -> TantivyResult<Box<dyn Query>> {
+        let query: Box<dyn Query> = match value {
+            QueryValue::Word(word) => {
+                let mut queries = self
+                    .tokenize(field, word)?
+                    .into_iter()
+                    .map(|term| {
+                        let query = TermQuery::new(term, IndexRecordOption::WithFreqs);
+                        (Occur::Should, Box::new(query) as Box<dyn Query>)
+                    })
+                    .collect::<Vec<_>>();
+
+                match queries.len() {
+                    0 => Box::new(EmptyQuery),
+                    1 => queries.remove(0).1,
+                    _ => Box::new(BooleanQuery::new(queries)),
+                }
+            }
+            QueryValue::Phrase(phrase) => {
+                let mut terms = self.tokenize(field, phrase)?;
+                match terms.len() {
+                    0 => Box::new(EmptyQuery),
+                    1 => build_term_query(terms.remove(0)),
+                    _ => Box::new(PhraseQuery::new(terms)),
+                }
+            }
+            QueryValue::Wildcard(pattern) => {
+                let regex = build_wildcard_regex(&pattern.to_lowercase());
+                Box::new(RegexQuery::from_pattern(&regex, field)?)
+            }
+            QueryValue::Fuzzy { word, distance } => {
+                let term = Term::from_field_text(field, &word.to_lowercase());
+                Box::new(FuzzyTermQuery::new(term, *distance, true))
+            }
+            QueryValue::Range(_) => Box::new(EmptyQuery),
+        };
+
+        Ok(query)
+    }
+
+    fn tokenize(&self, field: Field, text: &str) -> TantivyResult<Vec<Term>> {
+        let mut analyzer = self.index.tokenizer_for_field(field)?;
+        let mut stream = analyzer.token_stream(text);
+        let mut terms = Vec::new();
+        while let Some(token) = stream.next() {
+            terms.push(Term::from_field_text(field, &token.text));
+        }
+
+        Ok(terms)
+    }
+}
+
+fn build_keyword_query(field: Field, value: &QueryValue) -> TantivyResult<Box<dyn Query>> {
+    let query: Box<dyn Query> = match value {
+        QueryValue::Word(word) | QueryValue::Phrase(word) => {
+            build_term_query(Term::from_field_text(field, word))
+        }
+        QueryValue::Wildcard(pattern) => {
+            let regex = build_wildcard_regex(pattern);
+            Box::new(RegexQuery::from_pattern(&regex, field)?)
+        }
+        QueryValue::Fuzzy { word, distance } => {
+            let term = Term::from_field_text(field, word);
+            Box::new(FuzzyTermQuery::new(term, *distance, true))
+        }
+        QueryValue::Range(_) => Box::new(EmptyQuery),
+    };
+
+    Ok(query)
+}
+
+fn build_numeric_query<F>(value: &QueryValue, build_term: F) -> TantivyResult<Box<dyn Query>>
+where
+    F: Fn(i64) -> Term,
+{
+    let QueryValue::Range(QueryRange { from, to }) = value else {
+        return Ok(Box::new(EmptyQuery));
+    };
+
+    let lower = from.map(&build_term);
+    let upper = to.map(&build_term);
+    Ok(Box::new(RangeQuery::new(lower, upper)))
+}
+
+/// Converts wildcard pattern with backslash escapes into regex matched
+/// against whole indexed term.
+pub fn build_wildcard_regex(pattern: &str) -> String {
+    let mut regex = String::new();
+    let mut chars = pattern.chars();
+    while let Some(ch) = chars.next() {
+        let ch = match ch {
+            '*' => {
+                regex.push_str(".*");
+                continue;
+            }
+            '?' => {
+                regex.push('.');
+                continue;
+            }
+            '\\' => match chars.next() {
+                Some(escaped) => escaped,
+                None => break,
+            },
+            _ => ch,
+        };
+
+        if "\\.+*?()|[]{}^$#&-~".contains(ch) {
+            regex.push('\\');
+        }
+        regex.push(ch);
+    }
+
+    regex
+}
+
+pub fn build_filter_query(
+    params: &FilterParams,
+    fields: &IndexFields,
+) -> TantivyResult<Vec<Box<dyn Query>>> {
+    let mut filter_queries = Vec::new();
+
+    if let Some(doc_part_id) = params.doc_part_id {
+        let term = Term::from_field_u64(fields.doc_part_id, doc_part_id as u64);
+        filter_queries.push(build_term_query(term));
+    }
+
+    if let Some(pipeline_id) = params.pipeline_id {
+        let term = Term::from_field_i64(fields.pipeline_id, pipeline_id);
+        filter_queries.push(build_term_query(term));
+    }
+
+    if let Some(source) = params.source.as_ref() {
+        let term = Term::from_field_text(fields.source, source);
+        filter_queries.push(build_term_query(term));
+    }
+
+    if let Some(semantic_source) = params.semantic_source.as_ref() {
+        let term = Term::from_field_text(fields.semantic_source, semantic_source);
+        filter_queries.push(build_term_query(term));
+    }
+
+    if let Some(doc_class) = params.doc_class.as_ref() {
+        let term = Term::from_field_text(fields.doc_class, doc_class);
+        filter_queries.push(build_term_query(term));
+    }
+
+    let size_query = build_range_query(params.size_from, params.size_to, |value| {
+        Term::from_field_u64(fields.file_size, value as u64)
+    });
+
+    let created_query = build_range_query(params.created_from, params.created_to, |value| {
+        Term::from_field_i64(fields.created_at, value)
+    });
+
+    let modified_query = build_range_query(params.modified_from, params.modified_to, |value| {
+        Term::from_field_i64(fields.modified_at, value)
+    });
+
+    filter_queries.extend(size_query);
+    filter_queries.extend(created_query);
+    filter_queries.extend(modified_query);
+
+    params
+        .location_filter()
+        .map_err(TantivyError::ValidationError)?;
+
+    Ok(filter_queries)
+}
+
+/// Location and class probability filters are applied to the loaded
+/// metadata because the index stores them as plain json.
+pub fn has_post_filter(params: Option<&FilterParams>) -> bool {
+    params.is_some_and(|it| it.location_coords.is_some() || it.doc_class_probability.is_some())
+}
+
+pub fn match_post_filter(params: &FilterParams, metadata: Option<&DocumentMetadata>) -> bool {
+    // Class name alone is matched by the index term query.
+    if params.doc_class_probability.is_some() && !params.match_class(metadata) {
+        return false;
+    }
+
+    match params.location_filter() {
+        Ok(location) => location.is_none_or(|it| it.match_locations(metadata)),
+        Err(_) => false,
+    }
+}
+
+fn build_term_query(term: Term) -> Box<dyn Query> {
+    Box::new(TermQuery::new(term, IndexRecordOption::Basic))
+}
+
+fn build_range_query<T, F>(from: Option<T>, to: Option<T>, build_term: F) -> Option<Box<dyn Query>>
+where
+    F: Fn(T) -> Term,
+{
+    if from.is_none() && to.is_none() {
+        return None;
+    }
+
+    let lower = from.map_or(Bound::Unbounded, |it| Bound::Included(build_term(it)));
+    let upper = to.map_or(Bound::Unbounded, |it| Bound::Included(build_term(it)));
+    Some(Box::new(RangeQuery::new(lower, upper)))
+}
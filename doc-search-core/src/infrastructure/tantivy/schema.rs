@@ -0,0 +1,79 @@
+use tantivy::schema::{FAST, Field, INDEXED, STORED, STRING, Schema, TEXT};
+
+use crate::infrastructure::tantivy::error::TantivyResult;
+
+pub const ID_FIELD: &str = "id";
+pub const LARGE_DOC_ID_FIELD: &str = "large_doc_id";
+pub const DOC_PART_ID_FIELD: &str = "doc_part_id";
+pub const FILE_NAME_FIELD: &str = "file_name";
+pub const FILE_PATH_FIELD: &str = "file_path";
+pub const FILE_SIZE_FIELD: &str = "file_size";
+pub const CREATED_AT_FIELD: &str = "created_at";
+pub const MODIFIED_AT_FIELD: &str = "modified_at";
+pub const CONTENT_FIELD: &str = "content";
+pub const PIPELINE_ID_FIELD: &str = "pipeline_id";
+pub const SOURCE_FIELD: &str = "source";
+pub const SEMANTIC_SOURCE_FIELD: &str = "semantic_source";
+pub const DOC_CLASS_FIELD: &str = "doc_class";
+pub const METADATA_FIELD: &str = "metadata";
+
+/// Fields of metadata (source, classes, etc.) are indexed as flat keyword
+/// fields to be filtered by term queries, while the whole metadata object
+/// is kept as stored json to be restored without losses.
+pub fn build_index_schema() -> Schema {
+    let mut schema_builder = Schema::builder();
+    schema_builder.add_text_field(ID_FIELD, STRING | STORED);
+    schema_builder.add_text_field(LARGE_DOC_ID_FIELD, STRING | STORED);
+    schema_builder.add_u64_field(DOC_PART_ID_FIELD, INDEXED | STORED | FAST);
+    schema_builder.add_text_field(FILE_NAME_FIELD, TEXT | STORED);
+    schema_builder.add_text_field(FILE_PATH_FIELD, STRING | STORED);
+    schema_builder.add_u64_field(FILE_SIZE_FIELD, INDEXED | STORED | FAST);
+    schema_builder.add_i64_field(CREATED_AT_FIELD, INDEXED | STORED | FAST);
+    schema_builder.add_i64_field(MODIFIED_AT_FIELD, INDEXED | STORED | FAST);
+    schema_builder.add_text_field(CONTENT_FIELD, TEXT | STORED);
+    schema_builder.add_i64_field(PIPELINE_ID_FIELD, INDEXED);
+    schema_builder.add_text_field(SOURCE_FIELD, STRING);
+    schema_builder.add_text_field(SEMANTIC_SOURCE_FIELD, STRING);
+    schema_builder.add_text_field(DOC_CLASS_FIELD, STRING);
+    schema_builder.add_text_field(METADATA_FIELD, STORED);
+    schema_builder.build()
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct IndexFields {
+    pub id: Field,
+    pub large_doc_id: Field,
+    pub doc_part_id: Field,
+    pub file_name: Field,
+    pub file_path: Field,
+    pub file_size: Field,
+    pub created_at: Field,
+    pub modified_at: Field,
+    pub content: Field,
+    pub pipeline_id: Field,
+    pub source: Field,
+    pub semantic_source: Field,
+    pub doc_class: Field,
+    pub metadata: Field,
+}
+
+impl IndexFields {
+    pub fn from_schema(schema: &Schema) -> TantivyResult<Self> {
+        Ok(IndexFields {
+            id: schema.get_field(ID_FIELD)?,
+            large_doc_id: schema.get_field(LARGE_DOC_ID_FIELD)?,
+            doc_part_id: schema.get_field(DOC_PART_ID_FIELD)?,
+            file_name: schema.get_field(FILE_NAME_FIELD)?,
+            file_path: schema.get_field(FILE_PATH_FIELD)?,
+            file_size: schema.get_field(FILE_SIZE_FIELD)?,
+            created_at: schema.get_field(CREATED_AT_FIELD)?,
+            modified_at: schema.get_field(MODIFIED_AT_FIELD)?,
+            content: schema.get_field(CONTENT_FIELD)?,
+            pipeline_id: schema.get_field(PIPELINE_ID_FIELD)?,
+            source: schema.get_field(SOURCE_FIELD)?,
+            semantic_source: schema.get_field(SEMANTIC_SOURCE_FIELD)?,
+            doc_class: schema.get_field(DOC_CLASS_FIELD)?,
+            metadata: schema.get_field(METADATA_FIELD)?,
+        })
+    }
+}
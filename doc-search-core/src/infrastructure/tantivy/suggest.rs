@@ -0,0 +1,276 @@
+use levenshtein_automata::{DFA, Distance, LevenshteinAutomatonBuilder, SINK_STATE};
+use std::collections::HashMap;
+use tantivy::collector::TopDocs;
+use tantivy::query::{BooleanQuery, Occur, Query, RegexQuery, TermQuery};
+use tantivy::schema::{IndexRecordOption, Value};
+use tantivy::{InvertedIndexReader, TantivyDocument, Term};
+use tantivy_fst::Automaton;
+
+use crate::domain::searcher::models::{SuggestField, SuggestParams, Suggestion, Suggestions};
+use crate::infrastructure::tantivy::error::TantivyResult;
+use crate::infrastructure::tantivy::index::IndexHandle;
+use crate::infrastructure::tantivy::query;
+use crate::shared::kernel::edit_distance;
+
+const MAX_CORRECTION_DISTANCE: u8 = 2;
+const MAX_CORRECTED_WORDS: usize = 10;
+const MAX_COMPLETION_TERMS: usize = 1000;
+const MAX_FILE_NAME_HITS: usize = 100;
+
+/// Collects document frequencies of content terms completing the last typed
+/// word or close to misspelled query words. Completions are read from the
+/// term dictionary range starting with the typed prefix and corrections are
+/// matched by levenshtein automaton, so the whole dictionary is never scanned.
+/// Only first `MAX_CORRECTED_WORDS` words of query are corrected. File names
+/// are counted within first matched documents.
+pub struct SuggestCollector {
+    head_words: Vec<String>,
+    last_word: String,
+    docs_amount: u64,
+    completions: HashMap<String, u64>,
+    file_names: HashMap<String, u64>,
+    words: Vec<WordCandidates>,
+}
+
+struct WordCandidates {
+    word: String,
+    freq: u64,
+    is_file_name_term: bool,
+    corrections: HashMap<String, (usize, u64)>,
+}
+
+/// Matches terms within `MAX_CORRECTION_DISTANCE` edits of the word.
+struct LevenshteinAutomaton(DFA);
+
+impl Automaton for LevenshteinAutomaton {
+    type State = u32;
+
+    fn start(&self) -> Self::State {
+        self.0.initial_state()
+    }
+
+    fn is_match(&self, state: &Self::State) -> bool {
+        matches!(self.0.distance(*state), Distance::Exact(_))
+    }
+
+    fn can_match(&self, state: &Self::State) -> bool {
+        *state != SINK_STATE
+    }
+
+    fn accept(&self, state: &Self::State, byte: u8) -> Self::State {
+        self.0.transition(*state, byte)
+    }
+}
+
+impl SuggestCollector {
+    pub fn new(params: &SuggestParams) -> Self {
+        let (head, last_word) = params.split_last_word();
+        let words = split_words(&params.query)
+            .into_iter()
+            .map(|word| WordCandidates {
+                word,
+                freq: 0,
+                is_file_name_term: false,
+                corrections: HashMap::default(),
+            })
+            .collect();
+
+        SuggestCollector {
+            head_words: split_words(head),
+            last_word: last_word.to_lowercase(),
+            docs_amount: 0,
+            completions: HashMap::default(),
+            file_names: HashMap::default(),
+            words,
+        }
+    }
+
+    pub fn collect(&mut self, handle: &IndexHandle) -> TantivyResult<()> {
+        let fields = handle.fields();
+        let searcher = handle.reader().searcher();
+        self.docs_amount += searcher.num_docs();
+
+        for candidates in self.words.iter_mut() {
+            let term = Term::from_field_text(fields.file_name, &candidates.word);
+            candidates.is_file_name_term |= searcher.doc_freq(&term)? > 0;
+        }
+
+        let builder = LevenshteinAutomatonBuilder::new(MAX_CORRECTION_DISTANCE, false);
+        for segment in searcher.segment_readers() {
+            let inverted_index = segment.inverted_index(fields.content)?;
+            self.collect_completions(&inverted_index)?;
+            self.collect_corrections(&inverted_index, &builder)?;
+        }
+
+        self.collect_file_names(handle)
+    }
+
+    pub fn build_suggestions(self, params: &SuggestParams) -> Suggestions {
+        if self.docs_amount == 0 {
+            return Suggestions::default();
+        }
+
+        let docs_amount = self.docs_amount as f64;
+        let (head, _) = params.split_last_word();
+        let content_completions = self.completions.iter().map(|(term, freq)| Suggestion {
+            text: format!("{head} {term}").trim_start().to_string(),
+            score: *freq as f64 / docs_amount,
+            field: SuggestField::Content,
+        });
+
+        let file_name_completions = self.file_names.iter().map(|(file_name, count)| Suggestion {
+            text: file_name.to_owned(),
+            score: *count as f64 / docs_amount,
+            field: SuggestField::FileName,
+        });
+
+        let completions = content_completions.chain(file_name_completions).collect();
+
+        let words_amount = self.words.len();
+        let mut is_corrected = false;
+        let mut corrected_freqs = Vec::with_capacity(words_amount);
+        let mut corrected_words = Vec::with_capacity(words_amount);
+        for (position, candidates) in self.words.into_iter().enumerate() {
+            if candidates.freq > 0 {
+                corrected_freqs.push(candidates.freq);
+                corrected_words.push(candidates.word);
+                continue;
+            }
+
+            // Words of file names and the last word being typed yet while it
+            // completes any term are not misspelled.
+            let is_typed = !self.last_word.is_empty() && position + 1 == words_amount;
+            let is_completing = !self.completions.is_empty() || !self.file_names.is_empty();
+            if candidates.is_file_name_term || (is_typed && is_completing) {
+                corrected_words.push(candidates.word);
+                continue;
+            }
+
+            let correction = candidates.corrections.into_iter().min_by(
+                |(a, (a_dist, a_freq)), (b, (b_dist, b_freq))| {
+                    a_dist.cmp(b_dist).then(b_freq.cmp(a_freq)).then(a.cmp(b))
+                },
+            );
+
+            match correction {
+                None => corrected_words.push(candidates.word),
+                Some((term, (_, freq))) => {
+                    is_corrected = true;
+                    corrected_freqs.push(freq);
+                    corrected_words.push(term);
+                }
+            }
+        }
+
+        let mut corrections = Vec::new();
+        if is_corrected {
+            let freqs_sum = corrected_freqs.iter().sum::<u64>() as f64;
+            corrections.push(Suggestion {
+                text: corrected_words.join(" "),
+                score: freqs_sum / corrected_freqs.len() as f64 / docs_amount,
+                field: SuggestField::Content,
+            });
+        }
+
+        Suggestions::new(completions, corrections, params.size)
+    }
+
+    fn collect_completions(&mut self, inverted_index: &InvertedIndexReader) -> TantivyResult<()> {
+        if self.last_word.is_empty() {
+            return Ok(());
+        }
+
+        let prefix = self.last_word.as_bytes();
+        let mut stream = inverted_index.terms().range().ge(prefix).into_stream()?;
+        let mut terms_amount = 0;
+        while terms_amount < MAX_COMPLETION_TERMS && stream.advance() {
+            if !stream.key().starts_with(prefix) {
+                break;
+            }
+
+            if let Ok(term) = std::str::from_utf8(stream.key()) {
+                let freq = u64::from(stream.value().doc_freq);
+                *self.completions.entry(term.to_owned()).or_default() += freq;
+                terms_amount += 1;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn collect_corrections(
+        &mut self,
+        inverted_index: &InvertedIndexReader,
+        builder: &LevenshteinAutomatonBuilder,
+    ) -> TantivyResult<()> {
+        let terms = inverted_index.terms();
+        for candidates in self.words.iter_mut().take(MAX_CORRECTED_WORDS) {
+            let automaton = LevenshteinAutomaton(builder.build_dfa(&candidates.word));
+            let mut stream = terms.search(automaton).into_stream()?;
+            while stream.advance() {
+                let Ok(term) = std::str::from_utf8(stream.key()) else {
+                    continue;
+                };
+
+                let freq = u64::from(stream.value().doc_freq);
+                if candidates.word == term {
+                    candidates.freq += freq;
+                    continue;
+                }
+
+                let distance = edit_distance(&candidates.word, term);
+                let entry = candidates
+                    .corrections
+                    .entry(term.to_owned())
+                    .or_insert((distance, 0));
+                entry.1 += freq;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn collect_file_names(&mut self, handle: &IndexHandle) -> TantivyResult<()> {
+        if self.last_word.is_empty() {
+            return Ok(());
+        }
+
+        let field = handle.fields().file_name;
+        let mut clauses = self
+            .head_words
+            .iter()
+            .map(|word| {
+                let term = Term::from_field_text(field, word);
+                let query: Box<dyn Query> =
+                    Box::new(TermQuery::new(term, IndexRecordOption::Basic));
+                (Occur::Must, query)
+            })
+            .collect::<Vec<(Occur, Box<dyn Query>)>>();
+
+        let regex = query::build_wildcard_regex(&format!("{}*", self.last_word));
+        clauses.push((
+            Occur::Must,
+            Box::new(RegexQuery::from_pattern(&regex, field)?),
+        ));
+
+        let searcher = handle.reader().searcher();
+        let collector = TopDocs::with_limit(MAX_FILE_NAME_HITS);
+        let hits = searcher.search(&BooleanQuery::new(clauses), &collector)?;
+        for (_, address) in hits {
+            let document = searcher.doc::<TantivyDocument>(address)?;
+            if let Some(file_name) = document.get_first(field).and_then(|it| it.as_str()) {
+                *self.file_names.entry(file_name.to_owned()).or_default() += 1;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Splits text the same way as the default tokenizer of indexed text fields.
+fn split_words(text: &str) -> Vec<String> {
+    text.split(|ch: char| !ch.is_alphanumeric())
+        .filter(|it| !it.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
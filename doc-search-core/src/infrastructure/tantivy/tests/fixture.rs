@@ -0,0 +1,90 @@
+use serde_json::json;
+use std::path::PathBuf;
+
+use crate::ServiceConnect;
+use crate::application::tests::fixture::DEFAULT_INDEX_ID;
+use crate::domain::searcher::models::{FilterParams, FilterParamsBuilder, FullTextSearchingParams};
+use crate::domain::searcher::models::{ResultOrder, ResultParams, ResultParamsBuilder};
+use crate::domain::searcher::models::{SearchKindParams, SearchingParams};
+use crate::domain::searcher::models::{SuggestParams, SuggestParamsBuilder};
+use crate::domain::storage::IIndexStorage;
+use crate::domain::storage::models::CreateIndexParamsBuilder;
+use crate::infrastructure::tantivy::{TantivyClient, TantivyConfig};
+
+const WRITER_MEMORY_SIZE: usize = 20_000_000;
+
+pub struct TestEnvironment {
+    pub client: TantivyClient,
+    data_dir: PathBuf,
+}
+
+impl Drop for TestEnvironment {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.data_dir);
+    }
+}
+
+pub async fn init_test_environment() -> anyhow::Result<TestEnvironment> {
+    let data_dir = std::env::temp_dir().join(format!("tantivy-{}", uuid::Uuid::new_v4()));
+    let config = serde_json::from_value::<TantivyConfig>(json!({
+        "data_dir": data_dir.to_string_lossy(),
+        "writer_memory_size": WRITER_MEMORY_SIZE,
+    }))?;
+
+    let client = TantivyClient::connect(&config).await?;
+    let create_index_params = CreateIndexParamsBuilder::default()
+        .id(DEFAULT_INDEX_ID.to_string())
+        .knn(None)
+        .build()?;
+
+    client.create_index(&create_index_params).await?;
+    Ok(TestEnvironment { client, data_dir })
+}
+
+pub fn build_result_params(size: i64, offset: i64, order: ResultOrder) -> ResultParams {
+    ResultParamsBuilder::default()
+        .order(order)
+        .size(size)
+        .offset(offset)
+        .include_extra_fields(Some(true))
+        .highlight_items(None)
+        .highlight_item_size(None)
+        .build()
+        .expect("failed building result params builder")
+}
+
+pub fn build_empty_filter_params() -> FilterParamsBuilder {
+    let mut builder = FilterParamsBuilder::default();
+    builder
+        .doc_part_id(None)
+        .size_from(None)
+        .size_to(None)
+        .created_from(None)
+        .created_to(None)
+        .modified_from(None)
+        .modified_to(None);
+
+    builder
+}
+
+pub fn build_fulltext_params(
+    query: Option<&str>,
+    result: ResultParams,
+    filter: Option<FilterParams>,
+) -> SearchingParams {
+    let params = FullTextSearchingParams {
+        query: query.map(String::from),
+    };
+
+    let indexes = vec![DEFAULT_INDEX_ID.to_string()];
+    SearchingParams::new(indexes, SearchKindParams::FullText(params), result, filter)
+}
+
+pub fn build_suggest_params(query: &str) -> SuggestParams {
+    SuggestParamsBuilder::default()
+        .indexes(vec![DEFAULT_INDEX_ID.to_string()])
+        .query(query.to_string())
+        .size(5)
+        .build()
+        .expect("failed to build suggest params")
+}
@@ -0,0 +1,3 @@
+mod fixture;
+mod test_searcher;
+mod test_storage;
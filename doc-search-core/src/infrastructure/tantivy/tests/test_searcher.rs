@@ -0,0 +1,364 @@
+use rstest::rstest;
+use std::collections::HashSet;
+
+use crate::application::tests::fixture::document::{build_large_document, build_short_document};
+use crate::application::tests::fixture::search_params::build_similar_params;
+use crate::application::tests::fixture::search_params::build_simple_semantic_params;
+use crate::application::tests::fixture::{DEFAULT_INDEX_ID, DOCUMENT_SOURCE};
+use crate::domain::searcher::models::RetrieveIndexDocumentsParams;
+use crate::domain::searcher::models::{FilterParams, PaginationParams, ResultOrder};
+use crate::domain::searcher::models::{SearchKindParams, SearchingParams, TotalHits};
+use crate::domain::searcher::models::{SimilarMode, SimilarSource};
+use crate::domain::searcher::{IPaginator, ISearcher, SearchError};
+use crate::domain::storage::IDocumentPartStorage;
+use crate::domain::storage::models::LargeDocument;
+use crate::infrastructure::tantivy::MAX_LIVE_CURSORS;
+use crate::infrastructure::tantivy::tests::fixture::*;
+use crate::shared::kernel::{DocumentPartId, IndexId};
+
+const MAX_CONTENT_SIZE: usize = 500;
+
+async fn store_documents(
+    test_env: &TestEnvironment,
+    documents: Vec<LargeDocument>,
+) -> anyhow::Result<usize> {
+    let index_id = IndexId(DEFAULT_INDEX_ID.to_string());
+    let mut stored_parts_amount = 0;
+    for document in documents {
+        let doc_parts = document.divide_large_document_on_parts(MAX_CONTENT_SIZE)?;
+        let stored_info = test_env
+            .client
+            .store_document_parts(&index_id, doc_parts)
+            .await?;
+
+        stored_parts_amount += stored_info.doc_parts_amount;
+    }
+
+    Ok(stored_parts_amount)
+}
+
+#[rstest]
+#[case(Some("Petersburg"), true)]
+#[case(Some("nonexistentword"), false)]
+#[case(None, true)]
+#[tokio::test]
+async fn test_tantivy_fulltext_search(
+    #[case] query: Option<&str>,
+    #[case] is_founded: bool,
+) -> anyhow::Result<()> {
+    let test_env = init_test_environment().await?;
+    store_documents(&test_env, vec![build_short_document()]).await?;
+
+    let result = build_result_params(10, 0, ResultOrder::DESC);
+    let params = build_fulltext_params(query, result, None);
+    let pagination = test_env.client.search(&params).await?;
+    assert_eq!(!pagination.founded.is_empty(), is_founded);
+
+    if let (Some(_), Some(founded)) = (query, pagination.founded.first()) {
+        assert!(!founded.highlight.is_empty());
+        assert!(founded.document.content.is_some());
+    }
+
+    Ok(())
+}
+
+#[rstest]
+#[case("\"War and Peace\" AND Tolstoy", true)]
+#[case("\"Peace and War\"", false)]
+#[case("Tolstoy -file_name:test*", false)]
+#[case("+Petersburg NOT (draft OR report)", true)]
+#[case("Peterzburg~1 && Pier?e", true)]
+#[case("file_name:document AND file_path:./test-short*", true)]
+#[case("file_size:[1000 TO 2000] created_at:<1700000000", true)]
+#[case("+file_size:>2000 Petersburg", false)]
+#[case("-Petersburg", true)]
+#[tokio::test]
+async fn test_tantivy_fulltext_syntax_search(
+    #[case] query: &str,
+    #[case] is_founded: bool,
+) -> anyhow::Result<()> {
+    let test_env = init_test_environment().await?;
+    store_documents(&test_env, vec![build_short_document()]).await?;
+
+    let result = build_result_params(10, 0, ResultOrder::DESC);
+    let params = build_fulltext_params(Some(query), result, None);
+    let pagination = test_env.client.search(&params).await?;
+    assert_eq!(!pagination.founded.is_empty(), is_founded);
+    Ok(())
+}
+
+#[rstest]
+#[case("\"unterminated phrase")]
+#[case("summary:content")]
+#[tokio::test]
+async fn test_tantivy_fulltext_syntax_error(#[case] query: &str) -> anyhow::Result<()> {
+    let test_env = init_test_environment().await?;
+    let result = build_result_params(10, 0, ResultOrder::DESC);
+    let params = build_fulltext_params(Some(query), result, None);
+    let search_result = test_env.client.search(&params).await;
+    assert!(matches!(
+        search_result,
+        Err(SearchError::ValidationError(_))
+    ));
+    Ok(())
+}
+
+#[rstest]
+#[case("Peters", Some("petersburg"), None)]
+#[case("war and pe", Some("war and peace"), None)]
+#[case("test docu", Some("test-document.docx"), None)]
+#[case("Pierre did not let anyome", None, Some("pierre did not let anyone"))]
+#[case("Petersbrug ", None, Some("petersburg"))]
+#[case(
+    "pierre pierre pierre pierre pierre pierre pierre pierre pierre pierre anyome",
+    None,
+    None
+)]
+#[tokio::test]
+async fn test_tantivy_suggest(
+    #[case] query: &str,
+    #[case] completion: Option<&str>,
+    #[case] correction: Option<&str>,
+) -> anyhow::Result<()> {
+    let test_env = init_test_environment().await?;
+    store_documents(&test_env, vec![build_short_document()]).await?;
+
+    let params = build_suggest_params(query);
+    let suggestions = test_env.client.suggest(&params).await?;
+    assert!(suggestions.completions.len() <= params.size);
+    if let Some(completion) = completion {
+        assert!(
+            suggestions
+                .completions
+                .iter()
+                .any(|it| it.text == completion)
+        );
+    }
+
+    let corrections = suggestions
+        .corrections
+        .iter()
+        .map(|it| it.text.as_str())
+        .collect::<Vec<&str>>();
+
+    assert_eq!(corrections.first().copied(), correction);
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_tantivy_cursor_pagination() -> anyhow::Result<()> {
+    let test_env = init_test_environment().await?;
+    let stored_parts_amount = store_documents(&test_env, vec![build_large_document()]).await?;
+
+    let result = build_result_params(2, 0, ResultOrder::ASC);
+    let params = build_fulltext_params(None, result, None);
+    let mut pagination = test_env.client.search(&params).await?;
+    let mut founded_ids = pagination
+        .founded
+        .iter()
+        .map(|it| it.id.clone())
+        .collect::<HashSet<String>>();
+    let mut founded_amount = pagination.founded.len();
+    let expected_total = Some(TotalHits::exact(stored_parts_amount as u64));
+    assert_eq!(pagination.total, expected_total);
+    assert!(pagination.took.is_some());
+
+    while let Some(cursor) = pagination.cursor.take() {
+        let params = PaginationParams { cursor };
+        pagination = test_env.client.paginate(&params).await?;
+        assert_ne!(pagination.cursor.as_ref(), Some(&params.cursor));
+        assert!(test_env.client.paginate(&params).await.is_err());
+        founded_amount += pagination.founded.len();
+        founded_ids.extend(pagination.founded.iter().map(|it| it.id.clone()));
+        assert_eq!(pagination.total, expected_total);
+    }
+
+    assert_eq!(founded_amount, stored_parts_amount);
+    assert_eq!(founded_ids.len(), stored_parts_amount);
+
+    let result = build_result_params(2, 1, ResultOrder::ASC);
+    let params = build_fulltext_params(None, result, None);
+    let pagination = test_env.client.search(&params).await?;
+    assert!(pagination.cursor.is_none());
+    assert_eq!(pagination.founded.len(), 2.min(stored_parts_amount - 1));
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_tantivy_cursors_limited() -> anyhow::Result<()> {
+    let test_env = init_test_environment().await?;
+    store_documents(&test_env, vec![build_large_document()]).await?;
+
+    let result = build_result_params(1, 0, ResultOrder::ASC);
+    let params = build_fulltext_params(None, result, None);
+    let first_cursor = test_env.client.search(&params).await?.cursor;
+    assert!(first_cursor.is_some());
+
+    for _ in 0..MAX_LIVE_CURSORS {
+        let pagination = test_env.client.search(&params).await?;
+        assert!(pagination.cursor.is_some());
+    }
+
+    assert_eq!(
+        test_env.client.cursors.lock().unwrap().len(),
+        MAX_LIVE_CURSORS
+    );
+
+    let params = PaginationParams {
+        cursor: first_cursor.unwrap(),
+    };
+    let result = test_env.client.paginate(&params).await;
+    assert!(matches!(result, Err(SearchError::ValidationError(_))));
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_tantivy_search_grouped_by_document() -> anyhow::Result<()> {
+    let test_env = init_test_environment().await?;
+    let documents = vec![build_large_document(), build_short_document()];
+    let stored_parts_amount = store_documents(&test_env, documents).await?;
+
+    let mut result = build_result_params(10, 0, ResultOrder::DESC);
+    result.group_by_document = Some(true);
+    let params = build_fulltext_params(None, result, None);
+    let pagination = test_env.client.search(&params).await?;
+    assert_eq!(pagination.founded.len(), 2);
+
+    let matched_parts = pagination
+        .founded
+        .iter()
+        .filter_map(|it| it.grouped.as_ref())
+        .map(|it| it.matched_parts)
+        .sum::<usize>();
+
+    assert_eq!(matched_parts, stored_parts_amount);
+    Ok(())
+}
+
+#[rstest]
+#[case(build_empty_filter_params().source(Some(DOCUMENT_SOURCE.to_string())).build()?, true)]
+#[case(build_empty_filter_params().source(Some("unknown".to_string())).build()?, false)]
+#[case(build_empty_filter_params().size_to(Some(1)).build()?, false)]
+#[case(build_empty_filter_params().doc_part_id(Some(1)).build()?, true)]
+#[case(build_empty_filter_params().doc_class_probability(Some(0.5)).build()?, true)]
+#[case(build_empty_filter_params().location_coords(Some(vec![0.01, 0.01])).build()?, true)]
+#[case(build_empty_filter_params().location_coords(Some(vec![20.0, 20.0])).build()?, false)]
+#[tokio::test]
+async fn test_tantivy_filtered_search(
+    #[case] filter: FilterParams,
+    #[case] is_founded: bool,
+) -> anyhow::Result<()> {
+    let test_env = init_test_environment().await?;
+    store_documents(&test_env, vec![build_large_document()]).await?;
+
+    let result = build_result_params(10, 0, ResultOrder::DESC);
+    let params = build_fulltext_params(None, result, Some(filter));
+    let pagination = test_env.client.search(&params).await?;
+    assert_eq!(!pagination.founded.is_empty(), is_founded);
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_tantivy_retrieve_documents() -> anyhow::Result<()> {
+    let test_env = init_test_environment().await?;
+    let documents = vec![build_large_document(), build_short_document()];
+    store_documents(&test_env, documents).await?;
+
+    let result = build_result_params(10, 0, ResultOrder::DESC);
+    let params = RetrieveIndexDocumentsParams { path: None };
+    let indexes = vec![DEFAULT_INDEX_ID.to_string()];
+    let kind = SearchKindParams::Retrieve(params);
+    let params = SearchingParams::new(indexes, kind, result, None);
+
+    let pagination = test_env.client.search(&params).await?;
+    assert_eq!(pagination.founded.len(), 2);
+    assert!(
+        pagination
+            .founded
+            .iter()
+            .all(|it| it.document.doc_part_id == 1)
+    );
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_tantivy_semantic_search_unsupported() -> anyhow::Result<()> {
+    let test_env = init_test_environment().await?;
+
+    let result = build_result_params(10, 0, ResultOrder::DESC);
+    let indexes = vec![DEFAULT_INDEX_ID.to_string()];
+    let kind = SearchKindParams::Semantic(build_simple_semantic_params());
+    let params = SearchingParams::new(indexes, kind, result, None);
+
+    let result = test_env.client.search(&params).await;
+    assert!(matches!(result, Err(SearchError::ValidationError(_))));
+    Ok(())
+}
+
+#[rstest]
+#[case(false)]
+#[case(true)]
+#[tokio::test]
+async fn test_tantivy_similar_search(#[case] by_first_part: bool) -> anyhow::Result<()> {
+    let test_env = init_test_environment().await?;
+    let index_id = IndexId(DEFAULT_INDEX_ID.to_string());
+    let doc_parts = build_short_document().divide_large_document_on_parts(MAX_CONTENT_SIZE)?;
+    let stored_info = test_env
+        .client
+        .store_document_parts(&index_id, doc_parts)
+        .await?;
+    store_documents(&test_env, vec![build_large_document()]).await?;
+
+    let source = match by_first_part {
+        true => SimilarSource::DocumentPart(stored_info.first_part_id.clone()),
+        false => SimilarSource::LargeDocument(stored_info.large_doc_id.clone()),
+    };
+
+    let result = build_result_params(100, 0, ResultOrder::DESC);
+    let indexes = vec![DEFAULT_INDEX_ID.to_string()];
+    let kind = SearchKindParams::Similar(build_similar_params(source, SimilarMode::Lexical));
+    let params = SearchingParams::new(indexes, kind, result, None);
+
+    let pagination = test_env.client.search(&params).await?;
+    assert!(!pagination.founded.is_empty());
+    assert!(
+        pagination
+            .founded
+            .iter()
+            .all(|it| it.document.large_doc_id.0 != stored_info.large_doc_id.0)
+    );
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_tantivy_similar_search_errors() -> anyhow::Result<()> {
+    let test_env = init_test_environment().await?;
+    store_documents(&test_env, vec![build_short_document()]).await?;
+
+    let result = build_result_params(10, 0, ResultOrder::DESC);
+    let indexes = vec![DEFAULT_INDEX_ID.to_string()];
+    for (mode, is_not_found) in [(SimilarMode::Lexical, true), (SimilarMode::Vector, false)] {
+        let source = SimilarSource::DocumentPart(DocumentPartId("unknown".to_string()));
+        let kind = SearchKindParams::Similar(build_similar_params(source, mode));
+        let params = SearchingParams::new(indexes.clone(), kind, result.clone(), None);
+        let search_result = test_env.client.search(&params).await;
+        match is_not_found {
+            true => assert!(matches!(
+                search_result,
+                Err(SearchError::DocumentNotFound(_))
+            )),
+            false => assert!(matches!(
+                search_result,
+                Err(SearchError::ValidationError(_))
+            )),
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,270 @@
+use rstest::rstest;
+
+use crate::application::tests::fixture::document::{build_large_document, build_short_document};
+use crate::application::tests::fixture::{DEFAULT_INDEX_ID, DOC_FILE_PATH};
+use crate::domain::storage::models::UpdateDocumentParamsBuilder;
+use crate::domain::storage::models::{CreateIndexParamsBuilder, LargeDocument, SplitterParams};
+use crate::domain::storage::{IDocumentPartStorage, IIndexStorage, StorageError};
+use crate::infrastructure::tantivy::tests::fixture::init_test_environment;
+use crate::shared::kernel::IndexId;
+
+const MAX_CONTENT_SIZE: usize = 500;
+
+#[rstest]
+#[case("second-index", true)]
+#[case(DEFAULT_INDEX_ID, false)]
+#[case("Invalid/Index", false)]
+#[case(".hidden-index", false)]
+#[tokio::test]
+async fn test_tantivy_create_index(
+    #[case] index_id: &str,
+    #[case] is_created: bool,
+) -> anyhow::Result<()> {
+    let test_env = init_test_environment().await?;
+    let params = CreateIndexParamsBuilder::default()
+        .id(index_id.to_string())
+        .knn(None)
+        .build()?;
+
+    let result = test_env.client.create_index(&params).await;
+    assert_eq!(result.is_ok(), is_created);
+
+    let indexes = test_env.client.get_all_indexes().await?;
+    let expected_amount = if is_created { 2 } else { 1 };
+    assert_eq!(indexes.len(), expected_amount);
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_tantivy_create_existing_index() -> anyhow::Result<()> {
+    let test_env = init_test_environment().await?;
+    let params = CreateIndexParamsBuilder::default()
+        .id(DEFAULT_INDEX_ID.to_string())
+        .knn(None)
+        .build()?;
+
+    let result = test_env.client.create_index(&params).await;
+    assert!(matches!(result, Err(StorageError::IndexAlreadyExists(_))));
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_tantivy_delete_index() -> anyhow::Result<()> {
+    let test_env = init_test_environment().await?;
+    let index_id = IndexId(DEFAULT_INDEX_ID.to_string());
+
+    test_env.client.delete_index(&index_id).await?;
+    let result = test_env.client.get_index(&index_id).await;
+    assert!(matches!(result, Err(StorageError::IndexNotFound(_))));
+
+    let result = test_env.client.delete_index(&index_id).await;
+    assert!(matches!(result, Err(StorageError::IndexNotFound(_))));
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_tantivy_index_splitter() -> anyhow::Result<()> {
+    let test_env = init_test_environment().await?;
+    let splitter = SplitterParams::Markdown {
+        chunk_size: Some(256),
+    };
+    let params = CreateIndexParamsBuilder::default()
+        .id("splitter-index".to_string())
+        .knn(None)
+        .splitter(Some(splitter.clone()))
+        .build()?;
+
+    let index_id = test_env.client.create_index(&params).await?;
+    let stored_splitter = test_env.client.get_index_splitter(&index_id).await?;
+    assert_eq!(stored_splitter, Some(splitter));
+
+    let default_index_id = IndexId(DEFAULT_INDEX_ID.to_string());
+    let default_splitter = test_env
+        .client
+        .get_index_splitter(&default_index_id)
+        .await?;
+    assert_eq!(default_splitter, None);
+
+    test_env.client.delete_index(&index_id).await?;
+    let result = test_env.client.get_index_splitter(&index_id).await;
+    assert!(matches!(result, Err(StorageError::IndexNotFound(_))));
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_tantivy_store_document_parts(
+    build_large_document: LargeDocument,
+) -> anyhow::Result<()> {
+    let test_env = init_test_environment().await?;
+    let index_id = IndexId(DEFAULT_INDEX_ID.to_string());
+    let doc_parts = build_large_document.divide_large_document_on_parts(MAX_CONTENT_SIZE)?;
+    let doc_parts_amount = doc_parts.len();
+
+    let stored_info = test_env
+        .client
+        .store_document_parts(&index_id, doc_parts)
+        .await?;
+
+    assert_eq!(stored_info.doc_parts_amount, doc_parts_amount);
+
+    let large_doc_id = &stored_info.large_doc_id;
+    let stored_parts = test_env
+        .client
+        .get_document_parts(&index_id, large_doc_id)
+        .await?;
+
+    let stored_part_ids = stored_parts
+        .iter()
+        .map(|it| it.doc_part_id)
+        .collect::<Vec<usize>>();
+
+    assert_eq!(
+        stored_part_ids,
+        (1..=doc_parts_amount).collect::<Vec<usize>>()
+    );
+
+    let first_part = test_env
+        .client
+        .get_document_part(&index_id, &stored_info.first_part_id)
+        .await?;
+
+    assert_eq!(first_part.doc_part_id, 1);
+    assert_eq!(first_part.content, stored_parts[0].content);
+    let source = first_part.metadata.and_then(|it| it.source);
+    assert_eq!(source.as_deref(), Some("source"));
+
+    test_env
+        .client
+        .delete_document_parts(&index_id, large_doc_id)
+        .await?;
+
+    let stored_parts = test_env
+        .client
+        .get_document_parts(&index_id, large_doc_id)
+        .await?;
+
+    assert!(stored_parts.is_empty());
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_tantivy_store_replaces_document_parts(
+    build_large_document: LargeDocument,
+    build_short_document: LargeDocument,
+) -> anyhow::Result<()> {
+    let test_env = init_test_environment().await?;
+    let index_id = IndexId(DEFAULT_INDEX_ID.to_string());
+    let doc_parts = build_large_document.divide_large_document_on_parts(MAX_CONTENT_SIZE)?;
+    let stored_info = test_env
+        .client
+        .store_document_parts(&index_id, doc_parts)
+        .await?;
+
+    let mut short_document = build_short_document;
+    short_document.file_path = DOC_FILE_PATH.to_string();
+    let doc_parts = short_document.divide_large_document_on_parts(MAX_CONTENT_SIZE)?;
+    let replaced_info = test_env
+        .client
+        .store_document_parts(&index_id, doc_parts)
+        .await?;
+
+    assert_eq!(replaced_info.large_doc_id.0, stored_info.large_doc_id.0);
+
+    let stored_parts = test_env
+        .client
+        .get_document_parts(&index_id, &replaced_info.large_doc_id)
+        .await?;
+
+    assert_eq!(stored_parts.len(), replaced_info.doc_parts_amount);
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_tantivy_create_existing_document_parts(
+    build_large_document: LargeDocument,
+) -> anyhow::Result<()> {
+    let test_env = init_test_environment().await?;
+    let index_id = IndexId(DEFAULT_INDEX_ID.to_string());
+    let doc_parts = build_large_document.divide_large_document_on_parts(MAX_CONTENT_SIZE)?;
+    let stored_info = test_env
+        .client
+        .create_document_parts(&index_id, doc_parts.clone())
+        .await?;
+
+    let result = test_env
+        .client
+        .create_document_parts(&index_id, doc_parts)
+        .await;
+    assert!(matches!(
+        result,
+        Err(StorageError::DocumentAlreadyExists(_))
+    ));
+
+    let stored_parts = test_env
+        .client
+        .get_document_parts(&index_id, &stored_info.large_doc_id)
+        .await?;
+
+    assert_eq!(stored_parts.len(), stored_info.doc_parts_amount);
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_tantivy_update_document_parts(
+    build_large_document: LargeDocument,
+) -> anyhow::Result<()> {
+    let test_env = init_test_environment().await?;
+    let index_id = IndexId(DEFAULT_INDEX_ID.to_string());
+    let doc_parts = build_large_document.divide_large_document_on_parts(MAX_CONTENT_SIZE)?;
+    let stored_info = test_env
+        .client
+        .store_document_parts(&index_id, doc_parts)
+        .await?;
+
+    let large_doc_id = &stored_info.large_doc_id;
+    let stored_parts = test_env
+        .client
+        .get_document_parts(&index_id, large_doc_id)
+        .await?;
+
+    let params = UpdateDocumentParamsBuilder::default()
+        .file_name("renamed-document.docx".to_string())
+        .file_path("./renamed-document.docx".to_string())
+        .file_size(2048)
+        .modified_at(1)
+        .metadata(None)
+        .build()?;
+
+    test_env
+        .client
+        .update_document_parts(&index_id, large_doc_id, &params)
+        .await?;
+
+    let updated_parts = test_env
+        .client
+        .get_document_parts(&index_id, large_doc_id)
+        .await?;
+
+    assert_eq!(updated_parts.len(), stored_parts.len());
+    for (updated, stored) in updated_parts.iter().zip(stored_parts.iter()) {
+        assert_eq!(updated.file_path, params.file_path);
+        assert_eq!(updated.created_at, stored.created_at);
+        assert_eq!(updated.content, stored.content);
+        assert!(updated.metadata.is_none());
+    }
+
+    let first_part = test_env
+        .client
+        .get_document_part(&index_id, &stored_info.first_part_id)
+        .await?;
+
+    assert_eq!(first_part.file_name, params.file_name);
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use anyhow::{Context, anyhow};
+
+pub const DEFAULT_DISTANCE: &str = "5km";
+
+const EARTH_RADIUS_METERS: f64 = 6_371_008.8;
+
+/// Parses distance value like `500m`, `5km` or `2.5mi` into meters.
+/// A value without unit is treated as meters.
+pub fn parse_distance(distance: &str) -> anyhow::Result<f64> {
+    let distance = distance.trim().to_lowercase();
+    let split_pos = distance
+        .find(|ch: char| !(ch.is_ascii_digit() || ch == '.'))
+        .unwrap_or(distance.len());
+
+    let (value, unit) = distance.split_at(split_pos);
+    let value = value
+        .parse::<f64>()
+        .with_context(|| format!("invalid distance: {distance}"))?;
+
+    let multiplier = match unit.trim() {
+        "" | "m" => 1.0,
+        "km" => 1000.0,
+        "mi" => 1609.344,
+        unit => return Err(anyhow!("unknown distance unit: {unit}")),
+    };
+
+    Ok(value * multiplier)
+}
+
+/// Great-circle distance in meters between two points given in degrees.
+pub fn haversine_distance(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+    let d_lat = (lat2 - lat1).to_radians();
+    let d_lon = (lon2 - lon1).to_radians();
+    let a = (d_lat / 2.0).sin().powi(2)
+        + lat1.to_radians().cos() * lat2.to_radians().cos() * (d_lon / 2.0).sin().powi(2);
+
+    2.0 * EARTH_RADIUS_METERS * a.sqrt().asin()
+}
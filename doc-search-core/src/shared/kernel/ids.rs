@@ -1,3 +1,4 @@
+use serde_derive::{Deserialize, Serialize};
 use std::fmt::Display;
 use std::ops::Deref;
 
@@ -5,7 +6,7 @@ use std::ops::Deref;
 ///
 /// Represents the unique identifier of a complete document that may be split
 /// into multiple parts for processing and storage.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct LargeDocumentId(pub String);
 
 impl Deref for LargeDocumentId {
@@ -31,7 +32,7 @@ impl LargeDocumentId {
 ///
 /// Represents the unique identifier of an individual document part,
 /// typically combining the large document ID with the part number.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct DocumentPartId(pub String);
 
 impl Deref for DocumentPartId {
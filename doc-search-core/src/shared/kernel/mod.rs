@@ -3,4 +3,11 @@ pub use ids::DocumentPartId;
 pub use ids::IndexId;
 pub use ids::LargeDocumentId;
 
+mod pattern;
+pub use pattern::{edit_distance, is_index_pattern_matched};
+
 pub mod metadata;
+
+pub mod geo;
+
+pub mod vector;
@@ -0,0 +1,53 @@
+/// Checks whether an index name matches an index pattern.
+///
+/// Patterns follow the OpenSearch index pattern syntax restricted to `*`
+/// wildcards, e.g. `team-*` or `*-archive`. A `*` matches any sequence of
+/// characters, including the empty one.
+pub fn is_index_pattern_matched(pattern: &str, index: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let Some(prefix) = parts.next() else {
+        return index.is_empty();
+    };
+
+    let Some(mut rest) = index.strip_prefix(prefix) else {
+        return false;
+    };
+
+    let mut parts = parts.peekable();
+    if parts.peek().is_none() {
+        return rest.is_empty();
+    }
+
+    while let Some(part) = parts.next() {
+        if parts.peek().is_none() {
+            return rest.len() >= part.len() && rest.ends_with(part);
+        }
+
+        match rest.find(part) {
+            Some(position) => rest = &rest[position + part.len()..],
+            None => return false,
+        }
+    }
+
+    true
+}
+
+/// Returns Levenshtein distance between two strings counted by chars.
+pub fn edit_distance(left: &str, right: &str) -> usize {
+    let right = right.chars().collect::<Vec<char>>();
+    let mut row = (0..=right.len()).collect::<Vec<usize>>();
+    for (left_pos, left_ch) in left.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = left_pos + 1;
+        for (right_pos, right_ch) in right.iter().enumerate() {
+            let above = row[right_pos + 1];
+            row[right_pos + 1] = match left_ch.eq(right_ch) {
+                true => diagonal,
+                false => 1 + diagonal.min(above).min(row[right_pos]),
+            };
+            diagonal = above;
+        }
+    }
+
+    row[right.len()]
+}
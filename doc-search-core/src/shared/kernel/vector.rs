@@ -0,0 +1,36 @@
+/// Element-wise mean of embedding vectors, skipping ones of other dimension
+/// than the first vector has.
+pub fn mean_vector(vectors: &[Vec<f64>]) -> Option<Vec<f64>> {
+    let dimension = vectors.first()?.len();
+    let vectors = vectors
+        .iter()
+        .filter(|it| it.len() == dimension)
+        .collect::<Vec<&Vec<f64>>>();
+
+    let mut mean = vec![0.0; dimension];
+    for vector in vectors.iter() {
+        mean.iter_mut()
+            .zip(vector.iter())
+            .for_each(|(sum, it)| *sum += it);
+    }
+
+    let amount = vectors.len() as f64;
+    mean.iter_mut().for_each(|it| *it /= amount);
+    Some(mean)
+}
+
+/// Cosine similarity of two vectors, zero for vectors of different dimension.
+pub fn cosine_similarity(left: &[f64], right: &[f64]) -> f64 {
+    if left.len() != right.len() {
+        return 0.0;
+    }
+
+    let dot = left.iter().zip(right).map(|(a, b)| a * b).sum::<f64>();
+    let norm = left.iter().map(|it| it * it).sum::<f64>().sqrt()
+        * right.iter().map(|it| it * it).sum::<f64>().sqrt();
+
+    match norm > 0.0 {
+        true => dot / norm,
+        false => 0.0,
+    }
+}
@@ -10,7 +10,11 @@ async fn main() -> anyhow::Result<()> {
     let config = ServiceConfig::new()?;
     let _otlp_guard = otlp::init_telemetry(SERVICE_NAME, config.telemetry())?;
 
-    let os_config = config.storage().opensearch();
+    let os_config = config
+        .storage()
+        .opensearch()
+        .as_ref()
+        .ok_or_else(|| anyhow::anyhow!("storage.opensearch config is required"))?;
     let os_client = OSearchClient::connect(os_config).await?;
 
     os_client.update_cluster_settings().await?;
@@ -1,14 +1,25 @@
 use axum::Router;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::net::TcpListener;
 
 use doc_search::config::ServiceConfig;
 use doc_search::meter::AppMeterRegistry;
-use doc_search::server::{httpserver, httpserver::mw, ServerApp};
+use doc_search::server::httpserver::mw::cache::RedisClient;
+use doc_search::server::jobs::{IJobStore, IngestionQueue, JobStoreBackend};
+use doc_search::server::jobs::{MemoryJobStore, RedisJobStore};
+use doc_search::server::{httpserver, httpserver::mw, ServerApp, StorageBackend};
 use doc_search::SERVICE_NAME;
 use doc_search_core::application::usecase::searcher::SearcherUseCase;
 use doc_search_core::application::usecase::storage::StorageUseCase;
+use doc_search_core::domain::searcher::{IPaginator, ISearcher};
+use doc_search_core::domain::storage::{IDocumentPartStorage, IIndexStorage};
+use doc_search_core::infrastructure::composite::{CompositeSearcher, CompositeStorage};
+use doc_search_core::infrastructure::embedder::HttpEmbedder;
+use doc_search_core::infrastructure::memory::MemoryClient;
 use doc_search_core::infrastructure::osearch::OSearchClient;
+use doc_search_core::infrastructure::qdrant::QdrantClient;
+use doc_search_core::infrastructure::tantivy::TantivyClient;
 use doc_search_core::ServiceConnect;
 
 #[tokio::main(worker_threads = 8)]
@@ -16,19 +27,39 @@ async fn main() -> anyhow::Result<()> {
     let config = ServiceConfig::new()?;
     let _otlp_guard = otlp::init_telemetry(SERVICE_NAME, config.telemetry())?;
 
-    let osearch_config = config.storage().opensearch();
-    let osearch_client = Arc::new(OSearchClient::connect(osearch_config).await?);
-
-    let max_content_size = config.settings().max_content_size();
-    let storage_uc = Arc::new(StorageUseCase::new(
-        osearch_client.clone(),
-        max_content_size,
-    ));
-    let searcher_uc = Arc::new(SearcherUseCase::new(osearch_client.clone()));
-    let app_meter = AppMeterRegistry::build_meter_registry()?;
-    let server_app = ServerApp::new(storage_uc, searcher_uc, app_meter);
-
-    let app = httpserver::init_server(server_app);
+    let storage_config = config.storage();
+    let app = match storage_config.backend() {
+        StorageBackend::OpenSearch => {
+            let osearch_config = storage_config.opensearch().as_ref().ok_or_else(|| {
+                anyhow::anyhow!("storage.opensearch config is required by backend")
+            })?;
+            osearch_config.validate()?;
+            let osearch_client = Arc::new(OSearchClient::connect(osearch_config).await?);
+            match storage_config.qdrant() {
+                None => init_app(&config, osearch_client.clone(), osearch_client).await?,
+                Some(qdrant_config) => {
+                    let qdrant_client = Arc::new(QdrantClient::connect(qdrant_config).await?);
+                    let storage =
+                        CompositeStorage::new(osearch_client.clone(), qdrant_client.clone());
+                    let searcher = CompositeSearcher::new(osearch_client, qdrant_client);
+                    init_app(&config, Arc::new(storage), Arc::new(searcher)).await?
+                }
+            }
+        }
+        StorageBackend::Tantivy => {
+            let tantivy_config = storage_config
+                .tantivy()
+                .as_ref()
+                .ok_or_else(|| anyhow::anyhow!("storage.tantivy config is required by backend"))?;
+            let tantivy_client = Arc::new(TantivyClient::connect(tantivy_config).await?);
+            init_app(&config, tantivy_client.clone(), tantivy_client).await?
+        }
+        StorageBackend::Memory => {
+            let memory_config = storage_config.memory();
+            let memory_client = Arc::new(MemoryClient::connect(memory_config).await?);
+            init_app(&config, memory_client.clone(), memory_client).await?
+        }
+    };
 
     let cache_config = config.cache();
     let tmp_app_state: anyhow::Result<Router> = match cache_config.is_enabled() {
@@ -42,6 +73,12 @@ async fn main() -> anyhow::Result<()> {
 
     let app = tmp_app_state?;
     let server_config = config.server();
+    let auth_config = server_config.auth();
+    let app = match auth_config.is_enabled() {
+        false => app,
+        true => mw::auth::enable_auth_mw(app, auth_config)?,
+    };
+
     let listener = TcpListener::bind(server_config.http().address()).await?;
     if let Err(err) = axum::serve(listener, app).await {
         tracing::error!(err=?err, "failed to stop http server");
@@ -49,3 +86,45 @@ async fn main() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+async fn init_app<Storage, Searcher>(
+    config: &ServiceConfig,
+    storage: Arc<Storage>,
+    searcher: Arc<Searcher>,
+) -> anyhow::Result<Router>
+where
+    Searcher: ISearcher + IPaginator + Send + Sync + 'static,
+    Storage: IIndexStorage + IDocumentPartStorage + Send + Sync + 'static,
+{
+    let max_content_size = config.settings().max_content_size();
+    let storage_uc = Arc::new(StorageUseCase::new(storage, max_content_size));
+    let searcher_uc = match config.storage().embedder() {
+        None => SearcherUseCase::new(searcher),
+        Some(embedder_config) => {
+            let embedder = Arc::new(HttpEmbedder::connect(embedder_config).await?);
+            let indexes = embedder_config.indexes().clone();
+            SearcherUseCase::new(searcher).with_embedder(embedder, indexes)
+        }
+    };
+
+    let searcher_uc = Arc::new(searcher_uc);
+    let app_meter = AppMeterRegistry::build_meter_registry()?;
+
+    let jobs_config = config.jobs();
+    let job_store: Arc<dyn IJobStore + Send + Sync> = match jobs_config.store() {
+        JobStoreBackend::Memory => {
+            let expired = Duration::from_secs(jobs_config.expired());
+            Arc::new(MemoryJobStore::new(expired))
+        }
+        JobStoreBackend::Redis => {
+            let redis_config = config.cache().redis();
+            let redis_client = RedisClient::connect(redis_config).await?;
+            Arc::new(RedisJobStore::new(Arc::new(redis_client)))
+        }
+    };
+
+    let concurrency = jobs_config.concurrency();
+    let ingestion = IngestionQueue::new(storage_uc.clone(), job_store, concurrency);
+    let server_app = ServerApp::new(storage_uc, searcher_uc, ingestion, app_meter);
+    Ok(httpserver::init_server(server_app))
+}
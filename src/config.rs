@@ -4,6 +4,7 @@ use gset::Getset;
 use otlp::TelemetryConfig;
 use serde_derive::Deserialize;
 
+use crate::server::jobs::JobsConfig;
 use crate::server::{CacheConfig, ServerConfig, StorageConfig};
 
 const CONFIG_PREFIX: &str = "DOC_SEARCH";
@@ -22,6 +23,9 @@ pub struct ServiceConfig {
     storage: StorageConfig,
     #[getset(get, vis = "pub")]
     cache: CacheConfig,
+    #[serde(default)]
+    #[getset(get, vis = "pub")]
+    jobs: JobsConfig,
 }
 
 #[derive(Clone, Deserialize, Getset)]
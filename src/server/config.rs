@@ -1,7 +1,12 @@
+use doc_search_core::infrastructure::embedder::EmbedderConfig;
+use doc_search_core::infrastructure::memory::MemoryConfig;
 use doc_search_core::infrastructure::osearch::OSearchConfig;
+use doc_search_core::infrastructure::qdrant::QdrantConfig;
+use doc_search_core::infrastructure::tantivy::TantivyConfig;
 use gset::Getset;
 use serde_derive::Deserialize;
 
+use crate::server::httpserver::mw::auth::AuthConfig;
 use crate::server::httpserver::mw::cache::RedisConfig;
 use crate::server::httpserver::HttpServerConfig;
 
@@ -9,12 +14,40 @@ use crate::server::httpserver::HttpServerConfig;
 pub struct ServerConfig {
     #[getset(get, vis = "pub")]
     http: HttpServerConfig,
+    #[serde(default)]
+    #[getset(get, vis = "pub")]
+    auth: AuthConfig,
 }
 
 #[derive(Clone, Deserialize, Getset)]
 pub struct StorageConfig {
+    #[serde(default)]
+    #[getset(get_copy, vis = "pub")]
+    backend: StorageBackend,
+    #[serde(default)]
+    #[getset(get, vis = "pub")]
+    opensearch: Option<OSearchConfig>,
+    #[serde(default)]
+    #[getset(get, vis = "pub")]
+    tantivy: Option<TantivyConfig>,
+    #[serde(default)]
     #[getset(get, vis = "pub")]
-    opensearch: OSearchConfig,
+    memory: MemoryConfig,
+    #[serde(default)]
+    #[getset(get, vis = "pub")]
+    qdrant: Option<QdrantConfig>,
+    #[serde(default)]
+    #[getset(get, vis = "pub")]
+    embedder: Option<EmbedderConfig>,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StorageBackend {
+    #[default]
+    OpenSearch,
+    Tantivy,
+    Memory,
 }
 
 #[derive(Clone, Deserialize, Getset)]
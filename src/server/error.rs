@@ -15,6 +15,8 @@ pub type ServerResult<T> = Result<T, ServerError>;
 pub enum ServerError {
     #[error("server: auth failed: {0}")]
     AuthenticationFailed(String),
+    #[error("server: access denied: {0}")]
+    Forbidden(String),
     #[error("server: resource data conflict: {0}")]
     Conflict(String),
     #[error("server: not found error: {0}")]
@@ -36,6 +38,7 @@ impl From<StorageError> for ServerError {
                 ServerError::AuthenticationFailed(err.to_string())
             }
             StorageError::IndexNotFound(err) => ServerError::NotFound(err.to_string()),
+            StorageError::IndexAlreadyExists(err) => ServerError::Conflict(err.to_string()),
             StorageError::DocumentAlreadyExists(err) => {
                 ServerError::Conflict(err.to_string().to_string())
             }
@@ -58,6 +61,7 @@ impl From<SearchError> for ServerError {
                 ServerError::AuthenticationFailed(err.to_string())
             }
             SearchError::IndexNotFound(err) => ServerError::NotFound(err.to_string()),
+            SearchError::DocumentNotFound(err) => ServerError::NotFound(err.to_string()),
             SearchError::InternalError(err) => ServerError::InternalError(err.to_string()),
             SearchError::ValidationError(err) => ServerError::IncorrectInputForm(err.to_string()),
             SearchError::ConnectionError(err) => ServerError::InternalError(err.to_string()),
@@ -71,6 +75,7 @@ impl ServerError {
     pub fn status_code(&self) -> (StatusCode, &str) {
         match self {
             ServerError::AuthenticationFailed(err) => (StatusCode::UNAUTHORIZED, err),
+            ServerError::Forbidden(err) => (StatusCode::FORBIDDEN, err),
             ServerError::NotFound(err) => (StatusCode::NOT_FOUND, err),
             ServerError::Conflict(err) => (StatusCode::CONFLICT, err),
             ServerError::InternalError(err) => (StatusCode::INTERNAL_SERVER_ERROR, err),
@@ -1,4 +1,8 @@
+use axum::extract::Multipart;
+use doc_search_core::domain::storage::models::DocumentPartEmbeddings;
 use doc_search_core::domain::storage::models::{LargeDocument, LargeDocumentBuilder};
+use doc_search_core::domain::storage::models::{UpdateDocumentParams, UpdateDocumentParamsBuilder};
+use doc_search_core::domain::storage::models::{UploadedFile, UploadedFileBuilder};
 use serde_derive::{Deserialize, Serialize};
 use utoipa::{IntoParams, ToSchema};
 
@@ -23,6 +27,26 @@ pub struct CreateDocumentForm {
     #[schema(example = "There is some content data")]
     pub content: String,
     pub metadata: Option<Metadata>,
+    /// Chunks and embeddings of each document part computed by own embeddings
+    /// service. Document is divided on parts by them and stored as is.
+    pub embeddings: Option<Vec<DocumentPartEmbeddingsForm>>,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct DocumentPartEmbeddingsForm {
+    #[schema(example = json!(["There is some content data"]))]
+    pub chunked_text: Vec<String>,
+    #[schema(example = json!([[0.0123, -0.0456, 0.0789]]))]
+    pub embeddings: Vec<Vec<f64>>,
+}
+
+impl From<DocumentPartEmbeddingsForm> for DocumentPartEmbeddings {
+    fn from(form: DocumentPartEmbeddingsForm) -> Self {
+        DocumentPartEmbeddings {
+            chunked_text: form.chunked_text,
+            embeddings: form.embeddings,
+        }
+    }
 }
 
 impl TryFrom<CreateDocumentForm> for LargeDocument {
@@ -34,6 +58,10 @@ impl TryFrom<CreateDocumentForm> for LargeDocument {
             None => None,
         };
 
+        let embeddings = form
+            .embeddings
+            .map(|it| it.into_iter().map(DocumentPartEmbeddings::from).collect());
+
         LargeDocumentBuilder::default()
             .file_name(form.file_name)
             .file_path(form.file_path)
@@ -42,6 +70,7 @@ impl TryFrom<CreateDocumentForm> for LargeDocument {
             .created_at(form.created_at)
             .modified_at(form.modified_at)
             .metadata(meta)
+            .embeddings(embeddings)
             .build()
             .map_err(|err| ServerError::IncorrectInputForm(err.to_string()))
     }
@@ -58,7 +87,7 @@ pub struct UpdateDocumentForm {
     #[schema(example = 1750957115)]
     pub created_at: i64,
     #[schema(nullable, example = "There is some content data")]
-    pub content: String,
+    pub content: Option<String>,
     pub metadata: Option<Metadata>,
 }
 
@@ -76,7 +105,7 @@ impl TryFrom<UpdateDocumentForm> for LargeDocument {
             .file_name(form.file_name)
             .file_path(form.file_path)
             .file_size(form.file_size)
-            .content(form.content)
+            .content(form.content.unwrap_or_default())
             .created_at(form.created_at)
             .modified_at(modified_dt)
             .metadata(meta)
@@ -84,3 +113,142 @@ impl TryFrom<UpdateDocumentForm> for LargeDocument {
             .map_err(|err| ServerError::IncorrectInputForm(err.to_string()))
     }
 }
+
+impl TryFrom<UpdateDocumentForm> for UpdateDocumentParams {
+    type Error = ServerError;
+
+    fn try_from(form: UpdateDocumentForm) -> Result<Self, Self::Error> {
+        let meta = match form.metadata {
+            Some(data) => data.try_into().ok(),
+            None => None,
+        };
+
+        let modified_dt = chrono::Utc::now().timestamp();
+        UpdateDocumentParamsBuilder::default()
+            .file_name(form.file_name)
+            .file_path(form.file_path)
+            .file_size(form.file_size)
+            .modified_at(modified_dt)
+            .metadata(meta)
+            .build()
+            .map_err(|err| ServerError::IncorrectInputForm(err.to_string()))
+    }
+}
+
+const UPLOAD_FILE_FIELD: &str = "file";
+const UPLOAD_PATH_FIELD: &str = "path";
+const UPLOAD_METADATA_FIELD: &str = "metadata";
+
+/// Multipart form of uploaded files. The `file` part may be repeated
+/// to upload several files with the same `path` and `metadata`. Document
+/// id is derived from file path, so `path` is required to keep uploads of
+/// files with the same name apart.
+#[derive(ToSchema)]
+pub struct UploadDocumentForm {
+    #[schema(value_type = Vec<String>, format = Binary)]
+    pub file: Vec<UploadFilePart>,
+    #[schema(example = "./uploads")]
+    pub path: String,
+    #[schema(value_type = Option<String>, example = "{\"source\": \"upload\"}")]
+    pub metadata: Option<Metadata>,
+}
+
+pub struct UploadFilePart {
+    pub file_name: String,
+    pub content_type: Option<String>,
+    pub data: Vec<u8>,
+}
+
+impl UploadDocumentForm {
+    pub async fn from_multipart(mut multipart: Multipart) -> Result<Self, ServerError> {
+        let mut files = Vec::new();
+        let mut path = None;
+        let mut metadata = None;
+
+        while let Some(field) = multipart
+            .next_field()
+            .await
+            .map_err(|err| ServerError::IncorrectInputForm(err.body_text()))?
+        {
+            let field_name = field.name().unwrap_or_default().to_string();
+            match field_name.as_str() {
+                UPLOAD_FILE_FIELD => {
+                    let file_name = field.file_name().map(String::from).ok_or_else(|| {
+                        ServerError::IncorrectInputForm("file name is missing".to_string())
+                    })?;
+
+                    let content_type = field.content_type().map(String::from);
+                    let data = field
+                        .bytes()
+                        .await
+                        .map_err(|err| ServerError::IncorrectInputForm(err.body_text()))?;
+
+                    files.push(UploadFilePart {
+                        file_name,
+                        content_type,
+                        data: data.to_vec(),
+                    });
+                }
+                UPLOAD_PATH_FIELD => {
+                    let text = field
+                        .text()
+                        .await
+                        .map_err(|err| ServerError::IncorrectInputForm(err.body_text()))?;
+                    path = Some(text);
+                }
+                UPLOAD_METADATA_FIELD => {
+                    let data = field
+                        .bytes()
+                        .await
+                        .map_err(|err| ServerError::IncorrectInputForm(err.body_text()))?;
+                    let data = serde_json::from_slice::<Metadata>(&data)
+                        .map_err(|err| ServerError::IncorrectInputForm(err.to_string()))?;
+                    metadata = Some(data);
+                }
+                _ => {
+                    let msg = format!("unexpected form field: {field_name}");
+                    return Err(ServerError::IncorrectInputForm(msg));
+                }
+            }
+        }
+
+        if files.is_empty() {
+            let msg = "there is no file to upload".to_string();
+            return Err(ServerError::IncorrectInputForm(msg));
+        }
+
+        let Some(path) = path.filter(|it| !it.trim().is_empty()) else {
+            let msg = "path of uploaded files is missing".to_string();
+            return Err(ServerError::IncorrectInputForm(msg));
+        };
+
+        Ok(UploadDocumentForm {
+            file: files,
+            path,
+            metadata,
+        })
+    }
+
+    pub fn into_uploaded_files(self, uploaded_at: i64) -> Result<Vec<UploadedFile>, ServerError> {
+        let path = self.path.trim_end_matches('/');
+        let meta = match self.metadata {
+            Some(data) => data.try_into().ok(),
+            None => None,
+        };
+
+        self.file
+            .into_iter()
+            .map(|part| {
+                UploadedFileBuilder::default()
+                    .file_path(format!("{path}/{}", &part.file_name))
+                    .file_name(part.file_name)
+                    .content_type(part.content_type)
+                    .uploaded_at(uploaded_at)
+                    .data(part.data)
+                    .metadata(meta.clone())
+                    .build()
+                    .map_err(|err| ServerError::IncorrectInputForm(err.to_string()))
+            })
+            .collect()
+    }
+}
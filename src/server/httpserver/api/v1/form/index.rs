@@ -1,5 +1,6 @@
 use doc_search_core::domain::storage::models::{
     CreateIndexParams, CreateIndexParamsBuilder, KnnIndexParams, KnnIndexParamsBuilder,
+    SplitterParams,
 };
 use serde_derive::{Deserialize, Serialize};
 use utoipa::{IntoParams, ToSchema};
@@ -15,6 +16,8 @@ pub struct CreateIndexForm {
     pub id: String,
     #[schema(value_type = Option<KnnIndexForm>, example = KnnIndexForm)]
     pub knn: Option<KnnIndexForm>,
+    #[schema(value_type = Option<SplitterForm>, example = json!({"kind": "markdown", "chunk_size": 2048}))]
+    pub splitter: Option<SplitterForm>,
 }
 
 impl TryFrom<CreateIndexForm> for CreateIndexParams {
@@ -25,9 +28,11 @@ impl TryFrom<CreateIndexForm> for CreateIndexParams {
             .knn
             .map(|it| KnnIndexForm::try_into(it).ok())
             .unwrap_or_default();
+        let splitter_params = form.splitter.map(SplitterForm::try_into).transpose()?;
         CreateIndexParamsBuilder::default()
             .id(form.id)
             .knn(knn_params)
+            .splitter(splitter_params)
             .build()
             .map_err(|err| ServerError::IncorrectInputForm(err.to_string()))
     }
@@ -55,3 +60,50 @@ impl TryFrom<KnnIndexForm> for KnnIndexParams {
             .map_err(|err| ServerError::IncorrectInputForm(err.to_string()))
     }
 }
+
+#[derive(Clone, Copy, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SplitterKindForm {
+    Character,
+    Sentence,
+    Markdown,
+    Token,
+}
+
+#[derive(Serialize, Deserialize, IntoParams, ToSchema)]
+pub struct SplitterForm {
+    #[schema(example = "markdown")]
+    pub kind: SplitterKindForm,
+    #[schema(example = 2048)]
+    pub chunk_size: Option<usize>,
+    #[schema(example = json!(null))]
+    pub token_limit: Option<u32>,
+    #[schema(example = json!(null))]
+    pub overlap_rate: Option<f32>,
+}
+
+impl TryFrom<SplitterForm> for SplitterParams {
+    type Error = ServerError;
+
+    fn try_from(form: SplitterForm) -> Result<Self, Self::Error> {
+        let chunk_size = form.chunk_size;
+        let params = match form.kind {
+            SplitterKindForm::Character => SplitterParams::Character { chunk_size },
+            SplitterKindForm::Sentence => SplitterParams::Sentence { chunk_size },
+            SplitterKindForm::Markdown => SplitterParams::Markdown { chunk_size },
+            SplitterKindForm::Token => {
+                let Some(token_limit) = form.token_limit else {
+                    let msg = "token_limit is required by token splitter".to_string();
+                    return Err(ServerError::IncorrectInputForm(msg));
+                };
+
+                SplitterParams::Token {
+                    token_limit,
+                    overlap_rate: form.overlap_rate.unwrap_or_default(),
+                }
+            }
+        };
+
+        Ok(params)
+    }
+}
@@ -2,11 +2,14 @@
 
 mod document;
 pub use document::CreateDocumentForm;
+pub use document::DocumentPartEmbeddingsForm;
 pub use document::UpdateDocumentForm;
+pub use document::{UploadDocumentForm, UploadFilePart};
 
 mod index;
 pub use index::CreateIndexForm;
 pub use index::KnnIndexForm;
+pub use index::{SplitterForm, SplitterKindForm};
 
 mod metadata;
 pub use metadata::Class;
@@ -18,7 +21,8 @@ pub use metadata::Subject;
 
 mod search_params;
 pub use search_params::PaginateForm;
-pub use search_params::{FilterForm, ResultForm, ShortResultForm};
+pub use search_params::{AggregationForm, FilterForm, ResultForm, ShortResultForm, SortForm};
 pub use search_params::{
     FullTextSearchForm, HybridSearchForm, RetrieveDocumentForm, SemanticSearchForm,
+    SimilarSearchForm,
 };
@@ -1,10 +1,14 @@
 use doc_search_core::domain::searcher::models::{
-    FilterParams, FilterParamsBuilder, FullTextSearchingParamsBuilder,
-    HybridSearchingParamsBuilder, PaginationParams, PaginationParamsBuilder, ResultOrder,
-    ResultParams, ResultParamsBuilder, RetrieveIndexDocumentsParams,
-    RetrieveIndexDocumentsParamsBuilder, SearchKindParams, SearchingParams,
-    SemanticSearchingParamsBuilder,
+    AggregationParams, AggregationParamsBuilder, FacetKind, FilterParams, FilterParamsBuilder,
+    FullTextSearchingParamsBuilder, HistogramInterval, HybridFusion, HybridScoringParams,
+    HybridScoringParamsBuilder, HybridSearchingParamsBuilder, PaginationParams,
+    PaginationParamsBuilder, ResultOrder, ResultParams, ResultParamsBuilder,
+    RetrieveIndexDocumentsParams, RetrieveIndexDocumentsParamsBuilder, ScoreCombination,
+    ScoreNormalization, SearchKindParams, SearchingParams, SemanticSearchingParamsBuilder,
+    SimilarMode, SimilarSearchingParamsBuilder, SimilarSource, SortField, SortParams,
 };
+use doc_search_core::shared::kernel::geo::parse_distance;
+use doc_search_core::shared::kernel::{DocumentPartId, LargeDocumentId};
 use gset::Getset;
 use serde_derive::{Deserialize, Serialize};
 use utoipa::{IntoParams, ToSchema};
@@ -14,6 +18,11 @@ use serde_json::json;
 
 use crate::server::ServerError;
 
+const HYBRID_WEIGHTS_TOLERANCE: f32 = 0.001;
+const DEFAULT_SIMILAR_KNN_AMOUNT: u16 = 100;
+const MAX_LONGITUDE: f64 = 180.0;
+const MAX_LATITUDE: f64 = 90.0;
+
 #[derive(Serialize, Deserialize, IntoParams, ToSchema)]
 pub struct FilterForm {
     #[schema(example = 0)]
@@ -36,9 +45,11 @@ pub struct FilterForm {
     pub source: Option<String>,
     #[schema(example = "semantic-source-name")]
     pub semantic_source: Option<String>,
+    /// Radius around `location_coordinates` in `m`, `km` or `mi`, `5km` by default.
     #[schema(example = "80km")]
     pub distance: Option<String>,
-    #[schema(example = json!([45.99, 29.43]))]
+    /// Pair of longitude and latitude in degrees.
+    #[schema(example = json!([29.43, 45.99]))]
     pub location_coordinates: Option<Vec<f64>>,
     #[schema(example = "war")]
     pub document_class: Option<String>,
@@ -50,6 +61,26 @@ impl TryFrom<FilterForm> for FilterParams {
     type Error = ServerError;
 
     fn try_from(form: FilterForm) -> Result<Self, Self::Error> {
+        validate_range("size", form.size_from, form.size_to)?;
+        validate_range("created", form.created_from, form.created_to)?;
+        validate_range("modified", form.modified_from, form.modified_to)?;
+
+        if let Some(coords) = form.location_coordinates.as_deref() {
+            validate_coordinates(coords)?;
+        }
+
+        if let Some(distance) = form.distance.as_deref() {
+            validate_distance(distance)?;
+        }
+
+        if form
+            .document_class_probability
+            .is_some_and(|it| !(0.0..=1.0).contains(&it))
+        {
+            let msg = "document_class_probability must be within [0, 1]";
+            return Err(ServerError::IncorrectInputForm(msg.to_string()));
+        }
+
         FilterParamsBuilder::default()
             .doc_part_id(form.doc_part_id)
             .size_from(form.size_from)
@@ -70,6 +101,86 @@ impl TryFrom<FilterForm> for FilterParams {
     }
 }
 
+fn validate_range<T: PartialOrd>(
+    field: &str,
+    from: Option<T>,
+    to: Option<T>,
+) -> Result<(), ServerError> {
+    match (from, to) {
+        (Some(from), Some(to)) if from > to => {
+            let msg = format!("{field}_from must not be greater than {field}_to");
+            Err(ServerError::IncorrectInputForm(msg))
+        }
+        _ => Ok(()),
+    }
+}
+
+fn validate_coordinates(coords: &[f64]) -> Result<(), ServerError> {
+    let [longitude, latitude] = coords else {
+        let msg = "location_coordinates must be a pair of longitude and latitude";
+        return Err(ServerError::IncorrectInputForm(msg.to_string()));
+    };
+
+    if !(-MAX_LONGITUDE..=MAX_LONGITUDE).contains(longitude) {
+        let msg = format!("longitude must be within [-{MAX_LONGITUDE}, {MAX_LONGITUDE}]");
+        return Err(ServerError::IncorrectInputForm(msg));
+    }
+
+    if !(-MAX_LATITUDE..=MAX_LATITUDE).contains(latitude) {
+        let msg = format!("latitude must be within [-{MAX_LATITUDE}, {MAX_LATITUDE}]");
+        return Err(ServerError::IncorrectInputForm(msg));
+    }
+
+    Ok(())
+}
+
+fn validate_distance(distance: &str) -> Result<(), ServerError> {
+    let meters =
+        parse_distance(distance).map_err(|err| ServerError::IncorrectInputForm(err.to_string()))?;
+
+    if !meters.is_finite() || meters <= 0.0 {
+        let msg = format!("distance must be a positive finite value: {distance}");
+        return Err(ServerError::IncorrectInputForm(msg));
+    }
+
+    Ok(())
+}
+
+#[derive(Serialize, Deserialize, IntoParams, ToSchema)]
+pub struct AggregationForm {
+    #[schema(example = json!(["source", "classes", "created_at"]))]
+    pub facets: Vec<String>,
+    #[schema(example = 10)]
+    pub size: Option<u32>,
+    #[schema(example = "month")]
+    pub interval: Option<String>,
+}
+
+impl TryFrom<AggregationForm> for AggregationParams {
+    type Error = ServerError;
+
+    fn try_from(form: AggregationForm) -> Result<Self, Self::Error> {
+        let facets = form
+            .facets
+            .iter()
+            .map(|it| convert_string_to_facet_kind(it))
+            .collect::<Result<Vec<FacetKind>, ServerError>>()?;
+
+        let mut builder = AggregationParamsBuilder::default();
+        builder.facets(facets);
+        if let Some(size) = form.size {
+            builder.size(size);
+        }
+        if let Some(interval) = form.interval {
+            builder.interval(convert_string_to_histogram_interval(&interval)?);
+        }
+
+        builder
+            .build()
+            .map_err(|err| ServerError::IncorrectInputForm(err.to_string()))
+    }
+}
+
 #[derive(Serialize, Deserialize, IntoParams, ToSchema)]
 pub struct ResultForm {
     #[schema(example = "desc")]
@@ -84,13 +195,28 @@ pub struct ResultForm {
     pub highlight_items: Option<u16>,
     #[schema(example = 600)]
     pub highlight_item_size: Option<u32>,
+    /// Collapse matched parts of the same document into a single result.
+    #[schema(example = false)]
+    pub group_by_document: Option<bool>,
+    /// Sort keys applied in turn instead of default sorting.
+    pub sort: Option<Vec<SortForm>>,
+    /// Count all matched results exactly instead of stopping at a threshold.
+    #[schema(example = false)]
+    pub track_total_hits: Option<bool>,
 }
 
 impl TryFrom<ResultForm> for ResultParams {
     type Error = ServerError;
 
     fn try_from(form: ResultForm) -> Result<Self, Self::Error> {
-        let result_order = convert_string_to_result_form(form.order);
+        let result_order = convert_string_to_result_form(&form.order)?;
+        let sort = form
+            .sort
+            .unwrap_or_default()
+            .into_iter()
+            .map(SortParams::try_from)
+            .collect::<Result<Vec<SortParams>, ServerError>>()?;
+
         ResultParamsBuilder::default()
             .order(result_order)
             .size(form.size.into())
@@ -98,11 +224,36 @@ impl TryFrom<ResultForm> for ResultParams {
             .include_extra_fields(form.include_extra_fields)
             .highlight_items(form.highlight_items)
             .highlight_item_size(form.highlight_item_size)
+            .group_by_document(form.group_by_document)
+            .sort(sort)
+            .track_total_hits(form.track_total_hits)
             .build()
             .map_err(|err| ServerError::IncorrectInputForm(err.to_string()))
     }
 }
 
+#[derive(Serialize, Deserialize, IntoParams, ToSchema)]
+pub struct SortForm {
+    #[schema(example = "_score")]
+    pub field: String,
+    #[schema(example = "desc")]
+    pub order: Option<String>,
+}
+
+impl TryFrom<SortForm> for SortParams {
+    type Error = ServerError;
+
+    fn try_from(form: SortForm) -> Result<Self, Self::Error> {
+        let field = convert_string_to_sort_field(&form.field)?;
+        let order = match form.order {
+            None => ResultOrder::default(),
+            Some(order) => convert_string_to_result_form(&order)?,
+        };
+
+        Ok(SortParams::new(field, order))
+    }
+}
+
 #[derive(Serialize, Deserialize, IntoParams, ToSchema)]
 pub struct ShortResultForm {
     #[schema(example = "desc")]
@@ -119,7 +270,7 @@ impl TryFrom<ShortResultForm> for ResultParams {
     type Error = ServerError;
 
     fn try_from(form: ShortResultForm) -> Result<Self, Self::Error> {
-        let result_order = convert_string_to_result_form(form.order);
+        let result_order = convert_string_to_result_form(&form.order)?;
         ResultParamsBuilder::default()
             .order(result_order)
             .size(form.size.into())
@@ -159,6 +310,7 @@ pub struct FullTextSearchForm {
     pub indexes: String,
     pub filter: Option<FilterForm>,
     pub result: ResultForm,
+    pub aggregations: Option<AggregationForm>,
 }
 
 impl TryFrom<FullTextSearchForm> for SearchingParams {
@@ -170,11 +322,12 @@ impl TryFrom<FullTextSearchForm> for SearchingParams {
             .split(',')
             .map(String::from)
             .collect::<Vec<String>>();
-        let filter_params = form
-            .filter
-            .map(|it| FilterParams::try_from(it).ok())
-            .unwrap_or_default();
+        let filter_params = form.filter.map(FilterParams::try_from).transpose()?;
         let result = form.result.try_into()?;
+        let aggregations = form
+            .aggregations
+            .map(AggregationParams::try_from)
+            .transpose()?;
         let full_text_params = FullTextSearchingParamsBuilder::default()
             .query(form.query)
             .build()
@@ -185,7 +338,8 @@ impl TryFrom<FullTextSearchForm> for SearchingParams {
             SearchKindParams::FullText(full_text_params),
             result,
             filter_params,
-        ))
+        )
+        .with_aggregations(aggregations))
     }
 }
 
@@ -201,29 +355,99 @@ pub struct HybridSearchForm {
     pub model_id: Option<String>,
     #[schema(example = 0.7)]
     pub min_score: Option<f32>,
+    /// Weight of the semantic score, lexical one is `1 - semantic_weight` if omitted.
+    #[schema(example = 0.3)]
+    pub semantic_weight: Option<f32>,
+    /// Weight of the lexical score, semantic one is `1 - lexical_weight` if omitted.
+    #[schema(example = 0.7)]
+    pub lexical_weight: Option<f32>,
+    #[schema(example = "min_max")]
+    pub normalization: Option<String>,
+    #[schema(example = "arithmetic_mean")]
+    pub combination: Option<String>,
+    #[schema(example = 20)]
+    pub pagination_depth: Option<usize>,
+    /// Fuse fulltext and semantic results within service: `rrf` or `weighted_score`.
+    #[schema(example = "rrf")]
+    pub fusion: Option<String>,
     pub result: ResultForm,
     pub filter: Option<FilterForm>,
+    pub aggregations: Option<AggregationForm>,
+}
+
+impl HybridSearchForm {
+    fn build_scoring_params(&self) -> Result<Option<HybridScoringParams>, ServerError> {
+        let is_default_scoring = self.semantic_weight.is_none()
+            && self.lexical_weight.is_none()
+            && self.normalization.is_none()
+            && self.combination.is_none();
+
+        if is_default_scoring {
+            return Ok(None);
+        }
+
+        let mut builder = HybridScoringParamsBuilder::default();
+        if let Some(normalization) = self.normalization.as_deref() {
+            builder.normalization(convert_string_to_score_normalization(normalization)?);
+        }
+        if let Some(combination) = self.combination.as_deref() {
+            builder.combination(convert_string_to_score_combination(combination)?);
+        }
+
+        let weights = match (self.semantic_weight, self.lexical_weight) {
+            (Some(semantic), Some(lexical)) => Some((semantic, lexical)),
+            (Some(semantic), None) => Some((semantic, 1.0 - semantic)),
+            (None, Some(lexical)) => Some((1.0 - lexical, lexical)),
+            (None, None) => None,
+        };
+
+        if let Some((semantic, lexical)) = weights {
+            let is_in_range = (0.0..=1.0).contains(&semantic) && (0.0..=1.0).contains(&lexical);
+            if !is_in_range || (semantic + lexical - 1.0).abs() > HYBRID_WEIGHTS_TOLERANCE {
+                return Err(ServerError::IncorrectInputForm(format!(
+                    "hybrid weights must be within [0, 1] and sum up to 1: {semantic}, {lexical}"
+                )));
+            }
+
+            builder.semantic_weight(semantic).lexical_weight(lexical);
+        }
+
+        builder
+            .build()
+            .map(Some)
+            .map_err(|err| ServerError::IncorrectInputForm(err.to_string()))
+    }
 }
 
 impl TryFrom<HybridSearchForm> for SearchingParams {
     type Error = ServerError;
 
     fn try_from(form: HybridSearchForm) -> Result<Self, Self::Error> {
+        let scoring = form.build_scoring_params()?;
+        let fusion = form
+            .fusion
+            .as_deref()
+            .map(convert_string_to_hybrid_fusion)
+            .transpose()?;
         let indexes = form
             .indexes
             .split(',')
             .map(String::from)
             .collect::<Vec<String>>();
-        let filter_params = form
-            .filter
-            .map(|it| FilterParams::try_from(it).ok())
-            .unwrap_or_default();
+        let filter_params = form.filter.map(FilterParams::try_from).transpose()?;
         let result = form.result.try_into()?;
+        let aggregations = form
+            .aggregations
+            .map(AggregationParams::try_from)
+            .transpose()?;
         let hybrid_params = HybridSearchingParamsBuilder::default()
             .query(form.query)
             .knn_amount(form.knn_amount)
             .model_id(form.model_id)
             .min_score(form.min_score)
+            .scoring(scoring)
+            .pagination_depth(form.pagination_depth)
+            .fusion(fusion)
             .build()
             .map_err(|err| ServerError::IncorrectInputForm(err.to_string()))?;
 
@@ -232,7 +456,8 @@ impl TryFrom<HybridSearchForm> for SearchingParams {
             SearchKindParams::Hybrid(hybrid_params),
             result,
             filter_params,
-        ))
+        )
+        .with_aggregations(aggregations))
     }
 }
 
@@ -250,6 +475,7 @@ pub struct SemanticSearchForm {
     pub tokens: Option<Vec<f64>>,
     pub result: ShortResultForm,
     pub filter: Option<FilterForm>,
+    pub aggregations: Option<AggregationForm>,
 }
 
 impl TryFrom<SemanticSearchForm> for SearchingParams {
@@ -261,11 +487,12 @@ impl TryFrom<SemanticSearchForm> for SearchingParams {
             .split(',')
             .map(String::from)
             .collect::<Vec<String>>();
-        let filter_params = form
-            .filter
-            .map(|it| FilterParams::try_from(it).ok())
-            .unwrap_or_default();
+        let filter_params = form.filter.map(FilterParams::try_from).transpose()?;
         let result = form.result.try_into()?;
+        let aggregations = form
+            .aggregations
+            .map(AggregationParams::try_from)
+            .transpose()?;
         let semantic_params = SemanticSearchingParamsBuilder::default()
             .query(form.query)
             .tokens(form.tokens)
@@ -280,6 +507,75 @@ impl TryFrom<SemanticSearchForm> for SearchingParams {
             SearchKindParams::Semantic(semantic_params),
             result,
             filter_params,
+        )
+        .with_aggregations(aggregations))
+    }
+}
+
+#[derive(Serialize, Deserialize, IntoParams, ToSchema)]
+pub struct SimilarSearchForm {
+    #[schema(example = "test-folder-1,test-folder-2")]
+    pub indexes: String,
+    #[schema(example = "test-folder")]
+    pub source_index: String,
+    /// Source document compared by all its parts.
+    #[schema(example = "c5cdd3bfad598ec7")]
+    pub large_doc_id: Option<String>,
+    /// Single source document part, passed instead of `large_doc_id`.
+    #[schema(example = "98ac9896be35f47fb8442580cd9839b4")]
+    pub doc_part_id: Option<String>,
+    /// Comparison by content terms (`lexical`) or stored embeddings (`vector`).
+    #[schema(example = "lexical")]
+    pub mode: Option<String>,
+    #[schema(example = 100)]
+    pub knn_amount: Option<u16>,
+    #[schema(example = 0.5)]
+    pub min_score: Option<f32>,
+    pub result: ShortResultForm,
+    pub filter: Option<FilterForm>,
+}
+
+impl TryFrom<SimilarSearchForm> for SearchingParams {
+    type Error = ServerError;
+
+    fn try_from(form: SimilarSearchForm) -> Result<Self, Self::Error> {
+        let source = match (form.large_doc_id, form.doc_part_id) {
+            (Some(id), None) => SimilarSource::LargeDocument(LargeDocumentId(id)),
+            (None, Some(id)) => SimilarSource::DocumentPart(DocumentPartId(id)),
+            _ => {
+                return Err(ServerError::IncorrectInputForm(
+                    "exactly one of large_doc_id and doc_part_id must be passed".to_string(),
+                ))
+            }
+        };
+
+        let mode = form
+            .mode
+            .as_deref()
+            .map(convert_string_to_similar_mode)
+            .transpose()?
+            .unwrap_or_default();
+        let indexes = form
+            .indexes
+            .split(',')
+            .map(String::from)
+            .collect::<Vec<String>>();
+        let filter_params = form.filter.map(FilterParams::try_from).transpose()?;
+        let result = form.result.try_into()?;
+        let similar_params = SimilarSearchingParamsBuilder::default()
+            .source_index(form.source_index)
+            .source(source)
+            .mode(mode)
+            .knn_amount(form.knn_amount.unwrap_or(DEFAULT_SIMILAR_KNN_AMOUNT))
+            .min_score(form.min_score)
+            .build()
+            .map_err(|err| ServerError::IncorrectInputForm(err.to_string()))?;
+
+        Ok(SearchingParams::new(
+            indexes,
+            SearchKindParams::Similar(similar_params),
+            result,
+            filter_params,
         ))
     }
 }
@@ -287,7 +583,7 @@ impl TryFrom<SemanticSearchForm> for SearchingParams {
 #[derive(Deserialize, Serialize, IntoParams, ToSchema)]
 pub struct PaginateForm {
     #[schema(example = "FGluY2x1ZGVfY29udGV4dF91dWlkDXF1ZXJ5QW5kRmV0Y2gBFmOSWhk")]
-    scroll_id: String,
+    cursor: String,
 }
 
 impl TryFrom<PaginateForm> for PaginationParams {
@@ -295,16 +591,102 @@ impl TryFrom<PaginateForm> for PaginationParams {
 
     fn try_from(form: PaginateForm) -> Result<Self, Self::Error> {
         PaginationParamsBuilder::default()
-            .scroll_id(form.scroll_id)
+            .cursor(form.cursor)
             .build()
             .map_err(|err| ServerError::IncorrectInputForm(err.to_string()))
     }
 }
 
-fn convert_string_to_result_form(order: String) -> ResultOrder {
+fn convert_string_to_result_form(order: &str) -> Result<ResultOrder, ServerError> {
     match order.to_lowercase().as_str() {
-        "asc" => ResultOrder::ASC,
-        "desc" => ResultOrder::DESC,
-        _ => ResultOrder::ASC,
+        "asc" => Ok(ResultOrder::ASC),
+        "desc" => Ok(ResultOrder::DESC),
+        _ => Err(ServerError::IncorrectInputForm(format!(
+            "unknown result order: {order}"
+        ))),
+    }
+}
+
+fn convert_string_to_sort_field(field: &str) -> Result<SortField, ServerError> {
+    match field {
+        "_score" => Ok(SortField::Score),
+        "created_at" => Ok(SortField::CreatedAt),
+        "modified_at" => Ok(SortField::ModifiedAt),
+        "file_size" => Ok(SortField::FileSize),
+        "file_name.keyword" => Ok(SortField::FileName),
+        "metadata.pipeline_id" => Ok(SortField::PipelineId),
+        _ => Err(ServerError::IncorrectInputForm(format!(
+            "unknown sort field: {field}"
+        ))),
+    }
+}
+
+fn convert_string_to_facet_kind(facet: &str) -> Result<FacetKind, ServerError> {
+    match facet.to_lowercase().as_str() {
+        "source" => Ok(FacetKind::Source),
+        "semantic_source" => Ok(FacetKind::SemanticSource),
+        "classes" => Ok(FacetKind::Classes),
+        "subjects" => Ok(FacetKind::Subjects),
+        "groups" => Ok(FacetKind::Groups),
+        "file_extension" => Ok(FacetKind::FileExtension),
+        "created_at" => Ok(FacetKind::CreatedAt),
+        _ => Err(ServerError::IncorrectInputForm(format!(
+            "unknown facet: {facet}"
+        ))),
+    }
+}
+
+fn convert_string_to_similar_mode(mode: &str) -> Result<SimilarMode, ServerError> {
+    match mode.to_lowercase().as_str() {
+        "lexical" => Ok(SimilarMode::Lexical),
+        "vector" => Ok(SimilarMode::Vector),
+        _ => Err(ServerError::IncorrectInputForm(format!(
+            "unknown similar mode: {mode}"
+        ))),
+    }
+}
+
+fn convert_string_to_score_normalization(
+    technique: &str,
+) -> Result<ScoreNormalization, ServerError> {
+    match technique.to_lowercase().as_str() {
+        "min_max" => Ok(ScoreNormalization::MinMax),
+        "l2" => Ok(ScoreNormalization::L2),
+        _ => Err(ServerError::IncorrectInputForm(format!(
+            "unknown score normalization: {technique}"
+        ))),
+    }
+}
+
+fn convert_string_to_score_combination(technique: &str) -> Result<ScoreCombination, ServerError> {
+    match technique.to_lowercase().as_str() {
+        "arithmetic_mean" => Ok(ScoreCombination::ArithmeticMean),
+        "geometric_mean" => Ok(ScoreCombination::GeometricMean),
+        "harmonic_mean" => Ok(ScoreCombination::HarmonicMean),
+        _ => Err(ServerError::IncorrectInputForm(format!(
+            "unknown score combination: {technique}"
+        ))),
+    }
+}
+
+fn convert_string_to_hybrid_fusion(fusion: &str) -> Result<HybridFusion, ServerError> {
+    match fusion.to_lowercase().as_str() {
+        "rrf" => Ok(HybridFusion::ReciprocalRank),
+        "weighted_score" => Ok(HybridFusion::WeightedScore),
+        _ => Err(ServerError::IncorrectInputForm(format!(
+            "unknown hybrid fusion: {fusion}"
+        ))),
+    }
+}
+
+fn convert_string_to_histogram_interval(interval: &str) -> Result<HistogramInterval, ServerError> {
+    match interval.to_lowercase().as_str() {
+        "day" => Ok(HistogramInterval::Day),
+        "week" => Ok(HistogramInterval::Week),
+        "month" => Ok(HistogramInterval::Month),
+        "year" => Ok(HistogramInterval::Year),
+        _ => Err(ServerError::IncorrectInputForm(format!(
+            "unknown histogram interval: {interval}"
+        ))),
     }
 }
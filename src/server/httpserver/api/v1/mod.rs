@@ -6,14 +6,14 @@ mod query;
 pub mod router;
 pub mod schema;
 
+use crate::server::httpserver::mw;
+use crate::server::ServerApp;
 use axum::routing::{get, post, put};
 use axum::Router;
 use doc_search_core::domain::searcher::{IPaginator, ISearcher};
 use doc_search_core::domain::storage::{IDocumentPartStorage, IIndexStorage};
 use std::sync::Arc;
 use tower_http::trace;
-use crate::server::httpserver::mw;
-use crate::server::ServerApp;
 
 pub const API_VERSION_URL: &str = "/api/v1";
 
@@ -23,14 +23,14 @@ where
     Storage: IIndexStorage + IDocumentPartStorage + Send + Sync + 'static,
 {
     let http_log_layer = otlp::HttpLogger::new();
-    let trace_layer = trace::TraceLayer::new_for_http()
-        .make_span_with(otlp::PathFilter::default());
+    let trace_layer = trace::TraceLayer::new_for_http().make_span_with(otlp::PathFilter::default());
 
     let meter_mw = axum::middleware::from_fn(mw::prometheus::meter);
-    
+
     let router: Router<Arc<ServerApp<Storage, Searcher>>> = Router::new()
         .nest(API_VERSION_URL, init_storage_layer())
         .nest(API_VERSION_URL, init_searcher_layer())
+        .nest(API_VERSION_URL, init_jobs_layer())
         .layer(http_log_layer)
         .layer(trace_layer)
         .layer(meter_mw);
@@ -60,12 +60,30 @@ where
         )
         .route(
             router::document::STORAGE_DOCUMENT_URL,
-            get(router::document::get_document_parts).delete(router::document::delete_document),
+            get(router::document::get_document_parts)
+                .patch(router::document::update_document)
+                .delete(router::document::delete_document),
+        )
+        .route(
+            router::document::STORAGE_DOCUMENT_CONTENT_URL,
+            get(router::document::get_document_content),
         )
         .route(
             router::document::CREATE_DOCUMENT_URL,
             put(router::document::store_document),
         )
+        .route(
+            router::document::UPLOAD_DOCUMENT_URL,
+            post(router::document::upload_document),
+        )
+}
+
+fn init_jobs_layer<Storage, Searcher>() -> Router<Arc<ServerApp<Storage, Searcher>>>
+where
+    Searcher: ISearcher + IPaginator + Send + Sync + 'static,
+    Storage: IIndexStorage + IDocumentPartStorage + Send + Sync + 'static,
+{
+    Router::new().route(router::job::JOB_URL, get(router::job::get_job))
 }
 
 fn init_searcher_layer<Storage, Searcher>() -> Router<Arc<ServerApp<Storage, Searcher>>>
@@ -86,8 +104,16 @@ where
             router::searcher::SEARCH_HYBRID_URL,
             post(router::searcher::search_hybrid),
         )
+        .route(
+            router::searcher::SEARCH_SIMILAR_URL,
+            post(router::searcher::search_similar),
+        )
         .route(
             router::searcher::SEARCH_PAGINATE_URL,
-            get(router::searcher::paginate_next),
+            get(router::searcher::paginate_next).delete(router::searcher::release_pagination),
+        )
+        .route(
+            router::searcher::SEARCH_SUGGEST_URL,
+            get(router::searcher::suggest),
         )
 }
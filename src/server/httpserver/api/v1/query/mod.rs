@@ -1,8 +1,77 @@
+use doc_search_core::domain::searcher::models::{SuggestParams, SuggestParamsBuilder};
 use serde_derive::{Deserialize, Serialize};
 use utoipa::IntoParams;
 
+use crate::server::ServerError;
+
+const DEFAULT_SUGGEST_SIZE: usize = 5;
+
 #[derive(Deserialize, Serialize, IntoParams)]
 #[into_params(parameter_in = Query)]
 pub struct CreateDocumentQuery {
+    /// Replace a previously stored document with the same file path
+    /// instead of rejecting the request with a conflict.
+    pub force: Option<bool>,
+}
+
+#[derive(Deserialize, Serialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct StoreDocumentsQuery {
+    /// Store documents within background ingestion job and return
+    /// the job to track its progress instead of waiting for result.
+    #[serde(rename = "async")]
+    pub is_async: Option<bool>,
+    /// Replace previously stored documents with the same file paths
+    /// instead of rejecting them with a conflict.
     pub force: Option<bool>,
 }
+
+#[derive(Deserialize, Serialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct DocumentPartsQuery {
+    /// Return only parts around the document part with such id
+    /// instead of all document parts.
+    pub doc_part_id: Option<usize>,
+    /// Amount of parts returned before and after `doc_part_id`.
+    pub window: Option<usize>,
+}
+
+#[derive(Deserialize, Serialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SuggestQuery {
+    /// Text typed into the search box, the last word is completed.
+    #[param(example = "war and pe")]
+    pub q: String,
+    /// Comma-separated indexes to suggest from or `*` for all of them.
+    #[param(example = "test-folder")]
+    pub indexes: String,
+    /// Maximum amount of completions and corrections each.
+    #[param(example = 5)]
+    pub size: Option<usize>,
+}
+
+impl TryFrom<SuggestQuery> for SuggestParams {
+    type Error = ServerError;
+
+    fn try_from(query: SuggestQuery) -> Result<Self, Self::Error> {
+        if query.q.trim().is_empty() {
+            let msg = "suggested query must not be empty".to_string();
+            return Err(ServerError::IncorrectInputForm(msg));
+        }
+
+        let indexes = query
+            .indexes
+            .split(',')
+            .map(str::trim)
+            .filter(|it| !it.is_empty())
+            .map(String::from)
+            .collect();
+
+        SuggestParamsBuilder::default()
+            .indexes(indexes)
+            .query(query.q)
+            .size(query.size.unwrap_or(DEFAULT_SUGGEST_SIZE))
+            .build()
+            .map_err(|err| ServerError::IncorrectInputForm(err.to_string()))
+    }
+}
@@ -1,4 +1,5 @@
-use axum::extract::{Path, Query, State};
+use axum::extract::{Multipart, Path, Query, State};
+use axum::http::header::CONTENT_TYPE;
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::Json;
@@ -7,24 +8,39 @@ use std::sync::Arc;
 use doc_search_core::domain::searcher::models::RetrieveIndexDocumentsParamsBuilder;
 use doc_search_core::domain::searcher::models::{FilterParams, SearchKindParams, SearchingParams};
 use doc_search_core::domain::searcher::{IPaginator, ISearcher};
-use doc_search_core::domain::storage::models::LargeDocument;
+use doc_search_core::domain::storage::models::{BulkStoreReport, LargeDocument};
 use doc_search_core::domain::storage::{IDocumentPartStorage, IIndexStorage};
 use doc_search_core::shared::kernel::{IndexId, LargeDocumentId};
 
 use crate::server::httpserver::api::v1::form::{CreateDocumentForm, RetrieveDocumentForm};
-use crate::server::httpserver::api::v1::query::CreateDocumentQuery;
-use crate::server::httpserver::api::v1::schema::{DocumentPartSchema, StoredDocumentSchema};
+use crate::server::httpserver::api::v1::form::{UpdateDocumentForm, UploadDocumentForm};
+use crate::server::httpserver::api::v1::query::StoreDocumentsQuery;
+use crate::server::httpserver::api::v1::query::{CreateDocumentQuery, DocumentPartsQuery};
+use crate::server::httpserver::api::v1::router::{authorize_indexes, authorize_search};
+use crate::server::httpserver::api::v1::schema::StoredDocumentSchema;
+use crate::server::httpserver::api::v1::schema::{BulkStoreReportSchema, DocumentPartSchema};
+use crate::server::httpserver::mw::auth::{AccessLevel, Principal};
 use crate::server::httpserver::swagger::DefaultErrorForm;
 use crate::server::httpserver::ServerApp;
+use crate::server::jobs::IngestionJob;
 use crate::server::{ServerError, ServerResult, Success};
 
 pub const STORAGE_ALL_DOCUMENTS_URL: &str = "/storage/{index_ids}/documents";
 pub const STORAGE_DOCUMENT_URL: &str = "/storage/{index_id}/{document_id}";
 pub const STORAGE_GET_DOCUMENT_PARTS_URL: &str = "/storage/{index_id}/{large_document_id}";
+pub const STORAGE_DOCUMENT_CONTENT_URL: &str = "/storage/{index_id}/{large_document_id}/content";
 pub const CREATE_DOCUMENT_URL: &str = "/storage/{index_id}/create";
+pub const UPLOAD_DOCUMENT_URL: &str = "/storage/{index_id}/upload";
 
 const RETRIEVE_DESCRIPTION: &str = include_str!("../../../swagger/descriptions/searcher-retrieve");
 const CREATE_DOC_DESCRIPTION: &str = include_str!("../../../swagger/descriptions/document-create");
+const UPDATE_DOC_DESCRIPTION: &str = include_str!("../../../swagger/descriptions/document-update");
+const STORE_DOCS_DESCRIPTION: &str =
+    include_str!("../../../swagger/descriptions/document-store-bulk");
+const UPLOAD_DOC_DESCRIPTION: &str = include_str!("../../../swagger/descriptions/document-upload");
+
+const DEFAULT_PARTS_WINDOW: usize = 1;
+const TEXT_CONTENT_TYPE: &str = "text/plain; charset=utf-8";
 
 #[utoipa::path(
     get,
@@ -42,6 +58,7 @@ const CREATE_DOC_DESCRIPTION: &str = include_str!("../../../swagger/descriptions
             description = "Document id to load information",
             example = "c5cdd3bfad598ec73dc5fe83fecbba3e",
         ),
+        DocumentPartsQuery,
     ),
     responses(
         (
@@ -51,6 +68,7 @@ const CREATE_DOC_DESCRIPTION: &str = include_str!("../../../swagger/descriptions
             body = Vec<DocumentPartSchema>,
         ),
         (status = 401, description = "Unauthorized access"),
+        (status = 403, description = "Access denied"),
         (status = 404, description = "Not found"),
         (status = 500, description = "Internal error"),
         (status = 501, description = "Error form", body = DefaultErrorForm),
@@ -58,18 +76,32 @@ const CREATE_DOC_DESCRIPTION: &str = include_str!("../../../swagger/descriptions
 )]
 pub async fn get_document_parts<Storage, Searcher>(
     State(state): State<Arc<ServerApp<Storage, Searcher>>>,
+    principal: Option<Principal>,
     Path(path): Path<(String, String)>,
+    Query(query): Query<DocumentPartsQuery>,
 ) -> ServerResult<impl IntoResponse>
 where
     Searcher: ISearcher + IPaginator + Send + Sync + 'static,
     Storage: IIndexStorage + IDocumentPartStorage + Send + Sync + 'static,
 {
     let (index_id, large_doc_id) = path;
+    authorize_indexes(&principal, &index_id, AccessLevel::Read)?;
     let (index_id, large_doc_id) = (IndexId(index_id), LargeDocumentId(large_doc_id));
     let storage = state.get_storage();
-    let document = storage
-        .get_all_document_parts(&index_id, &large_doc_id)
-        .await?;
+    let document = match query.doc_part_id {
+        None => {
+            storage
+                .get_all_document_parts(&index_id, &large_doc_id)
+                .await?
+        }
+        Some(doc_part_id) => {
+            let window = query.window.unwrap_or(DEFAULT_PARTS_WINDOW);
+            storage
+                .get_document_parts_window(&index_id, &large_doc_id, doc_part_id, window)
+                .await?
+        }
+    };
+
     let response = document
         .into_iter()
         .filter_map(|it| it.try_into().ok())
@@ -78,6 +110,55 @@ where
     Ok(Json(response))
 }
 
+#[utoipa::path(
+    get,
+    tag = "document",
+    path = STORAGE_DOCUMENT_CONTENT_URL,
+    description = "Load full Document content assembled from all stored parts",
+    params(
+        (
+            "index_id" = &str,
+            description = "Index id where is stored Document",
+            example = "test-folder",
+        ),
+        (
+            "large_document_id" = &str,
+            description = "Document id to load content",
+            example = "c5cdd3bfad598ec73dc5fe83fecbba3e",
+        ),
+    ),
+    responses(
+        (
+            status = 200,
+            content_type="text/plain",
+            description = "Document content",
+            body = String,
+        ),
+        (status = 401, description = "Unauthorized access"),
+        (status = 403, description = "Access denied"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Internal error"),
+        (status = 501, description = "Error form", body = DefaultErrorForm),
+    )
+)]
+pub async fn get_document_content<Storage, Searcher>(
+    State(state): State<Arc<ServerApp<Storage, Searcher>>>,
+    principal: Option<Principal>,
+    Path(path): Path<(String, String)>,
+) -> ServerResult<impl IntoResponse>
+where
+    Searcher: ISearcher + IPaginator + Send + Sync + 'static,
+    Storage: IIndexStorage + IDocumentPartStorage + Send + Sync + 'static,
+{
+    let (index_id, large_doc_id) = path;
+    authorize_indexes(&principal, &index_id, AccessLevel::Read)?;
+    let (index_id, large_doc_id) = (IndexId(index_id), LargeDocumentId(large_doc_id));
+    let storage = state.get_storage();
+    let large_doc = storage.get_large_document(&index_id, &large_doc_id).await?;
+
+    Ok(([(CONTENT_TYPE, TEXT_CONTENT_TYPE)], large_doc.content))
+}
+
 #[utoipa::path(
     post,
     tag = "document",
@@ -100,6 +181,7 @@ where
         ),
         (status = 400, description = "Validation form error"),
         (status = 401, description = "Unauthorized access"),
+        (status = 403, description = "Access denied"),
         (status = 404, description = "Not found"),
         (status = 500, description = "Internal error"),
         (status = 501, description = "Error form", body = DefaultErrorForm),
@@ -107,6 +189,7 @@ where
 )]
 pub async fn get_index_documents<Storage, Searcher>(
     State(state): State<Arc<ServerApp<Storage, Searcher>>>,
+    principal: Option<Principal>,
     Path(index_ids): Path<String>,
     Json(form): Json<RetrieveDocumentForm>,
 ) -> ServerResult<impl IntoResponse>
@@ -120,10 +203,7 @@ where
         .collect::<Vec<String>>();
 
     let result = form.result.try_into()?;
-    let filter_params = form
-        .filter
-        .map(|it| FilterParams::try_from(it).ok())
-        .unwrap_or_default();
+    let filter_params = form.filter.map(FilterParams::try_from).transpose()?;
 
     let retrieve_params = RetrieveIndexDocumentsParamsBuilder::default()
         .path(form.path)
@@ -137,6 +217,8 @@ where
         filter_params,
     );
 
+    let params = authorize_search(&principal, params)?;
+
     let searcher = state.get_searcher();
     let documents = searcher.search_document_parts(&params).await?;
     let response = documents
@@ -162,17 +244,38 @@ where
     put,
     tag = "document",
     path = STORAGE_ALL_DOCUMENTS_URL,
-    description = "Store array of documents into index",
+    description = STORE_DOCS_DESCRIPTION,
     request_body(content = Vec<CreateDocumentForm>),
+    params(
+        (
+            "index_ids" = &str,
+            description = "Index id to store documents",
+            example = "test-folder",
+        ),
+        StoreDocumentsQuery,
+    ),
     responses(
         (
             status = 201,
             content_type="application/json",
-            description = "List of documents to store into passed index id",
-            body = Vec<StoredDocumentSchema>,
+            description = "All documents have been stored into passed index id",
+            body = BulkStoreReportSchema,
+        ),
+        (
+            status = 207,
+            content_type="application/json",
+            description = "Some documents have been rejected, see `failed` items with reasons",
+            body = BulkStoreReportSchema,
+        ),
+        (
+            status = 202,
+            content_type="application/json",
+            description = "Ingestion job has been accepted to store documents in background",
+            body = IngestionJob,
         ),
         (status = 400, description = "Validation form error"),
         (status = 401, description = "Unauthorized access"),
+        (status = 403, description = "Access denied"),
         (status = 404, description = "Index not found"),
         (status = 409, description = "Store documents conflict"),
         (status = 500, description = "Internal error"),
@@ -181,28 +284,59 @@ where
 )]
 pub async fn store_documents<Storage, Searcher>(
     State(state): State<Arc<ServerApp<Storage, Searcher>>>,
+    principal: Option<Principal>,
     Path(index_id): Path<String>,
+    Query(query): Query<StoreDocumentsQuery>,
     Json(form): Json<Vec<CreateDocumentForm>>,
 ) -> ServerResult<impl IntoResponse>
 where
     Searcher: ISearcher + IPaginator + Send + Sync + 'static,
     Storage: IIndexStorage + IDocumentPartStorage + Send + Sync + 'static,
 {
+    authorize_indexes(&principal, &index_id, AccessLevel::Write)?;
     let index_id = IndexId(index_id);
-    let documents = form
-        .into_iter()
-        .filter_map(|it| it.try_into().ok())
-        .collect::<Vec<LargeDocument>>();
+    let mut report = BulkStoreReport::default();
+    let mut positions = Vec::with_capacity(form.len());
+    let mut documents = Vec::with_capacity(form.len());
+    for (position, doc_form) in form.into_iter().enumerate() {
+        let file_path = doc_form.file_path.clone();
+        match LargeDocument::try_from(doc_form) {
+            Ok(document) => {
+                positions.push(position);
+                documents.push(document);
+            }
+            Err(err) => report.add_failed(position, file_path, err.to_string()),
+        }
+    }
 
+    let force = query.force.unwrap_or(false);
     let storage = state.get_storage();
-    let stored_documents_info = storage
-        .store_documents(&index_id, documents)
-        .await?
-        .into_iter()
-        .filter_map(|it| it.try_into().ok())
-        .collect::<Vec<StoredDocumentSchema>>();
+    if query.is_async.unwrap_or(false) {
+        if report.has_failures() {
+            let report = BulkStoreReportSchema::from(report);
+            return Ok((StatusCode::BAD_REQUEST, Json(report)).into_response());
+        }
+
+        let _ = storage.check_index_exists(&index_id).await?;
+        let job = state
+            .get_ingestion()
+            .submit(&index_id, documents, force)
+            .await
+            .map_err(|err| ServerError::InternalError(err.to_string()))?;
 
-    Ok((StatusCode::CREATED, Json(stored_documents_info)))
+        return Ok((StatusCode::ACCEPTED, Json(job)).into_response());
+    }
+
+    let stored_report = storage.store_documents(&index_id, documents, force).await?;
+    report.merge(stored_report, &positions);
+
+    let status = match report.has_failures() {
+        true => StatusCode::MULTI_STATUS,
+        false => StatusCode::CREATED,
+    };
+
+    let response = BulkStoreReportSchema::from(report);
+    Ok((status, Json(response)).into_response())
 }
 
 #[utoipa::path(
@@ -230,6 +364,7 @@ where
         ),
         (status = 400, description = "Validation form error"),
         (status = 401, description = "Unauthorized access"),
+        (status = 403, description = "Access denied"),
         (status = 404, description = "Index not found"),
         (status = 409, description = "Store document conflict"),
         (status = 500, description = "Internal error"),
@@ -238,6 +373,7 @@ where
 )]
 pub async fn store_document<Storage, Searcher>(
     State(state): State<Arc<ServerApp<Storage, Searcher>>>,
+    principal: Option<Principal>,
     Path(index_id): Path<String>,
     Query(query): Query<CreateDocumentQuery>,
     Json(form): Json<CreateDocumentForm>,
@@ -246,6 +382,7 @@ where
     Searcher: ISearcher + IPaginator + Send + Sync + 'static,
     Storage: IIndexStorage + IDocumentPartStorage + Send + Sync + 'static,
 {
+    authorize_indexes(&principal, &index_id, AccessLevel::Write)?;
     let index_id = IndexId(index_id);
     let is_force = query.force.unwrap_or(false);
     let storage = state.get_storage();
@@ -258,6 +395,140 @@ where
     Ok((StatusCode::CREATED, Json(response)))
 }
 
+#[utoipa::path(
+    post,
+    tag = "document",
+    path = UPLOAD_DOCUMENT_URL,
+    description = UPLOAD_DOC_DESCRIPTION,
+    request_body(content = UploadDocumentForm, content_type = "multipart/form-data"),
+    params(
+        (
+            "index_id" = &str,
+            description = "Index id to store uploaded Documents",
+            example = "test-folder",
+        ),
+        CreateDocumentQuery,
+    ),
+    responses(
+        (
+            status = 201,
+            content_type="application/json",
+            description = "All uploaded files have been stored into passed index id",
+            body = BulkStoreReportSchema,
+        ),
+        (
+            status = 207,
+            content_type="application/json",
+            description = "Some uploaded files have been rejected, see `failed` items with reasons",
+            body = BulkStoreReportSchema,
+        ),
+        (status = 400, description = "Validation form error or unsupported file format"),
+        (status = 401, description = "Unauthorized access"),
+        (status = 403, description = "Access denied"),
+        (status = 404, description = "Index not found"),
+        (status = 500, description = "Internal error"),
+        (status = 501, description = "Error form", body = DefaultErrorForm),
+    )
+)]
+pub async fn upload_document<Storage, Searcher>(
+    State(state): State<Arc<ServerApp<Storage, Searcher>>>,
+    principal: Option<Principal>,
+    Path(index_id): Path<String>,
+    Query(query): Query<CreateDocumentQuery>,
+    multipart: Multipart,
+) -> ServerResult<impl IntoResponse>
+where
+    Searcher: ISearcher + IPaginator + Send + Sync + 'static,
+    Storage: IIndexStorage + IDocumentPartStorage + Send + Sync + 'static,
+{
+    authorize_indexes(&principal, &index_id, AccessLevel::Write)?;
+    let index_id = IndexId(index_id);
+    let is_force = query.force.unwrap_or(false);
+    let form = UploadDocumentForm::from_multipart(multipart).await?;
+    let uploaded_at = chrono::Utc::now().timestamp();
+    let files = form.into_uploaded_files(uploaded_at)?;
+    for file in files.iter() {
+        let _ = file.get_format()?;
+    }
+
+    let storage = state.get_storage();
+    let report = storage.upload_documents(&index_id, files, is_force).await?;
+    let status = match report.has_failures() {
+        true => StatusCode::MULTI_STATUS,
+        false => StatusCode::CREATED,
+    };
+
+    let response = BulkStoreReportSchema::from(report);
+    Ok((status, Json(response)))
+}
+
+#[utoipa::path(
+    patch,
+    tag = "document",
+    path = STORAGE_DOCUMENT_URL,
+    description = UPDATE_DOC_DESCRIPTION,
+    params(
+        (
+            "index_id" = &str,
+            description = "Index id where is stored Document",
+            example = "test-folder",
+        ),
+        (
+            "document_id" = &str,
+            description = "Document id to update it",
+            example = "c5cdd3bfad598ec73dc5fe83fecbba3e",
+        ),
+    ),
+    request_body(content = UpdateDocumentForm),
+    responses(
+        (
+            status = 200,
+            content_type="application/json",
+            description = "Document has been updated successful",
+            body = Success,
+            example = json!(Success::new(200, "c5cdd3bfad598ec73dc5fe83fecbba3e")),
+        ),
+        (status = 400, description = "Validation form error"),
+        (status = 401, description = "Unauthorized access"),
+        (status = 403, description = "Access denied"),
+        (status = 404, description = "Index or Document not found"),
+        (status = 500, description = "Internal error"),
+        (status = 501, description = "Error form", body = DefaultErrorForm),
+    )
+)]
+pub async fn update_document<Storage, Searcher>(
+    State(state): State<Arc<ServerApp<Storage, Searcher>>>,
+    principal: Option<Principal>,
+    Path(path): Path<(String, String)>,
+    Json(form): Json<UpdateDocumentForm>,
+) -> ServerResult<impl IntoResponse>
+where
+    Searcher: ISearcher + IPaginator + Send + Sync + 'static,
+    Storage: IIndexStorage + IDocumentPartStorage + Send + Sync + 'static,
+{
+    let (index_id, large_doc_id) = path;
+    authorize_indexes(&principal, &index_id, AccessLevel::Write)?;
+    let (index_id, large_doc_id) = (IndexId(index_id), LargeDocumentId(large_doc_id));
+    let storage = state.get_storage();
+    match form.content.is_some() {
+        true => {
+            let document = form.try_into()?;
+            let _ = storage
+                .update_document(&index_id, &large_doc_id, document)
+                .await?;
+        }
+        false => {
+            let params = form.try_into()?;
+            storage
+                .update_document_metadata(&index_id, &large_doc_id, &params)
+                .await?;
+        }
+    }
+
+    let status = Success::new(StatusCode::OK.as_u16(), &large_doc_id);
+    Ok(Json(status))
+}
+
 #[utoipa::path(
     delete,
     tag = "document",
@@ -283,6 +554,7 @@ where
             body = Success,
         ),
         (status = 401, description = "Unauthorized access"),
+        (status = 403, description = "Access denied"),
         (status = 404, description = "Index or Document not found"),
         (status = 500, description = "Internal error"),
         (status = 501, description = "Error form", body = DefaultErrorForm),
@@ -290,6 +562,7 @@ where
 )]
 pub async fn delete_document<Storage, Searcher>(
     State(state): State<Arc<ServerApp<Storage, Searcher>>>,
+    principal: Option<Principal>,
     Path(path): Path<(String, String)>,
 ) -> ServerResult<impl IntoResponse>
 where
@@ -297,6 +570,7 @@ where
     Storage: IIndexStorage + IDocumentPartStorage + Send + Sync + 'static,
 {
     let (index_id, large_doc_id) = path;
+    authorize_indexes(&principal, &index_id, AccessLevel::Write)?;
     let (index_id, large_doc_id) = (IndexId(index_id), LargeDocumentId(large_doc_id));
     let storage = state.get_storage();
     storage.delete_document(&index_id, &large_doc_id).await?;
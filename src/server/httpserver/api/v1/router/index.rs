@@ -9,7 +9,9 @@ use doc_search_core::domain::storage::{IDocumentPartStorage, IIndexStorage};
 use doc_search_core::shared::kernel::IndexId;
 
 use crate::server::httpserver::api::v1::form::CreateIndexForm;
+use crate::server::httpserver::api::v1::router::authorize_indexes;
 use crate::server::httpserver::api::v1::schema::IndexSchema;
+use crate::server::httpserver::mw::auth::{AccessLevel, Principal};
 use crate::server::httpserver::swagger::DefaultErrorForm;
 use crate::server::httpserver::ServerApp;
 use crate::server::{ServerResult, Success};
@@ -35,6 +37,7 @@ pub const STORAGE_INDEX_URL: &str = "/storage/{index_id}";
 )]
 pub async fn get_all_indexes<Storage, Searcher>(
     State(state): State<Arc<ServerApp<Storage, Searcher>>>,
+    principal: Option<Principal>,
 ) -> ServerResult<impl IntoResponse>
 where
     Searcher: ISearcher + IPaginator + Send + Sync + 'static,
@@ -45,6 +48,7 @@ where
 
     let indexes_schema = indexes
         .into_iter()
+        .filter(|it| authorize_indexes(&principal, it, AccessLevel::Read).is_ok())
         .map(|it| it.into())
         .collect::<Vec<IndexSchema>>();
 
@@ -72,6 +76,7 @@ where
         ),
         (status = 400, description = "Failed to get index information"),
         (status = 401, description = "Unauthorized access"),
+        (status = 403, description = "Access denied"),
         (status = 404, description = "Index not found"),
         (status = 500, description = "Internal error"),
         (status = 501, description = "Error form", body = DefaultErrorForm),
@@ -79,12 +84,14 @@ where
 )]
 pub async fn get_index<Storage, Searcher>(
     State(state): State<Arc<ServerApp<Storage, Searcher>>>,
+    principal: Option<Principal>,
     Path(index_id): Path<String>,
 ) -> ServerResult<impl IntoResponse>
 where
     Searcher: ISearcher + IPaginator + Send + Sync + 'static,
     Storage: IIndexStorage + IDocumentPartStorage + Send + Sync + 'static,
 {
+    authorize_indexes(&principal, &index_id, AccessLevel::Read)?;
     let index_id = IndexId(index_id);
     let storage = state.get_storage();
     let index = storage.get_index(&index_id).await?;
@@ -114,6 +121,7 @@ where
         ),
         (status = 400, description = "Validation form error"),
         (status = 401, description = "Unauthorized access"),
+        (status = 403, description = "Access denied"),
         (status = 409, description = "Conflict while creating index"),
         (status = 500, description = "Internal error"),
         (status = 501, description = "Error form", body = DefaultErrorForm),
@@ -121,6 +129,7 @@ where
 )]
 pub async fn create_index<Storage, Searcher>(
     State(state): State<Arc<ServerApp<Storage, Searcher>>>,
+    principal: Option<Principal>,
     Path(_index_id): Path<String>,
     Json(form): Json<CreateIndexForm>,
 ) -> ServerResult<impl IntoResponse>
@@ -128,6 +137,7 @@ where
     Searcher: ISearcher + IPaginator + Send + Sync + 'static,
     Storage: IIndexStorage + IDocumentPartStorage + Send + Sync + 'static,
 {
+    authorize_indexes(&principal, &form.id, AccessLevel::Admin)?;
     let params = form.try_into()?;
     let storage = state.get_storage();
     let index = storage.create_index(&params).await?;
@@ -155,6 +165,7 @@ where
             body = Success
         ),
         (status = 401, description = "Unauthorized access"),
+        (status = 403, description = "Access denied"),
         (status = 404, description = "Not found"),
         (status = 500, description = "Internal error"),
         (status = 501, description = "Error form", body = DefaultErrorForm),
@@ -162,12 +173,14 @@ where
 )]
 pub async fn delete_index<Storage, Searcher>(
     State(state): State<Arc<ServerApp<Storage, Searcher>>>,
+    principal: Option<Principal>,
     Path(index_id): Path<String>,
 ) -> ServerResult<impl IntoResponse>
 where
     Searcher: ISearcher + IPaginator + Send + Sync + 'static,
     Storage: IIndexStorage + IDocumentPartStorage + Send + Sync + 'static,
 {
+    authorize_indexes(&principal, &index_id, AccessLevel::Admin)?;
     let index_id = IndexId(index_id);
     let storage = state.get_storage();
     storage.delete_index(&index_id).await?;
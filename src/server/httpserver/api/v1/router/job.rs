@@ -0,0 +1,62 @@
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use axum::Json;
+use std::sync::Arc;
+
+use doc_search_core::domain::searcher::{IPaginator, ISearcher};
+use doc_search_core::domain::storage::{IDocumentPartStorage, IIndexStorage};
+
+use crate::server::httpserver::api::v1::router::authorize_indexes;
+use crate::server::httpserver::mw::auth::{AccessLevel, Principal};
+use crate::server::httpserver::swagger::DefaultErrorForm;
+use crate::server::httpserver::ServerApp;
+use crate::server::jobs::IngestionJob;
+use crate::server::{ServerError, ServerResult};
+
+pub const JOB_URL: &str = "/jobs/{job_id}";
+
+#[utoipa::path(
+    get,
+    tag = "job",
+    path = JOB_URL,
+    description = "Load ingestion job progress with per-document status",
+    params(
+        (
+            "job_id" = &str,
+            description = "Ingestion job id returned while storing documents",
+            example = "0b6d4c02-5d8e-4b43-9f4c-8f0ae3a0b0f1",
+        ),
+    ),
+    responses(
+        (
+            status = 200,
+            content_type="application/json",
+            description = "Ingestion job state",
+            body = IngestionJob,
+        ),
+        (status = 401, description = "Unauthorized access"),
+        (status = 403, description = "Access denied"),
+        (status = 404, description = "Job not found"),
+        (status = 500, description = "Internal error"),
+        (status = 501, description = "Error form", body = DefaultErrorForm),
+    )
+)]
+pub async fn get_job<Storage, Searcher>(
+    State(state): State<Arc<ServerApp<Storage, Searcher>>>,
+    principal: Option<Principal>,
+    Path(job_id): Path<String>,
+) -> ServerResult<impl IntoResponse>
+where
+    Searcher: ISearcher + IPaginator + Send + Sync + 'static,
+    Storage: IIndexStorage + IDocumentPartStorage + Send + Sync + 'static,
+{
+    let ingestion = state.get_ingestion();
+    let job = ingestion
+        .get_job(&job_id)
+        .await
+        .map_err(|err| ServerError::InternalError(err.to_string()))?
+        .ok_or_else(|| ServerError::NotFound(format!("job {job_id} not found")))?;
+
+    authorize_indexes(&principal, &job.index, AccessLevel::Read)?;
+    Ok(Json(job))
+}
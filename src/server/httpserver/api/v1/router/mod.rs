@@ -1,3 +1,72 @@
 pub mod document;
 pub mod index;
+pub mod job;
 pub mod searcher;
+
+use doc_search_core::domain::searcher::models::{SearchingParams, SuggestParams};
+
+use crate::server::httpserver::mw::auth::{AccessLevel, Principal};
+use crate::server::ServerResult;
+
+/// Checks principal access to comma-separated indexes. Requests without
+/// principal are passed through because authentication is disabled.
+fn authorize_indexes(
+    principal: &Option<Principal>,
+    indexes: &str,
+    access: AccessLevel,
+) -> ServerResult<()> {
+    let Some(principal) = principal else {
+        return Ok(());
+    };
+
+    indexes
+        .split(',')
+        .try_for_each(|index| principal.authorize(index, access))
+}
+
+/// Checks read access to searching indexes. The `*` wildcard is restricted
+/// to readable index patterns instead of being rejected.
+fn authorize_search(
+    principal: &Option<Principal>,
+    params: SearchingParams,
+) -> ServerResult<SearchingParams> {
+    let Some(principal) = principal else {
+        return Ok(params);
+    };
+
+    if params.is_wildcard() {
+        let patterns = principal.granted_patterns(AccessLevel::Read);
+        return Ok(params.with_allowed_indexes(Some(patterns)));
+    }
+
+    params
+        .get_indexes()
+        .iter()
+        .try_for_each(|index| principal.authorize(index, AccessLevel::Read))?;
+
+    Ok(params)
+}
+
+/// Checks read access to indexes suggestions are built from, restricting
+/// the `*` wildcard like searching does.
+fn authorize_suggest(
+    principal: &Option<Principal>,
+    mut params: SuggestParams,
+) -> ServerResult<SuggestParams> {
+    let Some(principal) = principal else {
+        return Ok(params);
+    };
+
+    if params.is_wildcard() {
+        let patterns = principal.granted_patterns(AccessLevel::Read);
+        params.allowed_indexes = Some(patterns);
+        return Ok(params);
+    }
+
+    params
+        .indexes
+        .iter()
+        .try_for_each(|index| principal.authorize(index, AccessLevel::Read))?;
+
+    Ok(params)
+}
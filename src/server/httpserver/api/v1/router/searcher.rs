@@ -1,4 +1,4 @@
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
 use axum::Json;
 use doc_search_core::domain::searcher::models::PaginationParamsBuilder;
 use doc_search_core::domain::searcher::{IPaginator, ISearcher};
@@ -6,21 +6,29 @@ use doc_search_core::domain::storage::{IDocumentPartStorage, IIndexStorage};
 use std::sync::Arc;
 
 use crate::server::httpserver::api::v1::form::{
-    FullTextSearchForm, HybridSearchForm, SemanticSearchForm,
+    FullTextSearchForm, HybridSearchForm, SemanticSearchForm, SimilarSearchForm,
 };
-use crate::server::httpserver::api::v1::schema::PaginationSchema;
+use crate::server::httpserver::api::v1::query::SuggestQuery;
+use crate::server::httpserver::api::v1::router::authorize_suggest;
+use crate::server::httpserver::api::v1::router::{authorize_indexes, authorize_search};
+use crate::server::httpserver::api::v1::schema::{PaginationSchema, SuggestionsSchema};
+use crate::server::httpserver::mw::auth::{AccessLevel, Principal};
 use crate::server::httpserver::swagger::DefaultErrorForm;
 use crate::server::httpserver::ServerApp;
-use crate::server::{ServerError, ServerResult};
+use crate::server::{ServerError, ServerResult, Success};
 
 pub const SEARCH_FULLTEXT_URL: &str = "/search/fulltext";
 pub const SEARCH_SEMANTIC_URL: &str = "/search/semantic";
 pub const SEARCH_HYBRID_URL: &str = "/search/hybrid";
-pub const SEARCH_PAGINATE_URL: &str = "/search/paginate/{scroll_id}";
+pub const SEARCH_SIMILAR_URL: &str = "/search/similar";
+pub const SEARCH_PAGINATE_URL: &str = "/search/paginate/{cursor}";
+pub const SEARCH_SUGGEST_URL: &str = "/search/suggest";
 
 const FULLTEXT_DESCRIPTION: &str = include_str!("../../../swagger/descriptions/searcher-fulltext");
 const SEMANTIC_DESCRIPTION: &str = include_str!("../../../swagger/descriptions/searcher-semantic");
 const HYBRID_DESCRIPTION: &str = include_str!("../../../swagger/descriptions/searcher-hybrid");
+const SIMILAR_DESCRIPTION: &str = include_str!("../../../swagger/descriptions/searcher-similar");
+const SUGGEST_DESCRIPTION: &str = include_str!("../../../swagger/descriptions/searcher-suggest");
 
 #[utoipa::path(
     post,
@@ -37,19 +45,21 @@ const HYBRID_DESCRIPTION: &str = include_str!("../../../swagger/descriptions/sea
         ),
         (status = 400, description = "Failed while fulltext searching"),
         (status = 401, description = "Unauthorized access"),
+        (status = 403, description = "Access denied"),
         (status = 500, description = "Internal error"),
         (status = 501, description = "Error form", body = DefaultErrorForm),
     )
 )]
 pub async fn search_fulltext<Storage, Searcher>(
     State(state): State<Arc<ServerApp<Storage, Searcher>>>,
+    principal: Option<Principal>,
     Json(form): Json<FullTextSearchForm>,
 ) -> ServerResult<Json<PaginationSchema>>
 where
     Searcher: ISearcher + IPaginator + Send + Sync + 'static,
     Storage: IIndexStorage + IDocumentPartStorage + Send + Sync + 'static,
 {
-    let params = form.try_into()?;
+    let params = authorize_search(&principal, form.try_into()?)?;
     let searcher = state.get_searcher();
     let pagination = searcher.search_document_parts(&params).await?;
     let response = pagination.try_into()?;
@@ -71,19 +81,21 @@ where
         ),
         (status = 400, description = "Failed while semantic searching"),
         (status = 401, description = "Unauthorized access"),
+        (status = 403, description = "Access denied"),
         (status = 500, description = "Internal error"),
         (status = 501, description = "Error form", body = DefaultErrorForm),
     )
 )]
 pub async fn search_semantic<Storage, Searcher>(
     State(state): State<Arc<ServerApp<Storage, Searcher>>>,
+    principal: Option<Principal>,
     Json(form): Json<SemanticSearchForm>,
 ) -> ServerResult<Json<PaginationSchema>>
 where
     Searcher: ISearcher + IPaginator + Send + Sync + 'static,
     Storage: IIndexStorage + IDocumentPartStorage + Send + Sync + 'static,
 {
-    let params = form.try_into()?;
+    let params = authorize_search(&principal, form.try_into()?)?;
     let searcher = state.get_searcher();
     let pagination = searcher.search_document_parts(&params).await?;
     let response = pagination.try_into()?;
@@ -105,34 +117,109 @@ where
         ),
         (status = 400, description = "Failed while hybrid searching"),
         (status = 401, description = "Unauthorized access"),
+        (status = 403, description = "Access denied"),
         (status = 500, description = "Internal error"),
         (status = 501, description = "Error form", body = DefaultErrorForm),
     )
 )]
 pub async fn search_hybrid<Storage, Searcher>(
     State(state): State<Arc<ServerApp<Storage, Searcher>>>,
+    principal: Option<Principal>,
     Json(form): Json<HybridSearchForm>,
 ) -> ServerResult<Json<PaginationSchema>>
 where
     Searcher: ISearcher + IPaginator + Send + Sync + 'static,
     Storage: IIndexStorage + IDocumentPartStorage + Send + Sync + 'static,
 {
-    let params = form.try_into()?;
+    let params = authorize_search(&principal, form.try_into()?)?;
+    let searcher = state.get_searcher();
+    let pagination = searcher.search_document_parts(&params).await?;
+    let response = pagination.try_into()?;
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    post,
+    tag = "search",
+    path = SEARCH_SIMILAR_URL,
+    description = SIMILAR_DESCRIPTION,
+    request_body(content = SimilarSearchForm),
+    responses(
+        (
+            status = 200,
+            content_type="application/json",
+            description = "Paginate structure with list of founded similar Documents",
+            body = PaginationSchema,
+        ),
+        (status = 400, description = "Failed while similar searching"),
+        (status = 401, description = "Unauthorized access"),
+        (status = 403, description = "Access denied"),
+        (status = 404, description = "Source document not found"),
+        (status = 500, description = "Internal error"),
+        (status = 501, description = "Error form", body = DefaultErrorForm),
+    )
+)]
+pub async fn search_similar<Storage, Searcher>(
+    State(state): State<Arc<ServerApp<Storage, Searcher>>>,
+    principal: Option<Principal>,
+    Json(form): Json<SimilarSearchForm>,
+) -> ServerResult<Json<PaginationSchema>>
+where
+    Searcher: ISearcher + IPaginator + Send + Sync + 'static,
+    Storage: IIndexStorage + IDocumentPartStorage + Send + Sync + 'static,
+{
+    authorize_indexes(&principal, &form.source_index, AccessLevel::Read)?;
+    let params = authorize_search(&principal, form.try_into()?)?;
     let searcher = state.get_searcher();
     let pagination = searcher.search_document_parts(&params).await?;
     let response = pagination.try_into()?;
     Ok(Json(response))
 }
 
+#[utoipa::path(
+    get,
+    tag = "search",
+    path = SEARCH_SUGGEST_URL,
+    description = SUGGEST_DESCRIPTION,
+    params(SuggestQuery),
+    responses(
+        (
+            status = 200,
+            content_type="application/json",
+            description = "Completions of typed text and spelling corrections",
+            body = SuggestionsSchema,
+        ),
+        (status = 400, description = "Failed while building suggestions"),
+        (status = 401, description = "Unauthorized access"),
+        (status = 403, description = "Access denied"),
+        (status = 500, description = "Internal error"),
+        (status = 501, description = "Error form", body = DefaultErrorForm),
+    )
+)]
+pub async fn suggest<Storage, Searcher>(
+    State(state): State<Arc<ServerApp<Storage, Searcher>>>,
+    principal: Option<Principal>,
+    Query(query): Query<SuggestQuery>,
+) -> ServerResult<Json<SuggestionsSchema>>
+where
+    Searcher: ISearcher + IPaginator + Send + Sync + 'static,
+    Storage: IIndexStorage + IDocumentPartStorage + Send + Sync + 'static,
+{
+    let params = authorize_suggest(&principal, query.try_into()?)?;
+    let searcher = state.get_searcher();
+    let suggestions = searcher.suggest(&params).await?;
+    Ok(Json(suggestions.into()))
+}
+
 #[utoipa::path(
     get,
     tag = "search",
     path = SEARCH_PAGINATE_URL,
-    description = "Paginate search results by scroll",
+    description = "Paginate search results by cursor",
     params(
         (
-            "scroll_id" = &str,
-            description = "Scroll ID to load next founded documents",
+            "cursor" = &str,
+            description = "Cursor to load next founded documents",
             example = "FGluY2x1ZGVfY29udGV4dF91dWlkDXF1ZXJ5QW5kRmV0Y",
         ),
     ),
@@ -151,14 +238,14 @@ where
 )]
 pub async fn paginate_next<Storage, Searcher>(
     State(state): State<Arc<ServerApp<Storage, Searcher>>>,
-    Path(scroll_id): Path<String>,
+    Path(cursor): Path<String>,
 ) -> ServerResult<Json<PaginationSchema>>
 where
     Searcher: ISearcher + IPaginator + Send + Sync + 'static,
     Storage: IIndexStorage + IDocumentPartStorage + Send + Sync + 'static,
 {
     let params = PaginationParamsBuilder::default()
-        .scroll_id(scroll_id)
+        .cursor(cursor)
         .build()
         .map_err(|err| ServerError::InternalError(err.to_string()))?;
 
@@ -167,3 +254,41 @@ where
     let response = documents.try_into()?;
     Ok(Json(response))
 }
+
+#[utoipa::path(
+    delete,
+    tag = "search",
+    path = SEARCH_PAGINATE_URL,
+    description = "Release cursor of search results before its expiration",
+    params(
+        (
+            "cursor" = &str,
+            description = "Cursor of founded documents to release",
+            example = "FGluY2x1ZGVfY29udGV4dF91dWlkDXF1ZXJ5QW5kRmV0Y",
+        ),
+    ),
+    responses(
+        (status = 200, description = "Successful", body = Success),
+        (status = 400, description = "Cursor does not exist or expired"),
+        (status = 401, description = "Unauthorized access"),
+        (status = 500, description = "Internal error"),
+        (status = 501, description = "Error form", body = DefaultErrorForm),
+    )
+)]
+pub async fn release_pagination<Storage, Searcher>(
+    State(state): State<Arc<ServerApp<Storage, Searcher>>>,
+    Path(cursor): Path<String>,
+) -> ServerResult<Json<Success>>
+where
+    Searcher: ISearcher + IPaginator + Send + Sync + 'static,
+    Storage: IIndexStorage + IDocumentPartStorage + Send + Sync + 'static,
+{
+    let params = PaginationParamsBuilder::default()
+        .cursor(cursor)
+        .build()
+        .map_err(|err| ServerError::InternalError(err.to_string()))?;
+
+    let searcher = state.get_searcher();
+    searcher.release_pagination(&params).await?;
+    Ok(Json(Success::default()))
+}
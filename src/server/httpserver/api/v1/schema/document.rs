@@ -9,6 +9,7 @@ use utoipa::ToSchema;
 use doc_search_core::domain::searcher::models::{
     DocumentPartEntrails, DocumentPartEntrailsBuilder, Embeddings,
 };
+use doc_search_core::domain::storage::models::{BulkStoreReport, FailedBulkItem, StoredBulkItem};
 use doc_search_core::domain::storage::models::{
     DocumentPart, DocumentPartBuilder, LargeDocument, StoredDocumentPartsInfo,
 };
@@ -39,6 +40,69 @@ impl TryFrom<StoredDocumentPartsInfo> for StoredDocumentSchema {
     }
 }
 
+/// Per-document result of bulk storing. Positions refer to documents
+/// within request array.
+#[derive(Clone, Deserialize, Serialize, ToSchema)]
+pub struct BulkStoreReportSchema {
+    pub succeeded: Vec<StoredBulkItemSchema>,
+    pub failed: Vec<FailedBulkItemSchema>,
+}
+
+#[derive(Clone, Deserialize, Serialize, ToSchema)]
+pub struct StoredBulkItemSchema {
+    #[schema(example = 0)]
+    pub position: usize,
+    #[schema(example = "./test-document.docx")]
+    pub file_path: String,
+    #[schema(example = "dksfsjvJHZVFDskjdbfsdfsdfdsg")]
+    pub large_doc_id: String,
+    #[schema(example = "3b4kb534k5bkqjb1kj3b21kj23b")]
+    pub first_part_id: String,
+    #[schema(example = 10)]
+    pub doc_parts_amount: u64,
+}
+
+#[derive(Clone, Deserialize, Serialize, ToSchema)]
+pub struct FailedBulkItemSchema {
+    #[schema(example = 1)]
+    pub position: usize,
+    #[schema(example = "./test-document.docx")]
+    pub file_path: String,
+    #[schema(example = "can't split large document: document content is empty.")]
+    pub reason: String,
+}
+
+impl From<BulkStoreReport> for BulkStoreReportSchema {
+    fn from(report: BulkStoreReport) -> Self {
+        BulkStoreReportSchema {
+            succeeded: report.succeeded.into_iter().map(From::from).collect(),
+            failed: report.failed.into_iter().map(From::from).collect(),
+        }
+    }
+}
+
+impl From<StoredBulkItem> for StoredBulkItemSchema {
+    fn from(item: StoredBulkItem) -> Self {
+        StoredBulkItemSchema {
+            position: item.position,
+            file_path: item.file_path,
+            large_doc_id: item.info.large_doc_id.0,
+            first_part_id: item.info.first_part_id.0,
+            doc_parts_amount: item.info.doc_parts_amount as u64,
+        }
+    }
+}
+
+impl From<FailedBulkItem> for FailedBulkItemSchema {
+    fn from(item: FailedBulkItem) -> Self {
+        FailedBulkItemSchema {
+            position: item.position,
+            file_path: item.file_path,
+            reason: item.reason,
+        }
+    }
+}
+
 #[derive(Builder, Clone, Default, Serialize, Deserialize, ToSchema, Getset)]
 pub struct DocumentPartSchema {
     #[schema(example = "test-folder")]
@@ -1,5 +1,7 @@
 use derive_builder::Builder;
-use doc_search_core::domain::searcher::models::{FoundedDocument, FoundedDocumentBuilder};
+use doc_search_core::domain::searcher::models::{
+    FoundedDocument, FoundedDocumentBuilder, GroupedParts,
+};
 use serde_derive::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
@@ -22,6 +24,38 @@ pub struct FoundedDocumentPartSchema {
     #[schema(example = json!(vec!["There is", "some text"]))]
     pub highlight: Vec<String>,
     pub document: DocumentPartSchema,
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grouped: Option<GroupedPartsSchema>,
+}
+
+/// Summary of matched parts collapsed into the result
+/// when searching with `group_by_document` option.
+#[derive(Clone, Deserialize, Serialize, ToSchema)]
+pub struct GroupedPartsSchema {
+    #[schema(example = 3)]
+    pub matched_parts: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = 1.8243)]
+    pub score: Option<f64>,
+}
+
+impl From<GroupedParts> for GroupedPartsSchema {
+    fn from(grouped: GroupedParts) -> Self {
+        GroupedPartsSchema {
+            matched_parts: grouped.matched_parts,
+            score: grouped.score,
+        }
+    }
+}
+
+impl From<GroupedPartsSchema> for GroupedParts {
+    fn from(schema: GroupedPartsSchema) -> Self {
+        GroupedParts {
+            matched_parts: schema.matched_parts,
+            score: schema.score,
+        }
+    }
 }
 
 impl TryFrom<FoundedDocumentPartSchema> for FoundedDocument {
@@ -35,6 +69,7 @@ impl TryFrom<FoundedDocumentPartSchema> for FoundedDocument {
             .score(schema.score)
             .highlight(schema.highlight)
             .document(document)
+            .grouped(schema.grouped.map(GroupedParts::from))
             .build()
             .map_err(|err| ServerError::InternalError(err.to_string()))
     }
@@ -51,6 +86,7 @@ impl TryFrom<FoundedDocument> for FoundedDocumentPartSchema {
             .score(founded.score)
             .highlight(founded.highlight)
             .document(document)
+            .grouped(founded.grouped.map(GroupedPartsSchema::from))
             .build()
             .map_err(|err| ServerError::InternalError(err.to_string()))
     }
@@ -7,10 +7,18 @@ mod document;
 pub use document::DocumentPartSchema;
 pub use document::EmbeddingsSchema;
 pub use document::StoredDocumentSchema;
+pub use document::{BulkStoreReportSchema, FailedBulkItemSchema, StoredBulkItemSchema};
 
 mod pagination;
+pub use pagination::FacetBucketSchema;
 pub use pagination::PaginationSchema;
+pub use pagination::TotalHitsSchema;
+
+mod suggestion;
+pub use suggestion::SuggestionSchema;
+pub use suggestion::SuggestionsSchema;
 
 mod founded;
 pub use founded::FoundedDocumentPartSchema;
 pub use founded::FoundedDocumentPartSchemaBuilder;
+pub use founded::GroupedPartsSchema;
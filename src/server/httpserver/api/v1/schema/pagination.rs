@@ -1,6 +1,8 @@
 use derive_builder::Builder;
-use doc_search_core::domain::searcher::models::Pagination;
+use doc_search_core::domain::searcher::models::{FacetBucket, Facets, Pagination};
+use doc_search_core::domain::searcher::models::{TotalHits, TotalHitsRelation};
 use serde_derive::Serialize;
+use std::collections::BTreeMap;
 use utoipa::ToSchema;
 
 use crate::server::httpserver::api::v1::schema::founded::FoundedDocumentPartSchema;
@@ -11,7 +13,58 @@ pub struct PaginationSchema {
     pub founded: Vec<FoundedDocumentPartSchema>,
     #[schema(example = "dksfsjvJHZVFDskjdbfsdfsdfdsg")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scroll_id: Option<String>,
+    pub cursor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub facets: Option<BTreeMap<String, Vec<FacetBucketSchema>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<TotalHitsSchema>,
+    #[schema(example = 12.5)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_score: Option<f64>,
+    /// Time in milliseconds spent by search engine.
+    #[schema(example = 12)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub took: Option<u64>,
+}
+
+#[derive(Clone, Serialize, ToSchema)]
+pub struct TotalHitsSchema {
+    #[schema(example = 1234)]
+    pub value: u64,
+    /// `eq` if value is exact, `gte` if it is a lower bound.
+    #[schema(example = "eq")]
+    pub relation: String,
+}
+
+impl From<TotalHits> for TotalHitsSchema {
+    fn from(total: TotalHits) -> Self {
+        let relation = match total.relation {
+            TotalHitsRelation::Exact => "eq",
+            TotalHitsRelation::LowerBound => "gte",
+        };
+
+        TotalHitsSchema {
+            value: total.value,
+            relation: relation.to_string(),
+        }
+    }
+}
+
+#[derive(Clone, Serialize, ToSchema)]
+pub struct FacetBucketSchema {
+    #[schema(example = "source-name")]
+    pub key: String,
+    #[schema(example = 5)]
+    pub count: u64,
+}
+
+impl From<FacetBucket> for FacetBucketSchema {
+    fn from(bucket: FacetBucket) -> Self {
+        FacetBucketSchema {
+            key: bucket.key,
+            count: bucket.count,
+        }
+    }
 }
 
 impl TryFrom<Pagination> for PaginationSchema {
@@ -27,8 +80,22 @@ impl TryFrom<Pagination> for PaginationSchema {
 
         PaginationSchemaBuilder::default()
             .founded(founded)
-            .scroll_id(paginated.scroll_id)
+            .cursor(paginated.cursor)
+            .facets(paginated.facets.map(convert_facets_to_schema))
+            .total(paginated.total.map(TotalHitsSchema::from))
+            .max_score(paginated.max_score)
+            .took(paginated.took)
             .build()
             .map_err(|err| ServerError::InternalError(err.to_string()))
     }
 }
+
+fn convert_facets_to_schema(facets: Facets) -> BTreeMap<String, Vec<FacetBucketSchema>> {
+    facets
+        .into_iter()
+        .map(|(name, buckets)| {
+            let buckets = buckets.into_iter().map(FacetBucketSchema::from).collect();
+            (name, buckets)
+        })
+        .collect()
+}
@@ -0,0 +1,49 @@
+use doc_search_core::domain::searcher::models::{Suggestion, Suggestions};
+use serde_derive::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+pub struct SuggestionsSchema {
+    /// Texts completing the last typed word by file names or content terms.
+    pub completions: Vec<SuggestionSchema>,
+    /// Query texts with misspelled words replaced.
+    pub corrections: Vec<SuggestionSchema>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct SuggestionSchema {
+    #[schema(example = "war and peace")]
+    pub text: String,
+    #[schema(example = 0.75)]
+    pub score: f64,
+    /// Field the suggestion is built from: `file_name` or `content`.
+    #[schema(example = "content")]
+    pub field: String,
+}
+
+impl From<Suggestion> for SuggestionSchema {
+    fn from(suggestion: Suggestion) -> Self {
+        SuggestionSchema {
+            text: suggestion.text,
+            score: suggestion.score,
+            field: suggestion.field.to_string(),
+        }
+    }
+}
+
+impl From<Suggestions> for SuggestionsSchema {
+    fn from(suggestions: Suggestions) -> Self {
+        SuggestionsSchema {
+            completions: suggestions
+                .completions
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            corrections: suggestions
+                .corrections
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use crate::server::httpserver::api::v1::form::{
-    Class, CreateDocumentForm, Group, Icons, Location, Metadata, Subject, UpdateDocumentForm,
+    Class, CreateDocumentForm, DocumentPartEmbeddingsForm, Group, Icons, Location, Metadata,
+    Subject, UpdateDocumentForm,
 };
 
 pub fn create_document_form() -> CreateDocumentForm {
@@ -11,9 +12,21 @@ pub fn create_document_form() -> CreateDocumentForm {
         modified_at: chrono::Utc::now().timestamp(),
         content: "There is content".to_string(),
         metadata: None,
+        embeddings: None,
     }
 }
 
+pub fn create_document_form_with_embeddings() -> CreateDocumentForm {
+    let embeddings = DocumentPartEmbeddingsForm {
+        chunked_text: vec!["There is".to_string(), "content".to_string()],
+        embeddings: vec![vec![0.1, 0.2, 0.3], vec![0.4, 0.5, 0.6]],
+    };
+
+    let mut create_doc_form = create_document_form();
+    create_doc_form.embeddings = Some(vec![embeddings]);
+    create_doc_form
+}
+
 pub fn create_document_form_with_metadata() -> CreateDocumentForm {
     let metadata = create_metadata();
     let mut create_doc_form = create_document_form();
@@ -28,7 +41,7 @@ pub fn update_document_form() -> UpdateDocumentForm {
         file_path: create_doc_form.file_path,
         file_size: create_doc_form.file_size,
         created_at: create_doc_form.created_at,
-        content: create_doc_form.content,
+        content: Some(create_doc_form.content),
         metadata: create_doc_form.metadata,
     }
 }
@@ -40,11 +53,17 @@ pub fn update_document_form_with_metadata() -> UpdateDocumentForm {
         file_path: create_doc_form.file_path,
         file_size: create_doc_form.file_size,
         created_at: create_doc_form.created_at,
-        content: create_doc_form.content,
+        content: Some(create_doc_form.content),
         metadata: create_doc_form.metadata,
     }
 }
 
+pub fn update_document_form_metadata_only() -> UpdateDocumentForm {
+    let mut update_doc_form = update_document_form_with_metadata();
+    update_doc_form.content = None;
+    update_doc_form
+}
+
 fn create_metadata() -> Metadata {
     let location = Location {
         name: "location-name".to_string(),
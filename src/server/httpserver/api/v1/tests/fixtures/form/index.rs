@@ -1,4 +1,6 @@
-use crate::server::httpserver::api::v1::form::{CreateIndexForm, KnnIndexForm};
+use crate::server::httpserver::api::v1::form::{
+    CreateIndexForm, KnnIndexForm, SplitterForm, SplitterKindForm,
+};
 
 pub const TEST_INDEX_ID: &str = "test-index";
 
@@ -6,6 +8,7 @@ pub fn create_index_form() -> CreateIndexForm {
     CreateIndexForm {
         id: TEST_INDEX_ID.to_string(),
         knn: None,
+        splitter: None,
     }
 }
 
@@ -13,6 +16,23 @@ pub fn create_index_form_with_knn() -> CreateIndexForm {
     CreateIndexForm {
         id: TEST_INDEX_ID.to_string(),
         knn: Some(create_index_knn()),
+        splitter: None,
+    }
+}
+
+pub fn create_index_form_with_splitter(
+    kind: SplitterKindForm,
+    token_limit: Option<u32>,
+) -> CreateIndexForm {
+    CreateIndexForm {
+        id: TEST_INDEX_ID.to_string(),
+        knn: None,
+        splitter: Some(SplitterForm {
+            kind,
+            chunk_size: None,
+            token_limit,
+            overlap_rate: Some(0.2),
+        }),
     }
 }
 
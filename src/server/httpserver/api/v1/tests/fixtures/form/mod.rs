@@ -1,20 +1,32 @@
 mod index;
 pub use index::create_index_form;
 pub use index::create_index_form_with_knn;
+pub use index::create_index_form_with_splitter;
 pub use index::TEST_INDEX_ID;
 
 mod document;
 pub use document::create_document_form;
+pub use document::create_document_form_with_embeddings;
 pub use document::create_document_form_with_metadata;
 pub use document::update_document_form;
+pub use document::update_document_form_metadata_only;
 pub use document::update_document_form_with_metadata;
 
 mod search_params;
 pub use search_params::create_fulltext_search_form;
+pub use search_params::create_fulltext_search_form_with_aggregations;
 pub use search_params::create_fulltext_search_form_with_filter;
+pub use search_params::create_fulltext_search_form_with_order;
+pub use search_params::create_fulltext_search_form_with_sort;
+pub use search_params::create_fulltext_search_form_with_unknown_facet;
 pub use search_params::create_hybrid_search_form;
+pub use search_params::create_hybrid_search_form_with_aggregations;
 pub use search_params::create_hybrid_search_form_with_filter;
+pub use search_params::create_hybrid_search_form_with_fusion;
+pub use search_params::create_hybrid_search_form_with_scoring;
 pub use search_params::create_retrieve_document_form;
 pub use search_params::create_retrieve_document_form_with_filter;
 pub use search_params::create_semantic_search_form;
+pub use search_params::create_semantic_search_form_with_aggregations;
 pub use search_params::create_semantic_search_form_with_filter;
+pub use search_params::create_similar_search_form;
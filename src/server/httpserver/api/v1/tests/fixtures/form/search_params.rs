@@ -1,6 +1,6 @@
 use crate::server::httpserver::api::v1::form::{
-    FilterForm, FullTextSearchForm, HybridSearchForm, ResultForm, RetrieveDocumentForm,
-    SemanticSearchForm, ShortResultForm,
+    AggregationForm, FilterForm, FullTextSearchForm, HybridSearchForm, ResultForm,
+    RetrieveDocumentForm, SemanticSearchForm, ShortResultForm, SimilarSearchForm, SortForm,
 };
 
 pub fn create_retrieve_document_form() -> RetrieveDocumentForm {
@@ -25,6 +25,7 @@ pub fn create_fulltext_search_form() -> FullTextSearchForm {
         indexes: "test-index-1,test-index-2".to_string(),
         filter: None,
         result: create_result_form(),
+        aggregations: None,
     }
 }
 
@@ -34,6 +35,7 @@ pub fn create_fulltext_search_form_with_filter() -> FullTextSearchForm {
         indexes: "test-index-1,test-index-2".to_string(),
         filter: Some(create_filter_form()),
         result: create_result_form(),
+        aggregations: None,
     }
 }
 
@@ -46,6 +48,7 @@ pub fn create_semantic_search_form() -> SemanticSearchForm {
         tokens: Some(vec![]),
         filter: None,
         result: create_short_result_form(),
+        aggregations: None,
     }
 }
 
@@ -58,6 +61,7 @@ pub fn create_semantic_search_form_with_filter() -> SemanticSearchForm {
         tokens: Some(vec![]),
         filter: Some(create_filter_form()),
         result: create_short_result_form(),
+        aggregations: None,
     }
 }
 
@@ -70,6 +74,13 @@ pub fn create_hybrid_search_form() -> HybridSearchForm {
         filter: None,
         result: create_result_form(),
         min_score: Some(0.6),
+        semantic_weight: None,
+        lexical_weight: None,
+        normalization: None,
+        combination: None,
+        pagination_depth: None,
+        fusion: None,
+        aggregations: None,
     }
 }
 
@@ -82,6 +93,110 @@ pub fn create_hybrid_search_form_with_filter() -> HybridSearchForm {
         filter: Some(create_filter_form()),
         result: create_result_form(),
         min_score: Some(0.6),
+        semantic_weight: None,
+        lexical_weight: None,
+        normalization: None,
+        combination: None,
+        pagination_depth: None,
+        fusion: None,
+        aggregations: None,
+    }
+}
+
+pub fn create_fulltext_search_form_with_aggregations() -> FullTextSearchForm {
+    FullTextSearchForm {
+        aggregations: Some(create_aggregation_form()),
+        ..create_fulltext_search_form()
+    }
+}
+
+pub fn create_fulltext_search_form_with_unknown_facet() -> FullTextSearchForm {
+    let mut aggregations = create_aggregation_form();
+    aggregations.facets.push("unknown".to_string());
+    FullTextSearchForm {
+        aggregations: Some(aggregations),
+        ..create_fulltext_search_form()
+    }
+}
+
+pub fn create_fulltext_search_form_with_sort(
+    field: &str,
+    order: Option<&str>,
+) -> FullTextSearchForm {
+    let mut form = create_fulltext_search_form();
+    form.result.sort = Some(vec![SortForm {
+        field: field.to_string(),
+        order: order.map(String::from),
+    }]);
+    form
+}
+
+pub fn create_fulltext_search_form_with_custom_filter(
+    size_from: u32,
+    location_coordinates: Vec<f64>,
+    distance: &str,
+) -> FullTextSearchForm {
+    let mut filter = create_filter_form();
+    filter.size_from = Some(size_from);
+    filter.location_coordinates = Some(location_coordinates);
+    filter.distance = Some(distance.to_string());
+
+    let mut form = create_fulltext_search_form();
+    form.filter = Some(filter);
+    form
+}
+
+pub fn create_fulltext_search_form_with_order(order: &str) -> FullTextSearchForm {
+    let mut form = create_fulltext_search_form();
+    form.result.order = order.to_string();
+    form
+}
+
+pub fn create_hybrid_search_form_with_scoring(
+    semantic_weight: Option<f32>,
+    lexical_weight: Option<f32>,
+    normalization: Option<&str>,
+) -> HybridSearchForm {
+    HybridSearchForm {
+        semantic_weight,
+        lexical_weight,
+        normalization: normalization.map(String::from),
+        combination: Some("harmonic_mean".to_string()),
+        pagination_depth: Some(50),
+        ..create_hybrid_search_form()
+    }
+}
+
+pub fn create_hybrid_search_form_with_fusion(fusion: &str) -> HybridSearchForm {
+    HybridSearchForm {
+        fusion: Some(fusion.to_string()),
+        ..create_hybrid_search_form()
+    }
+}
+
+pub fn create_semantic_search_form_with_aggregations() -> SemanticSearchForm {
+    SemanticSearchForm {
+        aggregations: Some(create_aggregation_form()),
+        ..create_semantic_search_form()
+    }
+}
+
+pub fn create_hybrid_search_form_with_aggregations() -> HybridSearchForm {
+    HybridSearchForm {
+        aggregations: Some(create_aggregation_form()),
+        ..create_hybrid_search_form()
+    }
+}
+
+fn create_aggregation_form() -> AggregationForm {
+    AggregationForm {
+        facets: vec![
+            "source".to_string(),
+            "classes".to_string(),
+            "created_at".to_string(),
+        ],
+        size: Some(5),
+        interval: Some("week".to_string()),
     }
 }
 
@@ -102,6 +217,9 @@ fn create_result_form() -> ResultForm {
         include_extra_fields: Some(false),
         highlight_items: Some(3),
         highlight_item_size: Some(100),
+        group_by_document: None,
+        sort: None,
+        track_total_hits: None,
     }
 }
 
@@ -118,8 +236,26 @@ fn create_filter_form() -> FilterForm {
         source: Some("source-name".to_string()),
         semantic_source: Some("semantic-source".to_string()),
         distance: Some("80km".to_string()),
-        location_coordinates: Some(vec![45.99, 29.43]),
+        location_coordinates: Some(vec![29.43, 45.99]),
         document_class: Some("war".to_string()),
         document_class_probability: Some(0.8),
     }
 }
+
+pub fn create_similar_search_form(
+    large_doc_id: Option<&str>,
+    doc_part_id: Option<&str>,
+    mode: Option<&str>,
+) -> SimilarSearchForm {
+    SimilarSearchForm {
+        indexes: "test-index-1,test-index-2".to_string(),
+        source_index: "test-index-1".to_string(),
+        large_doc_id: large_doc_id.map(String::from),
+        doc_part_id: doc_part_id.map(String::from),
+        mode: mode.map(String::from),
+        knn_amount: None,
+        min_score: None,
+        filter: Some(create_filter_form()),
+        result: create_short_result_form(),
+    }
+}
@@ -4,6 +4,7 @@ pub mod stubs;
 mod test_form;
 mod test_routers_document;
 mod test_routers_index;
+mod test_routers_memory;
 mod test_routers_searcher;
 mod test_schema;
 
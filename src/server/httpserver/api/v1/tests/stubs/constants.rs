@@ -10,7 +10,7 @@ pub const DOCUMENT_FILE_NAME: &str = "test-document.docx";
 pub const DOCUMENT_FILE_PATH: &str = "./test-document.docx";
 pub const DOCUMENT_CONTENT: &str = "Intuition is very important to understanding a concept.";
 
-pub const SCROLL_ID: &str = "dksfsjvJHZVFDskjdbfsdfsdfdsg";
+pub const CURSOR: &str = "dksfsjvJHZVFDskjdbfsdfsdfdsg";
 pub const SEARCH_QUERY: &str = "any query message";
 pub const MODEL_ID: &str = "model-123234";
 pub const KNN_AMOUNT: u16 = 100;
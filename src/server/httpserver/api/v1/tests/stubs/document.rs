@@ -21,8 +21,28 @@ pub fn stored_document_info_json_object() -> Value {
     })
 }
 
-pub fn stored_documents_info_json_object() -> Value {
-    json!([stored_document_info_json_object()])
+pub fn stored_documents_report_json_object() -> Value {
+    json!({
+        "succeeded": [{
+            "position": 0,
+            "file_path": DOCUMENT_FILE_PATH,
+            "large_doc_id": LARGE_DOCUMENT_ID,
+            "first_part_id": FIRST_DOC_PART_ID,
+            "doc_parts_amount": 2,
+        }],
+        "failed": [],
+    })
+}
+
+pub fn failed_documents_report_json_object(reason: &str) -> Value {
+    json!({
+        "succeeded": [],
+        "failed": [{
+            "position": 0,
+            "file_path": DOCUMENT_FILE_PATH,
+            "reason": reason,
+        }],
+    })
 }
 
 pub fn create_document_json_object() -> Value {
@@ -40,6 +60,32 @@ pub fn create_documents_json_object() -> Value {
     json!([create_document_json_object()])
 }
 
+pub fn update_document_json_object() -> Value {
+    json!({
+        "file_name": DOCUMENT_FILE_NAME,
+        "file_path": DOCUMENT_FILE_PATH,
+        "content": DOCUMENT_CONTENT,
+        "file_size": DOCUMENT_FILE_SIZE,
+        "created_at": DOCUMENT_CREATED_AT,
+    })
+}
+
+pub fn update_document_metadata_json_object() -> Value {
+    json!({
+        "file_name": DOCUMENT_FILE_NAME,
+        "file_path": DOCUMENT_FILE_PATH,
+        "file_size": DOCUMENT_FILE_SIZE,
+        "created_at": DOCUMENT_CREATED_AT,
+    })
+}
+
+pub fn updated_document_json_response() -> Value {
+    json!({
+        "status": 200,
+        "message": LARGE_DOCUMENT_ID,
+    })
+}
+
 pub fn build_document_part_json_object(doc_part_id: usize) -> Value {
     let document_part = build_document_part(doc_part_id);
     let document_part_schema = DocumentPartSchema::try_from(document_part)
@@ -8,9 +8,13 @@ pub use document::build_document_part_json_object;
 pub use document::create_document_json_object;
 pub use document::create_documents_json_object;
 pub use document::document_parts_json_object;
+pub use document::failed_documents_report_json_object;
 pub use document::stored_document_info;
 pub use document::stored_document_info_json_object;
-pub use document::stored_documents_info_json_object;
+pub use document::stored_documents_report_json_object;
+pub use document::update_document_json_object;
+pub use document::update_document_metadata_json_object;
+pub use document::updated_document_json_response;
 
 mod error;
 pub use error::bad_request_error_json_response;
@@ -31,8 +35,11 @@ pub use search::fulltext_search_params_with_filter_json_object;
 pub use search::hybrid_search_params_json_object;
 pub use search::hybrid_search_params_with_filter_json_object;
 pub use search::pagination_result_json_object;
+pub use search::result_params_json_object;
 pub use search::retrieve_index_documents_params_json_object;
 pub use search::retrieve_index_documents_params_with_filter_json_object;
 pub use search::semantic_search_params_json_object;
 pub use search::semantic_search_params_with_filter_json_object;
 pub use search::semantic_search_params_with_tokens_json_object;
+pub use search::similar_search_params_json_object;
+pub use search::similar_search_params_with_filter_json_object;
@@ -8,9 +8,9 @@ use doc_search_core::domain::searcher::models::{
 use doc_search_core::shared::kernel::LargeDocumentId;
 
 use super::constants::{
-    COMPOSITE_INDEX_IDS, DOCUMENT_CONTENT, DOCUMENT_CREATED_AT, DOCUMENT_FILE_NAME,
-    DOCUMENT_FILE_PATH, DOCUMENT_FILE_SIZE, DOCUMENT_MODIFIED_AT, KNN_AMOUNT, LARGE_DOCUMENT_ID,
-    MIN_SCORE, MODEL_ID, SCROLL_ID, SEARCH_QUERY, TEST_INDEX_ID,
+    COMPOSITE_INDEX_IDS, CURSOR, DOCUMENT_CONTENT, DOCUMENT_CREATED_AT, DOCUMENT_FILE_NAME,
+    DOCUMENT_FILE_PATH, DOCUMENT_FILE_SIZE, DOCUMENT_MODIFIED_AT, FIRST_DOC_PART_ID, KNN_AMOUNT,
+    LARGE_DOCUMENT_ID, MIN_SCORE, MODEL_ID, SEARCH_QUERY, TEST_INDEX_ID,
 };
 
 const QUERY_TOKENS: [f64; 3] = [-1.123, 0.123, 1.123];
@@ -77,7 +77,7 @@ pub fn pagination_result_json_object() -> Value {
                 }
             },
         ],
-        "scroll_id": SCROLL_ID,
+        "cursor": CURSOR,
     })
 }
 
@@ -153,6 +153,29 @@ pub fn hybrid_search_params_with_filter_json_object() -> Value {
     })
 }
 
+pub fn similar_search_params_json_object() -> Value {
+    json!({
+        "indexes": COMPOSITE_INDEX_IDS,
+        "source_index": TEST_INDEX_ID,
+        "large_doc_id": LARGE_DOCUMENT_ID,
+        "mode": "lexical",
+        "result": result_params_json_object(),
+    })
+}
+
+pub fn similar_search_params_with_filter_json_object() -> Value {
+    json!({
+        "indexes": COMPOSITE_INDEX_IDS,
+        "source_index": TEST_INDEX_ID,
+        "doc_part_id": FIRST_DOC_PART_ID,
+        "mode": "vector",
+        "knn_amount": KNN_AMOUNT,
+        "min_score": MIN_SCORE,
+        "filter": filter_params_json_object(),
+        "result": result_params_json_object(),
+    })
+}
+
 pub fn retrieve_index_documents_params_json_object() -> Value {
     json!({
         "path": RETRIEVE_FILE_PATH,
@@ -1,9 +1,11 @@
 use doc_search_core::domain::searcher::models::{RetrieveIndexDocumentsParams, SearchingParams};
+use doc_search_core::domain::storage::models::UpdateDocumentParams;
 use doc_search_core::domain::storage::models::{CreateIndexParams, LargeDocument};
 
 use crate::server::httpserver::api::v1::form::{
     CreateDocumentForm, CreateIndexForm, FullTextSearchForm, HybridSearchForm,
-    RetrieveDocumentForm, SemanticSearchForm, UpdateDocumentForm,
+    RetrieveDocumentForm, SemanticSearchForm, SimilarSearchForm, SplitterKindForm,
+    UpdateDocumentForm,
 };
 use crate::server::ServerResult;
 
@@ -12,6 +14,15 @@ use super::fixtures::form::*;
 #[rstest::rstest]
 #[case(create_index_form(), true)]
 #[case(create_index_form_with_knn(), true)]
+#[case(
+    create_index_form_with_splitter(SplitterKindForm::Markdown, None),
+    true
+)]
+#[case(
+    create_index_form_with_splitter(SplitterKindForm::Token, Some(50)),
+    true
+)]
+#[case(create_index_form_with_splitter(SplitterKindForm::Token, None), false)]
 fn test_create_index_form_mapping(
     #[case] form: CreateIndexForm,
     #[case] is_success: bool,
@@ -24,6 +35,7 @@ fn test_create_index_form_mapping(
 #[rstest::rstest]
 #[case(create_document_form(), true)]
 #[case(create_document_form_with_metadata(), true)]
+#[case(create_document_form_with_embeddings(), true)]
 fn test_create_document_form_mapping(
     #[case] form: CreateDocumentForm,
     #[case] is_success: bool,
@@ -46,6 +58,18 @@ fn test_update_document_form_mapping(
     Ok(())
 }
 
+#[rstest::rstest]
+#[case(update_document_form(), true)]
+#[case(update_document_form_metadata_only(), true)]
+fn test_update_document_metadata_form_mapping(
+    #[case] form: UpdateDocumentForm,
+    #[case] is_success: bool,
+) -> anyhow::Result<()> {
+    let result: ServerResult<UpdateDocumentParams> = form.try_into();
+    assert_eq!(result.is_ok(), is_success);
+    Ok(())
+}
+
 #[rstest::rstest]
 #[case(create_retrieve_document_form(), true)]
 #[case(create_retrieve_document_form_with_filter(), true)]
@@ -61,6 +85,24 @@ fn test_retrieve_documents_form_mapping(
 #[rstest::rstest]
 #[case(create_fulltext_search_form(), true)]
 #[case(create_fulltext_search_form_with_filter(), true)]
+#[case(create_fulltext_search_form_with_aggregations(), true)]
+#[case(create_fulltext_search_form_with_unknown_facet(), false)]
+#[case(create_fulltext_search_form_with_sort("_score", Some("desc")), true)]
+#[case(create_fulltext_search_form_with_sort("file_name.keyword", None), true)]
+#[case(create_fulltext_search_form_with_sort("unknown", Some("asc")), false)]
+#[case(
+    create_fulltext_search_form_with_sort("created_at", Some("sideways")),
+    false
+)]
+#[case(create_fulltext_search_form_with_order("sideways"), false)]
+#[case(create_fulltext_search_form_with_custom_filter(0, vec![29.43, 45.99], "80km"), true)]
+#[case(create_fulltext_search_form_with_custom_filter(0, vec![29.43], "80km"), false)]
+#[case(create_fulltext_search_form_with_custom_filter(2048, vec![29.43, 45.99], "80km"), false)]
+#[case(create_fulltext_search_form_with_custom_filter(0, vec![29.43, 91.0], "80km"), false)]
+#[case(create_fulltext_search_form_with_custom_filter(0, vec![181.0, 45.99], "80km"), false)]
+#[case(create_fulltext_search_form_with_custom_filter(0, vec![29.43, 45.99], "0km"), false)]
+#[case(create_fulltext_search_form_with_custom_filter(0, vec![29.43, 45.99], "-5km"), false)]
+#[case(create_fulltext_search_form_with_custom_filter(0, vec![29.43, 45.99], &"9".repeat(400)), false)]
 fn test_fulltext_search_form_mapping(
     #[case] form: FullTextSearchForm,
     #[case] is_success: bool,
@@ -73,6 +115,7 @@ fn test_fulltext_search_form_mapping(
 #[rstest::rstest]
 #[case(create_semantic_search_form(), true)]
 #[case(create_semantic_search_form_with_filter(), true)]
+#[case(create_semantic_search_form_with_aggregations(), true)]
 fn test_semantic_search_form_mapping(
     #[case] form: SemanticSearchForm,
     #[case] is_success: bool,
@@ -82,9 +125,54 @@ fn test_semantic_search_form_mapping(
     Ok(())
 }
 
+#[rstest::rstest]
+#[case(create_similar_search_form(Some("c5cdd3bfad598ec7"), None, None), true)]
+#[case(
+    create_similar_search_form(None, Some("98ac9896be35f47f"), Some("vector")),
+    true
+)]
+#[case(
+    create_similar_search_form(Some("c5cdd3bfad598ec7"), None, Some("unknown")),
+    false
+)]
+#[case(
+    create_similar_search_form(Some("c5cdd3bfad598ec7"), Some("98ac9896be35f47f"), None),
+    false
+)]
+#[case(create_similar_search_form(None, None, None), false)]
+fn test_similar_search_form_mapping(
+    #[case] form: SimilarSearchForm,
+    #[case] is_success: bool,
+) -> anyhow::Result<()> {
+    let result: ServerResult<SearchingParams> = form.try_into();
+    assert_eq!(result.is_ok(), is_success);
+    Ok(())
+}
+
 #[rstest::rstest]
 #[case(create_hybrid_search_form(), true)]
 #[case(create_hybrid_search_form_with_filter(), true)]
+#[case(create_hybrid_search_form_with_aggregations(), true)]
+#[case(
+    create_hybrid_search_form_with_scoring(Some(0.6), None, Some("l2")),
+    true
+)]
+#[case(
+    create_hybrid_search_form_with_scoring(Some(0.6), Some(0.4), None),
+    true
+)]
+#[case(
+    create_hybrid_search_form_with_scoring(Some(0.6), Some(0.6), None),
+    false
+)]
+#[case(create_hybrid_search_form_with_scoring(None, Some(1.5), None), false)]
+#[case(
+    create_hybrid_search_form_with_scoring(None, None, Some("unknown")),
+    false
+)]
+#[case(create_hybrid_search_form_with_fusion("rrf"), true)]
+#[case(create_hybrid_search_form_with_fusion("weighted_score"), true)]
+#[case(create_hybrid_search_form_with_fusion("unknown"), false)]
 fn test_hybrid_search_form_mapping(
     #[case] form: HybridSearchForm,
     #[case] is_success: bool,
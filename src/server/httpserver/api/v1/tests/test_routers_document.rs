@@ -215,6 +215,10 @@ async fn test_store_document(
         .expect_get_index()
         .returning(|_| Ok(IndexId(TEST_INDEX_ID.to_string())));
 
+    storage
+        .expect_get_document_parts()
+        .returning(|_, _| Ok(Vec::new()));
+
     let expectation = storage.expect_store_document_parts().once();
 
     match expected_status {
@@ -271,12 +275,12 @@ async fn test_store_document(
 #[case(
     StatusCode::CREATED,
     stubs::create_documents_json_object(),
-    stubs::stored_documents_info_json_object()
+    stubs::stored_documents_report_json_object()
 )]
 #[case(
     StatusCode::BAD_REQUEST,
     stubs::create_documents_json_object(),
-    stubs::bad_request_error_json_response()
+    stubs::failed_documents_report_json_object("storage: validation error: bad request")
 )]
 #[case(
     StatusCode::NOT_FOUND,
@@ -284,43 +288,122 @@ async fn test_store_document(
     stubs::not_found_error_json_response()
 )]
 #[case(
-    StatusCode::CONFLICT,
+    StatusCode::INTERNAL_SERVER_ERROR,
     stubs::create_documents_json_object(),
-    stubs::conflict_error_json_response()
+    stubs::failed_documents_report_json_object("storage: internal error: internal server error")
 )]
+async fn test_store_documents(
+    #[case] store_status: StatusCode,
+    #[case] request_body: Value,
+    #[case] expected_body: Value,
+) -> anyhow::Result<()> {
+    let searcher = MockSearcherService::new();
+    let mut storage = MockStorageService::new();
+
+    let expected_status = match store_status {
+        StatusCode::NOT_FOUND => {
+            storage.expect_get_index().times(1).returning(|_| {
+                let err = anyhow!("not found");
+                Err(StorageError::IndexNotFound(err))
+            });
+            storage.expect_store_document_parts().never();
+            StatusCode::NOT_FOUND
+        }
+        _ => {
+            storage
+                .expect_get_index()
+                .returning(|index_id| Ok(index_id.clone()));
+
+            let expectation = storage.expect_store_document_parts().times(1);
+            match store_status {
+                StatusCode::CREATED => {
+                    expectation.returning(move |_, _| Ok(stubs::stored_document_info()));
+                    StatusCode::CREATED
+                }
+                StatusCode::BAD_REQUEST => {
+                    expectation.returning(move |_, _| {
+                        let err = anyhow!("bad request");
+                        Err(StorageError::ValidationError(err))
+                    });
+                    StatusCode::MULTI_STATUS
+                }
+                StatusCode::INTERNAL_SERVER_ERROR => {
+                    expectation.returning(move |_, _| {
+                        let err = anyhow!("internal server error");
+                        Err(StorageError::InternalError(err))
+                    });
+                    StatusCode::MULTI_STATUS
+                }
+                _ => return Err(anyhow!("unexpected test case")),
+            }
+        }
+    };
+
+    let test_server_context = test_server::create_test_server_context(storage, searcher);
+
+    let target_uri = format!(
+        "{}/storage/{}/documents?force=true",
+        API_VERSION_URL, TEST_INDEX_ID
+    );
+    let request_body = serde_json::to_vec(&request_body).expect("failed to serialize json");
+    let request = Request::builder()
+        .method(Method::PUT)
+        .uri(target_uri)
+        .header(CONTENT_TYPE, TEST_CONTENT_TYPE)
+        .body(Body::from(request_body))
+        .expect("failed to build request");
+
+    let response = test_server_context
+        .test_server
+        .clone()
+        .oneshot(request)
+        .await?;
+    assert_eq!(response.status(), expected_status);
+
+    let body = axum::body::to_bytes(response.into_body(), RESPONSE_BODY_SIZE_LIMIT)
+        .await
+        .expect("extracting response body failed");
+
+    let data = serde_json::from_slice::<Value>(&body).expect("failed to parse json");
+    assert_eq!(expected_body, data);
+
+    Ok(())
+}
+
+#[tokio::test]
+#[rstest::rstest]
+#[case(StatusCode::OK, stubs::updated_document_json_response())]
+#[case(StatusCode::NOT_FOUND, stubs::not_found_error_json_response())]
 #[case(
     StatusCode::INTERNAL_SERVER_ERROR,
-    stubs::create_documents_json_object(),
     stubs::internal_server_error_json_response()
 )]
-async fn test_store_documents(
+async fn test_update_document(
     #[case] expected_status: StatusCode,
-    #[case] request_body: Value,
     #[case] expected_body: Value,
 ) -> anyhow::Result<()> {
     let searcher = MockSearcherService::new();
     let mut storage = MockStorageService::new();
 
+    storage.expect_update_document_parts().never();
     storage
-        .expect_get_index()
-        .returning(|index_id| Ok(index_id.clone()));
+        .expect_get_document_parts()
+        .once()
+        .returning(|_, _| Ok(vec![stubs::build_document_part(1)]));
 
-    let expectation = storage.expect_store_document_parts().times(1);
+    let expectation = storage.expect_store_document_parts().once();
 
     match expected_status {
-        StatusCode::CREATED => expectation.returning(move |_, _| Ok(stubs::stored_document_info())),
-        StatusCode::BAD_REQUEST => expectation.returning(move |_, _| {
-            let err = anyhow!("bad request");
-            Err(StorageError::ValidationError(err))
+        StatusCode::OK => expectation.returning(move |_, parts| {
+            assert!(parts
+                .iter()
+                .all(|it| it.large_doc_id.0 == LARGE_DOCUMENT_ID));
+            Ok(stubs::stored_document_info())
         }),
         StatusCode::NOT_FOUND => expectation.returning(move |_, _| {
             let err = anyhow!("not found");
             Err(StorageError::IndexNotFound(err))
         }),
-        StatusCode::CONFLICT => expectation.returning(move |_, _| {
-            let err = anyhow!("conflict error");
-            Err(StorageError::DocumentAlreadyExists(err))
-        }),
         StatusCode::INTERNAL_SERVER_ERROR => expectation.returning(move |_, _| {
             let err = anyhow!("internal server error");
             Err(StorageError::InternalError(err))
@@ -330,10 +413,75 @@ async fn test_store_documents(
 
     let test_server_context = test_server::create_test_server_context(storage, searcher);
 
-    let target_uri = format!("{}/storage/{}/documents", API_VERSION_URL, TEST_INDEX_ID);
-    let request_body = serde_json::to_vec(&request_body).expect("failed to serialize json");
+    let target_uri = format!(
+        "{}/storage/{}/{}",
+        API_VERSION_URL, TEST_INDEX_ID, LARGE_DOCUMENT_ID
+    );
+    let request_body = serde_json::to_vec(&stubs::update_document_json_object())?;
     let request = Request::builder()
-        .method(Method::PUT)
+        .method(Method::PATCH)
+        .uri(target_uri)
+        .header(CONTENT_TYPE, TEST_CONTENT_TYPE)
+        .body(Body::from(request_body))
+        .expect("failed to build request");
+
+    let response = test_server_context
+        .test_server
+        .clone()
+        .oneshot(request)
+        .await?;
+    assert_eq!(response.status(), expected_status);
+
+    let body = axum::body::to_bytes(response.into_body(), RESPONSE_BODY_SIZE_LIMIT)
+        .await
+        .expect("extracting response body failed");
+
+    let data = serde_json::from_slice::<Value>(&body).expect("failed to parse json");
+    assert_eq!(expected_body, data);
+
+    Ok(())
+}
+
+#[tokio::test]
+#[rstest::rstest]
+#[case(StatusCode::OK, stubs::updated_document_json_response())]
+#[case(StatusCode::NOT_FOUND, stubs::not_found_error_json_response())]
+#[case(
+    StatusCode::INTERNAL_SERVER_ERROR,
+    stubs::internal_server_error_json_response()
+)]
+async fn test_update_document_metadata(
+    #[case] expected_status: StatusCode,
+    #[case] expected_body: Value,
+) -> anyhow::Result<()> {
+    let searcher = MockSearcherService::new();
+    let mut storage = MockStorageService::new();
+
+    storage.expect_store_document_parts().never();
+    let expectation = storage.expect_update_document_parts().once();
+
+    match expected_status {
+        StatusCode::OK => expectation.returning(move |_, _, _| Ok(())),
+        StatusCode::NOT_FOUND => expectation.returning(move |_, _, _| {
+            let err = anyhow!("not found");
+            Err(StorageError::DocumentNotFound(err))
+        }),
+        StatusCode::INTERNAL_SERVER_ERROR => expectation.returning(move |_, _, _| {
+            let err = anyhow!("internal server error");
+            Err(StorageError::InternalError(err))
+        }),
+        _ => return Err(anyhow!("unexpected test case")),
+    };
+
+    let test_server_context = test_server::create_test_server_context(storage, searcher);
+
+    let target_uri = format!(
+        "{}/storage/{}/{}",
+        API_VERSION_URL, TEST_INDEX_ID, LARGE_DOCUMENT_ID
+    );
+    let request_body = serde_json::to_vec(&stubs::update_document_metadata_json_object())?;
+    let request = Request::builder()
+        .method(Method::PATCH)
         .uri(target_uri)
         .header(CONTENT_TYPE, TEST_CONTENT_TYPE)
         .body(Body::from(request_body))
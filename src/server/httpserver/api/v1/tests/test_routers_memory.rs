@@ -0,0 +1,285 @@
+use axum::body::Body;
+use axum::http::{Method, Request, StatusCode};
+use axum::Router;
+use axum_test::http::header::CONTENT_TYPE;
+use serde_json::{json, Value};
+use tower::ServiceExt;
+
+use crate::server::httpserver::api::v1::API_VERSION_URL;
+use crate::server::httpserver::tests::context::test_server;
+
+use super::stubs;
+use super::stubs::constants::{DOCUMENT_CONTENT, TEST_INDEX_ID};
+use super::{RESPONSE_BODY_SIZE_LIMIT, TEST_CONTENT_TYPE};
+
+async fn send_request(
+    router: &Router,
+    method: Method,
+    uri: String,
+    body: Option<Value>,
+) -> anyhow::Result<(StatusCode, Value)> {
+    let body = match body {
+        None => Body::empty(),
+        Some(value) => Body::from(serde_json::to_vec(&value)?),
+    };
+
+    let request = Request::builder()
+        .method(method)
+        .uri(format!("{API_VERSION_URL}{uri}"))
+        .header(CONTENT_TYPE, TEST_CONTENT_TYPE)
+        .body(body)?;
+
+    let response = router.clone().oneshot(request).await?;
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), RESPONSE_BODY_SIZE_LIMIT).await?;
+    let data = serde_json::from_slice::<Value>(&body).unwrap_or_default();
+    Ok((status, data))
+}
+
+#[tokio::test]
+async fn test_memory_backend_store_and_search() -> anyhow::Result<()> {
+    let context = test_server::create_memory_server_context();
+    let router = &context.test_server;
+
+    let create_index_form = json!({ "id": TEST_INDEX_ID, "knn": null });
+    let uri = format!("/storage/{TEST_INDEX_ID}");
+    let (status, _) = send_request(router, Method::PUT, uri, Some(create_index_form)).await?;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let document = stubs::create_document_json_object();
+    let uri = format!("/storage/{TEST_INDEX_ID}/create");
+    let (status, _) = send_request(router, Method::PUT, uri, Some(document)).await?;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let search_params = json!({
+        "query": "intuition",
+        "indexes": TEST_INDEX_ID,
+        "result": stubs::result_params_json_object(),
+    });
+    let uri = "/search/fulltext".to_string();
+    let (status, data) = send_request(router, Method::POST, uri, Some(search_params)).await?;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(data["founded"].as_array().map(Vec::len), Some(1));
+    assert_eq!(data["founded"][0]["document"]["content"], DOCUMENT_CONTENT);
+
+    assert!(data["cursor"].is_null());
+
+    let uri = "/search/paginate/unknown-cursor".to_string();
+    let (status, _) = send_request(router, Method::GET, uri.clone(), None).await?;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (status, _) = send_request(router, Method::DELETE, uri, None).await?;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let search_params = json!({
+        "query": "nonexistentword",
+        "indexes": TEST_INDEX_ID,
+        "result": stubs::result_params_json_object(),
+    });
+    let uri = "/search/fulltext".to_string();
+    let (status, data) = send_request(router, Method::POST, uri, Some(search_params)).await?;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(data["founded"].as_array().map(Vec::len), Some(0));
+
+    let uri = format!("/storage/{TEST_INDEX_ID}");
+    let (status, _) = send_request(router, Method::DELETE, uri.clone(), None).await?;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, _) = send_request(router, Method::GET, uri, None).await?;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    Ok(())
+}
+
+const UPLOAD_BOUNDARY: &str = "doc-search-upload-boundary";
+
+async fn send_upload_request(
+    router: &Router,
+    uri: String,
+    files: &[(&str, &str)],
+) -> anyhow::Result<(StatusCode, Value)> {
+    let mut body = String::new();
+    for (file_name, content) in files {
+        body.push_str(&format!(
+            "--{UPLOAD_BOUNDARY}\r\n\
+             Content-Disposition: form-data; name=\"file\"; filename=\"{file_name}\"\r\n\
+             Content-Type: application/octet-stream\r\n\r\n\
+             {content}\r\n"
+        ));
+    }
+    body.push_str(&format!(
+        "--{UPLOAD_BOUNDARY}\r\n\
+         Content-Disposition: form-data; name=\"path\"\r\n\r\n\
+         ./uploads\r\n\
+         --{UPLOAD_BOUNDARY}--\r\n"
+    ));
+
+    let content_type = format!("multipart/form-data; boundary={UPLOAD_BOUNDARY}");
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri(format!("{API_VERSION_URL}{uri}"))
+        .header(CONTENT_TYPE, content_type)
+        .body(Body::from(body))?;
+
+    let response = router.clone().oneshot(request).await?;
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), RESPONSE_BODY_SIZE_LIMIT).await?;
+    let data = serde_json::from_slice::<Value>(&body).unwrap_or_default();
+    Ok((status, data))
+}
+
+#[tokio::test]
+async fn test_memory_backend_upload_documents() -> anyhow::Result<()> {
+    let context = test_server::create_memory_server_context();
+    let router = &context.test_server;
+
+    let uri = format!("/storage/{TEST_INDEX_ID}/upload");
+    let files = [("notes.txt", "uploaded plain text about intuition")];
+    let (status, _) = send_upload_request(router, uri.clone(), &files).await?;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let create_index_form = json!({ "id": TEST_INDEX_ID, "knn": null });
+    let index_uri = format!("/storage/{TEST_INDEX_ID}");
+    let (status, _) = send_request(router, Method::PUT, index_uri, Some(create_index_form)).await?;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let files = [("image.png", "not a document")];
+    let (status, _) = send_upload_request(router, uri.clone(), &files).await?;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let files = [
+        ("notes.txt", "uploaded plain text about intuition"),
+        (
+            "page.html",
+            "<html><body><p>uploaded html page</p></body></html>",
+        ),
+    ];
+    let (status, data) = send_upload_request(router, uri.clone(), &files).await?;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(data["succeeded"].as_array().map(Vec::len), Some(2));
+
+    let files = [
+        ("notes.txt", "uploaded plain text about intuition"),
+        ("other.txt", "another uploaded plain text"),
+    ];
+    let (status, data) = send_upload_request(router, uri, &files).await?;
+    assert_eq!(status, StatusCode::MULTI_STATUS);
+    assert_eq!(data["succeeded"][0]["position"], 1);
+    assert_eq!(data["failed"][0]["position"], 0);
+    assert_eq!(data["failed"][0]["file_path"], "./uploads/notes.txt");
+
+    let search_params = json!({
+        "query": "intuition",
+        "indexes": TEST_INDEX_ID,
+        "result": stubs::result_params_json_object(),
+    });
+    let uri = "/search/fulltext".to_string();
+    let (status, data) = send_request(router, Method::POST, uri, Some(search_params)).await?;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(data["founded"].as_array().map(Vec::len), Some(1));
+
+    let document = &data["founded"][0]["document"];
+    assert_eq!(document["file_name"], "notes.txt");
+    assert_eq!(document["file_path"], "./uploads/notes.txt");
+    assert_eq!(document["file_size"], 35);
+    assert_eq!(document["content"], "uploaded plain text about intuition");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_memory_backend_store_documents_async() -> anyhow::Result<()> {
+    let context = test_server::create_memory_server_context();
+    let router = &context.test_server;
+
+    let documents = json!([stubs::create_document_json_object()]);
+    let uri = format!("/storage/{TEST_INDEX_ID}/documents?async=true");
+    let (status, _) =
+        send_request(router, Method::PUT, uri.clone(), Some(documents.clone())).await?;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let create_index_form = json!({ "id": TEST_INDEX_ID, "knn": null });
+    let index_uri = format!("/storage/{TEST_INDEX_ID}");
+    let (status, _) = send_request(router, Method::PUT, index_uri, Some(create_index_form)).await?;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let (status, data) = send_request(router, Method::PUT, uri, Some(documents)).await?;
+    assert_eq!(status, StatusCode::ACCEPTED);
+    assert_eq!(data["index"], TEST_INDEX_ID);
+    assert_eq!(data["total"], 1);
+
+    let job_uri = format!("/jobs/{}", data["id"].as_str().unwrap_or_default());
+    let mut job = Value::Null;
+    for _ in 0..100 {
+        let (status, data) = send_request(router, Method::GET, job_uri.clone(), None).await?;
+        assert_eq!(status, StatusCode::OK);
+        job = data;
+        if job["status"] == "completed" {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    }
+
+    assert_eq!(job["status"], "completed");
+    assert_eq!(job["processed"], 1);
+    assert_eq!(job["documents"][0]["status"], "stored");
+
+    let (status, _) = send_request(router, Method::GET, "/jobs/unknown".to_string(), None).await?;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_memory_backend_document_content() -> anyhow::Result<()> {
+    const SPLIT_CONTENT: &str = "there is some huge content about current project";
+
+    let context = test_server::create_memory_server_context();
+    let router = &context.test_server;
+
+    let create_index_form = json!({
+        "id": TEST_INDEX_ID,
+        "knn": null,
+        "splitter": { "kind": "token", "token_limit": 3, "overlap_rate": 0.4 },
+    });
+    let uri = format!("/storage/{TEST_INDEX_ID}");
+    let (status, _) = send_request(router, Method::PUT, uri, Some(create_index_form)).await?;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let mut document = stubs::create_document_json_object();
+    document["content"] = json!(SPLIT_CONTENT);
+    let uri = format!("/storage/{TEST_INDEX_ID}/create");
+    let (status, data) = send_request(router, Method::PUT, uri, Some(document)).await?;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(data["doc_parts_amount"], 4);
+
+    let large_doc_id = data["large_doc_id"].as_str().unwrap_or_default();
+    let uri = format!("/storage/{TEST_INDEX_ID}/{large_doc_id}?doc_part_id=1&window=1");
+    let (status, data) = send_request(router, Method::GET, uri, None).await?;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(data.as_array().map(Vec::len), Some(2));
+    assert_eq!(data[1]["content"], "some huge content");
+
+    let uri = format!("/storage/{TEST_INDEX_ID}/{large_doc_id}?doc_part_id=10");
+    let (status, _) = send_request(router, Method::GET, uri, None).await?;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri(format!(
+            "{API_VERSION_URL}/storage/{TEST_INDEX_ID}/{large_doc_id}/content"
+        ))
+        .body(Body::empty())?;
+
+    let response = router.clone().oneshot(request).await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()[CONTENT_TYPE],
+        "text/plain; charset=utf-8"
+    );
+
+    let body = axum::body::to_bytes(response.into_body(), RESPONSE_BODY_SIZE_LIMIT).await?;
+    assert_eq!(body, SPLIT_CONTENT.as_bytes());
+
+    let uri = format!("/storage/{TEST_INDEX_ID}/unknown-document/content");
+    let (status, _) = send_request(router, Method::GET, uri, None).await?;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    Ok(())
+}
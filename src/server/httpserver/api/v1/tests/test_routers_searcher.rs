@@ -3,6 +3,7 @@ use axum::body::Body;
 use axum::http::{Method, Request, StatusCode};
 use axum_test::http::header::CONTENT_TYPE;
 use doc_search_core::domain::searcher::models::Pagination;
+use doc_search_core::domain::searcher::models::{SuggestField, Suggestion, Suggestions};
 use doc_search_core::domain::searcher::SearchError;
 use serde_json::Value;
 use tower::ServiceExt;
@@ -13,7 +14,7 @@ use crate::server::httpserver::tests::mocks::searcher::MockSearcherService;
 use crate::server::httpserver::tests::mocks::storage::MockStorageService;
 
 use super::stubs;
-use super::stubs::constants::SCROLL_ID;
+use super::stubs::constants::CURSOR;
 use super::RESPONSE_BODY_SIZE_LIMIT;
 
 #[tokio::test]
@@ -50,13 +51,13 @@ async fn test_search_fulltext(
 
     match expected_status {
         StatusCode::OK => expectation.returning(move |_| {
-            let scroll = Some(SCROLL_ID.to_string());
+            let cursor = Some(CURSOR.to_string());
             let documents = vec![
                 stubs::founded_document_with_part_id(1),
                 stubs::founded_document_with_part_id(2),
             ];
 
-            Ok(Pagination::new(scroll, documents))
+            Ok(Pagination::new(cursor, documents))
         }),
         StatusCode::BAD_REQUEST => expectation.returning(move |_| {
             let err = anyhow!("bad request");
@@ -134,13 +135,13 @@ async fn test_search_semantic(
 
     match expected_status {
         StatusCode::OK => expectation.returning(move |_| {
-            let scroll = Some(SCROLL_ID.to_string());
+            let cursor = Some(CURSOR.to_string());
             let documents = vec![
                 stubs::founded_document_with_part_id(1),
                 stubs::founded_document_with_part_id(2),
             ];
 
-            Ok(Pagination::new(scroll, documents))
+            Ok(Pagination::new(cursor, documents))
         }),
         StatusCode::BAD_REQUEST => expectation.returning(move |_| {
             let err = anyhow!("bad request");
@@ -213,13 +214,13 @@ async fn test_search_hybrid(
 
     match expected_status {
         StatusCode::OK => expectation.returning(move |_| {
-            let scroll = Some(SCROLL_ID.to_string());
+            let cursor = Some(CURSOR.to_string());
             let documents = vec![
                 stubs::founded_document_with_part_id(1),
                 stubs::founded_document_with_part_id(2),
             ];
 
-            Ok(Pagination::new(scroll, documents))
+            Ok(Pagination::new(cursor, documents))
         }),
         StatusCode::BAD_REQUEST => expectation.returning(move |_| {
             let err = anyhow!("bad request");
@@ -257,3 +258,181 @@ async fn test_search_hybrid(
 
     Ok(())
 }
+
+#[tokio::test]
+#[rstest::rstest]
+#[case(stubs::similar_search_params_json_object(), StatusCode::OK, None)]
+#[case(
+    stubs::similar_search_params_with_filter_json_object(),
+    StatusCode::OK,
+    None
+)]
+#[case(
+    stubs::similar_search_params_json_object(),
+    StatusCode::NOT_FOUND,
+    Some(stubs::not_found_error_json_response())
+)]
+#[case(
+    stubs::similar_search_params_with_filter_json_object(),
+    StatusCode::BAD_REQUEST,
+    Some(stubs::bad_request_error_json_response())
+)]
+async fn test_search_similar(
+    #[case] request_body: Value,
+    #[case] expected_status: StatusCode,
+    #[case] expected_error: Option<Value>,
+) -> anyhow::Result<()> {
+    let storage = MockStorageService::new();
+    let mut searcher = MockSearcherService::new();
+
+    let expectation = searcher.expect_search().once();
+
+    match expected_status {
+        StatusCode::OK => expectation.returning(move |_| {
+            let cursor = Some(CURSOR.to_string());
+            let documents = vec![
+                stubs::founded_document_with_part_id(1),
+                stubs::founded_document_with_part_id(2),
+            ];
+
+            Ok(Pagination::new(cursor, documents))
+        }),
+        StatusCode::NOT_FOUND => expectation.returning(move |_| {
+            let err = anyhow!("not found");
+            Err(SearchError::DocumentNotFound(err))
+        }),
+        StatusCode::BAD_REQUEST => expectation.returning(move |_| {
+            let err = anyhow!("bad request");
+            Err(SearchError::ValidationError(err))
+        }),
+        _ => return Err(anyhow!("unexpected test case")),
+    };
+
+    let test_server_context = test_server::create_test_server_context(storage, searcher);
+
+    let request_body = serde_json::to_vec(&request_body).expect("failed to serialize json");
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri(format!("{}/search/similar", API_VERSION_URL))
+        .header(CONTENT_TYPE, "application/json")
+        .body(Body::from(request_body))
+        .expect("failed to build request");
+
+    let response = test_server_context
+        .test_server
+        .clone()
+        .oneshot(request)
+        .await?;
+    assert_eq!(response.status(), expected_status);
+
+    let body = axum::body::to_bytes(response.into_body(), RESPONSE_BODY_SIZE_LIMIT)
+        .await
+        .expect("body should be ok");
+    let data = serde_json::from_slice::<Value>(&body).expect("failed to parse json");
+    match expected_error {
+        Some(expected_error) => assert_eq!(expected_error, data),
+        None => assert_eq!(data["founded"].as_array().map(Vec::len), Some(2)),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+#[rstest::rstest]
+#[case(StatusCode::OK)]
+#[case(StatusCode::BAD_REQUEST)]
+async fn test_release_pagination(#[case] expected_status: StatusCode) -> anyhow::Result<()> {
+    let storage = MockStorageService::new();
+    let mut searcher = MockSearcherService::new();
+
+    let expectation = searcher
+        .expect_release()
+        .withf(|params| params.cursor == CURSOR)
+        .once();
+
+    match expected_status {
+        StatusCode::OK => expectation.returning(|_| Ok(())),
+        StatusCode::BAD_REQUEST => expectation.returning(|_| {
+            let err = anyhow!("cursor does not exist or expired");
+            Err(SearchError::ValidationError(err))
+        }),
+        _ => return Err(anyhow!("unexpected test case")),
+    };
+
+    let test_server_context = test_server::create_test_server_context(storage, searcher);
+
+    let request = Request::builder()
+        .method(Method::DELETE)
+        .uri(format!("{}/search/paginate/{}", API_VERSION_URL, CURSOR))
+        .body(Body::empty())
+        .expect("failed to build request");
+
+    let response = test_server_context
+        .test_server
+        .clone()
+        .oneshot(request)
+        .await?;
+    assert_eq!(response.status(), expected_status);
+
+    Ok(())
+}
+
+#[tokio::test]
+#[rstest::rstest]
+#[case("q=war%20and%20pe&indexes=test-folder", StatusCode::OK)]
+#[case("q=war%20and%20pe&indexes=test-folder&size=1", StatusCode::OK)]
+#[case("q=%20&indexes=test-folder", StatusCode::BAD_REQUEST)]
+#[case("indexes=test-folder", StatusCode::BAD_REQUEST)]
+async fn test_suggest(
+    #[case] query: &str,
+    #[case] expected_status: StatusCode,
+) -> anyhow::Result<()> {
+    let storage = MockStorageService::new();
+    let mut searcher = MockSearcherService::new();
+
+    let expectation = searcher
+        .expect_suggest()
+        .withf(|params| params.query == "war and pe" && params.indexes == ["test-folder"]);
+
+    match expected_status {
+        StatusCode::OK => expectation.once().returning(|params| {
+            let completion = Suggestion {
+                text: "war and peace".to_string(),
+                score: 1.0,
+                field: SuggestField::Content,
+            };
+
+            Ok(Suggestions::new(
+                vec![completion],
+                Vec::default(),
+                params.size,
+            ))
+        }),
+        _ => expectation.never(),
+    };
+
+    let test_server_context = test_server::create_test_server_context(storage, searcher);
+
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri(format!("{}/search/suggest?{}", API_VERSION_URL, query))
+        .body(Body::empty())
+        .expect("failed to build request");
+
+    let response = test_server_context
+        .test_server
+        .clone()
+        .oneshot(request)
+        .await?;
+    assert_eq!(response.status(), expected_status);
+
+    if expected_status == StatusCode::OK {
+        let body = axum::body::to_bytes(response.into_body(), RESPONSE_BODY_SIZE_LIMIT).await?;
+        let data = serde_json::from_slice::<Value>(&body)?;
+        assert_eq!(data["completions"][0]["text"], "war and peace");
+        assert_eq!(data["completions"][0]["field"], "content");
+        assert_eq!(data["corrections"], Value::Array(Vec::default()));
+    }
+
+    Ok(())
+}
@@ -1,9 +1,11 @@
 use doc_search_core::domain::searcher::models::{DocumentPartEntrails, FoundedDocument};
+use doc_search_core::domain::searcher::models::{Pagination, TotalHits, TotalHitsRelation};
 use doc_search_core::domain::storage::models::DocumentPart;
 use doc_search_core::shared::kernel::IndexId;
+use serde_json::{json, Value};
 
 use crate::server::httpserver::api::v1::schema::{
-    DocumentPartSchema, FoundedDocumentPartSchema, IndexSchema,
+    DocumentPartSchema, FoundedDocumentPartSchema, IndexSchema, PaginationSchema,
 };
 use crate::server::ServerResult;
 
@@ -52,3 +54,30 @@ fn test_founded_documents_schema(
 
     Ok(())
 }
+
+#[rstest::rstest]
+#[case(TotalHits::exact(15), json!({"value": 15, "relation": "eq"}))]
+#[case(
+    TotalHits { value: 10000, relation: TotalHitsRelation::LowerBound },
+    json!({"value": 10000, "relation": "gte"})
+)]
+fn test_pagination_schema_total(
+    #[case] total: TotalHits,
+    #[case] expected_total: Value,
+) -> anyhow::Result<()> {
+    let pagination = Pagination::new(None, Vec::default())
+        .with_total(Some(total), Some(1.5))
+        .with_took(Some(12));
+
+    let schema = PaginationSchema::try_from(pagination)?;
+    let data = serde_json::to_value(schema)?;
+    assert_eq!(data["total"], expected_total);
+    assert_eq!(data["max_score"], json!(1.5));
+    assert_eq!(data["took"], json!(12));
+
+    let schema = PaginationSchema::try_from(Pagination::new(None, Vec::default()))?;
+    let data = serde_json::to_value(schema)?;
+    assert_eq!(data.get("total"), None);
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use gset::Getset;
+use serde_derive::Deserialize;
+
+use super::policy::AccessPolicyConfig;
+
+#[derive(Clone, Default, Deserialize, Getset)]
+pub struct AuthConfig {
+    #[serde(default)]
+    #[getset(get_copy, vis = "pub")]
+    is_enabled: bool,
+    #[serde(default)]
+    #[getset(get, vis = "pub")]
+    api_keys: Vec<ApiKeyConfig>,
+    #[serde(default)]
+    #[getset(get, vis = "pub")]
+    jwt: Option<JwtConfig>,
+    #[serde(default)]
+    #[getset(get, vis = "pub")]
+    policies: Vec<AccessPolicyConfig>,
+}
+
+#[derive(Clone, Deserialize, Getset)]
+pub struct ApiKeyConfig {
+    #[getset(get, vis = "pub")]
+    subject: String,
+    #[getset(get, vis = "pub")]
+    key: String,
+    #[serde(default)]
+    #[getset(get, vis = "pub")]
+    roles: Vec<String>,
+}
+
+#[derive(Clone, Deserialize, Getset)]
+pub struct JwtConfig {
+    #[getset(get_copy, vis = "pub")]
+    algorithm: JwtAlgorithm,
+    #[serde(default)]
+    #[getset(get, vis = "pub")]
+    secret: Option<String>,
+    #[serde(default)]
+    #[getset(get, vis = "pub")]
+    jwks_path: Option<String>,
+    #[serde(default)]
+    #[getset(get, vis = "pub")]
+    issuer: Option<String>,
+    #[serde(default)]
+    #[getset(get, vis = "pub")]
+    audience: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize)]
+pub enum JwtAlgorithm {
+    HS256,
+    RS256,
+}
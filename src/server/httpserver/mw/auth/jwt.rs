@@ -0,0 +1,103 @@
+use anyhow::{anyhow, Context};
+use jsonwebtoken::jwk::JwkSet;
+use jsonwebtoken::{Algorithm, DecodingKey, Validation};
+use serde_derive::Deserialize;
+
+use super::config::{JwtAlgorithm, JwtConfig};
+use super::principal::{AuthMethod, Principal};
+use crate::server::{ServerError, ServerResult};
+
+#[derive(Deserialize)]
+struct Claims {
+    sub: String,
+    #[serde(default)]
+    roles: Vec<String>,
+}
+
+enum VerifyingKeys {
+    Secret(DecodingKey),
+    KeySet(Vec<(Option<String>, DecodingKey)>),
+}
+
+pub struct JwtVerifier {
+    keys: VerifyingKeys,
+    validation: Validation,
+}
+
+impl JwtVerifier {
+    pub fn from_config(config: &JwtConfig) -> anyhow::Result<Self> {
+        let (algorithm, keys) = match config.algorithm() {
+            JwtAlgorithm::HS256 => {
+                let secret = config
+                    .secret()
+                    .as_ref()
+                    .ok_or_else(|| anyhow!("jwt secret is required for HS256"))?;
+                let key = DecodingKey::from_secret(secret.as_bytes());
+                (Algorithm::HS256, VerifyingKeys::Secret(key))
+            }
+            JwtAlgorithm::RS256 => {
+                let jwks_path = config
+                    .jwks_path()
+                    .as_ref()
+                    .ok_or_else(|| anyhow!("jwks file path is required for RS256"))?;
+                let keys = load_jwks_keys(jwks_path)?;
+                (Algorithm::RS256, VerifyingKeys::KeySet(keys))
+            }
+        };
+
+        let mut validation = Validation::new(algorithm);
+        if let Some(issuer) = config.issuer() {
+            validation.set_issuer(&[issuer]);
+        }
+
+        match config.audience() {
+            Some(audience) => validation.set_audience(&[audience]),
+            None => validation.validate_aud = false,
+        }
+
+        Ok(JwtVerifier { keys, validation })
+    }
+
+    pub fn verify(&self, token: &str) -> ServerResult<Principal> {
+        let key = self.select_key(token)?;
+        let token_data = jsonwebtoken::decode::<Claims>(token, key, &self.validation)
+            .map_err(|err| ServerError::AuthenticationFailed(err.to_string()))?;
+
+        let claims = token_data.claims;
+        Ok(Principal::new(claims.sub, claims.roles, AuthMethod::Jwt))
+    }
+
+    fn select_key(&self, token: &str) -> ServerResult<&DecodingKey> {
+        let keys = match &self.keys {
+            VerifyingKeys::Secret(key) => return Ok(key),
+            VerifyingKeys::KeySet(keys) => keys,
+        };
+
+        let header = jsonwebtoken::decode_header(token)
+            .map_err(|err| ServerError::AuthenticationFailed(err.to_string()))?;
+
+        let found = match header.kid {
+            Some(kid) => keys.iter().find(|(id, _)| id.as_ref() == Some(&kid)),
+            None if keys.len() == 1 => keys.first(),
+            None => None,
+        };
+
+        found
+            .map(|(_, key)| key)
+            .ok_or_else(|| ServerError::AuthenticationFailed("unknown token signing key".into()))
+    }
+}
+
+fn load_jwks_keys(path: &str) -> anyhow::Result<Vec<(Option<String>, DecodingKey)>> {
+    let data = std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read jwks file {path}"))?;
+    let jwks = serde_json::from_str::<JwkSet>(&data).context("failed to parse jwks file")?;
+
+    jwks.keys
+        .iter()
+        .map(|jwk| {
+            let key = DecodingKey::from_jwk(jwk).context("failed to load jwk")?;
+            Ok((jwk.common.key_id.clone(), key))
+        })
+        .collect()
+}
@@ -0,0 +1,118 @@
+#[cfg(test)]
+mod tests;
+
+mod config;
+pub use config::{ApiKeyConfig, AuthConfig, JwtAlgorithm, JwtConfig};
+
+mod jwt;
+pub use jwt::JwtVerifier;
+
+mod policy;
+pub use policy::{AccessLevel, AccessPolicyConfig, IndexPermission};
+
+mod principal;
+pub use principal::{AuthMethod, Principal};
+
+use axum::extract::{Request, State};
+use axum::http::header::AUTHORIZATION;
+use axum::http::HeaderMap;
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use std::sync::Arc;
+use subtle::ConstantTimeEq;
+
+use crate::server::httpserver::api::v1::API_VERSION_URL;
+use crate::server::{ServerError, ServerResult};
+
+pub const API_KEY_HEADER: &str = "X-API-Key";
+const BEARER_PREFIX: &str = "Bearer ";
+
+struct AuthState {
+    api_keys: Vec<ApiKeyConfig>,
+    jwt: Option<JwtVerifier>,
+    policies: Vec<AccessPolicyConfig>,
+}
+
+pub fn enable_auth_mw(app: axum::Router, config: &AuthConfig) -> anyhow::Result<axum::Router> {
+    let jwt = config
+        .jwt()
+        .as_ref()
+        .map(JwtVerifier::from_config)
+        .transpose()?;
+
+    let auth_state = AuthState {
+        api_keys: config.api_keys().to_owned(),
+        jwt,
+        policies: config.policies().to_owned(),
+    };
+
+    let auth_mw = axum::middleware::from_fn_with_state(Arc::new(auth_state), authenticate);
+    Ok(app.layer(auth_mw))
+}
+
+async fn authenticate(
+    State(auth): State<Arc<AuthState>>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    if !request.uri().path().starts_with(API_VERSION_URL) {
+        return next.run(request).await;
+    }
+
+    match extract_principal(&auth, request.headers()) {
+        Ok(mut principal) => {
+            principal.permissions = resolve_permissions(&auth.policies, &principal);
+            request.extensions_mut().insert(principal);
+            next.run(request).await
+        }
+        Err(err) => err.into_response(),
+    }
+}
+
+fn extract_principal(auth: &AuthState, headers: &HeaderMap) -> ServerResult<Principal> {
+    if let Some(api_key) = headers.get(API_KEY_HEADER) {
+        let api_key = api_key.as_bytes();
+        return find_api_key(&auth.api_keys, api_key)
+            .map(|it| {
+                let subject = it.subject().to_owned();
+                Principal::new(subject, it.roles().to_owned(), AuthMethod::ApiKey)
+            })
+            .ok_or_else(|| ServerError::AuthenticationFailed("invalid api key".into()));
+    }
+
+    let token = headers
+        .get(AUTHORIZATION)
+        .and_then(|it| it.to_str().ok())
+        .and_then(|it| it.strip_prefix(BEARER_PREFIX))
+        .ok_or_else(|| ServerError::AuthenticationFailed("missing credentials".into()))?;
+
+    match &auth.jwt {
+        Some(verifier) => verifier.verify(token.trim()),
+        None => Err(ServerError::AuthenticationFailed(
+            "jwt authentication is disabled".into(),
+        )),
+    }
+}
+
+/// Compares passed key with every configured one in constant time, so
+/// response latency does not reveal how many leading bytes have matched.
+fn find_api_key<'a>(api_keys: &'a [ApiKeyConfig], api_key: &[u8]) -> Option<&'a ApiKeyConfig> {
+    api_keys.iter().fold(None, |found, it| {
+        let is_equal = bool::from(it.key().as_bytes().ct_eq(api_key));
+        match found {
+            None if is_equal => Some(it),
+            _ => found,
+        }
+    })
+}
+
+fn resolve_permissions(
+    policies: &[AccessPolicyConfig],
+    principal: &Principal,
+) -> Vec<IndexPermission> {
+    policies
+        .iter()
+        .filter(|it| it.is_applied_to(&principal.subject, &principal.roles))
+        .map(IndexPermission::from)
+        .collect()
+}
@@ -0,0 +1,58 @@
+use doc_search_core::shared::kernel::is_index_pattern_matched;
+use gset::Getset;
+use serde_derive::Deserialize;
+
+/// Access level granted over indexes, each level includes the lower ones.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum AccessLevel {
+    Read,
+    Write,
+    Admin,
+}
+
+/// Grants access over indexes matching `index` pattern to the principal
+/// with passed `subject` or to every principal owning passed `role`.
+#[derive(Clone, Deserialize, Getset)]
+pub struct AccessPolicyConfig {
+    #[serde(default)]
+    #[getset(get, vis = "pub")]
+    subject: Option<String>,
+    #[serde(default)]
+    #[getset(get, vis = "pub")]
+    role: Option<String>,
+    #[getset(get, vis = "pub")]
+    index: String,
+    #[getset(get_copy, vis = "pub")]
+    access: AccessLevel,
+}
+
+impl AccessPolicyConfig {
+    pub fn is_applied_to(&self, subject: &str, roles: &[String]) -> bool {
+        let is_subject_matched = self.subject.as_deref() == Some(subject);
+        let is_role_matched = self.role.as_ref().is_some_and(|role| roles.contains(role));
+
+        is_subject_matched || is_role_matched
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct IndexPermission {
+    pub pattern: String,
+    pub access: AccessLevel,
+}
+
+impl IndexPermission {
+    pub fn is_granted(&self, index: &str, access: AccessLevel) -> bool {
+        self.access >= access && is_index_pattern_matched(&self.pattern, index)
+    }
+}
+
+impl From<&AccessPolicyConfig> for IndexPermission {
+    fn from(policy: &AccessPolicyConfig) -> Self {
+        IndexPermission {
+            pattern: policy.index.to_owned(),
+            access: policy.access,
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use axum::extract::{FromRequestParts, OptionalFromRequestParts};
+use axum::http::request::Parts;
+use std::convert::Infallible;
+
+use super::policy::{AccessLevel, IndexPermission};
+use crate::server::{ServerError, ServerResult};
+
+/// Identity of the caller attached to the request by the auth middleware.
+///
+/// Handlers receive it as an extractor. Extracting `Principal` fails with
+/// 401 when the request passed no auth layer, while `Option<Principal>`
+/// yields `None` in that case.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Principal {
+    pub subject: String,
+    pub roles: Vec<String>,
+    pub method: AuthMethod,
+    pub permissions: Vec<IndexPermission>,
+}
+
+impl Principal {
+    pub fn new(subject: String, roles: Vec<String>, method: AuthMethod) -> Self {
+        Principal {
+            subject,
+            roles,
+            method,
+            permissions: Vec::default(),
+        }
+    }
+
+    pub fn is_granted(&self, index: &str, access: AccessLevel) -> bool {
+        self.permissions
+            .iter()
+            .any(|it| it.is_granted(index, access))
+    }
+
+    pub fn authorize(&self, index: &str, access: AccessLevel) -> ServerResult<()> {
+        match self.is_granted(index, access) {
+            true => Ok(()),
+            false => {
+                let msg = format!("{} has no {access:?} access to {index}", self.subject);
+                Err(ServerError::Forbidden(msg))
+            }
+        }
+    }
+
+    /// Index patterns the principal is allowed to access with passed level.
+    pub fn granted_patterns(&self, access: AccessLevel) -> Vec<String> {
+        self.permissions
+            .iter()
+            .filter(|it| it.access >= access)
+            .map(|it| it.pattern.to_owned())
+            .collect()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AuthMethod {
+    ApiKey,
+    Jwt,
+}
+
+impl<S> FromRequestParts<S> for Principal
+where
+    S: Send + Sync,
+{
+    type Rejection = ServerError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        parts
+            .extensions
+            .get::<Principal>()
+            .cloned()
+            .ok_or_else(|| ServerError::AuthenticationFailed("request is not authenticated".into()))
+    }
+}
+
+impl<S> OptionalFromRequestParts<S> for Principal
+where
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        _state: &S,
+    ) -> Result<Option<Self>, Self::Rejection> {
+        Ok(parts.extensions.get::<Principal>().cloned())
+    }
+}
@@ -0,0 +1,2 @@
+mod test_acl;
+mod test_auth;
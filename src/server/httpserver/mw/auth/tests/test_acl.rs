@@ -0,0 +1,200 @@
+use axum::body::Body;
+use axum::http::{Method, Request, StatusCode};
+use axum::Router;
+use axum_test::http::header::CONTENT_TYPE;
+use doc_search_core::application::usecase::searcher::SearcherUseCase;
+use doc_search_core::application::usecase::storage::StorageUseCase;
+use doc_search_core::infrastructure::memory::{MemoryClient, MemoryConfig};
+use serde_json::{json, Value};
+use std::sync::Arc;
+use tower::ServiceExt;
+
+use crate::meter::AppMeterRegistry;
+use crate::server::httpserver::api::v1::tests::stubs;
+use crate::server::httpserver::api::v1::API_VERSION_URL;
+use crate::server::httpserver::init_server;
+use crate::server::httpserver::mw::auth::{enable_auth_mw, AuthConfig, API_KEY_HEADER};
+use crate::server::jobs::{IngestionQueue, MemoryJobStore, DEFAULT_JOBS_CONCURRENCY};
+use crate::server::ServerApp;
+
+const ADMIN_API_KEY: &str = "admin-api-key";
+const READER_API_KEY: &str = "reader-api-key";
+const WRITER_API_KEY: &str = "writer-api-key";
+const TEAM_A_INDEX: &str = "team-a";
+const TEAM_B_INDEX: &str = "team-b";
+const MAX_CONTENT_SIZE: usize = 100;
+
+fn build_acl_auth_config() -> AuthConfig {
+    serde_json::from_value(json!({
+        "is_enabled": true,
+        "api_keys": [
+            {"subject": "admin", "key": ADMIN_API_KEY},
+            {"subject": "reader", "key": READER_API_KEY, "roles": ["team-a-readers"]},
+            {"subject": "writer", "key": WRITER_API_KEY},
+        ],
+        "policies": [
+            {"subject": "admin", "index": "*", "access": "admin"},
+            {"role": "team-a-readers", "index": "team-a*", "access": "read"},
+            {"subject": "writer", "index": TEAM_B_INDEX, "access": "write"},
+        ],
+    }))
+    .expect("failed to build auth config")
+}
+
+async fn send_request(
+    router: &Router,
+    api_key: &str,
+    method: Method,
+    uri: String,
+    body: Option<Value>,
+) -> anyhow::Result<(StatusCode, Value)> {
+    let body = match body {
+        None => Body::empty(),
+        Some(value) => Body::from(serde_json::to_vec(&value)?),
+    };
+
+    let request = Request::builder()
+        .method(method)
+        .uri(format!("{API_VERSION_URL}{uri}"))
+        .header(CONTENT_TYPE, "application/json")
+        .header(API_KEY_HEADER, api_key)
+        .body(body)?;
+
+    let response = router.clone().oneshot(request).await?;
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await?;
+    let data = serde_json::from_slice::<Value>(&body).unwrap_or_default();
+    Ok((status, data))
+}
+
+fn build_search_form(indexes: &str) -> Value {
+    json!({
+        "query": "intuition",
+        "indexes": indexes,
+        "result": stubs::result_params_json_object(),
+    })
+}
+
+async fn init_acl_router() -> anyhow::Result<Router> {
+    // Handlers must be built by this crate to share the principal type with the middleware
+    let meter = AppMeterRegistry::build_local_meter_register()?;
+    let client = Arc::new(MemoryClient::new(MemoryConfig::default()));
+    let searcher_uc = SearcherUseCase::new(client.clone());
+    let storage_uc = Arc::new(StorageUseCase::new(client, MAX_CONTENT_SIZE));
+    let job_store = Arc::new(MemoryJobStore::default());
+    let ingestion = IngestionQueue::new(storage_uc.clone(), job_store, DEFAULT_JOBS_CONCURRENCY);
+    let app = ServerApp::new(storage_uc, Arc::new(searcher_uc), ingestion, meter);
+    let router = enable_auth_mw(init_server(app), &build_acl_auth_config())?;
+
+    for index in [TEAM_A_INDEX, TEAM_B_INDEX] {
+        let form = json!({ "id": index, "knn": null });
+        let uri = format!("/storage/{index}");
+        let (status, _) =
+            send_request(&router, ADMIN_API_KEY, Method::PUT, uri, Some(form)).await?;
+        assert_eq!(status, StatusCode::CREATED);
+
+        let document = stubs::create_document_json_object();
+        let uri = format!("/storage/{index}/create");
+        let (status, _) =
+            send_request(&router, ADMIN_API_KEY, Method::PUT, uri, Some(document)).await?;
+        assert_eq!(status, StatusCode::CREATED);
+    }
+
+    Ok(router)
+}
+
+#[tokio::test]
+#[rstest::rstest]
+#[case(READER_API_KEY, Method::GET, format!("/storage/{TEAM_A_INDEX}"), None, StatusCode::OK)]
+#[case(READER_API_KEY, Method::GET, format!("/storage/{TEAM_B_INDEX}"), None, StatusCode::FORBIDDEN)]
+#[case(READER_API_KEY, Method::DELETE, format!("/storage/{TEAM_A_INDEX}"), None, StatusCode::FORBIDDEN)]
+#[case(
+    READER_API_KEY,
+    Method::PUT,
+    format!("/storage/{TEAM_A_INDEX}/create"),
+    Some(stubs::create_document_json_object()),
+    StatusCode::FORBIDDEN
+)]
+#[case(
+    WRITER_API_KEY,
+    Method::PUT,
+    format!("/storage/{TEAM_B_INDEX}/create?force=true"),
+    Some(stubs::create_document_json_object()),
+    StatusCode::CREATED
+)]
+#[case(
+    WRITER_API_KEY,
+    Method::PUT,
+    "/storage/team-c".to_string(),
+    Some(json!({ "id": "team-c", "knn": null })),
+    StatusCode::FORBIDDEN
+)]
+#[case(
+    READER_API_KEY,
+    Method::POST,
+    "/search/fulltext".to_string(),
+    Some(build_search_form(TEAM_A_INDEX)),
+    StatusCode::OK
+)]
+#[case(
+    READER_API_KEY,
+    Method::POST,
+    "/search/fulltext".to_string(),
+    Some(build_search_form("team-a,team-b")),
+    StatusCode::FORBIDDEN
+)]
+async fn test_acl_enforced_by_routers(
+    #[case] api_key: &str,
+    #[case] method: Method,
+    #[case] uri: String,
+    #[case] body: Option<Value>,
+    #[case] expected_status: StatusCode,
+) -> anyhow::Result<()> {
+    let router = init_acl_router().await?;
+    let (status, _) = send_request(&router, api_key, method, uri, body).await?;
+    assert_eq!(status, expected_status);
+    Ok(())
+}
+
+#[tokio::test]
+#[rstest::rstest]
+#[case(ADMIN_API_KEY, vec![TEAM_A_INDEX, TEAM_B_INDEX])]
+#[case(READER_API_KEY, vec![TEAM_A_INDEX])]
+#[case(WRITER_API_KEY, vec![TEAM_B_INDEX])]
+async fn test_acl_restricts_wildcard_search(
+    #[case] api_key: &str,
+    #[case] expected_indexes: Vec<&str>,
+) -> anyhow::Result<()> {
+    let router = init_acl_router().await?;
+
+    let uri = "/search/fulltext".to_string();
+    let form = Some(build_search_form("*"));
+    let (status, data) = send_request(&router, api_key, Method::POST, uri, form).await?;
+    assert_eq!(status, StatusCode::OK);
+
+    let mut founded_indexes = data["founded"]
+        .as_array()
+        .expect("expected founded documents")
+        .iter()
+        .filter_map(|it| it["index"].as_str())
+        .collect::<Vec<&str>>();
+
+    founded_indexes.sort();
+    assert_eq!(founded_indexes, expected_indexes);
+
+    let uri = "/storage/indexes".to_string();
+    let (status, data) = send_request(&router, api_key, Method::GET, uri, None).await?;
+    assert_eq!(status, StatusCode::OK);
+
+    let mut listed_indexes = data
+        .as_array()
+        .expect("expected indexes")
+        .iter()
+        .filter_map(|it| it["id"].as_str())
+        .collect::<Vec<&str>>();
+
+    listed_indexes.sort();
+    assert_eq!(listed_indexes, expected_indexes);
+
+    Ok(())
+}
@@ -0,0 +1,181 @@
+use axum::body::Body;
+use axum::http::header::AUTHORIZATION;
+use axum::http::{Method, Request, StatusCode};
+use axum::routing::get;
+use axum::{Json, Router};
+use jsonwebtoken::{EncodingKey, Header};
+use serde_json::{json, Value};
+use tower::ServiceExt;
+
+use crate::server::httpserver::api::v1::API_VERSION_URL;
+use crate::server::httpserver::mw::auth::{enable_auth_mw, AuthConfig, Principal, API_KEY_HEADER};
+use crate::server::httpserver::tests::context::test_server;
+
+const TEST_API_KEY: &str = "test-api-key";
+const TEST_API_KEY_SUBJECT: &str = "indexer-service";
+const TEST_JWT_SECRET: &str = "test-jwt-secret";
+const TEST_JWT_SUBJECT: &str = "test-user";
+const TEST_JWT_ISSUER: &str = "doc-search-test";
+const TEST_RSA_KEY_ID: &str = "test-key";
+const TEST_RSA_PRIVATE_KEY: &[u8] = include_bytes!("resources/private-key.pem");
+const TEST_JWKS_PATH: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/src/server/httpserver/mw/auth/tests/resources/jwks.json"
+);
+const WHOAMI_URL: &str = "/api/v1/whoami";
+
+fn build_auth_config(jwt: Value) -> AuthConfig {
+    serde_json::from_value(json!({
+        "is_enabled": true,
+        "api_keys": [{
+            "subject": TEST_API_KEY_SUBJECT,
+            "key": TEST_API_KEY,
+            "roles": ["writer"],
+        }],
+        "jwt": jwt,
+    }))
+    .expect("failed to build auth config")
+}
+
+fn build_hs256_auth_config() -> AuthConfig {
+    build_auth_config(json!({
+        "algorithm": "HS256",
+        "secret": TEST_JWT_SECRET,
+        "issuer": TEST_JWT_ISSUER,
+    }))
+}
+
+fn build_rs256_auth_config() -> AuthConfig {
+    build_auth_config(json!({
+        "algorithm": "RS256",
+        "jwks_path": TEST_JWKS_PATH,
+    }))
+}
+
+fn build_claims(expired_in: i64) -> Value {
+    let exp = chrono::Utc::now().timestamp() + expired_in;
+    json!({
+        "sub": TEST_JWT_SUBJECT,
+        "iss": TEST_JWT_ISSUER,
+        "roles": ["reader"],
+        "exp": exp,
+    })
+}
+
+fn build_hs256_token(claims: Value, secret: &str) -> String {
+    let key = EncodingKey::from_secret(secret.as_bytes());
+    jsonwebtoken::encode(&Header::default(), &claims, &key).expect("failed to encode token")
+}
+
+fn build_rs256_token(claims: Value) -> String {
+    let key = EncodingKey::from_rsa_pem(TEST_RSA_PRIVATE_KEY).expect("failed to load rsa key");
+    let mut header = Header::new(jsonwebtoken::Algorithm::RS256);
+    header.kid = Some(TEST_RSA_KEY_ID.to_string());
+    jsonwebtoken::encode(&header, &claims, &key).expect("failed to encode token")
+}
+
+fn build_whoami_router(config: &AuthConfig) -> Router {
+    let router = Router::new().route(
+        WHOAMI_URL,
+        get(|principal: Principal| async move { Json(principal.subject) }),
+    );
+
+    enable_auth_mw(router, config).expect("failed to enable auth middleware")
+}
+
+#[tokio::test]
+#[rstest::rstest]
+#[case(None, StatusCode::UNAUTHORIZED)]
+#[case(Some((API_KEY_HEADER, TEST_API_KEY.to_string())), StatusCode::OK)]
+#[case(Some((API_KEY_HEADER, "wrong-key".to_string())), StatusCode::UNAUTHORIZED)]
+#[case(
+    Some((AUTHORIZATION.as_str(), format!("Bearer {}", build_hs256_token(build_claims(600), TEST_JWT_SECRET)))),
+    StatusCode::OK
+)]
+#[case(
+    Some((AUTHORIZATION.as_str(), format!("Bearer {}", build_hs256_token(build_claims(-600), TEST_JWT_SECRET)))),
+    StatusCode::UNAUTHORIZED
+)]
+#[case(
+    Some((AUTHORIZATION.as_str(), format!("Bearer {}", build_hs256_token(build_claims(600), "wrong-secret")))),
+    StatusCode::UNAUTHORIZED
+)]
+#[case(
+    Some((AUTHORIZATION.as_str(), format!("Basic {TEST_API_KEY}"))),
+    StatusCode::UNAUTHORIZED
+)]
+async fn test_auth_protects_api_routes(
+    #[case] credentials: Option<(&str, String)>,
+    #[case] expected_status: StatusCode,
+) -> anyhow::Result<()> {
+    let context = test_server::create_memory_server_context();
+    let app = enable_auth_mw(context.test_server, &build_hs256_auth_config())?;
+
+    let mut request = Request::builder()
+        .method(Method::GET)
+        .uri(format!("{API_VERSION_URL}/storage/indexes"));
+
+    if let Some((header, value)) = credentials {
+        request = request.header(header, value);
+    }
+
+    let response = app.oneshot(request.body(Body::empty())?).await?;
+    assert_eq!(response.status(), expected_status);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_auth_skips_system_routes() -> anyhow::Result<()> {
+    let context = test_server::create_memory_server_context();
+    let app = enable_auth_mw(context.test_server, &build_hs256_auth_config())?;
+
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri("/health")
+        .body(Body::empty())?;
+
+    let response = app.oneshot(request).await?;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    Ok(())
+}
+
+#[tokio::test]
+#[rstest::rstest]
+#[case(build_hs256_auth_config(), API_KEY_HEADER, TEST_API_KEY.to_string(), TEST_API_KEY_SUBJECT)]
+#[case(
+    build_hs256_auth_config(),
+    AUTHORIZATION.as_str(),
+    format!("Bearer {}", build_hs256_token(build_claims(600), TEST_JWT_SECRET)),
+    TEST_JWT_SUBJECT
+)]
+#[case(
+    build_rs256_auth_config(),
+    AUTHORIZATION.as_str(),
+    format!("Bearer {}", build_rs256_token(build_claims(600))),
+    TEST_JWT_SUBJECT
+)]
+async fn test_auth_provides_principal(
+    #[case] config: AuthConfig,
+    #[case] header: &str,
+    #[case] value: String,
+    #[case] expected_subject: &str,
+) -> anyhow::Result<()> {
+    let app = build_whoami_router(&config);
+
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri(WHOAMI_URL)
+        .header(header, value)
+        .body(Body::empty())?;
+
+    let response = app.oneshot(request).await?;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await?;
+    let subject = serde_json::from_slice::<String>(&body)?;
+    assert_eq!(subject, expected_subject);
+
+    Ok(())
+}
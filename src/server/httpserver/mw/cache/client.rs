@@ -30,19 +30,18 @@ impl ServiceConnect for RedisClient {
 
 impl RedisClient {
     pub(crate) async fn store(&self, key: &String, value: Vec<u8>) {
+        if let Err(err) = self.try_store(key, value).await {
+            tracing::warn!(err=?err, "failed to insert value to redis");
+        }
+    }
+
+    /// Same as `store` but returns an error instead of logging it, for
+    /// callers which must not lose written values silently.
+    pub(crate) async fn try_store(&self, key: &String, value: Vec<u8>) -> RedisResult<()> {
         let expired_secs = self.options.expired();
         let cxt = self.client.write().await;
-        match cxt.get_multiplexed_tokio_connection().await {
-            Err(err) => {
-                tracing::warn!(err=?err, "failed to get redis service connection");
-            }
-            Ok(mut conn) => {
-                let set_result: RedisResult<()> = conn.set_ex(key, value, expired_secs).await;
-                if let Err(err) = set_result {
-                    tracing::warn!(err=?err, "failed to insert value to redis");
-                };
-            }
-        }
+        let mut conn = cxt.get_multiplexed_tokio_connection().await?;
+        conn.set_ex(key, value, expired_secs).await
     }
 
     pub(crate) async fn load(&self, key: &String) -> Option<Vec<u8>> {
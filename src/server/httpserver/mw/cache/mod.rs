@@ -6,14 +6,17 @@ pub use client::RedisClient;
 
 use axum::body::{Body, Bytes};
 use axum::extract::{Request, State};
-use axum::http::HeaderMap;
+use axum::http::{HeaderMap, Method};
 use axum::middleware::Next;
 use axum::response::Response;
 use doc_search_core::ServiceConnect;
+use sha2::{Digest, Sha256};
 use std::sync::Arc;
 use tower_http::add_extension::AddExtensionLayer;
 
-const HEADER_FIELDS: [&str; 2] = ["Accept", "Authorization"];
+use crate::server::httpserver::mw::auth::API_KEY_HEADER;
+
+const HEADER_FIELDS: [&str; 3] = ["Accept", "Authorization", API_KEY_HEADER];
 const NULL_HEADER_VALUE: &str = "null";
 
 struct CacheState {
@@ -50,10 +53,11 @@ pub async fn enable_caching_mw(
 }
 
 async fn cache(State(cache): State<Arc<CacheState>>, request: Request, next: Next) -> Response {
+    // Only pages of cursors are cached: every page is returned with its own
+    // cursor, while other methods like release must always reach the storage.
     let path = request.uri().path();
     let is_matched_path = cache.filters.iter().any(|it| it.is_match_at(path, 0));
-
-    if !is_matched_path {
+    if request.method() != Method::GET || !is_matched_path {
         return next.run(request).await;
     }
 
@@ -81,13 +85,18 @@ async fn cache(State(cache): State<Arc<CacheState>>, request: Request, next: Nex
     }
 }
 
+/// Header values carry credentials, so they are hashed instead of being
+/// stored as plain text within redis keys.
 fn headers_to_key(headers: &HeaderMap) -> String {
-    HEADER_FIELDS
+    let header_values = HEADER_FIELDS
         .into_iter()
         .map(|it| match headers.get(it) {
             None => NULL_HEADER_VALUE,
             Some(value) => value.to_str().unwrap_or(NULL_HEADER_VALUE),
         })
         .collect::<Vec<&str>>()
-        .join(":")
+        .join(":");
+
+    let digest = Sha256::digest(header_values.as_bytes());
+    format!("{digest:x}")
 }
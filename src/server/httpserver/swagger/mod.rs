@@ -1,7 +1,8 @@
 mod examples;
 pub use examples::DefaultErrorForm;
 
-use utoipa::OpenApi;
+use utoipa::openapi::security::{ApiKey, ApiKeyValue, HttpAuthScheme, HttpBuilder, SecurityScheme};
+use utoipa::{Modify, OpenApi};
 use utoipa_swagger_ui::SwaggerUi;
 
 use crate::server::ServerError;
@@ -10,12 +11,17 @@ use crate::server::Success;
 use crate::server::httpserver::api::v1::form::*;
 use crate::server::httpserver::api::v1::router::document::*;
 use crate::server::httpserver::api::v1::router::index::*;
+use crate::server::httpserver::api::v1::router::job::*;
 use crate::server::httpserver::api::v1::router::searcher::*;
 use crate::server::httpserver::api::v1::schema::*;
+use crate::server::httpserver::mw::auth::API_KEY_HEADER;
+use crate::server::jobs::{DocumentStatus, IngestionJob, JobDocument, JobStatus};
 
 const SWAGGER_URL_PATH: &str = "/api/swagger";
 const SWAGGER_CONFIG_PATH: &str = "/api-docs/openapi.json";
 const PROJECT_DESCRIPTION: &str = include_str!("./descriptions/project");
+const API_KEY_SCHEME_NAME: &str = "api_key";
+const BEARER_SCHEME_NAME: &str = "bearer_jwt";
 
 pub fn init_swagger_layer() -> SwaggerUi {
     let swagger_app = ApiDoc::openapi();
@@ -24,6 +30,11 @@ pub fn init_swagger_layer() -> SwaggerUi {
 
 #[derive(OpenApi)]
 #[openapi(
+    modifiers(&SecurityAddon),
+    security(
+        ("api_key" = []),
+        ("bearer_jwt" = []),
+    ),
     info(
         title = "Doc-Search",
         description = PROJECT_DESCRIPTION,
@@ -41,6 +52,10 @@ pub fn init_swagger_layer() -> SwaggerUi {
             name = "search",
             description = "APIs to search Document objects",
         ),
+        (
+            name = "job",
+            description = "APIs to track background ingestion jobs",
+        ),
     ),
     servers(
         (url = "/api/v1", description = "Stable API version"),
@@ -52,32 +67,76 @@ pub fn init_swagger_layer() -> SwaggerUi {
         create_index,
         delete_index,
         get_document_parts,
+        get_document_content,
         get_index_documents,
+        store_documents,
         store_document,
+        upload_document,
+        update_document,
         delete_document,
         search_fulltext,
         search_semantic,
         search_hybrid,
+        search_similar,
+        suggest,
         paginate_next,
+        release_pagination,
+        get_job,
     ),
     components(
         schemas(
             CreateDocumentForm,
+            DocumentPartEmbeddingsForm,
             UpdateDocumentForm,
+            UploadDocumentForm,
             CreateIndexForm,
             KnnIndexForm,
+            SplitterForm,
+            SplitterKindForm,
             DocumentPartSchema,
             IndexSchema,
             FilterForm,
+            AggregationForm,
             ResultForm,
+            SortForm,
             ShortResultForm,
             FullTextSearchForm,
             RetrieveDocumentForm,
             SemanticSearchForm,
             HybridSearchForm,
+            SimilarSearchForm,
+            FacetBucketSchema,
+            TotalHitsSchema,
+            SuggestionSchema,
+            BulkStoreReportSchema,
+            StoredBulkItemSchema,
+            FailedBulkItemSchema,
+            IngestionJob,
+            JobDocument,
+            JobStatus,
+            DocumentStatus,
             ServerError,
             Success,
         ),
     ),
 )]
 struct ApiDoc;
+
+struct SecurityAddon;
+
+impl Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let Some(components) = openapi.components.as_mut() else {
+            return;
+        };
+
+        let api_key = ApiKey::Header(ApiKeyValue::new(API_KEY_HEADER));
+        components.add_security_scheme(API_KEY_SCHEME_NAME, SecurityScheme::ApiKey(api_key));
+
+        let bearer = HttpBuilder::new()
+            .scheme(HttpAuthScheme::Bearer)
+            .bearer_format("JWT")
+            .build();
+        components.add_security_scheme(BEARER_SCHEME_NAME, SecurityScheme::Http(bearer));
+    }
+}
@@ -4,9 +4,11 @@ use test_context::AsyncTestContext;
 
 use doc_search::meter::AppMeterRegistry;
 use doc_search::server::httpserver::init_server;
+use doc_search::server::jobs::{IngestionQueue, MemoryJobStore, DEFAULT_JOBS_CONCURRENCY};
 use doc_search::server::ServerApp;
 use doc_search_core::application::usecase::searcher::SearcherUseCase;
 use doc_search_core::application::usecase::storage::StorageUseCase;
+use doc_search_core::infrastructure::memory::{MemoryClient, MemoryConfig};
 
 use super::super::mocks::searcher::MockSearcherService;
 use super::super::mocks::storage::MockStorageService;
@@ -31,8 +33,25 @@ pub fn create_test_server_context(
         .expect("failed to create local meter registry");
 
     let searcher_uc = SearcherUseCase::new(Arc::new(searcher));
-    let storage_uc = StorageUseCase::new(Arc::new(storage), MAX_CONTENT_SIZE);
-    let app = ServerApp::new(Arc::new(storage_uc), Arc::new(searcher_uc), meter);
+    let storage_uc = Arc::new(StorageUseCase::new(Arc::new(storage), MAX_CONTENT_SIZE));
+    let job_store = Arc::new(MemoryJobStore::default());
+    let ingestion = IngestionQueue::new(storage_uc.clone(), job_store, DEFAULT_JOBS_CONCURRENCY);
+    let app = ServerApp::new(storage_uc, Arc::new(searcher_uc), ingestion, meter);
+
+    let test_server = init_server(app);
+    TestServerContext { test_server }
+}
+
+pub fn create_memory_server_context() -> TestServerContext {
+    let meter = AppMeterRegistry::build_local_meter_register()
+        .expect("failed to create local meter registry");
+
+    let client = Arc::new(MemoryClient::new(MemoryConfig::default()));
+    let searcher_uc = SearcherUseCase::new(client.clone());
+    let storage_uc = Arc::new(StorageUseCase::new(client, MAX_CONTENT_SIZE));
+    let job_store = Arc::new(MemoryJobStore::default());
+    let ingestion = IngestionQueue::new(storage_uc.clone(), job_store, DEFAULT_JOBS_CONCURRENCY);
+    let app = ServerApp::new(storage_uc, Arc::new(searcher_uc), ingestion, meter);
 
     let test_server = init_server(app);
     TestServerContext { test_server }
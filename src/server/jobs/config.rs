@@ -0,0 +1,46 @@
+use gset::Getset;
+use serde_derive::Deserialize;
+
+pub const DEFAULT_JOBS_CONCURRENCY: usize = 4;
+pub const DEFAULT_JOBS_EXPIRED: u64 = 24 * 60 * 60;
+
+#[derive(Clone, Deserialize, Getset)]
+pub struct JobsConfig {
+    #[serde(default)]
+    #[getset(get_copy, vis = "pub")]
+    store: JobStoreBackend,
+    #[serde(default = "default_concurrency")]
+    #[getset(get_copy, vis = "pub")]
+    concurrency: usize,
+    #[serde(default = "default_expired")]
+    #[getset(get_copy, vis = "pub")]
+    expired: u64,
+}
+
+impl Default for JobsConfig {
+    fn default() -> Self {
+        JobsConfig {
+            store: JobStoreBackend::default(),
+            concurrency: DEFAULT_JOBS_CONCURRENCY,
+            expired: DEFAULT_JOBS_EXPIRED,
+        }
+    }
+}
+
+fn default_concurrency() -> usize {
+    DEFAULT_JOBS_CONCURRENCY
+}
+
+fn default_expired() -> u64 {
+    DEFAULT_JOBS_EXPIRED
+}
+
+/// Where ingestion jobs state is kept. The redis store reuses
+/// `[cache.redis]` connection settings.
+#[derive(Clone, Copy, Debug, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JobStoreBackend {
+    #[default]
+    Memory,
+    Redis,
+}
@@ -0,0 +1,14 @@
+#[cfg(test)]
+mod tests;
+
+mod config;
+pub use config::{JobStoreBackend, JobsConfig, DEFAULT_JOBS_CONCURRENCY, DEFAULT_JOBS_EXPIRED};
+
+mod models;
+pub use models::{DocumentStatus, IngestionJob, JobDocument, JobStatus};
+
+mod queue;
+pub use queue::IngestionQueue;
+
+mod store;
+pub use store::{IJobStore, MemoryJobStore, RedisJobStore};
@@ -0,0 +1,134 @@
+use doc_search_core::domain::storage::models::{LargeDocument, StoredDocumentPartsInfo};
+use doc_search_core::domain::storage::StorageError;
+use serde_derive::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum JobStatus {
+    Pending,
+    Running,
+    Completed,
+    CompletedWithErrors,
+    Failed,
+}
+
+impl JobStatus {
+    pub fn is_finished(&self) -> bool {
+        matches!(
+            self,
+            JobStatus::Completed | JobStatus::CompletedWithErrors | JobStatus::Failed
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DocumentStatus {
+    Pending,
+    Stored,
+    Failed,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct JobDocument {
+    #[schema(example = "./test-document.docx")]
+    pub file_path: String,
+    pub status: DocumentStatus,
+    #[schema(nullable, example = "c5cdd3bfad598ec73dc5fe83fecbba3e")]
+    pub large_doc_id: Option<String>,
+    #[schema(nullable)]
+    pub error: Option<String>,
+}
+
+/// Background ingestion job state persisted within job store.
+///
+/// Documents are listed in the order they were passed by client, so
+/// `documents[i]` always reports the state of i-th stored document.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct IngestionJob {
+    #[schema(example = "0b6d4c02-5d8e-4b43-9f4c-8f0ae3a0b0f1")]
+    pub id: String,
+    #[schema(example = "test-folder")]
+    pub index: String,
+    pub status: JobStatus,
+    #[schema(example = 2)]
+    pub total: usize,
+    #[schema(example = 1)]
+    pub processed: usize,
+    #[schema(example = 1)]
+    pub failed: usize,
+    #[schema(example = 1750957115)]
+    pub created_at: i64,
+    #[schema(example = 1750957115)]
+    pub updated_at: i64,
+    pub documents: Vec<JobDocument>,
+}
+
+impl IngestionJob {
+    pub fn new(index: &str, documents: &[LargeDocument]) -> Self {
+        let timestamp = chrono::Utc::now().timestamp();
+        let documents = documents
+            .iter()
+            .map(|doc| JobDocument {
+                file_path: doc.file_path.clone(),
+                status: DocumentStatus::Pending,
+                large_doc_id: None,
+                error: None,
+            })
+            .collect::<Vec<JobDocument>>();
+
+        IngestionJob {
+            id: uuid::Uuid::new_v4().to_string(),
+            index: index.to_string(),
+            status: JobStatus::Pending,
+            total: documents.len(),
+            processed: 0,
+            failed: 0,
+            created_at: timestamp,
+            updated_at: timestamp,
+            documents,
+        }
+    }
+
+    pub fn start(&mut self) {
+        self.status = match self.total {
+            0 => JobStatus::Completed,
+            _ => JobStatus::Running,
+        };
+        self.updated_at = chrono::Utc::now().timestamp();
+    }
+
+    pub fn complete_document(
+        &mut self,
+        position: usize,
+        result: Result<StoredDocumentPartsInfo, StorageError>,
+    ) {
+        let Some(document) = self.documents.get_mut(position) else {
+            tracing::warn!(job = self.id, position, "unknown job document position");
+            return;
+        };
+
+        match result {
+            Ok(info) => {
+                document.status = DocumentStatus::Stored;
+                document.large_doc_id = Some(info.large_doc_id.0);
+            }
+            Err(err) => {
+                document.status = DocumentStatus::Failed;
+                document.error = Some(err.to_string());
+                self.failed += 1;
+            }
+        }
+
+        self.processed += 1;
+        self.updated_at = chrono::Utc::now().timestamp();
+        if self.processed == self.total {
+            self.status = match self.failed {
+                0 => JobStatus::Completed,
+                failed if failed == self.total => JobStatus::Failed,
+                _ => JobStatus::CompletedWithErrors,
+            };
+        }
+    }
+}
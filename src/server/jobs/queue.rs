@@ -0,0 +1,146 @@
+use doc_search_core::application::usecase::storage::StorageUseCase;
+use doc_search_core::domain::storage::models::LargeDocument;
+use doc_search_core::domain::storage::{IDocumentPartStorage, IIndexStorage, StorageError};
+use doc_search_core::shared::kernel::IndexId;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+use tokio::time::Instant;
+
+use crate::server::jobs::{IJobStore, IngestionJob};
+
+const PROGRESS_SAVE_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Runs bulk storing in background. All jobs share the same pool of
+/// permits, so no more than `concurrency` documents are stored at once.
+pub struct IngestionQueue<Storage>
+where
+    Storage: IIndexStorage + IDocumentPartStorage + Send + Sync,
+{
+    storage: Arc<StorageUseCase<Storage>>,
+    jobs: Arc<dyn IJobStore + Send + Sync>,
+    permits: Arc<Semaphore>,
+}
+
+impl<Storage> IngestionQueue<Storage>
+where
+    Storage: IIndexStorage + IDocumentPartStorage + Send + Sync + 'static,
+{
+    pub fn new(
+        storage: Arc<StorageUseCase<Storage>>,
+        jobs: Arc<dyn IJobStore + Send + Sync>,
+        concurrency: usize,
+    ) -> Self {
+        IngestionQueue {
+            storage,
+            jobs,
+            permits: Arc::new(Semaphore::new(concurrency.max(1))),
+        }
+    }
+
+    pub async fn get_job(&self, job_id: &str) -> anyhow::Result<Option<IngestionJob>> {
+        self.jobs.load(job_id).await
+    }
+
+    pub async fn submit(
+        &self,
+        index: &IndexId,
+        documents: Vec<LargeDocument>,
+        force: bool,
+    ) -> anyhow::Result<IngestionJob> {
+        let job = IngestionJob::new(index.as_string(), &documents);
+        self.jobs.save(&job).await?;
+
+        let job_snapshot = job.clone();
+        let worker = JobWorker {
+            index: index.clone(),
+            force,
+            job,
+            storage: self.storage.clone(),
+            jobs: self.jobs.clone(),
+            permits: self.permits.clone(),
+        };
+
+        tokio::spawn(worker.run(documents));
+        Ok(job_snapshot)
+    }
+}
+
+struct JobWorker<Storage>
+where
+    Storage: IIndexStorage + IDocumentPartStorage + Send + Sync,
+{
+    index: IndexId,
+    force: bool,
+    job: IngestionJob,
+    storage: Arc<StorageUseCase<Storage>>,
+    jobs: Arc<dyn IJobStore + Send + Sync>,
+    permits: Arc<Semaphore>,
+}
+
+impl<Storage> JobWorker<Storage>
+where
+    Storage: IIndexStorage + IDocumentPartStorage + Send + Sync + 'static,
+{
+    /// Documents are stored by spawned tasks while their results are applied
+    /// to the job here, so progress is saved at most once per
+    /// `PROGRESS_SAVE_INTERVAL` instead of after every stored document.
+    async fn run(mut self, documents: Vec<LargeDocument>) {
+        self.job.start();
+        self.save_job().await;
+
+        let mut tasks = JoinSet::new();
+        let mut positions = HashMap::new();
+        for (position, document) in documents.into_iter().enumerate() {
+            let index = self.index.clone();
+            let force = self.force;
+            let storage = self.storage.clone();
+            let permits = self.permits.clone();
+            let task = tasks.spawn(async move {
+                let Ok(_permit) = permits.acquire_owned().await else {
+                    let err = anyhow::anyhow!("ingestion queue has been closed");
+                    return (position, Err(StorageError::InternalError(err)));
+                };
+
+                let result = storage.store_document(&index, document, force).await;
+                (position, result)
+            });
+
+            positions.insert(task.id(), position);
+        }
+
+        let mut saved_at = Instant::now();
+        // Panicked or cancelled task is reported as failed document, otherwise
+        // the job never gets all documents processed and stays running.
+        while let Some(result) = tasks.join_next_with_id().await {
+            match result {
+                Ok((_, (position, result))) => self.job.complete_document(position, result),
+                Err(err) => {
+                    tracing::error!(err=?err, "ingestion task has been aborted");
+                    let Some(position) = positions.get(&err.id()).copied() else {
+                        continue;
+                    };
+
+                    let err = anyhow::anyhow!("ingestion task has been aborted: {err}");
+                    let result = Err(StorageError::InternalError(err));
+                    self.job.complete_document(position, result);
+                }
+            }
+
+            if saved_at.elapsed() >= PROGRESS_SAVE_INTERVAL {
+                self.save_job().await;
+                saved_at = Instant::now();
+            }
+        }
+
+        self.save_job().await;
+    }
+
+    async fn save_job(&self) {
+        if let Err(err) = self.jobs.save(&self.job).await {
+            tracing::warn!(job = self.job.id, err=?err, "failed to save ingestion job");
+        }
+    }
+}
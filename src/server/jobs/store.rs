@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+use tokio::time::Instant;
+
+use crate::server::httpserver::mw::cache::RedisClient;
+use crate::server::jobs::{IngestionJob, DEFAULT_JOBS_EXPIRED};
+
+const REDIS_JOB_KEY_PREFIX: &str = "ingestion-job";
+
+#[async_trait::async_trait]
+pub trait IJobStore {
+    async fn save(&self, job: &IngestionJob) -> anyhow::Result<()>;
+    async fn load(&self, job_id: &str) -> anyhow::Result<Option<IngestionJob>>;
+}
+
+/// Keeps jobs within process memory. Jobs are lost on restart, so it is
+/// used when redis is not configured. Like redis keys, jobs expire after
+/// `expired` time since the last save, so finished jobs do not pile up.
+pub struct MemoryJobStore {
+    expired: Duration,
+    jobs: RwLock<HashMap<String, (Instant, IngestionJob)>>,
+}
+
+impl Default for MemoryJobStore {
+    fn default() -> Self {
+        MemoryJobStore::new(Duration::from_secs(DEFAULT_JOBS_EXPIRED))
+    }
+}
+
+impl MemoryJobStore {
+    pub fn new(expired: Duration) -> Self {
+        MemoryJobStore {
+            expired,
+            jobs: RwLock::default(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl IJobStore for MemoryJobStore {
+    async fn save(&self, job: &IngestionJob) -> anyhow::Result<()> {
+        let mut jobs = self.jobs.write().await;
+        jobs.retain(|_, (saved_at, _)| saved_at.elapsed() < self.expired);
+        jobs.insert(job.id.clone(), (Instant::now(), job.clone()));
+        Ok(())
+    }
+
+    async fn load(&self, job_id: &str) -> anyhow::Result<Option<IngestionJob>> {
+        let jobs = self.jobs.read().await;
+        let job = jobs
+            .get(job_id)
+            .filter(|(saved_at, _)| saved_at.elapsed() < self.expired)
+            .map(|(_, job)| job.clone());
+
+        Ok(job)
+    }
+}
+
+/// Persists jobs as json values within redis. Jobs expire like cached
+/// responses after `cache.redis.expired` seconds.
+pub struct RedisJobStore {
+    client: Arc<RedisClient>,
+}
+
+impl RedisJobStore {
+    pub fn new(client: Arc<RedisClient>) -> Self {
+        RedisJobStore { client }
+    }
+
+    fn build_key(job_id: &str) -> String {
+        format!("{REDIS_JOB_KEY_PREFIX}:{job_id}")
+    }
+}
+
+#[async_trait::async_trait]
+impl IJobStore for RedisJobStore {
+    async fn save(&self, job: &IngestionJob) -> anyhow::Result<()> {
+        let key = Self::build_key(&job.id);
+        let value = serde_json::to_vec(job)?;
+        self.client.try_store(&key, value).await?;
+        Ok(())
+    }
+
+    async fn load(&self, job_id: &str) -> anyhow::Result<Option<IngestionJob>> {
+        let key = Self::build_key(job_id);
+        let Some(value) = self.client.load(&key).await else {
+            return Ok(None);
+        };
+
+        let job = serde_json::from_slice::<IngestionJob>(&value)?;
+        Ok(Some(job))
+    }
+}
@@ -0,0 +1,224 @@
+use doc_search_core::application::usecase::storage::StorageUseCase;
+use doc_search_core::domain::storage::models::LargeDocumentBuilder;
+use doc_search_core::domain::storage::models::{CreateIndexParams, LargeDocument};
+use doc_search_core::infrastructure::memory::{MemoryClient, MemoryConfig};
+use doc_search_core::shared::kernel::IndexId;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::server::jobs::{DocumentStatus, IngestionJob, IngestionQueue, JobStatus};
+use crate::server::jobs::{IJobStore, MemoryJobStore};
+
+const TEST_INDEX_ID: &str = "test-folder";
+const MAX_CONTENT_SIZE: usize = 100;
+const POLL_ATTEMPTS: usize = 100;
+
+fn build_document(file_path: &str, content: &str) -> LargeDocument {
+    LargeDocumentBuilder::default()
+        .file_name(file_path.trim_start_matches("./").to_string())
+        .file_path(file_path.to_string())
+        .file_size(content.len() as u32)
+        .created_at(1750957115)
+        .modified_at(1750957115)
+        .content(content.to_string())
+        .metadata(None)
+        .build()
+        .expect("failed to build large document")
+}
+
+/// Counts saved job states and fails on demand, to check how often the
+/// worker writes progress and how write errors are surfaced.
+#[derive(Default)]
+struct CountingJobStore {
+    inner: MemoryJobStore,
+    saves: AtomicUsize,
+    is_failing: bool,
+}
+
+#[async_trait::async_trait]
+impl IJobStore for CountingJobStore {
+    async fn save(&self, job: &IngestionJob) -> anyhow::Result<()> {
+        if self.is_failing {
+            anyhow::bail!("job store is unavailable");
+        }
+
+        self.saves.fetch_add(1, Ordering::SeqCst);
+        self.inner.save(job).await
+    }
+
+    async fn load(&self, job_id: &str) -> anyhow::Result<Option<IngestionJob>> {
+        self.inner.load(job_id).await
+    }
+}
+
+async fn init_queue() -> anyhow::Result<IngestionQueue<MemoryClient>> {
+    let job_store: Arc<dyn IJobStore + Send + Sync> = Arc::new(MemoryJobStore::default());
+    init_queue_with(job_store).await
+}
+
+async fn init_queue_with(
+    job_store: Arc<dyn IJobStore + Send + Sync>,
+) -> anyhow::Result<IngestionQueue<MemoryClient>> {
+    let client = Arc::new(MemoryClient::new(MemoryConfig::default()));
+    let storage = Arc::new(StorageUseCase::new(client, MAX_CONTENT_SIZE));
+    let params = CreateIndexParams {
+        id: TEST_INDEX_ID.to_string(),
+        knn: None,
+        splitter: None,
+    };
+    storage.create_index(&params).await?;
+    Ok(IngestionQueue::new(storage, job_store, 2))
+}
+
+async fn wait_job_finished(
+    queue: &IngestionQueue<MemoryClient>,
+    job_id: &str,
+) -> anyhow::Result<IngestionJob> {
+    for _ in 0..POLL_ATTEMPTS {
+        let job = queue.get_job(job_id).await?;
+        if let Some(job) = job.filter(|it| it.status.is_finished()) {
+            return Ok(job);
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    anyhow::bail!("job {job_id} has not been finished")
+}
+
+#[tokio::test]
+async fn test_ingestion_job_reports_documents() -> anyhow::Result<()> {
+    let queue = init_queue().await?;
+    let documents = vec![
+        build_document("./first.txt", "there is first document content"),
+        build_document("./empty.txt", ""),
+        build_document("./second.txt", "there is second document content"),
+    ];
+
+    let index = IndexId(TEST_INDEX_ID.to_string());
+    let job = queue.submit(&index, documents, true).await?;
+    assert_eq!(job.status, JobStatus::Pending);
+    assert_eq!(job.total, 3);
+
+    let job = wait_job_finished(&queue, &job.id).await?;
+    assert_eq!(job.status, JobStatus::CompletedWithErrors);
+    assert_eq!(job.processed, 3);
+    assert_eq!(job.failed, 1);
+
+    let statuses = job
+        .documents
+        .iter()
+        .map(|it| it.status)
+        .collect::<Vec<DocumentStatus>>();
+
+    let expected = [
+        DocumentStatus::Stored,
+        DocumentStatus::Failed,
+        DocumentStatus::Stored,
+    ];
+    assert_eq!(statuses, expected);
+    assert_eq!(job.documents[1].file_path, "./empty.txt");
+    assert!(job.documents[1].error.is_some());
+    assert!(job.documents[0].large_doc_id.is_some());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_ingestion_job_failed_index() -> anyhow::Result<()> {
+    let queue = init_queue().await?;
+    let documents = vec![build_document("./first.txt", "there is some content")];
+
+    let index = IndexId("unknown-folder".to_string());
+    let job = queue.submit(&index, documents, true).await?;
+
+    let job = wait_job_finished(&queue, &job.id).await?;
+    assert_eq!(job.status, JobStatus::Failed);
+    assert_eq!(job.failed, 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_ingestion_job_empty_documents() -> anyhow::Result<()> {
+    let queue = init_queue().await?;
+    let index = IndexId(TEST_INDEX_ID.to_string());
+    let job = queue.submit(&index, Vec::new(), true).await?;
+
+    let job = wait_job_finished(&queue, &job.id).await?;
+    assert_eq!(job.status, JobStatus::Completed);
+    assert_eq!(job.total, 0);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_unknown_ingestion_job() -> anyhow::Result<()> {
+    let queue = init_queue().await?;
+    let job = queue.get_job("unknown-job").await?;
+    assert!(job.is_none());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_ingestion_job_without_force() -> anyhow::Result<()> {
+    let queue = init_queue().await?;
+    let index = IndexId(TEST_INDEX_ID.to_string());
+    let documents = vec![build_document("./first.txt", "there is some content")];
+    let job = queue.submit(&index, documents, false).await?;
+    let job = wait_job_finished(&queue, &job.id).await?;
+    assert_eq!(job.status, JobStatus::Completed);
+
+    let documents = vec![build_document("./first.txt", "there is another content")];
+    let job = queue.submit(&index, documents, false).await?;
+    let job = wait_job_finished(&queue, &job.id).await?;
+    assert_eq!(job.status, JobStatus::Failed);
+    assert_eq!(job.failed, 1);
+
+    let documents = vec![build_document("./first.txt", "there is another content")];
+    let job = queue.submit(&index, documents, true).await?;
+    let job = wait_job_finished(&queue, &job.id).await?;
+    assert_eq!(job.status, JobStatus::Completed);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_ingestion_job_progress_saves_throttled() -> anyhow::Result<()> {
+    let job_store = Arc::new(CountingJobStore::default());
+    let queue = init_queue_with(job_store.clone()).await?;
+    let documents = (0..20)
+        .map(|it| build_document(&format!("./doc-{it}.txt"), "there is some content"))
+        .collect::<Vec<LargeDocument>>();
+
+    let index = IndexId(TEST_INDEX_ID.to_string());
+    let job = queue.submit(&index, documents, true).await?;
+    let job = wait_job_finished(&queue, &job.id).await?;
+    assert_eq!(job.status, JobStatus::Completed);
+    assert_eq!(job.processed, 20);
+    assert!(job_store.saves.load(Ordering::SeqCst) < 20);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_memory_job_store_expired() -> anyhow::Result<()> {
+    let job = IngestionJob::new(TEST_INDEX_ID, &[]);
+    let job_store = MemoryJobStore::new(Duration::ZERO);
+    job_store.save(&job).await?;
+    assert!(job_store.load(&job.id).await?.is_none());
+
+    let job_store = MemoryJobStore::default();
+    job_store.save(&job).await?;
+    assert!(job_store.load(&job.id).await?.is_some());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_ingestion_job_store_failure() -> anyhow::Result<()> {
+    let job_store = Arc::new(CountingJobStore {
+        is_failing: true,
+        ..Default::default()
+    });
+
+    let queue = init_queue_with(job_store).await?;
+    let index = IndexId(TEST_INDEX_ID.to_string());
+    let documents = vec![build_document("./first.txt", "there is some content")];
+    assert!(queue.submit(&index, documents, true).await.is_err());
+    Ok(())
+}
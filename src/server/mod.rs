@@ -1,10 +1,11 @@
 mod config;
-pub use config::{CacheConfig, ServerConfig, StorageConfig};
+pub use config::{CacheConfig, ServerConfig, StorageBackend, StorageConfig};
 
 mod error;
 pub use error::{ServerError, ServerResult, Success};
 
 pub mod httpserver;
+pub mod jobs;
 
 use doc_search_core::application::usecase::searcher::SearcherUseCase;
 use doc_search_core::application::usecase::storage::StorageUseCase;
@@ -13,6 +14,7 @@ use doc_search_core::domain::storage::{IDocumentPartStorage, IIndexStorage};
 use std::sync::Arc;
 
 use crate::meter::AppMeterRegistry;
+use crate::server::jobs::IngestionQueue;
 
 pub struct ServerApp<Storage, Searcher>
 where
@@ -21,26 +23,35 @@ where
 {
     storage: Arc<StorageUseCase<Storage>>,
     searcher: Arc<SearcherUseCase<Searcher>>,
+    ingestion: Arc<IngestionQueue<Storage>>,
     meter_handle: Arc<AppMeterRegistry>,
 }
 
 impl<Storage, Searcher> ServerApp<Storage, Searcher>
 where
     Searcher: ISearcher + IPaginator + Send + Sync,
-    Storage: IIndexStorage + IDocumentPartStorage + Send + Sync,
+    Storage: IIndexStorage + IDocumentPartStorage + Send + Sync + 'static,
 {
     pub fn new(
         storage: Arc<StorageUseCase<Storage>>,
         searcher: Arc<SearcherUseCase<Searcher>>,
+        ingestion: IngestionQueue<Storage>,
         meter_handle: Arc<AppMeterRegistry>,
     ) -> Self {
         ServerApp {
             storage,
             searcher,
+            ingestion: Arc::new(ingestion),
             meter_handle,
         }
     }
+}
 
+impl<Storage, Searcher> ServerApp<Storage, Searcher>
+where
+    Searcher: ISearcher + IPaginator + Send + Sync,
+    Storage: IIndexStorage + IDocumentPartStorage + Send + Sync,
+{
     pub fn get_storage(&self) -> Arc<StorageUseCase<Storage>> {
         self.storage.clone()
     }
@@ -49,6 +60,10 @@ where
         self.searcher.clone()
     }
 
+    pub fn get_ingestion(&self) -> Arc<IngestionQueue<Storage>> {
+        self.ingestion.clone()
+    }
+
     pub fn get_meter_handle(&self) -> Arc<AppMeterRegistry> {
         self.meter_handle.clone()
     }
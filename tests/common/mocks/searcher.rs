@@ -1,6 +1,7 @@
 use mockall::mock;
 
 use doc_search_core::domain::searcher::models::{Pagination, PaginationParams, SearchingParams};
+use doc_search_core::domain::searcher::models::{SuggestParams, Suggestions};
 use doc_search_core::domain::searcher::SearchError;
 use doc_search_core::domain::searcher::{IPaginator, ISearcher};
 
@@ -10,10 +11,12 @@ mock! {
     #[async_trait::async_trait]
     impl ISearcher for SearcherService {
         async fn search(&self, params: &SearchingParams) -> Result<Pagination, SearchError>;
+        async fn suggest(&self, params: &SuggestParams) -> Result<Suggestions, SearchError>;
     }
 
     #[async_trait::async_trait]
     impl IPaginator for SearcherService {
         async fn paginate(&self, params: &PaginationParams) -> Result<Pagination, SearchError>;
+        async fn release(&self, params: &PaginationParams) -> Result<(), SearchError>;
     }
 }
@@ -1,5 +1,6 @@
 use mockall::mock;
 
+use doc_search_core::domain::storage::models::UpdateDocumentParams;
 use doc_search_core::domain::storage::models::{AllDocumentParts, DocumentPart};
 use doc_search_core::domain::storage::models::{CreateIndexParams, StoredDocumentPartsInfo};
 use doc_search_core::domain::storage::StorageError;
@@ -37,6 +38,13 @@ mock! {
             doc_part_id: &DocumentPartId,
         ) -> Result<DocumentPart, StorageError>;
 
+        async fn update_document_parts(
+            &self,
+            index: &IndexId,
+            large_doc_id: &LargeDocumentId,
+            params: &UpdateDocumentParams,
+        ) -> Result<(), StorageError>;
+
         async fn delete_document_parts(
             &self,
             index: &IndexId,
@@ -30,7 +30,11 @@ impl TestEnvironment {
 
 pub async fn setup_osearch_environment(index_id: &str) -> anyhow::Result<TestEnvironment> {
     let config = ServiceConfig::new()?;
-    let config = config.storage().opensearch();
+    let config = config
+        .storage()
+        .opensearch()
+        .as_ref()
+        .ok_or_else(|| anyhow::anyhow!("storage.opensearch config is required"))?;
     let client = OSearchClient::connect(config).await?;
 
     let index_id = &IndexId(index_id.to_string());
@@ -6,7 +6,7 @@ use common::setup_osearch_environment;
 use doc_search_core::application::usecase::searcher::SearcherUseCase;
 use doc_search_core::application::usecase::storage::StorageUseCase;
 use doc_search_core::domain::searcher::models::{Pagination, SearchingParams};
-use doc_search_core::domain::storage::models::{AllDocumentParts, StoredDocumentPartsInfo};
+use doc_search_core::domain::storage::models::{AllDocumentParts, BulkStoreReport};
 use doc_search_core::shared::kernel::IndexId;
 use rstest::rstest;
 use serial_test::serial;
@@ -93,15 +93,18 @@ async fn test_opensearch_store_multiple_documents() -> anyhow::Result<()> {
 
     let index_id = &IndexId(test_env.get_index().clone());
     let large_documents = build_real_large_documents()?;
-    let result: anyhow::Result<Vec<StoredDocumentPartsInfo>> = {
+    let result: anyhow::Result<BulkStoreReport> = {
         tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
-        let stored_docs_info = storage.store_documents(index_id, large_documents).await?;
-        println!("documents: {stored_docs_info:?} has been stored into index: {index_id:?}");
-        Ok(stored_docs_info)
+        let stored_report = storage
+            .store_documents(index_id, large_documents, true)
+            .await?;
+        println!("documents: {stored_report:?} has been stored into index: {index_id:?}");
+        Ok(stored_report)
     };
 
-    let stored_docs_info = result?;
-    assert_eq!(stored_docs_info.len(), 3);
+    let stored_report = result?;
+    assert_eq!(stored_report.succeeded.len(), 3);
+    assert!(!stored_report.has_failures());
 
     Ok(())
 }
@@ -125,7 +128,9 @@ async fn test_opensearch_search_documents(
     let large_documents = build_real_large_documents()?;
     let result: anyhow::Result<Pagination> = {
         tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
-        let _ = storage.store_documents(index_id, large_documents).await?;
+        let _ = storage
+            .store_documents(index_id, large_documents, true)
+            .await?;
 
         tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
         let pagination = searcher.search_document_parts(&searching_params).await?;